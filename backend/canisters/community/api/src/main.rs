@@ -75,31 +75,44 @@ fn main() {
         std::fs::remove_dir_all(&directory).unwrap();
     }
 
+    generate_ts_method!(community, achievements);
     generate_ts_method!(community, api_key);
     generate_ts_method!(community, channel_summary_updates);
     generate_ts_method!(community, channel_summary);
+    generate_ts_method!(community, chit_leaderboard);
+    generate_ts_method!(community, community_analytics);
     generate_ts_method!(community, deleted_message);
     generate_ts_method!(community, events_by_index);
     generate_ts_method!(community, events_window);
     generate_ts_method!(community, events);
     generate_ts_method!(community, explore_channels);
+    generate_ts_method!(community, export_channel_events);
+    generate_ts_method!(community, export_channel_members);
     generate_ts_method!(community, invite_code);
+    generate_ts_method!(community, join_requests);
     generate_ts_method!(community, local_user_index);
     generate_ts_method!(community, lookup_members);
+    generate_ts_method!(community, message_edit_history);
     generate_ts_method!(community, messages_by_message_index);
+    generate_ts_method!(community, permission_templates);
     generate_ts_method!(community, search_channel);
+    generate_ts_method!(community, search_community);
     generate_ts_method!(community, selected_channel_initial);
     generate_ts_method!(community, selected_channel_updates_v2);
     generate_ts_method!(community, selected_initial);
     generate_ts_method!(community, selected_updates_v2);
+    generate_ts_method!(community, subscription_payments);
     generate_ts_method!(community, summary);
     generate_ts_method!(community, summary_updates);
     generate_ts_method!(community, thread_previews);
+    generate_ts_method!(community, typing);
     generate_ts_method!(community, video_call_participants);
 
     generate_ts_method!(community, accept_p2p_swap);
     generate_ts_method!(community, add_members_to_channel);
     generate_ts_method!(community, add_reaction);
+    generate_ts_method!(community, approve_join_request);
+    generate_ts_method!(community, award_achievement);
     generate_ts_method!(community, block_user);
     generate_ts_method!(community, cancel_p2p_swap);
     generate_ts_method!(community, cancel_invites);
@@ -109,8 +122,10 @@ fn main() {
     generate_ts_method!(community, create_channel);
     generate_ts_method!(community, create_user_group);
     generate_ts_method!(community, decline_invitation);
+    generate_ts_method!(community, delete_achievement);
     generate_ts_method!(community, delete_channel);
     generate_ts_method!(community, delete_messages);
+    generate_ts_method!(community, delete_permission_template);
     generate_ts_method!(community, delete_user_groups);
     generate_ts_method!(community, disable_invite_code);
     generate_ts_method!(community, edit_message);
@@ -124,13 +139,19 @@ fn main() {
     generate_ts_method!(community, register_poll_vote);
     generate_ts_method!(community, register_proposal_vote);
     generate_ts_method!(community, register_proposal_vote_v2);
+    generate_ts_method!(community, reject_join_request);
     generate_ts_method!(community, remove_member_from_channel);
     generate_ts_method!(community, remove_member);
     generate_ts_method!(community, remove_reaction);
     generate_ts_method!(community, report_message);
     generate_ts_method!(community, reset_invite_code);
+    generate_ts_method!(community, revoke_achievement);
     generate_ts_method!(community, send_message);
+    generate_ts_method!(community, set_achievement);
+    generate_ts_method!(community, set_channel_categories);
     generate_ts_method!(community, set_member_display_name);
+    generate_ts_method!(community, set_permission_template);
+    generate_ts_method!(community, set_typing);
     generate_ts_method!(community, set_video_call_presence);
     generate_ts_method!(community, toggle_mute_notifications);
     generate_ts_method!(community, unblock_user);
@@ -139,6 +160,7 @@ fn main() {
     generate_ts_method!(community, unpin_message);
     generate_ts_method!(community, update_bot);
     generate_ts_method!(community, update_channel);
+    generate_ts_method!(community, update_channel_permissions);
     generate_ts_method!(community, update_community);
     generate_ts_method!(community, update_user_group);
 
@@ -0,0 +1,26 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{AwardedCommunityAchievement, CommunityAchievement, TimestampMillis, UserId};
+
+#[ts_export(community, achievements)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // If set, the achievements awarded to this member are also returned
+    pub user_id: Option<UserId>,
+}
+
+#[ts_export(community, achievements)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    PrivateCommunity,
+}
+
+#[ts_export(community, achievements)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub achievements: Vec<CommunityAchievement>,
+    pub awarded: Vec<AwardedCommunityAchievement>,
+    pub last_updated: TimestampMillis,
+}
@@ -0,0 +1,36 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChitLeaderboardWindow, TimestampMillis, UserId};
+
+#[ts_export(community, chit_leaderboard)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub window: ChitLeaderboardWindow,
+    pub max_results: u16,
+}
+
+#[ts_export(community, chit_leaderboard)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    PrivateCommunity,
+}
+
+#[ts_export(community, chit_leaderboard)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub leaders: Vec<ChitLeaderboardMember>,
+    // Only populated if the caller is a member with a positive balance in the requested window
+    pub my_position: Option<ChitLeaderboardMember>,
+    // The leaderboards are refreshed periodically, so will not include CHIT earned after this time
+    pub last_updated: TimestampMillis,
+}
+
+#[ts_export(community, chit_leaderboard)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChitLeaderboardMember {
+    pub user_id: UserId,
+    pub rank: u32,
+    pub balance: u32,
+}
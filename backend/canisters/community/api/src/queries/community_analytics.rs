@@ -0,0 +1,31 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{AnalyticsWindow, ChannelMessageCount, DailyActivity, RetentionCohort, TimestampMillis, TopContributor};
+
+#[ts_export(community, community_analytics)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub window: AnalyticsWindow,
+}
+
+#[ts_export(community, community_analytics)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    NotAuthorized,
+}
+
+#[ts_export(community, community_analytics)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // The analytics are rolled up periodically, so will not include activity after this time
+    pub last_updated: TimestampMillis,
+    pub daily_activity: Vec<DailyActivity>,
+    pub messages_per_channel: Vec<ChannelMessageCount>,
+    pub top_contributors: Vec<TopContributor>,
+    pub retention_cohorts: Vec<RetentionCohort>,
+}
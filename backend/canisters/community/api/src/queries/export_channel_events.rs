@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, ChatArchiveCursor, ChatArchiveEventsPage};
+
+#[ts_export(community, export_channel_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub after: Option<ChatArchiveCursor>,
+    pub max_events: u32,
+}
+
+#[ts_export(community, export_channel_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatArchiveEventsPage),
+    NotAuthorized,
+    UserNotInCommunity,
+    UserNotInChannel,
+    UserSuspended,
+    UserLapsed,
+    ChannelNotFound,
+}
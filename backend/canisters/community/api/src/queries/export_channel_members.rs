@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, ChatArchiveMembersPage, UserId};
+
+#[ts_export(community, export_channel_members)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub after: Option<UserId>,
+    pub max_members: u32,
+}
+
+#[ts_export(community, export_channel_members)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatArchiveMembersPage),
+    NotAuthorized,
+    UserNotInCommunity,
+    UserNotInChannel,
+    UserSuspended,
+    UserLapsed,
+    ChannelNotFound,
+}
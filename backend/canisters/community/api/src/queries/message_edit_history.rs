@@ -0,0 +1,32 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, MessageId, MessageIndex, MessageVersion};
+
+#[ts_export(community, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+}
+
+#[ts_export(community, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    UserNotInChannel,
+    ChannelNotFound,
+    ThreadNotFound,
+    MessageNotFound,
+    UserSuspended,
+    UserLapsed,
+}
+
+#[ts_export(community, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // The previous versions of the message, oldest first
+    pub versions: Vec<MessageVersion>,
+}
@@ -1,3 +1,4 @@
+pub mod achievements;
 pub mod api_key;
 pub mod c2c_bot_api_key;
 pub mod c2c_bot_channel_details;
@@ -10,21 +11,31 @@ pub mod c2c_summary;
 pub mod c2c_summary_updates;
 pub mod channel_summary;
 pub mod channel_summary_updates;
+pub mod chit_leaderboard;
+pub mod community_analytics;
 pub mod deleted_message;
 pub mod events;
 pub mod events_by_index;
 pub mod events_window;
 pub mod explore_channels;
+pub mod export_channel_events;
+pub mod export_channel_members;
 pub mod invite_code;
+pub mod join_requests;
 pub mod local_user_index;
 pub mod lookup_members;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
+pub mod permission_templates;
 pub mod search_channel;
+pub mod search_community;
 pub mod selected_channel_initial;
 pub mod selected_channel_updates_v2;
 pub mod selected_initial;
 pub mod selected_updates_v2;
+pub mod subscription_payments;
 pub mod summary;
 pub mod summary_updates;
 pub mod thread_previews;
+pub mod typing;
 pub mod video_call_participants;
@@ -0,0 +1,29 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, Empty, GroupPermissions, TimestampMillis};
+
+pub type Args = Empty;
+
+#[ts_export(community, permission_templates)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+}
+
+#[ts_export(community, permission_templates)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub templates: Vec<PermissionTemplate>,
+}
+
+#[ts_export(community, permission_templates)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct PermissionTemplate {
+    pub template_id: u32,
+    pub name: String,
+    pub permissions: GroupPermissions,
+    pub channel_ids: Vec<ChannelId>,
+    pub last_updated: TimestampMillis,
+}
@@ -0,0 +1,45 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use ts_export::ts_export;
+use types::{ChannelId, MessageContent, MessageIndex, TimestampMillis, UserId};
+
+#[ts_export(community, search_community)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub search_term: String,
+    pub max_results: u8,
+    pub channel_ids: Option<Vec<ChannelId>>,
+    pub users: Option<HashSet<UserId>>,
+    pub from: Option<TimestampMillis>,
+    pub to: Option<TimestampMillis>,
+}
+
+#[ts_export(community, search_community)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    InvalidTerm,
+    TermTooLong(u8),
+    TermTooShort(u8),
+    TooManyUsers(u8),
+    UserNotInCommunity,
+    UserLapsed,
+}
+
+#[ts_export(community, search_community)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub matches: Vec<ChannelMessageMatch>,
+}
+
+#[ts_export(community, search_community)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ChannelMessageMatch {
+    pub channel_id: ChannelId,
+    pub message_index: MessageIndex,
+    pub sender: UserId,
+    pub content: MessageContent,
+    pub timestamp: TimestampMillis,
+    pub score: u32,
+}
@@ -0,0 +1,28 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, SubscriptionPayment, TimestampMillis, UserId};
+
+#[ts_export(community, subscription_payments)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: Option<ChannelId>,
+    // Only community owners can view the payments of other members. Defaults to the caller.
+    pub user_id: Option<UserId>,
+}
+
+#[ts_export(community, subscription_payments)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    ChannelNotFound,
+    NotAuthorized,
+}
+
+#[ts_export(community, subscription_payments)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub paid_until: Option<TimestampMillis>,
+    pub payments: Vec<SubscriptionPayment>,
+}
@@ -0,0 +1,34 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, MessageIndex, UserId};
+
+#[ts_export(community, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channels: Vec<ChannelOrThread>,
+}
+
+#[ts_export(community, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ChannelOrThread {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+}
+
+// Only channels / threads which the caller is a member of and in which someone other than the caller is typing are
+// included
+#[ts_export(community, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<UsersTyping>),
+    UserNotInCommunity,
+}
+
+#[ts_export(community, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct UsersTyping {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub user_ids: Vec<UserId>,
+}
@@ -0,0 +1,25 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::UserId;
+
+#[ts_export(community, award_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub achievement_id: u32,
+    pub user_id: UserId,
+}
+
+#[ts_export(community, award_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AchievementNotFound,
+    AlreadyAwarded,
+    TargetUserNotInCommunity,
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use types::{BotInitiator, UserId};
+
+use super::award_achievement;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub initiator: BotInitiator,
+    pub achievement_id: u32,
+    pub user_id: UserId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    NotAuthorized,
+    CommunityFrozen,
+    InvalidRequest(String),
+}
+
+impl From<award_achievement::Response> for Response {
+    fn from(value: award_achievement::Response) -> Self {
+        use Response::*;
+
+        match value {
+            award_achievement::Response::Success => Success,
+            award_achievement::Response::AchievementNotFound => InvalidRequest("Achievement not found".to_string()),
+            award_achievement::Response::AlreadyAwarded => InvalidRequest("Achievement already awarded".to_string()),
+            award_achievement::Response::TargetUserNotInCommunity => InvalidRequest("Target user not in community".to_string()),
+            award_achievement::Response::UserNotInCommunity
+            | award_achievement::Response::UserSuspended
+            | award_achievement::Response::UserLapsed
+            | award_achievement::Response::NotAuthorized => NotAuthorized,
+            award_achievement::Response::CommunityFrozen => CommunityFrozen,
+        }
+    }
+}
@@ -35,6 +35,7 @@ impl From<Args> for create_channel::Args {
             events_ttl: value.events_ttl,
             gate_config: value.gate_config,
             external_url: value.external_url,
+            category_id: None,
         }
     }
 }
@@ -75,6 +76,7 @@ impl From<create_channel::Response> for Response {
             create_channel::Response::MaxChannelsCreated(r) => InvalidRequest(format!("Max channels created: {:?}", r)),
             create_channel::Response::NameTaken => InvalidRequest("Name taken".to_string()),
             create_channel::Response::ExternalUrlInvalid => InvalidRequest("External URL invalid".to_string()),
+            create_channel::Response::CategoryNotFound(id) => InvalidRequest(format!("Category not found: {id}")),
             create_channel::Response::UserLapsed
             | create_channel::Response::UserSuspended
             | create_channel::Response::NotAuthorized => NotAuthorized,
@@ -1,8 +1,8 @@
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
 use types::{
-    ChannelId, CommunityCanisterChannelSummary, CommunityCanisterCommunitySummary, GateCheckFailedReason, TimestampMillis,
-    UniquePersonProof, UserId, UserType, VerifiedCredentialGateArgs,
+    ChannelId, Chit, CommunityCanisterChannelSummary, CommunityCanisterCommunitySummary, GateCheckFailedReason,
+    TimestampMillis, UniquePersonProof, UserId, UserType, VerifiedCredentialGateArgs,
 };
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -20,6 +20,8 @@ pub struct Args {
     pub diamond_membership_expires_at: Option<TimestampMillis>,
     pub verified_credential_args: Option<VerifiedCredentialGateArgs>,
     pub unique_person_proof: Option<UniquePersonProof>,
+    #[serde(default)]
+    pub chit: Option<Chit>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
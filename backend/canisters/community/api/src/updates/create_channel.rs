@@ -21,6 +21,7 @@ pub struct Args {
     pub events_ttl: Option<Milliseconds>,
     pub gate_config: Option<AccessGateConfig>,
     pub external_url: Option<String>,
+    pub category_id: Option<u32>,
 }
 
 #[ts_export(community, create_channel)]
@@ -43,6 +44,7 @@ pub enum Response {
     ExternalUrlInvalid,
     InternalError(String),
     UserLapsed,
+    CategoryNotFound(u32),
 }
 
 #[ts_export(community, create_channel)]
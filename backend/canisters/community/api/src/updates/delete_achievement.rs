@@ -0,0 +1,21 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export(community, delete_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub achievement_id: u32,
+}
+
+#[ts_export(community, delete_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AchievementNotFound,
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
@@ -0,0 +1,21 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export(community, delete_permission_template)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub template_id: u32,
+}
+
+#[ts_export(community, delete_permission_template)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    TemplateNotFound,
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
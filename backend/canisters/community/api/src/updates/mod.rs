@@ -1,7 +1,10 @@
 pub mod accept_p2p_swap;
 pub mod add_members_to_channel;
 pub mod add_reaction;
+pub mod approve_join_request;
+pub mod award_achievement;
 pub mod block_user;
+pub mod c2c_bot_award_achievement;
 pub mod c2c_bot_create_channel;
 pub mod c2c_bot_delete_channel;
 pub mod c2c_bot_send_message;
@@ -17,12 +20,15 @@ pub mod c2c_join_community;
 pub mod c2c_leave_community;
 pub mod c2c_local_group_index;
 pub mod c2c_notify_events;
+pub mod c2c_request_to_join;
 pub mod c2c_send_message;
 pub mod c2c_set_user_suspended;
 pub mod c2c_tip_message;
 pub mod c2c_unfreeze_community;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_blocked_users;
 pub mod c2c_update_proposals;
+pub mod c2c_update_scoped_principals;
 pub mod c2c_update_user_principal;
 pub mod cancel_invites;
 pub mod cancel_p2p_swap;
@@ -32,8 +38,10 @@ pub mod claim_prize;
 pub mod create_channel;
 pub mod create_user_group;
 pub mod decline_invitation;
+pub mod delete_achievement;
 pub mod delete_channel;
 pub mod delete_messages;
+pub mod delete_permission_template;
 pub mod delete_user_groups;
 pub mod disable_invite_code;
 pub mod edit_message;
@@ -48,13 +56,19 @@ pub mod pin_message;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
 pub mod register_proposal_vote_v2;
+pub mod reject_join_request;
 pub mod remove_member;
 pub mod remove_member_from_channel;
 pub mod remove_reaction;
 pub mod report_message;
 pub mod reset_invite_code;
+pub mod revoke_achievement;
 pub mod send_message;
+pub mod set_achievement;
+pub mod set_channel_categories;
 pub mod set_member_display_name;
+pub mod set_permission_template;
+pub mod set_typing;
 pub mod set_video_call_presence;
 pub mod start_video_call_v2;
 pub mod toggle_mute_notifications;
@@ -64,5 +78,6 @@ pub mod unfollow_thread;
 pub mod unpin_message;
 pub mod update_bot;
 pub mod update_channel;
+pub mod update_channel_permissions;
 pub mod update_community;
 pub mod update_user_group;
@@ -0,0 +1,22 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::UserId;
+
+#[ts_export(community, reject_join_request)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+}
+
+#[ts_export(community, reject_join_request)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    RequestNotFound,
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
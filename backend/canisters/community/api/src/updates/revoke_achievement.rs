@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::UserId;
+
+#[ts_export(community, revoke_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub achievement_id: u32,
+    pub user_id: UserId,
+}
+
+#[ts_export(community, revoke_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AchievementNotFound,
+    NotAwarded,
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
@@ -0,0 +1,39 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{CommunityAchievementCriteria, Document, FieldTooLongResult, FieldTooShortResult};
+
+#[ts_export(community, set_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // None to create a new achievement
+    pub achievement_id: Option<u32>,
+    pub name: String,
+    pub description: String,
+    pub image: Option<Document>,
+    pub criteria: CommunityAchievementCriteria,
+}
+
+#[ts_export(community, set_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NameTooShort(FieldTooShortResult),
+    NameTooLong(FieldTooLongResult),
+    NameTaken,
+    DescriptionTooLong(FieldTooLongResult),
+    ImageTooBig(FieldTooLongResult),
+    AchievementNotFound,
+    TooManyAchievements(u32),
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
+
+#[ts_export(community, set_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub achievement_id: u32,
+}
@@ -0,0 +1,47 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelCategory, ChannelId, FieldTooLongResult, FieldTooShortResult, GroupPermissions};
+
+#[ts_export(community, set_channel_categories)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // The full, ordered list of categories. Existing categories which are omitted are deleted.
+    pub categories: Vec<ChannelCategoryArgs>,
+}
+
+#[ts_export(community, set_channel_categories)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ChannelCategoryArgs {
+    // None for new categories
+    pub id: Option<u32>,
+    pub name: String,
+    pub channel_ids: Vec<ChannelId>,
+    pub collapsed_by_default: bool,
+    pub default_permissions: Option<GroupPermissions>,
+}
+
+#[ts_export(community, set_channel_categories)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NameTooShort(FieldTooShortResult),
+    NameTooLong(FieldTooLongResult),
+    NameTaken(String),
+    CategoryNotFound(u32),
+    ChannelNotFound(ChannelId),
+    ChannelInMultipleCategories(ChannelId),
+    TooManyCategories(u32),
+    InvalidDefaultPermissions(String),
+    NotAuthorized,
+    UserNotInCommunity,
+    CommunityFrozen,
+    UserSuspended,
+    UserLapsed,
+}
+
+#[ts_export(community, set_channel_categories)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub categories: Vec<ChannelCategory>,
+}
@@ -0,0 +1,39 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, FieldTooLongResult, FieldTooShortResult, GroupPermissions};
+
+#[ts_export(community, set_permission_template)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // None to create a new template
+    pub template_id: Option<u32>,
+    pub name: String,
+    pub permissions: GroupPermissions,
+}
+
+#[ts_export(community, set_permission_template)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NameTooShort(FieldTooShortResult),
+    NameTooLong(FieldTooLongResult),
+    NameTaken,
+    InvalidPermissions,
+    TemplateNotFound,
+    TooManyTemplates(u32),
+    UserNotInChannel(ChannelId),
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
+
+#[ts_export(community, set_permission_template)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub template_id: u32,
+    // The linked channels whose permissions were updated to match the template
+    pub channels_updated: Vec<ChannelId>,
+}
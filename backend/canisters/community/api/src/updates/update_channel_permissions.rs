@@ -0,0 +1,36 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, OptionalGroupPermissions};
+
+#[ts_export(community, update_channel_permissions)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_ids: Vec<ChannelId>,
+    pub update: PermissionsUpdate,
+}
+
+#[ts_export(community, update_channel_permissions)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum PermissionsUpdate {
+    // Link the channels to the template and apply its permissions
+    Template(u32),
+    // Apply the changes to each channel and unlink them from any template
+    Permissions(OptionalGroupPermissions),
+}
+
+#[ts_export(community, update_channel_permissions)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    ChannelNotFound(ChannelId),
+    UserNotInChannel(ChannelId),
+    TemplateNotFound,
+    InvalidPermissions,
+    TooManyChannels(u32),
+    NotAuthorized,
+    UserNotInCommunity,
+    UserSuspended,
+    UserLapsed,
+    CommunityFrozen,
+}
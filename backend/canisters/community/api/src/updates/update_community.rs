@@ -2,7 +2,7 @@ use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
 use types::{
-    AccessGateConfig, Document, FieldTooLongResult, FieldTooShortResult, OptionUpdate, OptionalCommunityPermissions,
+    AccessGateConfig, Document, FieldTooLongResult, FieldTooShortResult, OptionUpdate, OptionalCommunityPermissions, Tag,
     UpdatedRules, Version,
 };
 
@@ -21,6 +21,8 @@ pub struct Args {
     pub gate_config: OptionUpdate<AccessGateConfig>,
     pub public: Option<bool>,
     pub primary_language: Option<String>,
+    pub tags: Option<Vec<Tag>>,
+    pub languages: Option<Vec<String>>,
 }
 
 #[ts_export(community, update_community)]
@@ -44,6 +46,7 @@ pub enum Response {
     CommunityFrozen,
     InvalidLanguage,
     UserLapsed,
+    TooManyTags(u32),
 }
 
 #[ts_export(community, update_community)]
@@ -15,6 +15,7 @@ generate_c2c_call!(summary);
 generate_c2c_call!(summary_updates);
 
 // Updates
+generate_c2c_call!(c2c_bot_award_achievement);
 generate_c2c_call!(c2c_bot_create_channel);
 generate_c2c_call!(c2c_bot_delete_channel);
 generate_c2c_call!(c2c_bot_send_message);
@@ -29,6 +30,7 @@ generate_c2c_call!(c2c_join_channel);
 generate_c2c_call!(c2c_join_community);
 generate_c2c_call!(c2c_leave_community);
 generate_c2c_call!(c2c_notify_events);
+generate_c2c_call!(c2c_request_to_join);
 generate_c2c_call!(c2c_send_message);
 generate_c2c_call!(c2c_set_user_suspended);
 generate_c2c_call!(c2c_tip_message);
@@ -4,8 +4,11 @@ use constants::{DAY_IN_MS, HOUR_IN_MS};
 use fire_and_forget_handler::FireAndForgetHandler;
 use group_index_canister::c2c_mark_community_active;
 use msgpack::serialize_then_unwrap;
-use std::collections::HashSet;
-use types::{CanisterId, Milliseconds, PublicCommunityActivity, TimestampMillis};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use types::{CanisterId, Milliseconds, PublicCommunityActivity, TimestampMillis, UserId};
+
+const MAX_ACTIVE_USERS: usize = 100;
 
 // If needed, notify the group index canister that there has been activity in this community
 pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
@@ -14,12 +17,18 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
     let now = state.env.now();
 
     if let Some(mark_active_duration) = state.data.activity_notification_state.notify_if_required(now) {
-        let public_community_activity = state.data.is_public.then(|| extract_activity(now, &state.data));
+        let (public_community_activity, active_users) = if state.data.is_public.value {
+            let (activity, active_users) = extract_activity(now, &state.data);
+            (Some(activity), active_users)
+        } else {
+            (None, Vec::new())
+        };
 
         call_group_index_canister(
             state.data.group_index_canister_id,
             mark_active_duration,
             public_community_activity,
+            active_users,
             &mut state.data.fire_and_forget_handler,
         );
     }
@@ -28,11 +37,13 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
         canister_id: CanisterId,
         duration: Milliseconds,
         public_community_activity: Option<PublicCommunityActivity>,
+        active_users: Vec<UserId>,
         fire_and_forget_handler: &mut FireAndForgetHandler,
     ) {
         let args = c2c_mark_community_active::Args {
             duration,
             public_community_activity,
+            active_users,
         };
 
         fire_and_forget_handler.send(
@@ -43,7 +54,8 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
     }
 }
 
-pub(crate) fn extract_activity(now: TimestampMillis, data: &Data) -> PublicCommunityActivity {
+// Returns the community's activity along with the users who have most recently sent messages
+pub(crate) fn extract_activity(now: TimestampMillis, data: &Data) -> (PublicCommunityActivity, Vec<UserId>) {
     let one_hour_ago = now - HOUR_IN_MS;
     let one_day_ago = now - DAY_IN_MS;
 
@@ -56,6 +68,7 @@ pub(crate) fn extract_activity(now: TimestampMillis, data: &Data) -> PublicCommu
 
     let mut message_unique_users = HashSet::new();
     let mut reaction_unique_users = HashSet::new();
+    let mut latest_message_per_user: HashMap<UserId, TimestampMillis> = HashMap::new();
 
     for channel in data.channels.iter().filter(|channel| channel.chat.is_public.value) {
         for event in channel
@@ -76,6 +89,9 @@ pub(crate) fn extract_activity(now: TimestampMillis, data: &Data) -> PublicCommu
                     activity.last_hour.reactions += m.reactions.len() as u32
                 }
 
+                let latest = latest_message_per_user.entry(m.sender).or_default();
+                *latest = (*latest).max(event.timestamp);
+
                 if message_unique_users.insert(m.sender) {
                     activity.last_day.message_unique_users += 1;
                     if within_last_hour {
@@ -95,5 +111,11 @@ pub(crate) fn extract_activity(now: TimestampMillis, data: &Data) -> PublicCommu
         }
     }
 
-    activity
+    let mut active_users: Vec<_> = latest_message_per_user.into_iter().collect();
+    active_users.sort_unstable_by_key(|(user_id, timestamp)| (Reverse(*timestamp), *user_id));
+
+    (
+        activity,
+        active_users.into_iter().take(MAX_ACTIVE_USERS).map(|(u, _)| u).collect(),
+    )
 }
@@ -0,0 +1,91 @@
+use crate::jobs::refresh_chit_leaderboards::fetch_chit_balances;
+use crate::{mutate_state, read_state, RuntimeState};
+use constants::{DAY_IN_MS, MINUTE_IN_MS};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{error, trace};
+use types::{CanisterId, Milliseconds, UserId};
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+const CHECK_INTERVAL: Milliseconds = DAY_IN_MS;
+const RETRY_INTERVAL: Milliseconds = 30 * MINUTE_IN_MS;
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if !state.data.community_achievements.has_automatic_criteria() {
+        return false;
+    }
+
+    let next_check = state.data.community_achievements.last_automatic_check() + CHECK_INTERVAL;
+    schedule(next_check.saturating_sub(state.env.now()))
+}
+
+fn schedule(delay: Milliseconds) -> bool {
+    if TIMER_ID.get().is_none() {
+        let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(delay), run);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    trace!("'award_community_achievements' job running");
+    TIMER_ID.set(None);
+
+    let (group_index_canister_id, members, chit_required) = read_state(|state| {
+        let bots = state.data.members.bots();
+        let members: Vec<_> = state
+            .data
+            .members
+            .iter_member_ids()
+            .filter(|user_id| !bots.contains_key(user_id))
+            .collect();
+
+        (
+            state.data.group_index_canister_id,
+            members,
+            state.data.community_achievements.has_chit_criteria(),
+        )
+    });
+
+    ic_cdk::spawn(award_community_achievements(group_index_canister_id, members, chit_required));
+}
+
+async fn award_community_achievements(group_index_canister_id: CanisterId, members: Vec<UserId>, chit_required: bool) {
+    // CHIT balances are only fetched if any of the achievements are awarded based on CHIT earned
+    let chit_earned: HashMap<UserId, i32> = if chit_required {
+        match fetch_chit_balances(group_index_canister_id, &members).await {
+            Ok(balances) => balances.into_iter().map(|b| (b.user_id, b.all_time)).collect(),
+            Err(error) => {
+                error!(%error, "Failed to get CHIT balances");
+                schedule(RETRY_INTERVAL);
+                return;
+            }
+        }
+    } else {
+        HashMap::new()
+    };
+
+    mutate_state(|state| {
+        let now = state.env.now();
+        let members: Vec<_> = members
+            .into_iter()
+            .filter_map(|user_id| {
+                state.data.members.get_by_user_id(&user_id).map(|m| {
+                    let chit_earned = chit_earned.get(&user_id).copied().unwrap_or_default();
+                    (user_id, m.date_added, chit_earned)
+                })
+            })
+            .collect();
+
+        let awarded = state.data.community_achievements.award_automatic(members.into_iter(), now);
+        trace!(awarded, "Automatic community achievements awarded");
+        start_job_if_required(state);
+    });
+}
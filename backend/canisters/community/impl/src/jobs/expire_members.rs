@@ -75,6 +75,10 @@ fn run() {
                     is_unique_person: false,
                     verified_credential_args: None,
                     referred_by_member: false,
+                    chit: None,
+                    user_index_canister_id: state.data.user_index_canister_id,
+                    community_member_since: state.data.members.get_by_user_id(&member.user_id).map(|m| m.date_added),
+                    community_message_count: state.data.community_message_count(&member.user_id),
                     now: state.env.now(),
                 };
 
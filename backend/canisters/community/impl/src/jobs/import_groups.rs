@@ -292,6 +292,7 @@ pub(crate) async fn process_channel_members(group_id: ChatId, channel_id: Channe
                     ) {
                         AddResult::Success(_) => {
                             state.data.invited_users.remove(&user_id, now);
+                            state.data.analytics.record_join(user_id, now);
 
                             let user_type = state.data.members.bots().get(&user_id).copied().unwrap_or_default();
 
@@ -377,7 +378,7 @@ pub(crate) fn mark_import_complete(group_id: ChatId, channel_id: ChannelId) {
         let now = state.env.now();
         state.data.channels.get_mut(&channel_id).unwrap().date_imported = Some(now);
         let channel = state.data.channels.get(&channel_id).unwrap();
-        let public_community_activity = state.data.is_public.then(|| extract_activity(now, &state.data));
+        let public_community_activity = state.data.is_public.then(|| extract_activity(now, &state.data).0);
 
         state.data.fire_and_forget_handler.send(
             state.data.group_index_canister_id,
@@ -1,15 +1,21 @@
 use crate::RuntimeState;
 
+pub mod award_community_achievements;
 pub mod expire_members;
 pub mod garbage_collect_stable_memory;
 pub mod import_groups;
 pub mod make_pending_payments;
 pub mod process_expire_member_actions;
+pub mod refresh_chit_leaderboards;
+pub mod roll_up_analytics;
 
 pub(crate) fn start(state: &RuntimeState) {
+    award_community_achievements::start_job_if_required(state);
     expire_members::start_job_if_required(state);
     garbage_collect_stable_memory::start_job_if_required(state);
     import_groups::start_job_if_required(state);
     make_pending_payments::start_job_if_required(state);
     process_expire_member_actions::start_job_if_required(state);
+    refresh_chit_leaderboards::start_job_if_required(state);
+    roll_up_analytics::start_job_if_required(state);
 }
@@ -0,0 +1,82 @@
+use crate::{mutate_state, read_state, RuntimeState};
+use constants::{HOUR_IN_MS, MINUTE_IN_MS};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{error, trace};
+use types::{CanisterId, ChitBalances, Milliseconds, UserId};
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+const REFRESH_INTERVAL: Milliseconds = 6 * HOUR_IN_MS;
+const RETRY_INTERVAL: Milliseconds = 30 * MINUTE_IN_MS;
+const BATCH_SIZE: usize = 5000;
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    let next_refresh = state.data.chit_leaderboards.last_updated() + REFRESH_INTERVAL;
+    schedule(next_refresh.saturating_sub(state.env.now()))
+}
+
+fn schedule(delay: Milliseconds) -> bool {
+    if TIMER_ID.get().is_none() {
+        let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(delay), run);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    trace!("'refresh_chit_leaderboards' job running");
+    TIMER_ID.set(None);
+
+    let (group_index_canister_id, members) = read_state(|state| {
+        let bots = state.data.members.bots();
+        let members: Vec<_> = state
+            .data
+            .members
+            .iter_member_ids()
+            .filter(|user_id| !bots.contains_key(user_id))
+            .collect();
+
+        (state.data.group_index_canister_id, members)
+    });
+
+    ic_cdk::spawn(refresh_chit_leaderboards(group_index_canister_id, members));
+}
+
+async fn refresh_chit_leaderboards(group_index_canister_id: CanisterId, members: Vec<UserId>) {
+    match fetch_chit_balances(group_index_canister_id, &members).await {
+        Ok(balances) => mutate_state(|state| {
+            let now = state.env.now();
+            state.data.chit_leaderboards.set(&balances, now);
+            start_job_if_required(state);
+        }),
+        Err(error) => {
+            error!(%error, "Failed to get CHIT balances");
+            schedule(RETRY_INTERVAL);
+        }
+    }
+}
+
+pub(crate) async fn fetch_chit_balances(
+    group_index_canister_id: CanisterId,
+    members: &[UserId],
+) -> Result<Vec<ChitBalances>, String> {
+    let mut balances: Vec<ChitBalances> = Vec::with_capacity(members.len());
+
+    for batch in members.chunks(BATCH_SIZE) {
+        let args = group_index_canister::c2c_chit_balances::Args { users: batch.to_vec() };
+
+        match group_index_canister_c2c_client::c2c_chit_balances(group_index_canister_id, &args).await {
+            Ok(group_index_canister::c2c_chit_balances::Response::Success(result)) => balances.extend(result.balances),
+            Ok(group_index_canister::c2c_chit_balances::Response::InternalError(error)) => return Err(error),
+            Err(error) => return Err(format!("{error:?}")),
+        }
+    }
+
+    Ok(balances)
+}
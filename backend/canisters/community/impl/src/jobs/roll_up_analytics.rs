@@ -0,0 +1,41 @@
+use crate::{mutate_state, RuntimeState};
+use constants::HOUR_IN_MS;
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::trace;
+use types::Milliseconds;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+const ROLLUP_INTERVAL: Milliseconds = HOUR_IN_MS;
+const MAX_CHANNELS_PER_BATCH: usize = 20;
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() {
+        let delay = if state.data.analytics.rollup_in_progress() {
+            0
+        } else {
+            (state.data.analytics.last_rolled_up() + ROLLUP_INTERVAL).saturating_sub(state.env.now())
+        };
+
+        let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(delay), run);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    trace!("'roll_up_analytics' job running");
+    TIMER_ID.set(None);
+
+    mutate_state(|state| {
+        let now = state.env.now();
+        state.data.roll_up_analytics_next_batch(MAX_CHANNELS_PER_BATCH, now);
+        start_job_if_required(state);
+    });
+}
@@ -1,7 +1,12 @@
 use crate::memory::{get_instruction_counts_data_memory, get_instruction_counts_index_memory};
+use crate::model::achievements::CommunityAchievements;
+use crate::model::analytics::{CommunityAnalytics, UserActivity};
+use crate::model::channel_categories::ChannelCategories;
 use crate::model::channels::Channels;
+use crate::model::chit_leaderboards::ChitLeaderboards;
 use crate::model::groups_being_imported::{GroupBeingImportedSummary, GroupsBeingImported};
 use crate::model::members::CommunityMembers;
+use crate::model::permission_templates::PermissionTemplates;
 use crate::timer_job_types::{DeleteFileReferencesJob, MakeTransferJob, RemoveExpiredEventsJob, TimerJob};
 use activity_notification_state::ActivityNotificationState;
 use candid::Principal;
@@ -17,8 +22,9 @@ use fire_and_forget_handler::FireAndForgetHandler;
 use gated_groups::GatePayment;
 use group_chat_core::{AccessRulesInternal, AddResult};
 use group_community_common::{
-    Achievements, ExpiringMember, ExpiringMemberActions, ExpiringMembers, Members, PaymentReceipts, PaymentRecipient,
-    PendingPayment, PendingPaymentReason, PendingPaymentsQueue, UserCache,
+    Achievements, ExpiringMember, ExpiringMemberActions, ExpiringMembers, JoinRequests, Members, PaymentReceipts,
+    PaymentRecipient, PendingPayment, PendingPaymentReason, PendingPaymentsQueue, PersonalBlockLists, ScopedPrincipals,
+    Subscriptions, Typing, UserCache,
 };
 use installed_bots::{BotApiKeys, InstalledBots};
 use instruction_counts_log::{InstructionCountEntry, InstructionCountFunctionId, InstructionCountsLog};
@@ -38,11 +44,11 @@ use std::ops::Deref;
 use std::time::Duration;
 use timer_job_queues::GroupedTimerJobQueue;
 use types::{
-    AccessGate, AccessGateConfigInternal, Achievement, BotAdded, BotCaller, BotEventsCaller, BotGroupConfig, BotInitiator,
-    BotPermissions, BotRemoved, BotUpdated, BuildVersion, Caller, CanisterId, ChannelId, ChatMetrics,
-    CommunityCanisterCommunitySummary, CommunityMembership, CommunityPermissions, Cryptocurrency, Cycles, Document, Empty,
-    EventIndex, EventsCaller, FrozenGroupInfo, GroupRole, IdempotentEnvelope, MembersAdded, Milliseconds, Notification, Rules,
-    TimestampMillis, Timestamped, UserId, UserType,
+    AccessGateConfigInternal, Achievement, BotAdded, BotCaller, BotEventsCaller, BotGroupConfig, BotInitiator, BotPermissions,
+    BotRemoved, BotUpdated, BuildVersion, Caller, CanisterId, ChannelCategory, ChannelId, ChatMetrics,
+    CommunityCanisterCommunitySummary, CommunityMember, CommunityMembership, CommunityPermissions, Cryptocurrency, Cycles,
+    DelegationScope, Document, Empty, EventIndex, EventsCaller, FrozenGroupInfo, GroupRole, IdempotentEnvelope, MembersAdded,
+    MessageIndex, Milliseconds, Notification, Rules, TimestampMillis, Timestamped, UserId, UserType,
 };
 use types::{CommunityId, SNS_FEE_SHARE_PERCENT};
 use user_canister::CommunityCanisterEvent;
@@ -107,6 +113,16 @@ impl RuntimeState {
         self.data.video_call_operators.iter().any(|o| *o == caller)
     }
 
+    // Scoped principals (see `DelegationScope`) are resolved to the member they belong to if their scope matches,
+    // otherwise the caller is returned unchanged
+    pub fn caller_with_scope(&self, scope: DelegationScope) -> Principal {
+        let caller = self.env.caller();
+        self.data
+            .scoped_principals
+            .resolve(&caller, scope, self.env.now())
+            .map_or(caller, |user_id| user_id.into())
+    }
+
     pub fn push_notification(&mut self, sender: Option<UserId>, recipients: Vec<UserId>, notification: Notification) {
         if !recipients.is_empty() {
             let args = c2c_push_notification::Args {
@@ -123,6 +139,34 @@ impl RuntimeState {
         }
     }
 
+    pub fn handle_access_gate_payments(
+        &mut self,
+        user_id: UserId,
+        channel_id: Option<ChannelId>,
+        payments: Vec<GatePayment>,
+        now: TimestampMillis,
+    ) {
+        let subscription_period = self
+            .data
+            .get_access_gate_config(channel_id)
+            .and_then(|gc| gc.gate().subscription_gate())
+            .map(|g| g.period);
+
+        for payment in payments {
+            if let Some(period) = subscription_period {
+                self.data.subscriptions.record_payment(
+                    user_id,
+                    channel_id,
+                    payment.ledger_canister_id,
+                    payment.amount,
+                    now + period,
+                    now,
+                );
+            }
+            self.queue_access_gate_payments(payment);
+        }
+    }
+
     pub fn queue_access_gate_payments(&mut self, payment: GatePayment) {
         // Queue a payment to each owner less the fee
         let owners = self.data.members.owners();
@@ -176,6 +220,7 @@ impl RuntimeState {
                     .is_some_and(|version| version.value >= self.data.rules.text.version),
                 display_name: m.display_name().value.clone(),
                 lapsed: m.lapsed().value,
+                achievements: self.data.community_achievements.awarded_ids(&m.user_id),
             };
 
             // Return all the channels that the user is a member of
@@ -185,7 +230,15 @@ impl RuntimeState {
                 .channels_for_member(m.user_id)
                 .iter()
                 .filter_map(|c| self.data.channels.get(c))
-                .filter_map(|c| c.summary(Some(m.user_id), true, data.is_public.value, &data.members))
+                .filter_map(|c| {
+                    c.summary(
+                        Some(m.user_id),
+                        true,
+                        data.is_public.value,
+                        &data.members,
+                        &data.personal_block_lists,
+                    )
+                })
                 .collect();
 
             (channels, Some(membership))
@@ -196,7 +249,7 @@ impl RuntimeState {
                 .channels
                 .public_channels()
                 .iter()
-                .filter_map(|c| c.summary(None, false, data.is_public.value, &data.members))
+                .filter_map(|c| c.summary(None, false, data.is_public.value, &data.members, &data.personal_block_lists))
                 .collect();
 
             (channels, None)
@@ -206,6 +259,7 @@ impl RuntimeState {
             member.map(|m| m.last_updated()).unwrap_or_default(),
             self.data.events.latest_event_timestamp(),
             self.data.members.user_groups_last_updated(),
+            self.data.channel_categories.last_updated(),
         ]
         .into_iter()
         .chain(channels.iter().map(|c| c.last_updated))
@@ -231,6 +285,7 @@ impl RuntimeState {
             channels,
             membership,
             user_groups: data.members.iter_user_groups().map(|u| u.into()).collect(),
+            channel_categories: data.channel_categories(member.map(|m| m.user_id)),
             is_invited,
             metrics: data.cached_chat_metrics.value.clone(),
             verified: data.verified.value,
@@ -344,6 +399,11 @@ impl RuntimeState {
     }
 
     pub fn verified_caller(&self, bot_caller: Option<BotCaller>) -> CallerResult {
+        self.verified_caller_with_scope(bot_caller, None)
+    }
+
+    // If a scope is provided, scoped principals with that scope are accepted in place of the member's own principal
+    pub fn verified_caller_with_scope(&self, bot_caller: Option<BotCaller>, scope: Option<DelegationScope>) -> CallerResult {
         use CallerResult::*;
 
         if let Some(bot_caller) = bot_caller {
@@ -363,7 +423,7 @@ impl RuntimeState {
             return Success(Caller::BotV2(bot_caller));
         }
 
-        let caller = self.env.caller();
+        let caller = scope.map_or_else(|| self.env.caller(), |s| self.caller_with_scope(s));
 
         if caller == self.data.user_index_canister_id {
             return Success(Caller::OCBot(OPENCHAT_BOT_USER_ID));
@@ -414,8 +474,26 @@ struct Data {
     date_created: TimestampMillis,
     members: CommunityMembers,
     channels: Channels,
+    #[serde(default)]
+    channel_categories: ChannelCategories,
+    #[serde(default)]
+    permission_templates: PermissionTemplates,
+    #[serde(default)]
+    analytics: CommunityAnalytics,
+    #[serde(default)]
+    chit_leaderboards: ChitLeaderboards,
+    #[serde(default)]
+    community_achievements: CommunityAchievements,
+    #[serde(default)]
+    personal_block_lists: PersonalBlockLists,
+    #[serde(default)]
+    scoped_principals: ScopedPrincipals,
+    #[serde(skip)]
+    typing: Typing<(ChannelId, Option<MessageIndex>)>,
     events: CommunityEvents,
     invited_users: InvitedUsers,
+    #[serde(default)]
+    join_requests: JoinRequests,
     invite_code: Timestamped<Option<u64>>,
     invite_code_enabled: Timestamped<bool>,
     frozen: Timestamped<Option<FrozenGroupInfo>>,
@@ -446,6 +524,8 @@ struct Data {
     verified: Timestamped<bool>,
     #[serde(default)]
     idempotency_checker: IdempotencyChecker,
+    #[serde(default)]
+    subscriptions: Subscriptions,
 }
 
 impl Data {
@@ -523,6 +603,7 @@ impl Data {
             channels,
             events,
             invited_users: InvitedUsers::default(),
+            join_requests: JoinRequests::default(),
             invite_code: Timestamped::default(),
             invite_code_enabled: Timestamped::default(),
             frozen: Timestamped::default(),
@@ -552,6 +633,15 @@ impl Data {
             bot_api_keys: BotApiKeys::default(),
             verified: Timestamped::default(),
             idempotency_checker: IdempotencyChecker::default(),
+            subscriptions: Subscriptions::default(),
+            channel_categories: ChannelCategories::default(),
+            permission_templates: PermissionTemplates::default(),
+            analytics: CommunityAnalytics::default(),
+            chit_leaderboards: ChitLeaderboards::default(),
+            community_achievements: CommunityAchievements::default(),
+            personal_block_lists: PersonalBlockLists::default(),
+            scoped_principals: ScopedPrincipals::default(),
+            typing: Typing::default(),
         }
     }
 
@@ -582,6 +672,38 @@ impl Data {
         self.cached_chat_metrics = Timestamped::new(metrics.hydrate(), now);
     }
 
+    // Processes the next batch of channels for the analytics rollup, starting a new rollup if none is in progress.
+    // Returns true once the rollup has completed.
+    pub fn roll_up_analytics_next_batch(&mut self, max_channels: usize, now: TimestampMillis) -> bool {
+        if !self.analytics.rollup_in_progress() {
+            self.analytics.start_rollup(self.channels.iter().map(|c| c.id).collect());
+        }
+
+        for channel_id in self.analytics.next_rollup_batch(max_channels) {
+            if let Some(channel) = self.channels.get(&channel_id) {
+                let user_activity = channel.chat.events.iter_user_metrics().map(|(user_id, metrics)| {
+                    (
+                        *user_id,
+                        UserActivity {
+                            messages: metrics.message_count(),
+                            last_active: metrics.last_active,
+                        },
+                    )
+                });
+
+                self.analytics.record_channel_activity(
+                    channel_id,
+                    channel.chat.events.metrics().message_count(),
+                    user_activity,
+                );
+            }
+        }
+
+        let members = &self.members;
+        self.analytics
+            .complete_rollup_if_ready(|user_id| members.get_by_user_id(user_id).is_some(), now)
+    }
+
     pub fn record_instructions_count(&self, function_id: InstructionCountFunctionId, now: TimestampMillis) {
         let wasm_version = WASM_VERSION.with_borrow(|v| **v);
         let instructions_count = ic_cdk::api::instruction_counter();
@@ -602,24 +724,57 @@ impl Data {
     pub fn details_last_updated(&self) -> TimestampMillis {
         [
             self.invited_users.last_updated(),
+            self.join_requests.last_updated(),
             self.events.latest_event_timestamp(),
             self.members.last_updated(),
             self.bots.last_updated(),
             self.bot_api_keys.last_updated(),
+            self.channel_categories.last_updated(),
+            self.community_achievements.last_updated(),
         ]
         .into_iter()
         .max()
         .unwrap()
     }
 
+    pub fn community_member(&self, member: CommunityMemberInternal) -> CommunityMember {
+        let achievements = self.community_achievements.awarded_ids(&member.user_id);
+        CommunityMember {
+            achievements,
+            ..member.into()
+        }
+    }
+
+    // Returns the channel categories, each containing only the channels which are visible to the given user
+    pub fn channel_categories(&self, user_id: Option<UserId>) -> Vec<ChannelCategory> {
+        self.channel_categories
+            .summary(|channel_id| self.is_channel_visible(channel_id, user_id))
+    }
+
+    pub fn is_channel_visible(&self, channel_id: &ChannelId, user_id: Option<UserId>) -> bool {
+        self.channels
+            .get(channel_id)
+            .is_some_and(|c| c.chat.is_public.value || user_id.is_some_and(|u| c.chat.members.contains(&u)))
+    }
+
     pub fn has_payment_gate(&self) -> bool {
         self.gate_config
             .value
             .as_ref()
-            .map(|g| matches!(g.gate, AccessGate::Payment(_)))
+            .map(|g| g.gate.is_payment_gate())
             .unwrap_or_default()
     }
 
+    pub fn community_message_count(&self, user_id: &UserId) -> u32 {
+        self.members
+            .channels_for_member(*user_id)
+            .iter()
+            .filter_map(|channel_id| self.channels.get(channel_id))
+            .filter_map(|channel| channel.chat.events.user_metrics(user_id, None))
+            .map(|metrics| metrics.message_count())
+            .sum()
+    }
+
     pub fn handle_event_expiry(&mut self, expiry: TimestampMillis, now: TimestampMillis) {
         if self.next_event_expiry.is_none_or(|ex| expiry < ex) {
             self.next_event_expiry = Some(expiry);
@@ -649,11 +804,19 @@ impl Data {
         now: TimestampMillis,
     ) -> Option<CommunityMemberInternal> {
         let removed = self.members.remove(user_id, principal, now);
+        if removed.is_some() {
+            self.analytics.record_leave(&user_id, now);
+        }
         self.channels.leave_all_channels(user_id, now);
         self.expiring_members.remove_member(user_id, None);
         self.expiring_member_actions.remove_member(user_id, None);
         self.achievements.remove_user(&user_id);
+        self.community_achievements.remove_user(&user_id, now);
+        self.subscriptions.remove_user(user_id);
         self.user_cache.delete(user_id);
+        self.personal_block_lists.remove(&user_id);
+        self.scoped_principals.remove_user(&user_id);
+        self.typing.remove_user(&user_id);
         removed
     }
 
@@ -661,6 +824,7 @@ impl Data {
         self.members.mark_member_left_channel(user_id, channel_id, false, now);
         self.expiring_members.remove_member(user_id, Some(channel_id));
         self.expiring_member_actions.remove_member(user_id, Some(channel_id));
+        self.subscriptions.remove_member(user_id, Some(channel_id));
     }
 
     fn can_member_lapse(&self, user_id: &UserId, channel_id: Option<ChannelId>) -> bool {
@@ -703,6 +867,13 @@ impl Data {
         let new_gate_config = self.get_access_gate_config(channel_id);
         let new_gate_expiry = new_gate_config.and_then(|gc| gc.expiry());
 
+        // Subscriptions taken out under a different subscription gate no longer apply
+        if prev_gate_config.as_ref().and_then(|gc| gc.gate().subscription_gate())
+            != new_gate_config.and_then(|gc| gc.gate().subscription_gate())
+        {
+            self.subscriptions.remove_gate(channel_id);
+        }
+
         if let Some(prev_gate_expiry) = prev_gate_expiry {
             if let Some(new_gate_expiry) = new_gate_expiry {
                 // If there is also a new expiring gate then update the expiry schedule of members if necessary
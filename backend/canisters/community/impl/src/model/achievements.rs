@@ -0,0 +1,456 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use types::{
+    AwardedCommunityAchievement, CommunityAchievement, CommunityAchievementCriteria, Document, TimestampMillis, UserId,
+};
+
+// Achievements (badges) defined by the community which are either awarded manually by admins or bots, or are
+// awarded automatically once a member meets the achievement's criteria
+#[derive(Serialize, Deserialize, Default)]
+pub struct CommunityAchievements {
+    achievements: BTreeMap<u32, AchievementInternal>,
+    awarded: HashMap<UserId, BTreeMap<u32, AwardInternal>>,
+    last_updated: TimestampMillis,
+    #[serde(default)]
+    last_automatic_check: TimestampMillis,
+    // When each member's awarded achievements last changed, indexed both ways so that members can be looked up
+    // individually or iterated in order of their latest change
+    #[serde(default)]
+    member_updates: HashMap<UserId, TimestampMillis>,
+    #[serde(default)]
+    member_updates_by_timestamp: BTreeSet<(TimestampMillis, UserId)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AchievementInternal {
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "d")]
+    pub description: String,
+    #[serde(rename = "i", skip_serializing_if = "Option::is_none")]
+    pub image: Option<Document>,
+    #[serde(rename = "c")]
+    pub criteria: CommunityAchievementCriteria,
+    #[serde(rename = "a", default)]
+    pub awarded_count: u32,
+    // Members who had the achievement revoked, who should not have it automatically awarded again
+    #[serde(rename = "r", default, skip_serializing_if = "BTreeSet::is_empty")]
+    revoked: BTreeSet<UserId>,
+    #[serde(rename = "t")]
+    pub created: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AwardInternal {
+    #[serde(rename = "t")]
+    awarded: TimestampMillis,
+    #[serde(rename = "b", skip_serializing_if = "Option::is_none")]
+    awarded_by: Option<UserId>,
+}
+
+pub enum AwardResult {
+    Success,
+    AchievementNotFound,
+    AlreadyAwarded,
+}
+
+pub enum RevokeResult {
+    Success,
+    AchievementNotFound,
+    NotAwarded,
+}
+
+impl CommunityAchievements {
+    pub fn get(&self, achievement_id: u32) -> Option<&AchievementInternal> {
+        self.achievements.get(&achievement_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = CommunityAchievement> + '_ {
+        self.achievements.iter().map(|(id, a)| a.hydrate(*id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.achievements.len()
+    }
+
+    pub fn last_updated(&self) -> TimestampMillis {
+        self.last_updated
+    }
+
+    pub fn last_automatic_check(&self) -> TimestampMillis {
+        self.last_automatic_check
+    }
+
+    pub fn is_name_taken(&self, name: &str, excluding: Option<u32>) -> bool {
+        self.achievements
+            .iter()
+            .any(|(id, a)| Some(*id) != excluding && a.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn has_automatic_criteria(&self) -> bool {
+        self.achievements
+            .values()
+            .any(|a| a.criteria != CommunityAchievementCriteria::Manual)
+    }
+
+    pub fn has_chit_criteria(&self) -> bool {
+        self.achievements
+            .values()
+            .any(|a| matches!(a.criteria, CommunityAchievementCriteria::ChitEarned(_)))
+    }
+
+    pub fn create<R: RngCore>(
+        &mut self,
+        name: String,
+        description: String,
+        image: Option<Document>,
+        criteria: CommunityAchievementCriteria,
+        rng: &mut R,
+        now: TimestampMillis,
+    ) -> u32 {
+        let achievement_id = loop {
+            let id = rng.next_u32();
+            if !self.achievements.contains_key(&id) {
+                break id;
+            }
+        };
+
+        self.achievements.insert(
+            achievement_id,
+            AchievementInternal {
+                name,
+                description,
+                image,
+                criteria,
+                awarded_count: 0,
+                revoked: BTreeSet::new(),
+                created: now,
+            },
+        );
+        self.last_updated = now;
+        achievement_id
+    }
+
+    pub fn update(
+        &mut self,
+        achievement_id: u32,
+        name: String,
+        description: String,
+        image: Option<Document>,
+        criteria: CommunityAchievementCriteria,
+        now: TimestampMillis,
+    ) -> bool {
+        let Some(achievement) = self.achievements.get_mut(&achievement_id) else {
+            return false;
+        };
+
+        achievement.name = name;
+        achievement.description = description;
+        achievement.image = image;
+        achievement.criteria = criteria;
+        self.last_updated = now;
+        true
+    }
+
+    pub fn delete(&mut self, achievement_id: u32, now: TimestampMillis) -> bool {
+        if self.achievements.remove(&achievement_id).is_none() {
+            return false;
+        }
+
+        let mut members_updated = Vec::new();
+        self.awarded.retain(|user_id, awards| {
+            if awards.remove(&achievement_id).is_some() {
+                members_updated.push(*user_id);
+            }
+            !awards.is_empty()
+        });
+        for user_id in members_updated {
+            self.mark_member_updated(user_id, now);
+        }
+        self.last_updated = now;
+        true
+    }
+
+    pub fn award(
+        &mut self,
+        achievement_id: u32,
+        user_id: UserId,
+        awarded_by: Option<UserId>,
+        now: TimestampMillis,
+    ) -> AwardResult {
+        let Some(achievement) = self.achievements.get_mut(&achievement_id) else {
+            return AwardResult::AchievementNotFound;
+        };
+
+        let awards = self.awarded.entry(user_id).or_default();
+        if awards.contains_key(&achievement_id) {
+            return AwardResult::AlreadyAwarded;
+        }
+
+        awards.insert(
+            achievement_id,
+            AwardInternal {
+                awarded: now,
+                awarded_by,
+            },
+        );
+        achievement.awarded_count += 1;
+        achievement.revoked.remove(&user_id);
+        self.mark_member_updated(user_id, now);
+        self.last_updated = now;
+        AwardResult::Success
+    }
+
+    pub fn revoke(&mut self, achievement_id: u32, user_id: UserId, now: TimestampMillis) -> RevokeResult {
+        let Some(achievement) = self.achievements.get_mut(&achievement_id) else {
+            return RevokeResult::AchievementNotFound;
+        };
+
+        let Some(awards) = self.awarded.get_mut(&user_id) else {
+            return RevokeResult::NotAwarded;
+        };
+
+        if awards.remove(&achievement_id).is_none() {
+            return RevokeResult::NotAwarded;
+        }
+
+        if awards.is_empty() {
+            self.awarded.remove(&user_id);
+        }
+
+        achievement.awarded_count = achievement.awarded_count.saturating_sub(1);
+        achievement.revoked.insert(user_id);
+        self.mark_member_updated(user_id, now);
+        self.last_updated = now;
+        RevokeResult::Success
+    }
+
+    // Removes the achievements awarded to a member who has left the community. Revocations are kept so that
+    // they still apply if the user rejoins.
+    pub fn remove_user(&mut self, user_id: &UserId, now: TimestampMillis) {
+        if let Some(previous) = self.member_updates.remove(user_id) {
+            self.member_updates_by_timestamp.remove(&(previous, *user_id));
+        }
+
+        let Some(awards) = self.awarded.remove(user_id) else {
+            return;
+        };
+
+        for achievement_id in awards.keys() {
+            if let Some(achievement) = self.achievements.get_mut(achievement_id) {
+                achievement.awarded_count = achievement.awarded_count.saturating_sub(1);
+            }
+        }
+        self.last_updated = now;
+    }
+
+    pub fn awarded_ids(&self, user_id: &UserId) -> Vec<u32> {
+        self.awarded
+            .get(user_id)
+            .map(|awards| awards.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn members_with_awards(&self) -> impl Iterator<Item = UserId> + '_ {
+        self.awarded.keys().copied()
+    }
+
+    pub fn member_last_updated(&self, user_id: &UserId) -> Option<TimestampMillis> {
+        self.member_updates.get(user_id).copied()
+    }
+
+    pub fn members_updated_since(&self, since: TimestampMillis) -> impl Iterator<Item = UserId> + '_ {
+        self.member_updates_by_timestamp
+            .iter()
+            .rev()
+            .take_while(move |(ts, _)| *ts > since)
+            .map(|(_, user_id)| *user_id)
+    }
+
+    pub fn awarded_to(&self, user_id: &UserId) -> Vec<AwardedCommunityAchievement> {
+        self.awarded
+            .get(user_id)
+            .map(|awards| {
+                awards
+                    .iter()
+                    .map(|(id, a)| AwardedCommunityAchievement {
+                        achievement_id: *id,
+                        awarded: a.awarded,
+                        awarded_by: a.awarded_by,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // Awards any achievements with automatic criteria which the members now meet.
+    // Each member is passed in as (user_id, date_added, total_chit_earned).
+    // Returns the number of achievements awarded.
+    pub fn award_automatic(
+        &mut self,
+        members: impl Iterator<Item = (UserId, TimestampMillis, i32)>,
+        now: TimestampMillis,
+    ) -> u32 {
+        let mut count = 0;
+        let mut members_updated = Vec::new();
+
+        for (user_id, date_added, chit_earned) in members {
+            let count_before = count;
+            for (achievement_id, achievement) in self.achievements.iter_mut() {
+                let criteria_met = match achievement.criteria {
+                    CommunityAchievementCriteria::Manual => false,
+                    CommunityAchievementCriteria::ChitEarned(min) => chit_earned >= 0 && chit_earned as u32 >= min,
+                    CommunityAchievementCriteria::MembershipDuration(duration) => now.saturating_sub(date_added) >= duration,
+                };
+
+                if !criteria_met || achievement.revoked.contains(&user_id) {
+                    continue;
+                }
+
+                let awards = self.awarded.entry(user_id).or_default();
+                if !awards.contains_key(achievement_id) {
+                    awards.insert(
+                        *achievement_id,
+                        AwardInternal {
+                            awarded: now,
+                            awarded_by: None,
+                        },
+                    );
+                    achievement.awarded_count += 1;
+                    count += 1;
+                }
+            }
+            if count > count_before {
+                members_updated.push(user_id);
+            }
+        }
+
+        for user_id in members_updated {
+            self.mark_member_updated(user_id, now);
+        }
+        if count > 0 {
+            self.last_updated = now;
+        }
+        self.last_automatic_check = now;
+        count
+    }
+
+    fn mark_member_updated(&mut self, user_id: UserId, now: TimestampMillis) {
+        if let Some(previous) = self.member_updates.insert(user_id, now) {
+            self.member_updates_by_timestamp.remove(&(previous, user_id));
+        }
+        self.member_updates_by_timestamp.insert((now, user_id));
+    }
+}
+
+impl AchievementInternal {
+    fn hydrate(&self, id: u32) -> CommunityAchievement {
+        CommunityAchievement {
+            id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            image_id: self.image.as_ref().map(|i| i.id),
+            criteria: self.criteria,
+            awarded_count: self.awarded_count,
+            created: self.created,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+    use constants::DAY_IN_MS;
+
+    #[test]
+    fn automatic_achievements_awarded_once_criteria_met() {
+        let mut achievements = CommunityAchievements::default();
+        let mut rng = rand::thread_rng();
+        let veteran = achievements.create(
+            "Veteran".to_string(),
+            String::new(),
+            None,
+            CommunityAchievementCriteria::MembershipDuration(30 * DAY_IN_MS),
+            &mut rng,
+            0,
+        );
+        let high_roller = achievements.create(
+            "High roller".to_string(),
+            String::new(),
+            None,
+            CommunityAchievementCriteria::ChitEarned(1000),
+            &mut rng,
+            0,
+        );
+
+        let user1: UserId = Principal::from_slice(&[1]).into();
+        let user2: UserId = Principal::from_slice(&[2]).into();
+        let now = 40 * DAY_IN_MS;
+
+        let awarded = achievements.award_automatic([(user1, 0, 2000), (user2, 20 * DAY_IN_MS, 500)].into_iter(), now);
+        assert_eq!(awarded, 2);
+        assert_eq!(achievements.awarded_to(&user1).len(), 2);
+        assert!(achievements.awarded_to(&user2).is_empty());
+
+        // Running again should not award the same achievements twice
+        let awarded = achievements.award_automatic([(user1, 0, 2000)].into_iter(), now);
+        assert_eq!(awarded, 0);
+        assert_eq!(achievements.get(veteran).unwrap().awarded_count, 1);
+        assert_eq!(achievements.get(high_roller).unwrap().awarded_count, 1);
+    }
+
+    #[test]
+    fn revoked_achievements_not_awarded_automatically() {
+        let mut achievements = CommunityAchievements::default();
+        let achievement_id = achievements.create(
+            "Contributor".to_string(),
+            String::new(),
+            None,
+            CommunityAchievementCriteria::ChitEarned(100),
+            &mut rand::thread_rng(),
+            0,
+        );
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+
+        assert_eq!(achievements.award_automatic([(user_id, 0, 200)].into_iter(), 1), 1);
+        assert!(matches!(
+            achievements.revoke(achievement_id, user_id, 2),
+            RevokeResult::Success
+        ));
+        assert_eq!(achievements.award_automatic([(user_id, 0, 200)].into_iter(), 3), 0);
+        assert!(achievements.awarded_to(&user_id).is_empty());
+
+        // Awarding manually again clears the revocation
+        assert!(matches!(
+            achievements.award(achievement_id, user_id, None, 4),
+            AwardResult::Success
+        ));
+        assert_eq!(achievements.get(achievement_id).unwrap().awarded_count, 1);
+    }
+
+    #[test]
+    fn removing_user_clears_their_awards() {
+        let mut achievements = CommunityAchievements::default();
+        let achievement_id = achievements.create(
+            "Helper".to_string(),
+            String::new(),
+            None,
+            CommunityAchievementCriteria::Manual,
+            &mut rand::thread_rng(),
+            0,
+        );
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+
+        assert!(matches!(
+            achievements.award(achievement_id, user_id, None, 1),
+            AwardResult::Success
+        ));
+        achievements.remove_user(&user_id, 2);
+
+        assert!(achievements.awarded_to(&user_id).is_empty());
+        assert_eq!(achievements.get(achievement_id).unwrap().awarded_count, 0);
+        assert_eq!(achievements.last_updated(), 2);
+    }
+}
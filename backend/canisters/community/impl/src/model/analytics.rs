@@ -0,0 +1,379 @@
+use constants::DAY_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use types::{
+    is_default, AnalyticsWindow, ChannelId, ChannelMessageCount, DailyActivity, RetentionCohort, TimestampMillis,
+    TopContributor, UserId,
+};
+use utils::iterator_extensions::IteratorExtensions;
+
+const MAX_DAYS: u64 = 90;
+const MAX_CONTRIBUTORS_PER_DAY: usize = 100;
+const MAX_TOP_CONTRIBUTORS: usize = 10;
+const MAX_COHORT_WEEKS: u64 = 12;
+
+// Aggregates of community activity which are periodically rolled up from the per-channel and per-user chat metrics,
+// so that the `community_analytics` query doesn't need to iterate over every channel and member
+#[derive(Serialize, Deserialize, Default)]
+pub struct CommunityAnalytics {
+    #[serde(rename = "d")]
+    days: BTreeMap<u64, DailyAggregate>,
+    // The cumulative message counts as of the previous rollup
+    #[serde(rename = "cm")]
+    channel_message_counts: HashMap<ChannelId, u32>,
+    #[serde(rename = "um")]
+    user_message_counts: HashMap<UserId, u32>,
+    // For each member, bit `n` is set if they were active `n` days before `today`
+    #[serde(rename = "a")]
+    member_activity: HashMap<UserId, u128>,
+    // The day on which each member joined, for members who joined within the retention cohort period
+    #[serde(rename = "j")]
+    joined: HashMap<UserId, u64>,
+    #[serde(rename = "rc")]
+    retention_cohorts: Vec<RetentionCohort>,
+    #[serde(rename = "t")]
+    today: u64,
+    #[serde(rename = "r")]
+    last_rolled_up: TimestampMillis,
+    // Any rollup in progress during an upgrade is simply restarted by the next run of the job
+    #[serde(skip)]
+    rollup: Option<Rollup>,
+}
+
+// The channels still to be processed by the rollup in progress, along with the counts accumulated from the channels
+// processed so far
+#[derive(Default)]
+struct Rollup {
+    channel_ids: Vec<ChannelId>,
+    channel_message_counts: HashMap<ChannelId, u32>,
+    user_activity: HashMap<UserId, UserActivity>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct DailyAggregate {
+    #[serde(rename = "a", default, skip_serializing_if = "is_default")]
+    active_members: u32,
+    #[serde(rename = "m", default, skip_serializing_if = "HashMap::is_empty")]
+    messages: HashMap<ChannelId, u32>,
+    #[serde(rename = "j", default, skip_serializing_if = "is_default")]
+    joins: u32,
+    #[serde(rename = "l", default, skip_serializing_if = "is_default")]
+    leaves: u32,
+    #[serde(rename = "c", default, skip_serializing_if = "HashMap::is_empty")]
+    contributors: HashMap<UserId, u32>,
+}
+
+pub struct UserActivity {
+    pub messages: u32,
+    pub last_active: TimestampMillis,
+}
+
+impl CommunityAnalytics {
+    pub fn record_join(&mut self, user_id: UserId, now: TimestampMillis) {
+        let day = now / DAY_IN_MS;
+        self.days.entry(day).or_default().joins += 1;
+        self.joined.insert(user_id, day);
+    }
+
+    pub fn record_leave(&mut self, user_id: &UserId, now: TimestampMillis) {
+        self.days.entry(now / DAY_IN_MS).or_default().leaves += 1;
+        self.joined.remove(user_id);
+        self.member_activity.remove(user_id);
+    }
+
+    pub fn rollup_in_progress(&self) -> bool {
+        self.rollup.is_some()
+    }
+
+    // Starts a new rollup, discarding any rollup which is still in progress
+    pub fn start_rollup(&mut self, channel_ids: Vec<ChannelId>) {
+        self.rollup = Some(Rollup {
+            channel_ids,
+            ..Default::default()
+        });
+    }
+
+    // Takes the next `max_channels` channels to be processed by the rollup in progress
+    pub fn next_rollup_batch(&mut self, max_channels: usize) -> Vec<ChannelId> {
+        self.rollup.as_mut().map_or(Vec::new(), |r| {
+            let start = r.channel_ids.len().saturating_sub(max_channels);
+            r.channel_ids.split_off(start)
+        })
+    }
+
+    pub fn record_channel_activity(
+        &mut self,
+        channel_id: ChannelId,
+        message_count: u32,
+        user_activity: impl Iterator<Item = (UserId, UserActivity)>,
+    ) {
+        if let Some(rollup) = self.rollup.as_mut() {
+            rollup.channel_message_counts.insert(channel_id, message_count);
+
+            for (user_id, activity) in user_activity {
+                let total = rollup.user_activity.entry(user_id).or_insert(UserActivity {
+                    messages: 0,
+                    last_active: 0,
+                });
+                total.messages += activity.messages;
+                total.last_active = total.last_active.max(activity.last_active);
+            }
+        }
+    }
+
+    // Once every channel has been processed the aggregates are updated and this returns true
+    pub fn complete_rollup_if_ready<F: Fn(&UserId) -> bool>(&mut self, is_member: F, now: TimestampMillis) -> bool {
+        if self.rollup.as_ref().is_some_and(|r| !r.channel_ids.is_empty()) {
+            return false;
+        }
+
+        if let Some(rollup) = self.rollup.take() {
+            self.roll_up(rollup.channel_message_counts, rollup.user_activity, is_member, now);
+        }
+        true
+    }
+
+    // The first rollup only records the baseline counts, so that historic activity isn't attributed to the current day
+    fn roll_up<F: Fn(&UserId) -> bool>(
+        &mut self,
+        channel_message_counts: HashMap<ChannelId, u32>,
+        user_activity: HashMap<UserId, UserActivity>,
+        is_member: F,
+        now: TimestampMillis,
+    ) {
+        let today = now / DAY_IN_MS;
+
+        if self.last_rolled_up > 0 {
+            self.advance_to(today);
+
+            let aggregate = self.days.entry(today).or_default();
+
+            for (channel_id, count) in channel_message_counts.iter() {
+                let previous = self.channel_message_counts.get(channel_id).copied().unwrap_or_default();
+                let delta = count.saturating_sub(previous);
+                if delta > 0 {
+                    *aggregate.messages.entry(*channel_id).or_default() += delta;
+                }
+            }
+
+            for (user_id, activity) in user_activity.iter() {
+                let previous = self.user_message_counts.get(user_id).copied().unwrap_or_default();
+                let delta = activity.messages.saturating_sub(previous);
+                if delta > 0 {
+                    *aggregate.contributors.entry(*user_id).or_default() += delta;
+                }
+                if activity.last_active > self.last_rolled_up && is_member(user_id) {
+                    let bits = self.member_activity.entry(*user_id).or_default();
+                    if *bits & 1 == 0 {
+                        *bits |= 1;
+                        aggregate.active_members += 1;
+                    }
+                }
+            }
+
+            if aggregate.contributors.len() > MAX_CONTRIBUTORS_PER_DAY {
+                aggregate.contributors = std::mem::take(&mut aggregate.contributors)
+                    .into_iter()
+                    .max_n_by(MAX_CONTRIBUTORS_PER_DAY, |(_, count)| *count)
+                    .collect();
+            }
+        }
+
+        self.channel_message_counts = channel_message_counts;
+        self.user_message_counts = user_activity.into_iter().map(|(u, a)| (u, a.messages)).collect();
+        self.today = today;
+        self.days.retain(|day, _| day + MAX_DAYS > today);
+        self.joined.retain(|_, day| *day + 7 * MAX_COHORT_WEEKS > today);
+        self.retention_cohorts = self.calculate_retention_cohorts();
+        self.last_rolled_up = now;
+    }
+
+    pub fn last_rolled_up(&self) -> TimestampMillis {
+        self.last_rolled_up
+    }
+
+    pub fn daily_activity(&self, window: AnalyticsWindow) -> Vec<DailyActivity> {
+        self.window_days(window)
+            .map(|day| {
+                let aggregate = self.days.get(&day);
+                DailyActivity {
+                    date: day * DAY_IN_MS,
+                    active_members: aggregate.map_or(0, |a| a.active_members),
+                    messages: aggregate.map_or(0, |a| a.messages.values().sum()),
+                    joins: aggregate.map_or(0, |a| a.joins),
+                    leaves: aggregate.map_or(0, |a| a.leaves),
+                }
+            })
+            .collect()
+    }
+
+    pub fn messages_per_channel(&self, window: AnalyticsWindow) -> Vec<ChannelMessageCount> {
+        let mut counts: HashMap<ChannelId, u32> = HashMap::new();
+        for aggregate in self.window_days(window).filter_map(|day| self.days.get(&day)) {
+            for (channel_id, count) in aggregate.messages.iter() {
+                *counts.entry(*channel_id).or_default() += count;
+            }
+        }
+
+        let mut counts: Vec<_> = counts
+            .into_iter()
+            .map(|(channel_id, messages)| ChannelMessageCount { channel_id, messages })
+            .collect();
+        counts.sort_unstable_by(|a, b| b.messages.cmp(&a.messages));
+        counts
+    }
+
+    pub fn top_contributors(&self, window: AnalyticsWindow) -> Vec<TopContributor> {
+        let mut counts: HashMap<UserId, u32> = HashMap::new();
+        for aggregate in self.window_days(window).filter_map(|day| self.days.get(&day)) {
+            for (user_id, count) in aggregate.contributors.iter() {
+                *counts.entry(*user_id).or_default() += count;
+            }
+        }
+
+        counts
+            .into_iter()
+            .max_n_by(MAX_TOP_CONTRIBUTORS, |(_, count)| *count)
+            .map(|(user_id, messages)| TopContributor { user_id, messages })
+            .collect()
+    }
+
+    pub fn retention_cohorts(&self, window: AnalyticsWindow) -> Vec<RetentionCohort> {
+        let from = self.window_days(window).next().unwrap_or_default() * DAY_IN_MS;
+
+        self.retention_cohorts
+            .iter()
+            .filter(|c| c.week_starting >= from)
+            .cloned()
+            .collect()
+    }
+
+    fn window_days(&self, window: AnalyticsWindow) -> impl Iterator<Item = u64> {
+        (self.today + 1).saturating_sub(window.days())..=self.today
+    }
+
+    fn advance_to(&mut self, today: u64) {
+        let elapsed = today.saturating_sub(self.today);
+        if elapsed > 0 {
+            self.member_activity.retain(|_, bits| {
+                *bits = if elapsed >= 128 { 0 } else { *bits << elapsed };
+                *bits != 0
+            });
+        }
+    }
+
+    // Groups members by the week in which they joined, then for each group counts how many members were active in
+    // each week since joining. Weeks are aligned so that the most recent week ends today.
+    fn calculate_retention_cohorts(&self) -> Vec<RetentionCohort> {
+        let active_in_week = |user_id: &UserId, week: u64| {
+            self.member_activity
+                .get(user_id)
+                .is_some_and(|bits| bits & (0x7F << (7 * week)) != 0)
+        };
+
+        let mut cohorts = Vec::new();
+        for week in (0..MAX_COHORT_WEEKS).rev() {
+            let members: Vec<_> = self
+                .joined
+                .iter()
+                .filter(|(_, day)| (self.today - **day) / 7 == week)
+                .map(|(user_id, _)| *user_id)
+                .collect();
+
+            if !members.is_empty() {
+                cohorts.push(RetentionCohort {
+                    week_starting: self.today.saturating_sub(7 * week + 6) * DAY_IN_MS,
+                    members: members.len() as u32,
+                    active_by_week: (0..=week)
+                        .rev()
+                        .map(|w| members.iter().filter(|u| active_in_week(u, w)).count() as u32)
+                        .collect(),
+                });
+            }
+        }
+        cohorts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    fn roll_up(
+        analytics: &mut CommunityAnalytics,
+        channel_message_counts: Vec<(ChannelId, u32)>,
+        user_activity: Vec<(UserId, u32, TimestampMillis)>,
+        now: TimestampMillis,
+    ) {
+        analytics.start_rollup(channel_message_counts.iter().map(|(c, _)| *c).collect());
+        for channel_id in analytics.next_rollup_batch(1) {
+            let count = channel_message_counts.iter().find(|(c, _)| *c == channel_id).unwrap().1;
+            let activity = user_activity.iter().map(|(u, messages, last_active)| {
+                (
+                    *u,
+                    UserActivity {
+                        messages: *messages,
+                        last_active: *last_active,
+                    },
+                )
+            });
+
+            analytics.record_channel_activity(channel_id, count, activity);
+        }
+        assert!(analytics.complete_rollup_if_ready(|_| true, now));
+        assert!(!analytics.rollup_in_progress());
+    }
+
+    #[test]
+    fn roll_up_records_activity_since_previous_roll_up() {
+        let mut analytics = CommunityAnalytics::default();
+        let user = |i: u8| UserId::from(Principal::from_slice(&[i]));
+        let channel_id = ChannelId::from(1u32);
+
+        let day = 100 * DAY_IN_MS;
+        roll_up(&mut analytics, vec![(channel_id, 10)], vec![(user(1), 10, day - 1)], day);
+
+        analytics.record_join(user(2), day + 2);
+        roll_up(
+            &mut analytics,
+            vec![(channel_id, 15)],
+            vec![(user(1), 12, day + 1), (user(2), 3, day + 2)],
+            day + 10,
+        );
+
+        let daily = analytics.daily_activity(AnalyticsWindow::Week);
+        assert_eq!(daily.len(), 7);
+        let today = daily.last().unwrap();
+        assert_eq!(today.messages, 5);
+        assert_eq!(today.active_members, 2);
+        assert_eq!(today.joins, 1);
+
+        let contributors = analytics.top_contributors(AnalyticsWindow::Week);
+        assert_eq!(contributors[0].user_id, user(2));
+        assert_eq!(contributors[0].messages, 3);
+
+        let cohorts = analytics.retention_cohorts(AnalyticsWindow::Week);
+        assert_eq!(cohorts.len(), 1);
+        assert_eq!(cohorts[0].members, 1);
+        assert_eq!(cohorts[0].active_by_week, vec![1]);
+    }
+
+    #[test]
+    fn rollup_completes_once_every_channel_processed() {
+        let mut analytics = CommunityAnalytics::default();
+        analytics.start_rollup((1..=3u32).map(ChannelId::from).collect());
+        for channel_id in analytics.next_rollup_batch(2) {
+            analytics.record_channel_activity(channel_id, 1, std::iter::empty());
+        }
+        assert!(!analytics.complete_rollup_if_ready(|_| true, 1));
+        assert_eq!(analytics.last_rolled_up(), 0);
+
+        for channel_id in analytics.next_rollup_batch(2) {
+            analytics.record_channel_activity(channel_id, 1, std::iter::empty());
+        }
+        assert!(analytics.complete_rollup_if_ready(|_| true, 1));
+        assert_eq!(analytics.last_rolled_up(), 1);
+        assert_eq!(analytics.channel_message_counts.len(), 3);
+    }
+}
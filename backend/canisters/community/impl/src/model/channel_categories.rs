@@ -0,0 +1,133 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use types::{is_default, ChannelCategory, ChannelId, GroupPermissions, TimestampMillis};
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ChannelCategories {
+    categories: Vec<ChannelCategoryInternal>,
+    last_updated: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChannelCategoryInternal {
+    #[serde(rename = "i")]
+    id: u32,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "c", default, skip_serializing_if = "Vec::is_empty")]
+    channel_ids: Vec<ChannelId>,
+    #[serde(rename = "cd", default, skip_serializing_if = "is_default")]
+    collapsed_by_default: bool,
+    #[serde(rename = "p", default, skip_serializing_if = "Option::is_none")]
+    default_permissions: Option<GroupPermissions>,
+}
+
+pub struct CategoryUpdate {
+    pub id: Option<u32>,
+    pub name: String,
+    pub channel_ids: Vec<ChannelId>,
+    pub collapsed_by_default: bool,
+    pub default_permissions: Option<GroupPermissions>,
+}
+
+impl ChannelCategories {
+    pub fn exists(&self, category_id: u32) -> bool {
+        self.categories.iter().any(|c| c.id == category_id)
+    }
+
+    pub fn default_permissions(&self, category_id: u32) -> Option<&GroupPermissions> {
+        self.categories
+            .iter()
+            .find(|c| c.id == category_id)
+            .and_then(|c| c.default_permissions.as_ref())
+    }
+
+    // Replaces the categories with those provided, in the order provided. Channels which are not visible to the caller
+    // (`is_visible` returns false) retain their existing category, so long as that category has not been removed.
+    pub fn set<R: RngCore>(
+        &mut self,
+        updates: Vec<CategoryUpdate>,
+        is_visible: impl Fn(&ChannelId) -> bool,
+        rng: &mut R,
+        now: TimestampMillis,
+    ) {
+        let mut ids: HashSet<_> = self.categories.iter().map(|c| c.id).collect();
+
+        let mut categories: Vec<_> = updates
+            .into_iter()
+            .map(|u| ChannelCategoryInternal {
+                id: u.id.unwrap_or_else(|| generate_id(&mut ids, rng)),
+                name: u.name,
+                channel_ids: u.channel_ids,
+                collapsed_by_default: u.collapsed_by_default,
+                default_permissions: u.default_permissions,
+            })
+            .collect();
+
+        for previous in self.categories.iter() {
+            if let Some(category) = categories.iter_mut().find(|c| c.id == previous.id) {
+                category
+                    .channel_ids
+                    .extend(previous.channel_ids.iter().filter(|c| !is_visible(c)).copied());
+            }
+        }
+
+        self.categories = categories;
+        self.last_updated = now;
+    }
+
+    pub fn add_channel(&mut self, category_id: u32, channel_id: ChannelId, now: TimestampMillis) -> bool {
+        if let Some(category) = self.categories.iter_mut().find(|c| c.id == category_id) {
+            category.channel_ids.push(channel_id);
+            self.last_updated = now;
+            true
+        } else {
+            false
+        }
+    }
+
+    // Called when a channel's visibility changes, since that changes which users can see it within its category
+    pub fn mark_channel_updated(&mut self, channel_id: ChannelId, now: TimestampMillis) {
+        if self.categories.iter().any(|c| c.channel_ids.contains(&channel_id)) {
+            self.last_updated = now;
+        }
+    }
+
+    pub fn remove_channel(&mut self, channel_id: ChannelId, now: TimestampMillis) {
+        for category in self.categories.iter_mut() {
+            let len_before = category.channel_ids.len();
+            category.channel_ids.retain(|c| *c != channel_id);
+            if category.channel_ids.len() != len_before {
+                self.last_updated = now;
+            }
+        }
+    }
+
+    // Returns the categories in order, each containing only the channels which are visible to the caller
+    pub fn summary(&self, is_visible: impl Fn(&ChannelId) -> bool) -> Vec<ChannelCategory> {
+        self.categories
+            .iter()
+            .map(|c| ChannelCategory {
+                id: c.id,
+                name: c.name.clone(),
+                channel_ids: c.channel_ids.iter().filter(|c| is_visible(c)).copied().collect(),
+                collapsed_by_default: c.collapsed_by_default,
+                default_permissions: c.default_permissions.clone(),
+            })
+            .collect()
+    }
+
+    pub fn last_updated(&self) -> TimestampMillis {
+        self.last_updated
+    }
+}
+
+fn generate_id<R: RngCore>(ids: &mut HashSet<u32>, rng: &mut R) -> u32 {
+    loop {
+        let id = rng.next_u32();
+        if ids.insert(id) {
+            return id;
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use super::members::CommunityMembers;
 use chat_events::Reader;
 use group_chat_core::{CanLeaveResult, GroupChatCore, GroupMemberInternal, LeaveResult};
+use group_community_common::PersonalBlockLists;
 use installed_bots::BotApiKeys;
 use rand::rngs::StdRng;
 use rand::{Rng, RngCore};
@@ -254,6 +255,7 @@ impl Channel {
         is_community_member: bool,
         is_public_community: bool,
         community_members: &CommunityMembers,
+        personal_block_lists: &PersonalBlockLists,
     ) -> Option<CommunityCanisterChannelSummary> {
         let chat = &self.chat;
         let member = user_id.and_then(|user_id| chat.members.get(&user_id));
@@ -276,7 +278,13 @@ impl Channel {
         let can_view_latest_message = self.can_view_latest_message(member.is_some(), is_community_member, is_public_community);
 
         let main_events_reader = chat.events.visible_main_events_reader(min_visible_event_index);
-        let latest_message = if can_view_latest_message { main_events_reader.latest_message_event(user_id) } else { None };
+        let latest_message = if !can_view_latest_message {
+            None
+        } else if let Some(user_id) = user_id {
+            personal_block_lists.latest_visible_message(&user_id, main_events_reader.iter_latest_messages(Some(user_id)))
+        } else {
+            main_events_reader.latest_message_event(None)
+        };
         let events_ttl = chat.events.get_events_time_to_live();
 
         let latest_message_sender_display_name = latest_message
@@ -357,6 +365,7 @@ impl Channel {
         is_community_member: bool,
         is_public_community: bool,
         community_members: &CommunityMembers,
+        personal_block_lists: &PersonalBlockLists,
     ) -> ChannelUpdates {
         let chat = &self.chat;
         let member = user_id.and_then(|id| chat.members.get(&id));
@@ -364,8 +373,14 @@ impl Channel {
         if let Some(m) = &member {
             if m.date_added() > since {
                 return ChannelUpdates::Added(
-                    self.summary(user_id, is_community_member, is_public_community, community_members)
-                        .expect("Channel should be accessible"),
+                    self.summary(
+                        user_id,
+                        is_community_member,
+                        is_public_community,
+                        community_members,
+                        personal_block_lists,
+                    )
+                    .expect("Channel should be accessible"),
                 );
             }
         }
@@ -373,7 +388,17 @@ impl Channel {
         let can_view_latest_message = self.can_view_latest_message(member.is_some(), is_community_member, is_public_community);
         let updates = chat.summary_updates(since, user_id);
 
-        let latest_message = can_view_latest_message.then_some(updates.latest_message).flatten();
+        let latest_message = can_view_latest_message
+            .then_some(updates.latest_message)
+            .flatten()
+            .and_then(|m| match user_id {
+                Some(user_id) if personal_block_lists.has_blocked(&user_id, &m.event.sender) => {
+                    let min_visible_event_index = member.as_ref().map(|m| m.min_visible_event_index()).unwrap_or_default();
+                    let events_reader = chat.events.visible_main_events_reader(min_visible_event_index);
+                    personal_block_lists.latest_visible_message(&user_id, events_reader.iter_latest_messages(Some(user_id)))
+                }
+                _ => Some(m),
+            });
 
         let latest_message_sender_display_name = latest_message
             .as_ref()
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use types::{ChitBalances, ChitLeaderboardWindow, TimestampMillis, UserId};
+
+// Snapshots of the members' CHIT balances, ranked per window, which are periodically refreshed from the
+// UserIndex canister
+#[derive(Serialize, Deserialize, Default)]
+pub struct ChitLeaderboards {
+    #[serde(rename = "w")]
+    week: Vec<RankedMember>,
+    #[serde(rename = "m")]
+    month: Vec<RankedMember>,
+    #[serde(rename = "a")]
+    all_time: Vec<RankedMember>,
+    #[serde(rename = "u")]
+    last_updated: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct RankedMember {
+    #[serde(rename = "u")]
+    pub user_id: UserId,
+    #[serde(rename = "r")]
+    pub rank: u32,
+    #[serde(rename = "b")]
+    pub balance: u32,
+}
+
+impl ChitLeaderboards {
+    pub fn set(&mut self, balances: &[ChitBalances], now: TimestampMillis) {
+        self.week = rank(balances.iter().map(|b| (b.user_id, b.week)));
+        self.month = rank(balances.iter().map(|b| (b.user_id, b.month)));
+        self.all_time = rank(balances.iter().map(|b| (b.user_id, b.all_time)));
+        self.last_updated = now;
+    }
+
+    // Returns the ranked members, highest first
+    pub fn ranked(&self, window: ChitLeaderboardWindow) -> &[RankedMember] {
+        self.get(window)
+    }
+
+    pub fn position(&self, window: ChitLeaderboardWindow, user_id: UserId) -> Option<RankedMember> {
+        self.get(window).iter().find(|m| m.user_id == user_id).copied()
+    }
+
+    pub fn last_updated(&self) -> TimestampMillis {
+        self.last_updated
+    }
+
+    fn get(&self, window: ChitLeaderboardWindow) -> &[RankedMember] {
+        match window {
+            ChitLeaderboardWindow::Week => &self.week,
+            ChitLeaderboardWindow::Month => &self.month,
+            ChitLeaderboardWindow::AllTime => &self.all_time,
+        }
+    }
+}
+
+// Members with equal balances share the same rank, eg. 1, 2, 2, 4
+fn rank(balances: impl Iterator<Item = (UserId, i32)>) -> Vec<RankedMember> {
+    let mut balances: Vec<_> = balances
+        .filter(|(_, balance)| *balance > 0)
+        .map(|(user_id, balance)| (user_id, balance as u32))
+        .collect();
+
+    balances.sort_unstable_by_key(|(user_id, balance)| (Reverse(*balance), *user_id));
+
+    let mut ranked: Vec<RankedMember> = Vec::with_capacity(balances.len());
+    for (index, (user_id, balance)) in balances.into_iter().enumerate() {
+        let rank = match ranked.last() {
+            Some(previous) if previous.balance == balance => previous.rank,
+            _ => index as u32 + 1,
+        };
+        ranked.push(RankedMember { user_id, rank, balance });
+    }
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn members_ranked_by_balance_with_ties_sharing_rank() {
+        let mut leaderboards = ChitLeaderboards::default();
+        let balances: Vec<_> = [(1, 100), (2, 300), (3, 100), (4, 0), (5, 50)]
+            .into_iter()
+            .map(|(id, all_time)| ChitBalances {
+                user_id: Principal::from_slice(&[id]).into(),
+                week: 0,
+                month: 0,
+                all_time,
+            })
+            .collect();
+
+        leaderboards.set(&balances, 1);
+
+        let ranks: Vec<_> = leaderboards
+            .top(ChitLeaderboardWindow::AllTime, 10)
+            .iter()
+            .map(|m| (m.rank, m.balance))
+            .collect();
+
+        assert_eq!(ranks, vec![(1, 300), (2, 100), (2, 100), (4, 50)]);
+        assert!(leaderboards.top(ChitLeaderboardWindow::Week, 10).is_empty());
+        assert!(leaderboards
+            .position(ChitLeaderboardWindow::AllTime, Principal::from_slice(&[4]).into())
+            .is_none());
+        assert_eq!(
+            leaderboards
+                .position(ChitLeaderboardWindow::AllTime, Principal::from_slice(&[5]).into())
+                .map(|m| m.rank),
+            Some(4)
+        );
+    }
+}
@@ -5,7 +5,8 @@ use types::{
     AvatarChanged, BannerChanged, BotAdded, BotRemoved, BotUpdated, ChannelDeleted, ChannelId, ChatId, CommunityMembersRemoved,
     CommunityPermissionsChanged, CommunityRoleChanged, CommunityUsersBlocked, CommunityVisibilityChanged, EventIndex,
     EventWrapperInternal, GroupCreated, GroupDescriptionChanged, GroupFrozen, GroupInviteCodeChanged, GroupNameChanged,
-    GroupRulesChanged, GroupUnfrozen, PrimaryLanguageChanged, TimestampMillis, UserId, UsersInvited, UsersUnblocked,
+    GroupRulesChanged, GroupUnfrozen, JoinRequestRejected, JoinRequested, PrimaryLanguageChanged, TimestampMillis, UserId,
+    UsersInvited, UsersUnblocked,
 };
 
 mod stable_memory;
@@ -65,6 +66,10 @@ pub enum CommunityEventInternal {
     BotRemoved(Box<BotRemoved>),
     #[serde(rename = "bu")]
     BotUpdated(Box<BotUpdated>),
+    #[serde(rename = "jr")]
+    JoinRequested(Box<JoinRequested>),
+    #[serde(rename = "jrr")]
+    JoinRequestRejected(Box<JoinRequestRejected>),
 }
 
 impl CommunityEvents {
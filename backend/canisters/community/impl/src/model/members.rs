@@ -804,6 +804,7 @@ impl From<CommunityMemberInternal> for CommunityMember {
             display_name: m.display_name.value,
             referred_by: m.referred_by,
             lapsed: m.lapsed.value,
+            achievements: Vec::new(),
         }
     }
 }
@@ -817,6 +818,7 @@ impl From<&CommunityMemberInternal> for CommunityMember {
             display_name: m.display_name.value.clone(),
             referred_by: m.referred_by,
             lapsed: m.lapsed.value,
+            achievements: Vec::new(),
         }
     }
 }
@@ -1,7 +1,12 @@
+pub mod achievements;
+pub mod analytics;
+pub mod channel_categories;
 pub mod channels;
+pub mod chit_leaderboards;
 pub mod events;
 pub mod groups_being_imported;
 pub mod invited_users;
 pub mod members;
+pub mod permission_templates;
 pub mod user_event_batch;
 pub mod user_groups;
@@ -0,0 +1,112 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use types::{ChannelId, GroupPermissions, TimestampMillis};
+
+// Named sets of channel permissions defined at the community level. Each channel may be linked to at most one
+// template, and any changes to a template are applied to all of its linked channels.
+#[derive(Serialize, Deserialize, Default)]
+pub struct PermissionTemplates {
+    templates: BTreeMap<u32, PermissionTemplateInternal>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PermissionTemplateInternal {
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "p")]
+    pub permissions: GroupPermissions,
+    #[serde(rename = "c", default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub channel_ids: BTreeSet<ChannelId>,
+    #[serde(rename = "u")]
+    pub last_updated: TimestampMillis,
+}
+
+impl PermissionTemplates {
+    pub fn iter(&self) -> impl Iterator<Item = (&u32, &PermissionTemplateInternal)> {
+        self.templates.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    pub fn is_name_taken(&self, name: &str, excluding: Option<u32>) -> bool {
+        self.templates
+            .iter()
+            .any(|(id, t)| Some(*id) != excluding && t.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn create<R: RngCore>(
+        &mut self,
+        name: String,
+        permissions: GroupPermissions,
+        rng: &mut R,
+        now: TimestampMillis,
+    ) -> u32 {
+        let template_id = loop {
+            let id = rng.next_u32();
+            if !self.templates.contains_key(&id) {
+                break id;
+            }
+        };
+
+        self.templates.insert(
+            template_id,
+            PermissionTemplateInternal {
+                name,
+                permissions,
+                channel_ids: BTreeSet::new(),
+                last_updated: now,
+            },
+        );
+        template_id
+    }
+
+    pub fn channel_ids(&self, template_id: u32) -> Option<&BTreeSet<ChannelId>> {
+        self.templates.get(&template_id).map(|t| &t.channel_ids)
+    }
+
+    // Returns the channels linked to the template, to which the new permissions should be applied
+    pub fn update(
+        &mut self,
+        template_id: u32,
+        name: String,
+        permissions: GroupPermissions,
+        now: TimestampMillis,
+    ) -> Option<Vec<ChannelId>> {
+        let template = self.templates.get_mut(&template_id)?;
+        template.name = name;
+        template.permissions = permissions;
+        template.last_updated = now;
+        Some(template.channel_ids.iter().copied().collect())
+    }
+
+    pub fn delete(&mut self, template_id: u32) -> bool {
+        self.templates.remove(&template_id).is_some()
+    }
+
+    // Links the channels to the template, unlinking them from any other template they were previously linked to
+    pub fn link(&mut self, template_id: u32, channel_ids: &[ChannelId], now: TimestampMillis) -> Option<&GroupPermissions> {
+        if !self.templates.contains_key(&template_id) {
+            return None;
+        }
+
+        self.unlink(channel_ids, now);
+
+        let template = self.templates.get_mut(&template_id)?;
+        template.channel_ids.extend(channel_ids.iter().copied());
+        template.last_updated = now;
+        Some(&template.permissions)
+    }
+
+    pub fn unlink(&mut self, channel_ids: &[ChannelId], now: TimestampMillis) {
+        for template in self.templates.values_mut() {
+            let len_before = template.channel_ids.len();
+            template.channel_ids.retain(|c| !channel_ids.contains(c));
+            if template.channel_ids.len() != len_before {
+                template.last_updated = now;
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::achievements::{Response::*, *};
+
+#[query(msgpack = true)]
+fn achievements(args: Args) -> Response {
+    read_state(|state| achievements_impl(args, state))
+}
+
+fn achievements_impl(args: Args, state: &RuntimeState) -> Response {
+    if !state.data.is_public.value {
+        let caller = state.env.caller();
+        if !state.data.is_accessible(caller, None) {
+            return PrivateCommunity;
+        }
+    }
+
+    let awarded = args
+        .user_id
+        .filter(|user_id| state.data.members.contains(user_id))
+        .map(|user_id| state.data.community_achievements.awarded_to(&user_id))
+        .unwrap_or_default();
+
+    Success(SuccessResult {
+        achievements: state.data.community_achievements.iter().collect(),
+        awarded,
+        last_updated: state.data.community_achievements.last_updated(),
+    })
+}
@@ -2,6 +2,7 @@ use crate::read_state;
 use crate::RuntimeState;
 use canister_api_macros::query;
 use community_canister::channel_summary::{Response::*, *};
+use types::DelegationScope;
 
 #[query(candid = true, msgpack = true)]
 fn channel_summary(args: Args) -> Response {
@@ -9,7 +10,7 @@ fn channel_summary(args: Args) -> Response {
 }
 
 fn summary_impl(args: Args, state: &RuntimeState) -> Response {
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
 
     if !state.data.is_accessible(caller, args.invite_code) {
         return PrivateCommunity;
@@ -19,7 +20,13 @@ fn summary_impl(args: Args, state: &RuntimeState) -> Response {
         let user_id = state.data.members.lookup_user_id(caller);
         let is_community_member = state.data.members.get(caller).is_some();
 
-        match channel.summary(user_id, is_community_member, state.data.is_public.value, &state.data.members) {
+        match channel.summary(
+            user_id,
+            is_community_member,
+            state.data.is_public.value,
+            &state.data.members,
+            &state.data.personal_block_lists,
+        ) {
             Some(summary) => Success(summary),
             None => PrivateChannel,
         }
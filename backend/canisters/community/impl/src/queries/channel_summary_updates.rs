@@ -3,6 +3,7 @@ use crate::read_state;
 use crate::RuntimeState;
 use canister_api_macros::query;
 use community_canister::channel_summary_updates::{Response::*, *};
+use types::DelegationScope;
 
 #[query(candid = true, msgpack = true)]
 fn channel_summary_updates(args: Args) -> Response {
@@ -10,7 +11,7 @@ fn channel_summary_updates(args: Args) -> Response {
 }
 
 fn channel_summary_updates_impl(args: Args, state: &RuntimeState) -> Response {
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
 
     if !state.data.is_accessible(caller, args.invite_code) {
         return PrivateCommunity;
@@ -35,6 +36,7 @@ fn channel_summary_updates_impl(args: Args, state: &RuntimeState) -> Response {
             is_community_member,
             state.data.is_public.value,
             &state.data.members,
+            &state.data.personal_block_lists,
         ) {
             ChannelUpdates::Added(s) => SuccessAdded(s),
             ChannelUpdates::Updated(s) => SuccessUpdated(s),
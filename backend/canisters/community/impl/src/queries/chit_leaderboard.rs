@@ -0,0 +1,51 @@
+use crate::model::chit_leaderboards::RankedMember;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::chit_leaderboard::{Response::*, *};
+
+const MAX_RESULTS: usize = 500;
+
+#[query(msgpack = true)]
+fn chit_leaderboard(args: Args) -> Response {
+    read_state(|state| chit_leaderboard_impl(args, state))
+}
+
+fn chit_leaderboard_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if !state.data.is_public.value && !state.data.is_accessible(caller, None) {
+        return PrivateCommunity;
+    }
+
+    let leaderboards = &state.data.chit_leaderboards;
+    let max_results = (args.max_results as usize).min(MAX_RESULTS);
+
+    // The leaderboards are snapshots, so skip any users who have left the community since they were last refreshed
+    let leaders = leaderboards
+        .ranked(args.window)
+        .iter()
+        .filter(|m| state.data.members.contains(&m.user_id))
+        .take(max_results)
+        .map(|m| to_leaderboard_member(*m))
+        .collect();
+
+    let my_position = state
+        .data
+        .members
+        .get(caller)
+        .and_then(|m| leaderboards.position(args.window, m.user_id))
+        .map(to_leaderboard_member);
+
+    Success(SuccessResult {
+        leaders,
+        my_position,
+        last_updated: leaderboards.last_updated(),
+    })
+}
+
+fn to_leaderboard_member(member: RankedMember) -> ChitLeaderboardMember {
+    ChitLeaderboardMember {
+        user_id: member.user_id,
+        rank: member.rank,
+        balance: member.balance,
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::community_analytics::{Response::*, *};
+use types::CommunityRole;
+
+#[query(msgpack = true)]
+fn community_analytics(args: Args) -> Response {
+    read_state(|state| community_analytics_impl(args, state))
+}
+
+fn community_analytics_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().is_same_or_senior(CommunityRole::Admin) {
+        return NotAuthorized;
+    }
+
+    let analytics = &state.data.analytics;
+
+    Success(SuccessResult {
+        last_updated: analytics.last_rolled_up(),
+        daily_activity: analytics.daily_activity(args.window),
+        messages_per_channel: analytics.messages_per_channel(args.window),
+        top_contributors: analytics.top_contributors(args.window),
+        retention_cohorts: analytics.retention_cohorts(args.window),
+    })
+}
@@ -6,7 +6,7 @@ use canister_api_macros::query;
 use community_canister::c2c_events_by_index::Args as C2CArgs;
 use community_canister::events_by_index::{Response::*, *};
 use group_chat_core::EventsResult;
-use types::BotInitiator;
+use types::{BotInitiator, DelegationScope};
 
 #[query(candid = true, msgpack = true)]
 fn events_by_index(args: Args) -> Response {
@@ -28,7 +28,7 @@ fn events_by_index_impl(
         return ReplicaNotUpToDateV2(now);
     }
 
-    let caller = on_behalf_of.unwrap_or_else(|| state.env.caller());
+    let caller = on_behalf_of.unwrap_or_else(|| state.caller_with_scope(DelegationScope::ReadOnly));
     let events_caller = match state.data.get_caller_for_events(caller, args.channel_id, bot_initiator) {
         Ok(ec) => ec,
         Err(response) => return response,
@@ -37,9 +37,15 @@ fn events_by_index_impl(
     if let Some(channel) = state.data.channels.get(&args.channel_id) {
         match channel
             .chat
-            .events_by_index(events_caller, args.thread_root_message_index, args.events)
+            .events_by_index(events_caller.clone(), args.thread_root_message_index, args.events)
         {
-            EventsResult::Success(response) => Success(response),
+            EventsResult::Success(mut response) => {
+                state
+                    .data
+                    .personal_block_lists
+                    .hide_blocked_users(&events_caller, &mut response);
+                Success(response)
+            }
             EventsResult::UserNotInGroup => UserNotInChannel,
             EventsResult::ThreadNotFound => ThreadNotFound,
             EventsResult::UserSuspended => UserSuspended,
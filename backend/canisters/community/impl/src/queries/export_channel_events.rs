@@ -0,0 +1,42 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::export_channel_events::{Response::*, *};
+
+const MAX_EVENTS_PER_PAGE: u32 = 1000;
+
+#[query(msgpack = true)]
+fn export_channel_events(args: Args) -> Response {
+    read_state(|state| export_channel_events_impl(args, state))
+}
+
+fn export_channel_events_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    let Some(channel) = state.data.channels.get(&args.channel_id) else {
+        return ChannelNotFound;
+    };
+
+    let Some(channel_member) = channel.chat.members.get(&member.user_id) else {
+        return UserNotInChannel;
+    };
+
+    if member.suspended().value || channel_member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value || channel_member.lapsed().value {
+        return UserLapsed;
+    }
+
+    // Either an owner of the channel or a community owner who is a member of the channel may export it
+    if !(channel_member.role().is_owner() || member.role().is_owner()) {
+        return NotAuthorized;
+    }
+
+    Success(
+        channel
+            .chat
+            .export_events(args.after, args.max_events.clamp(1, MAX_EVENTS_PER_PAGE)),
+    )
+}
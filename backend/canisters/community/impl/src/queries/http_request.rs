@@ -21,6 +21,18 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         }
     }
 
+    fn get_achievement_image_impl(achievement_id: u32, requested_image_id: Option<u128>, state: &RuntimeState) -> HttpResponse {
+        if let Some(achievement) = state.data.community_achievements.get(achievement_id) {
+            get_document(
+                requested_image_id,
+                achievement.image.as_ref(),
+                &format!("achievement/{achievement_id}/image"),
+            )
+        } else {
+            HttpResponse::not_found()
+        }
+    }
+
     fn get_banner_impl(requested_banner_id: Option<u128>, state: &RuntimeState) -> HttpResponse {
         get_document(requested_banner_id, state.data.banner.as_ref(), "banner")
     }
@@ -61,6 +73,9 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         Route::ChannelAvatar((channel_id, requested_avatar_id)) => {
             read_state(|state| get_channel_avatar_impl(channel_id, requested_avatar_id, state))
         }
+        Route::AchievementImage(achievement_id, requested_image_id) => {
+            read_state(|state| get_achievement_image_impl(achievement_id, requested_image_id, state))
+        }
         Route::Banner(requested_banner_id) => read_state(|state| get_banner_impl(requested_banner_id, state)),
         Route::Errors(since) => get_errors_impl(since),
         Route::Logs(since) => get_logs_impl(since),
@@ -0,0 +1,23 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::join_requests::{Response::*, *};
+
+#[query(msgpack = true)]
+fn join_requests(_args: Args) -> Response {
+    read_state(join_requests_impl)
+}
+
+fn join_requests_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value || member.lapsed().value || !member.role().can_invite_users(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    Success(SuccessResult {
+        requests: state.data.join_requests.pending(),
+    })
+}
@@ -19,7 +19,7 @@ fn lookup_members_impl(args: Args, state: &RuntimeState) -> Response {
         .user_ids
         .into_iter()
         .filter_map(|user_id| state.data.members.get_by_user_id(&user_id))
-        .map(|member| member.clone().into())
+        .map(|member| state.data.community_member(member))
         .collect();
 
     Success(SuccessResult { members })
@@ -0,0 +1,37 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::message_edit_history::{Response::*, *};
+use group_chat_core::MessageEditHistoryResult;
+use types::{DelegationScope, EventsCaller};
+
+#[query(msgpack = true)]
+fn message_edit_history(args: Args) -> Response {
+    read_state(|state| message_edit_history_impl(args, state))
+}
+
+fn message_edit_history_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
+    let user_id = state.data.members.get(caller).map(|m| m.user_id);
+
+    if user_id.is_none() && (!state.data.is_public.value || state.data.has_payment_gate()) {
+        return UserNotInCommunity;
+    }
+
+    let events_caller = user_id.map_or(EventsCaller::Unknown, EventsCaller::User);
+
+    if let Some(channel) = state.data.channels.get(&args.channel_id) {
+        match channel
+            .chat
+            .message_edit_history(events_caller, args.thread_root_message_index, args.message_id)
+        {
+            MessageEditHistoryResult::Success(versions) => Success(SuccessResult { versions }),
+            MessageEditHistoryResult::UserNotInGroup => UserNotInChannel,
+            MessageEditHistoryResult::ThreadNotFound => ThreadNotFound,
+            MessageEditHistoryResult::MessageNotFound => MessageNotFound,
+            MessageEditHistoryResult::UserSuspended => UserSuspended,
+            MessageEditHistoryResult::UserLapsed => UserLapsed,
+        }
+    } else {
+        ChannelNotFound
+    }
+}
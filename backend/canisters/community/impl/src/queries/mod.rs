@@ -1,30 +1,41 @@
 use crate::RuntimeState;
 use types::TimestampMillis;
 
+mod achievements;
 mod api_key;
 mod c2c_bot_channel_details;
 mod c2c_can_issue_access_token;
 mod c2c_can_issue_access_token_for_channel;
 mod channel_summary;
 mod channel_summary_updates;
+mod chit_leaderboard;
+mod community_analytics;
 mod deleted_message;
 mod events;
 mod events_by_index;
 mod events_window;
 mod explore_channels;
+mod export_channel_events;
+mod export_channel_members;
 mod http_request;
 mod invite_code;
+mod join_requests;
 mod local_user_index;
 mod lookup_members;
+mod message_edit_history;
 mod messages_by_message_index;
+mod permission_templates;
 mod search_channel;
+mod search_community;
 mod selected_channel_initial;
 mod selected_channel_updates;
 mod selected_initial;
 mod selected_updates;
+mod subscription_payments;
 mod summary;
 mod summary_updates;
 mod thread_previews;
+mod typing;
 mod video_call_participants;
 
 fn check_replica_up_to_date(latest_known_update: Option<TimestampMillis>, state: &RuntimeState) -> Result<(), TimestampMillis> {
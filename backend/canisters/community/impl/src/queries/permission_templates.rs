@@ -0,0 +1,37 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::permission_templates::{Response::*, *};
+
+#[query(msgpack = true)]
+fn permission_templates(_args: Args) -> Response {
+    read_state(permission_templates_impl)
+}
+
+fn permission_templates_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+    let user_id = member.user_id;
+
+    Success(SuccessResult {
+        templates: state
+            .data
+            .permission_templates
+            .iter()
+            .map(|(id, t)| PermissionTemplate {
+                template_id: *id,
+                name: t.name.clone(),
+                permissions: t.permissions.clone(),
+                // Don't reveal private channels which the caller isn't a member of
+                channel_ids: t
+                    .channel_ids
+                    .iter()
+                    .filter(|id| state.data.is_channel_visible(id, Some(user_id)))
+                    .copied()
+                    .collect(),
+                last_updated: t.last_updated,
+            })
+            .collect(),
+    })
+}
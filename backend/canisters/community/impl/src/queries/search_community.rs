@@ -0,0 +1,77 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::search_community::{Response::*, *};
+use group_chat_core::{validate_search_args, SearchResults};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use types::DelegationScope;
+
+#[query(msgpack = true)]
+fn search_community(args: Args) -> Response {
+    read_state(|state| search_community_impl(args, state))
+}
+
+fn search_community_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
+
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.lapsed().value {
+        return UserLapsed;
+    }
+
+    let users = args.users.unwrap_or_default();
+
+    if let Err(error) = validate_search_args(&args.search_term, &users) {
+        return match error {
+            SearchResults::TermTooLong(v) => TermTooLong(v),
+            SearchResults::TermTooShort(v) => TermTooShort(v),
+            SearchResults::TooManyUsers(v) => TooManyUsers(v),
+            _ => InvalidTerm,
+        };
+    }
+
+    let channel_filter: Option<HashSet<_>> = args.channel_ids.map(|ids| ids.into_iter().collect());
+
+    // Each channel returns its best matches, which are then merged and ranked by score and then by recency. Each
+    // channel only returns messages which are visible to the user in that channel.
+    let mut matches: Vec<_> = state
+        .data
+        .members
+        .channels_for_member(member.user_id)
+        .iter()
+        .filter(|channel_id| channel_filter.as_ref().map_or(true, |f| f.contains(*channel_id)))
+        .filter_map(|channel_id| state.data.channels.get(channel_id))
+        .filter_map(|channel| {
+            channel
+                .chat
+                .search_in_range(
+                    member.user_id,
+                    &args.search_term,
+                    users.clone(),
+                    args.from,
+                    args.to,
+                    args.max_results,
+                )
+                .map(|m| (channel.id, m))
+        })
+        .flat_map(|(channel_id, m)| {
+            m.into_iter().map(move |(message, timestamp, score)| ChannelMessageMatch {
+                channel_id,
+                message_index: message.message_index,
+                sender: message.sender,
+                content: message.content,
+                timestamp,
+                score,
+            })
+        })
+        .filter(|m| !state.data.personal_block_lists.has_blocked(&member.user_id, &m.sender))
+        .collect();
+
+    matches.sort_unstable_by_key(|m| (Reverse(m.score), Reverse(m.timestamp)));
+    matches.truncate(args.max_results as usize);
+
+    Success(SuccessResult { matches })
+}
@@ -2,7 +2,7 @@ use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use community_canister::selected_channel_initial::{Response::*, *};
 use std::collections::HashSet;
-use types::GroupMember;
+use types::{DelegationScope, GroupMember};
 
 #[query(candid = true, msgpack = true)]
 fn selected_channel_initial(args: Args) -> Response {
@@ -10,7 +10,7 @@ fn selected_channel_initial(args: Args) -> Response {
 }
 
 fn selected_channel_initial_impl(args: Args, state: &RuntimeState) -> Response {
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
 
     if !state.data.is_accessible(caller, None) {
         return PrivateCommunity;
@@ -1,6 +1,7 @@
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use community_canister::selected_channel_updates_v2::{Response::*, *};
+use types::DelegationScope;
 
 #[query(candid = true, msgpack = true)]
 fn selected_channel_updates_v2(args: Args) -> Response {
@@ -14,7 +15,7 @@ fn selected_channel_updates_impl(args: Args, state: &RuntimeState) -> Response {
             return SuccessNoUpdates(last_updated);
         }
 
-        let caller = state.env.caller();
+        let caller = state.caller_with_scope(DelegationScope::ReadOnly);
         if !state.data.is_accessible(caller, None) {
             return PrivateCommunity;
         }
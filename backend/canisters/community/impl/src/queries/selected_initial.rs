@@ -2,7 +2,7 @@ use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use community_canister::selected_initial::{Response::*, *};
 use std::collections::HashSet;
-use types::InstalledBotDetails;
+use types::{DelegationScope, InstalledBotDetails};
 
 #[query(candid = true, msgpack = true)]
 fn selected_initial(args: Args) -> Response {
@@ -12,13 +12,13 @@ fn selected_initial(args: Args) -> Response {
 fn selected_initial_impl(args: Args, state: &RuntimeState) -> Response {
     // Don't call `ic0.caller()` if the community is public or the invite_code is valid to maximise query caching
     if !state.data.is_public.value || !state.data.is_invite_code_valid(args.invite_code) {
-        let caller = state.env.caller();
+        let caller = state.caller_with_scope(DelegationScope::ReadOnly);
         if !state.data.is_accessible(caller, None) {
             return PrivateCommunity;
         }
     }
 
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
     let data = &state.data;
     let last_updated = data.details_last_updated();
     let referrals = data
@@ -33,13 +33,14 @@ fn selected_initial_impl(args: Args, state: &RuntimeState) -> Response {
     non_basic_members.extend(data.members.suspended().iter().copied());
     non_basic_members.extend(data.members.members_with_display_names().iter().copied());
     non_basic_members.extend(data.members.members_with_referrals().iter().copied());
+    non_basic_members.extend(data.community_achievements.members_with_awards());
 
     let mut members = Vec::new();
     let mut basic_members = Vec::new();
     for user_id in data.members.iter_member_ids() {
         if non_basic_members.contains(&user_id) {
             if let Some(member) = data.members.get_by_user_id(&user_id) {
-                members.push(member.into());
+                members.push(data.community_member(member));
             }
         } else {
             basic_members.push(user_id);
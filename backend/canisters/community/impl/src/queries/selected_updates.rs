@@ -1,11 +1,11 @@
-use crate::{model::members::CommunityMembers, read_state, RuntimeState};
+use crate::{read_state, Data, RuntimeState};
 use canister_api_macros::query;
 use community_canister::selected_updates_v2::{Response::*, *};
 use group_community_common::MemberUpdate;
 use installed_bots::BotUpdate;
 use std::cell::LazyCell;
 use std::collections::HashSet;
-use types::{InstalledBotDetails, UserId};
+use types::{DelegationScope, InstalledBotDetails, UserId};
 
 #[query(candid = true, msgpack = true)]
 fn selected_updates_v2(args: Args) -> Response {
@@ -14,7 +14,7 @@ fn selected_updates_v2(args: Args) -> Response {
 
 fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
     // Only call `ic0.caller()` if we have to in order to maximise query caching
-    let caller = LazyCell::new(|| state.env.caller());
+    let caller = LazyCell::new(|| state.caller_with_scope(DelegationScope::ReadOnly));
 
     if !state.data.is_public.value
         && !state.data.is_invite_code_valid(args.invite_code)
@@ -60,7 +60,7 @@ fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
     };
 
     let mut user_updates_handler = UserUpdatesHandler {
-        members: &data.members,
+        data,
         users_updated: HashSet::new(),
         referrals_updated: HashSet::new(),
     };
@@ -91,6 +91,10 @@ fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
         }
     }
 
+    for user_id in data.community_achievements.members_updated_since(args.updates_since) {
+        user_updates_handler.mark_member_updated(&mut result, user_id, false, false);
+    }
+
     let mut bots_changed = HashSet::new();
     for (user_id, update) in state.data.bots.iter_latest_updates(args.updates_since) {
         match update {
@@ -117,7 +121,7 @@ fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
 }
 
 struct UserUpdatesHandler<'a> {
-    members: &'a CommunityMembers,
+    data: &'a Data,
     users_updated: HashSet<UserId>,
     referrals_updated: HashSet<UserId>,
 }
@@ -127,8 +131,8 @@ impl UserUpdatesHandler<'_> {
         if self.users_updated.insert(user_id) {
             if removed {
                 result.members_removed.push(user_id);
-            } else if let Some(member) = self.members.get_by_user_id(&user_id) {
-                result.members_added_or_updated.push(member.into());
+            } else if let Some(member) = self.data.members.get_by_user_id(&user_id) {
+                result.members_added_or_updated.push(self.data.community_member(member));
             }
         }
 
@@ -0,0 +1,31 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::subscription_payments::{Response::*, *};
+
+#[query(msgpack = true)]
+fn subscription_payments(args: Args) -> Response {
+    read_state(|state| subscription_payments_impl(args, state))
+}
+
+fn subscription_payments_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if let Some(channel_id) = args.channel_id {
+        if state.data.channels.get(&channel_id).is_none() {
+            return ChannelNotFound;
+        }
+    }
+
+    let user_id = args.user_id.unwrap_or(member.user_id);
+    if user_id != member.user_id && !member.role().is_owner() {
+        return NotAuthorized;
+    }
+
+    Success(SuccessResult {
+        paid_until: state.data.subscriptions.paid_until(user_id, args.channel_id),
+        payments: state.data.subscriptions.payments(user_id, args.channel_id),
+    })
+}
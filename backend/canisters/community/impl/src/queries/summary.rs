@@ -3,6 +3,7 @@ use crate::RuntimeState;
 use candid::Principal;
 use canister_api_macros::query;
 use community_canister::summary::{Response::*, *};
+use types::DelegationScope;
 
 #[query(candid = true, msgpack = true)]
 fn summary(args: Args) -> Response {
@@ -19,7 +20,7 @@ fn summary_impl(invite_code: Option<u64>, on_behalf_of: Option<Principal>, state
         assert!(state.is_caller_local_user_index());
         principal
     } else {
-        state.env.caller()
+        state.caller_with_scope(DelegationScope::ReadOnly)
     };
 
     if !state.data.is_accessible(caller, invite_code) {
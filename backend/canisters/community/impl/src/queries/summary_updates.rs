@@ -6,7 +6,8 @@ use canister_api_macros::query;
 use community_canister::summary_updates::{Response::*, *};
 use std::cmp::max;
 use types::{
-    AccessGateConfig, CommunityCanisterCommunitySummaryUpdates, CommunityMembershipUpdates, OptionUpdate, TimestampMillis,
+    AccessGateConfig, CommunityCanisterCommunitySummaryUpdates, CommunityMembershipUpdates, DelegationScope, OptionUpdate,
+    TimestampMillis,
 };
 
 #[query(candid = true, msgpack = true)]
@@ -29,7 +30,7 @@ fn summary_updates_impl(
         assert!(state.is_caller_local_user_index());
         principal
     } else {
-        state.env.caller()
+        state.caller_with_scope(DelegationScope::ReadOnly)
     };
 
     if !state.data.is_accessible(caller, invite_code) {
@@ -40,6 +41,10 @@ fn summary_updates_impl(
     let mut last_updated = [
         state.data.details_last_updated(),
         member.as_ref().map(|m| m.last_updated()).unwrap_or_default(),
+        member
+            .as_ref()
+            .and_then(|m| state.data.community_achievements.member_last_updated(&m.user_id))
+            .unwrap_or_default(),
         state.data.verified.timestamp,
     ]
     .into_iter()
@@ -97,9 +102,13 @@ fn summary_updates_impl(
 
     for channel in channels_with_updates {
         if channel.date_imported.is_some_and(|ts| ts > updates_since) {
-            if let Some(summary) =
-                channel.summary(user_id, is_community_member, state.data.is_public.value, &state.data.members)
-            {
+            if let Some(summary) = channel.summary(
+                user_id,
+                is_community_member,
+                state.data.is_public.value,
+                &state.data.members,
+                &state.data.personal_block_lists,
+            ) {
                 last_updated = max(last_updated, summary.last_updated);
                 channels_added.push(summary);
             }
@@ -110,6 +119,7 @@ fn summary_updates_impl(
                 is_community_member,
                 state.data.is_public.value,
                 &state.data.members,
+                &state.data.personal_block_lists,
             ) {
                 ChannelUpdates::Added(s) => {
                     last_updated = max(last_updated, s.last_updated);
@@ -123,6 +133,12 @@ fn summary_updates_impl(
         }
     }
 
+    // The channels within each category are filtered to those visible to the user, so the categories must also be
+    // resent whenever the set of channels visible to the user changes
+    let categories_updated = state.data.channel_categories.last_updated() > updates_since
+        || !channels_added.is_empty()
+        || !channels_removed.is_empty();
+
     let name = state.data.name.if_set_after(updates_since).cloned();
     let description = state.data.description.if_set_after(updates_since).cloned();
     let is_public = state.data.is_public.if_set_after(updates_since).cloned();
@@ -174,6 +190,12 @@ fn summary_updates_impl(
                 None => OptionUpdate::SetToNone,
             }),
         lapsed: m.lapsed().if_set_after(updates_since).copied(),
+        achievements: state
+            .data
+            .community_achievements
+            .member_last_updated(&m.user_id)
+            .filter(|ts| *ts > updates_since)
+            .map(|_| state.data.community_achievements.awarded_ids(&m.user_id)),
     });
 
     Success(CommunityCanisterCommunitySummaryUpdates {
@@ -203,6 +225,7 @@ fn summary_updates_impl(
             .map(|u| u.into())
             .collect(),
         user_groups_deleted: state.data.members.user_groups_deleted_since(updates_since),
+        channel_categories: categories_updated.then(|| state.data.channel_categories(user_id)),
         metrics: state.data.cached_chat_metrics.if_set_after(updates_since).cloned(),
         verified: state.data.verified.if_set_after(updates_since).copied(),
     })
@@ -3,6 +3,7 @@ use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use community_canister::thread_previews::{Response::*, *};
 use group_chat_core::ThreadPreviewsResult;
+use types::DelegationScope;
 
 #[query(msgpack = true)]
 fn thread_previews(args: Args) -> Response {
@@ -14,7 +15,7 @@ fn thread_previews_impl(args: Args, state: &RuntimeState) -> Response {
         return ReplicaNotUpToDate(now);
     }
 
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
 
     if let Some(member) = state.data.members.get(caller) {
         let user_id = member.user_id;
@@ -23,7 +24,14 @@ fn thread_previews_impl(args: Args, state: &RuntimeState) -> Response {
             let now = state.env.now();
 
             match channel.chat.thread_previews(user_id, args.threads) {
-                ThreadPreviewsResult::Success(threads) => Success(SuccessResult { threads, timestamp: now }),
+                ThreadPreviewsResult::Success(mut threads) => {
+                    state
+                        .data
+                        .personal_block_lists
+                        .hide_blocked_users_in_thread_previews(&user_id, &mut threads);
+
+                    Success(SuccessResult { threads, timestamp: now })
+                }
                 ThreadPreviewsResult::UserNotInGroup => UserNotInChannel,
             }
         } else {
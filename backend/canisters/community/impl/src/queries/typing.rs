@@ -0,0 +1,48 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use community_canister::typing::{Response::*, *};
+
+const MAX_CHANNELS: usize = 100;
+
+#[query(msgpack = true)]
+fn typing(args: Args) -> Response {
+    read_state(|state| typing_impl(args, state))
+}
+
+fn typing_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(user_id) = state.data.members.get(caller).map(|m| m.user_id) else {
+        return UserNotInCommunity;
+    };
+
+    let now = state.env.now();
+    let result = args
+        .channels
+        .into_iter()
+        .take(MAX_CHANNELS)
+        .filter(|c| {
+            state
+                .data
+                .channels
+                .get(&c.channel_id)
+                .is_some_and(|channel| channel.chat.members.contains(&user_id))
+        })
+        .filter_map(|c| {
+            let user_ids: Vec<_> = state
+                .data
+                .typing
+                .get(user_id, &(c.channel_id, c.thread_root_message_index), now)
+                .into_iter()
+                .filter(|u| !state.data.personal_block_lists.has_blocked(&user_id, u))
+                .collect();
+
+            (!user_ids.is_empty()).then_some(UsersTyping {
+                channel_id: c.channel_id,
+                thread_root_message_index: c.thread_root_message_index,
+                user_ids,
+            })
+        })
+        .collect();
+
+    Success(result)
+}
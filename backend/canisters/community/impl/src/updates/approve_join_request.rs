@@ -0,0 +1,66 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::events::CommunityEventInternal;
+use crate::model::invited_users::UserInvitation;
+use crate::updates::c2c_invite_users::MAX_INVITES;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::approve_join_request::{Response::*, *};
+use types::UsersInvited;
+
+#[update(msgpack = true)]
+#[trace]
+fn approve_join_request(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| approve_join_request_impl(args, state))
+}
+
+fn approve_join_request_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_invite_users(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    if !state.data.join_requests.contains(&args.user_id) {
+        return RequestNotFound;
+    }
+    if state.data.invited_users.len() >= MAX_INVITES {
+        return TooManyInvites(MAX_INVITES as u32);
+    }
+
+    let now = state.env.now();
+    let request = state.data.join_requests.remove(&args.user_id, now).unwrap();
+
+    state.data.invited_users.add(
+        args.user_id,
+        UserInvitation {
+            invited_by: member.user_id,
+            timestamp: now,
+        },
+    );
+    state.data.members.add_user_id(request.principal, args.user_id);
+
+    state.data.events.push_event(
+        CommunityEventInternal::UsersInvited(Box::new(UsersInvited {
+            user_ids: vec![args.user_id],
+            invited_by: member.user_id,
+        })),
+        now,
+    );
+
+    handle_activity_notification(state);
+    Success
+}
@@ -0,0 +1,89 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::guards::caller_is_local_user_index;
+use crate::model::achievements::AwardResult;
+use crate::{mutate_state, run_regular_jobs, CallerResult, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::award_achievement::{Response::*, *};
+use community_canister::c2c_bot_award_achievement;
+use types::{BotCaller, BotPermissions, Caller, CommunityPermission};
+
+#[update(msgpack = true)]
+#[trace]
+fn award_achievement(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| award_achievement_impl(args, None, state))
+}
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_bot_award_achievement(args: c2c_bot_award_achievement::Args) -> c2c_bot_award_achievement::Response {
+    run_regular_jobs();
+
+    let bot_caller = BotCaller {
+        bot: args.bot_id,
+        initiator: args.initiator,
+    };
+
+    mutate_state(|state| {
+        award_achievement_impl(
+            Args {
+                achievement_id: args.achievement_id,
+                user_id: args.user_id,
+            },
+            Some(bot_caller),
+            state,
+        )
+    })
+    .into()
+}
+
+fn award_achievement_impl(args: Args, bot_caller: Option<BotCaller>, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = match state.verified_caller(bot_caller) {
+        CallerResult::Success(caller) => caller,
+        CallerResult::NotFound => return UserNotInCommunity,
+        CallerResult::Suspended => return UserSuspended,
+        CallerResult::Lapsed => return UserLapsed,
+    };
+
+    let is_permitted = match &caller {
+        Caller::BotV2(bot_caller) => state.data.is_bot_permitted(
+            &bot_caller.bot,
+            None,
+            &bot_caller.initiator,
+            BotPermissions::from_community_permission(CommunityPermission::ManageAchievements),
+        ),
+        _ => state
+            .data
+            .members
+            .get_by_user_id(&caller.agent())
+            .is_some_and(|m| m.role().can_manage_achievements(&state.data.permissions)),
+    };
+
+    if !is_permitted {
+        return NotAuthorized;
+    }
+
+    if !state.data.members.contains(&args.user_id) {
+        return TargetUserNotInCommunity;
+    }
+
+    let now = state.env.now();
+    match state
+        .data
+        .community_achievements
+        .award(args.achievement_id, args.user_id, Some(caller.agent()), now)
+    {
+        AwardResult::Success => {
+            handle_activity_notification(state);
+            Success
+        }
+        AwardResult::AchievementNotFound => AchievementNotFound,
+        AwardResult::AlreadyAwarded => AlreadyAwarded,
+    }
+}
@@ -9,7 +9,7 @@ use community_canister::c2c_invite_users::{Response::*, *};
 use itertools::Itertools;
 use types::UsersInvited;
 
-const MAX_INVITES: usize = 100;
+pub(crate) const MAX_INVITES: usize = 100;
 
 #[update(guard = "caller_is_user_index_or_local_user_index", msgpack = true)]
 #[trace]
@@ -36,7 +36,8 @@ pub(crate) fn invite_users_to_community_impl(args: Args, state: &mut RuntimeStat
             return NotAuthorized;
         }
 
-        // Filter out users who are already members and those who have already been invited
+        // Filter out users who are already members, those who have already been invited and those who have blocked the
+        // caller
         let invited_users: Vec<_> = args
             .users
             .iter()
@@ -45,6 +46,7 @@ pub(crate) fn invite_users_to_community_impl(args: Args, state: &mut RuntimeStat
                 state.data.members.get(*principal).is_none()
                     && !state.data.invited_users.contains(user_id)
                     && !state.data.members.is_blocked(user_id)
+                    && !state.data.personal_block_lists.has_blocked(user_id, &args.caller)
             })
             .copied()
             .collect();
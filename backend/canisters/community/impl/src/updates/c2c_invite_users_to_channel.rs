@@ -27,16 +27,17 @@ fn c2c_invite_users_to_channel_impl(args: Args, state: &mut RuntimeState) -> Res
 
         let mut users_to_invite_to_channel = Vec::new();
         let mut users_to_invite_to_community = Vec::new();
+        let mut failed_users = Vec::new();
         for (user_id, principal) in args.users {
-            if state.data.members.get_by_user_id(&user_id).is_some() || state.data.invited_users.contains(&user_id) {
+            if state.data.personal_block_lists.has_blocked(&user_id, &args.caller) {
+                failed_users.push(user_id);
+            } else if state.data.members.get_by_user_id(&user_id).is_some() || state.data.invited_users.contains(&user_id) {
                 users_to_invite_to_channel.push(user_id);
             } else {
                 users_to_invite_to_community.push((user_id, principal));
             }
         }
 
-        let mut failed_users = Vec::new();
-
         if !users_to_invite_to_community.is_empty() {
             if let community_canister::c2c_invite_users::Response::Success(r) = invite_users_to_community_impl(
                 community_canister::c2c_invite_users::Args {
@@ -16,7 +16,7 @@ use gated_groups::{
 use group_chat_core::{AddMemberSuccess, AddResult};
 use group_community_common::ExpiringMember;
 use types::{
-    AccessGateConfigInternal, ChannelId, MemberJoinedInternal, TimestampMillis, UniquePersonProof, UserId, UserType,
+    AccessGateConfigInternal, ChannelId, Chit, MemberJoinedInternal, TimestampMillis, UniquePersonProof, UserId, UserType,
     VerifiedCredentialGateArgs,
 };
 
@@ -46,6 +46,7 @@ async fn c2c_join_channel(args: Args) -> Response {
             diamond_membership_expires_at: args.diamond_membership_expires_at,
             verified_credential_args: args.verified_credential_args.clone(),
             unique_person_proof: args.unique_person_proof.clone(),
+            chit: args.chit.clone(),
         })
         .await
         {
@@ -79,6 +80,7 @@ pub(crate) fn join_channel_synchronously(
     user_principal: Principal,
     diamond_membership_expires_at: Option<TimestampMillis>,
     unique_person_proof: Option<UniquePersonProof>,
+    chit: Option<Chit>,
 ) {
     let is_unique_person = unique_person_proof.is_some();
 
@@ -89,6 +91,7 @@ pub(crate) fn join_channel_synchronously(
             diamond_membership_expires_at,
             unique_person_proof,
             None,
+            chit,
             state,
         )
     }) {
@@ -124,6 +127,7 @@ async fn check_gate_then_join_channel(args: &Args) -> Response {
             args.diamond_membership_expires_at,
             args.unique_person_proof.clone(),
             args.verified_credential_args.clone(),
+            args.chit.clone(),
             state,
         )
     }) {
@@ -154,6 +158,7 @@ fn is_permitted_to_join(
     diamond_membership_expires_at: Option<TimestampMillis>,
     unique_person_proof: Option<UniquePersonProof>,
     verified_credential_args: Option<VerifiedCredentialGateArgs>,
+    chit: Option<Chit>,
     state: &RuntimeState,
 ) -> Result<Option<(AccessGateConfigInternal, CheckGateArgs)>, Response> {
     if state.data.is_frozen() {
@@ -175,6 +180,7 @@ fn is_permitted_to_join(
                                 true,
                                 state.data.is_public.value,
                                 &state.data.members,
+                                &state.data.personal_block_lists,
                             )
                             .unwrap(),
                     )));
@@ -205,6 +211,10 @@ fn is_permitted_to_join(
                             ii_origin: vc.ii_origin,
                         }),
                         referred_by_member: false,
+                        chit,
+                        user_index_canister_id: state.data.user_index_canister_id,
+                        community_member_since: Some(member.date_added),
+                        community_message_count: state.data.community_message_count(&member.user_id),
                         now: state.env.now(),
                     },
                 )
@@ -246,7 +256,13 @@ fn commit(
     ) {
         AddResult::Success(result) => {
             let summary = channel
-                .summary(Some(user_id), true, state.data.is_public.value, &state.data.members)
+                .summary(
+                    Some(user_id),
+                    true,
+                    state.data.is_public.value,
+                    &state.data.members,
+                    &state.data.personal_block_lists,
+                )
                 .unwrap();
 
             if let Some(gate_expiry) = channel.chat.gate_config.value.as_ref().and_then(|gc| gc.expiry()) {
@@ -258,9 +274,7 @@ fn commit(
             }
 
             // If there is a payment gate on this channel then queue payments to *community* owner(s) and treasury
-            for payment in payments {
-                state.queue_access_gate_payments(payment);
-            }
+            state.handle_access_gate_payments(user_id, Some(channel_id), payments, now);
 
             if result.unlapse {
                 state.data.update_lapsed(user_id, Some(channel_id), false, now);
@@ -281,8 +295,19 @@ fn commit(
             channel.chat.members.update_lapsed(user_id, false, now);
 
             let summary = channel
-                .summary(Some(user_id), true, state.data.is_public.value, &state.data.members)
+                .summary(
+                    Some(user_id),
+                    true,
+                    state.data.is_public.value,
+                    &state.data.members,
+                    &state.data.personal_block_lists,
+                )
                 .unwrap();
+
+            // Lapsed members rejoining a payment or subscription gated channel pay again, so their payment
+            // is distributed (and recorded against their subscription) in the same way as for new members
+            state.handle_access_gate_payments(user_id, Some(channel_id), payments, now);
+
             AlreadyInChannel(Box::new(summary))
         }
         AddResult::Blocked => UserBlocked,
@@ -40,6 +40,7 @@ pub(crate) async fn join_community(args: Args) -> Response {
                     args.principal,
                     args.diamond_membership_expires_at,
                     args.unique_person_proof.clone(),
+                    args.chit.clone(),
                 );
             }
             read_state(|state| {
@@ -96,6 +97,10 @@ fn is_permitted_to_join(args: &Args, state: &RuntimeState) -> Result<Option<(Acc
                 referred_by_member: args
                     .referred_by
                     .is_some_and(|user_id| state.data.members.get_by_user_id(&user_id).is_some()),
+                chit: args.chit.clone(),
+                user_index_canister_id: state.data.user_index_canister_id,
+                community_member_since: None,
+                community_message_count: 0,
                 now: state.env.now(),
             },
         )
@@ -137,7 +142,7 @@ pub(crate) fn join_community_impl(
         .add(args.user_id, args.principal, args.user_type, referred_by, now);
 
     match result {
-        AddResult::Success(_) => {}
+        AddResult::Success(_) => state.data.analytics.record_join(args.user_id, now),
         AddResult::AlreadyInCommunity => {
             let member = state.data.members.get_by_user_id(&args.user_id).unwrap();
             if !member.lapsed().value {
@@ -149,15 +154,14 @@ pub(crate) fn join_community_impl(
     }
 
     state.data.invited_users.remove(&args.user_id, now);
+    state.data.join_requests.remove(&args.user_id, now);
 
     if matches!(result, AddResult::AlreadyInCommunity) {
         state.data.update_lapsed(args.user_id, None, false, now);
     }
 
     // If there is a payment gate on this community then queue payments to owner(s) and treasury
-    for payment in payments {
-        state.queue_access_gate_payments(payment);
-    }
+    state.handle_access_gate_payments(args.user_id, None, payments, now);
 
     if let Some(gate_expiry) = state.data.gate_config.value.as_ref().and_then(|gc| gc.expiry()) {
         state.data.expiring_members.push(ExpiringMember {
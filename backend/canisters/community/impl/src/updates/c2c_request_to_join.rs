@@ -0,0 +1,81 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::guards::caller_is_user_index_or_local_user_index;
+use crate::model::events::CommunityEventInternal;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::c2c_request_to_join::{Response::*, *};
+use group_community_common::AddJoinRequestError;
+use types::{CommunityJoinRequestedNotification, Document, JoinRequested, Notification};
+
+#[update(guard = "caller_is_user_index_or_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_request_to_join(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_request_to_join_impl(args, state))
+}
+
+fn c2c_request_to_join_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    if state.data.members.contains(&args.user_id) {
+        return AlreadyInCommunity;
+    }
+    if state.data.members.is_blocked(&args.user_id) {
+        return UserBlocked;
+    }
+    if state.data.invited_users.contains(&args.user_id) {
+        return AlreadyInvited;
+    }
+
+    let now = state.env.now();
+
+    match state.data.join_requests.add(args.user_id, args.principal, args.answer, now) {
+        Ok(()) => {
+            state.data.events.push_event(
+                CommunityEventInternal::JoinRequested(Box::new(JoinRequested { user_id: args.user_id })),
+                now,
+            );
+
+            // Only notify when the first request arrives, any further requests will be seen by the
+            // approvers when they go to review the pending requests
+            if state.data.join_requests.len() == 1 {
+                let recipients: Vec<_> = state
+                    .data
+                    .members
+                    .owners()
+                    .iter()
+                    .chain(state.data.members.admins().iter())
+                    .filter(|u| {
+                        state
+                            .data
+                            .members
+                            .get_by_user_id(u)
+                            .is_some_and(|m| !m.lapsed().value && m.role().can_invite_users(&state.data.permissions))
+                    })
+                    .copied()
+                    .collect();
+
+                let notification = Notification::CommunityJoinRequested(CommunityJoinRequestedNotification {
+                    community_id: state.env.canister_id().into(),
+                    community_name: state.data.name.value.clone(),
+                    requested_by: args.user_id,
+                    requested_by_name: args.username,
+                    community_avatar_id: Document::id(&state.data.avatar),
+                });
+                state.push_notification(Some(args.user_id), recipients, notification);
+            }
+
+            handle_activity_notification(state);
+            Success
+        }
+        Err(AddJoinRequestError::AlreadyRequested) => AlreadyRequested,
+        Err(AddJoinRequestError::RecentlyRejected(until)) => RecentlyRejected(until),
+        Err(AddJoinRequestError::TooManyRequests(max)) => TooManyRequests(max),
+        Err(AddJoinRequestError::RateLimited(until)) => RateLimited(until),
+        Err(AddJoinRequestError::AnswerTooLong(max)) => AnswerTooLong(max),
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::c2c_update_blocked_users::{Response::*, *};
+
+// Called via the user's user canister
+#[update(msgpack = true)]
+#[trace]
+fn c2c_update_blocked_users(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_update_blocked_users_impl(args, state))
+}
+
+fn c2c_update_blocked_users_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let Some(member) = state.data.members.get(state.env.caller()) else {
+        return UserNotInCommunity;
+    };
+
+    let user_id = member.user_id;
+    state.data.personal_block_lists.update(user_id, args.blocked, args.unblocked);
+
+    Success
+}
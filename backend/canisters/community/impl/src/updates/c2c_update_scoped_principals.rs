@@ -0,0 +1,25 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::c2c_update_scoped_principals::{Response::*, *};
+
+// Called via the user's user canister
+#[update(msgpack = true)]
+#[trace]
+fn c2c_update_scoped_principals(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_update_scoped_principals_impl(args, state))
+}
+
+fn c2c_update_scoped_principals_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let Some(member) = state.data.members.get(state.env.caller()) else {
+        return UserNotInCommunity;
+    };
+
+    let user_id = member.user_id;
+    let now = state.env.now();
+    state.data.scoped_principals.update(user_id, args.principals, now);
+
+    Success
+}
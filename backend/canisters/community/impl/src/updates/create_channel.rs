@@ -59,6 +59,7 @@ fn c2c_create_proposals_channel(args: Args) -> Response {
                 diamond_membership_expires_at: None,
                 verified_credential_args: None,
                 unique_person_proof: None,
+                chit: None,
             },
             Vec::new(),
             state,
@@ -169,8 +170,21 @@ fn create_channel_impl(
         return NameTaken;
     }
 
+    if let Some(category_id) = args.category_id {
+        if !state.data.channel_categories.exists(category_id) {
+            return CategoryNotFound(category_id);
+        }
+    }
+
     let now = state.env.now();
-    let permissions = args.permissions_v2.unwrap_or_default();
+    let permissions = args
+        .permissions_v2
+        .or_else(|| {
+            args.category_id
+                .and_then(|id| state.data.channel_categories.default_permissions(id))
+                .cloned()
+        })
+        .unwrap_or_default();
 
     let chat = GroupChatCore::new(
         MultiUserChat::Channel(state.env.canister_id().into(), channel_id),
@@ -201,6 +215,10 @@ fn create_channel_impl(
         bot_api_keys: Default::default(),
     });
 
+    if let Some(category_id) = args.category_id {
+        state.data.channel_categories.add_channel(category_id, channel_id, now);
+    }
+
     if args.is_public && args.gate_config.is_none() {
         JoinMembersToPublicChannelJob {
             channel_id,
@@ -0,0 +1,41 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::delete_achievement::{Response::*, *};
+
+#[update(msgpack = true)]
+#[trace]
+fn delete_achievement(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| delete_achievement_impl(args, state))
+}
+
+// Deleting an achievement also removes it from every member it was awarded to
+fn delete_achievement_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_manage_achievements(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    let now = state.env.now();
+    if state.data.community_achievements.delete(args.achievement_id, now) {
+        handle_activity_notification(state);
+        Success
+    } else {
+        AchievementNotFound
+    }
+}
@@ -76,6 +76,8 @@ fn delete_channel_impl(channel_id: ChannelId, bot_caller: Option<BotCaller>, sta
 
     let now = state.env.now();
     let channel = state.data.channels.delete(channel_id, now).expect("Channel should exist");
+    state.data.channel_categories.remove_channel(channel_id, now);
+    state.data.permission_templates.unlink(&[channel_id], now);
 
     state
         .data
@@ -116,6 +118,7 @@ fn delete_channel_impl(channel_id: ChannelId, bot_caller: Option<BotCaller>, sta
     if channel.chat.gate_config.value.is_some_and(|gc| gc.expiry.is_some()) {
         state.data.expiring_members.remove_gate(Some(channel_id));
     }
+    state.data.subscriptions.remove_gate(Some(channel_id));
 
     handle_activity_notification(state);
 
@@ -0,0 +1,40 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::delete_permission_template::{Response::*, *};
+
+#[update(msgpack = true)]
+#[trace]
+fn delete_permission_template(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| delete_permission_template_impl(args, state))
+}
+
+// Channels linked to the template keep their current permissions
+fn delete_permission_template_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_change_permissions() {
+        return NotAuthorized;
+    }
+
+    if state.data.permission_templates.delete(args.template_id) {
+        handle_activity_notification(state);
+        Success
+    } else {
+        TemplateNotFound
+    }
+}
@@ -0,0 +1,50 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::events::CommunityEventInternal;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::reject_join_request::{Response::*, *};
+use types::JoinRequestRejected;
+
+#[update(msgpack = true)]
+#[trace]
+fn reject_join_request(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| reject_join_request_impl(args, state))
+}
+
+fn reject_join_request_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_invite_users(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    let now = state.env.now();
+    if state.data.join_requests.reject(&args.user_id, now).is_none() {
+        return RequestNotFound;
+    }
+
+    state.data.events.push_event(
+        CommunityEventInternal::JoinRequestRejected(Box::new(JoinRequestRejected {
+            user_id: args.user_id,
+            rejected_by: member.user_id,
+        })),
+        now,
+    );
+
+    handle_activity_notification(state);
+    Success
+}
@@ -0,0 +1,47 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::achievements::RevokeResult;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::revoke_achievement::{Response::*, *};
+
+#[update(msgpack = true)]
+#[trace]
+fn revoke_achievement(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| revoke_achievement_impl(args, state))
+}
+
+fn revoke_achievement_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_manage_achievements(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    let now = state.env.now();
+    match state
+        .data
+        .community_achievements
+        .revoke(args.achievement_id, args.user_id, now)
+    {
+        RevokeResult::Success => {
+            handle_activity_notification(state);
+            Success
+        }
+        RevokeResult::AchievementNotFound => AchievementNotFound,
+        RevokeResult::NotAwarded => NotAwarded,
+    }
+}
@@ -11,6 +11,7 @@ use community_canister::c2c_bot_send_message;
 use community_canister::c2c_send_message::{Args as C2CArgs, Response as C2CResponse};
 use community_canister::send_message::{Response::*, *};
 use group_chat_core::SendMessageResult;
+use group_community_common::PersonalBlockLists;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use rand::RngCore;
@@ -18,8 +19,8 @@ use regex_lite::Regex;
 use std::str::FromStr;
 use types::{
     Achievement, BotCaller, BotPermissions, Caller, ChannelId, ChannelMessageNotification, Chat, ContentValidationError,
-    EventIndex, EventWrapper, IdempotentEnvelope, Message, MessageContent, MessageIndex, Notification, TimestampMillis, User,
-    UserId, Version,
+    DelegationScope, EventIndex, EventWrapper, IdempotentEnvelope, Message, MessageContent, MessageIndex, Notification,
+    TimestampMillis, User, UserId, Version,
 };
 use user_canister::{CommunityCanisterEvent, MessageActivity, MessageActivityEvent};
 
@@ -66,7 +67,7 @@ fn c2c_bot_send_message(args: c2c_bot_send_message::Args) -> c2c_bot_send_messag
 }
 
 pub(crate) fn send_message_impl(args: Args, bot: Option<BotCaller>, finalised: bool, state: &mut RuntimeState) -> Response {
-    let caller = match state.verified_caller(bot) {
+    let caller = match state.verified_caller_with_scope(bot, Some(DelegationScope::SendMessages)) {
         CallerResult::Success(caller) => caller,
         CallerResult::NotFound => return UserNotInCommunity,
         CallerResult::Suspended => return UserSuspended,
@@ -98,7 +99,13 @@ pub(crate) fn send_message_impl(args: Args, bot: Option<BotCaller>, finalised: b
             return NotAuthorized;
         }
 
-        let users_mentioned = extract_users_mentioned(args.mentioned, content.text(), &state.data.members);
+        let users_mentioned = extract_users_mentioned(
+            args.mentioned,
+            content.text(),
+            caller.initiator(),
+            &state.data.members,
+            &state.data.personal_block_lists,
+        );
 
         let result = channel.chat.send_message(
             &caller,
@@ -155,7 +162,13 @@ fn c2c_send_message_impl(args: C2CArgs, state: &mut RuntimeState) -> C2CResponse
 
     if let Some(channel) = state.data.channels.get_mut(&args.channel_id) {
         let now = state.env.now();
-        let users_mentioned = extract_users_mentioned(args.mentioned, args.content.text(), &state.data.members);
+        let users_mentioned = extract_users_mentioned(
+            args.mentioned,
+            args.content.text(),
+            caller.initiator(),
+            &state.data.members,
+            &state.data.personal_block_lists,
+        );
 
         let result = channel.chat.send_message(
             &caller,
@@ -448,13 +461,21 @@ struct UsersMentioned {
     user_groups_mentioned: Vec<(u32, String)>,
 }
 
-fn extract_users_mentioned(mentioned: Vec<User>, text: Option<&str>, members: &CommunityMembers) -> UsersMentioned {
+// Users who have blocked the sender are excluded from `all_users_mentioned` so that they don't receive the mention
+fn extract_users_mentioned(
+    mentioned: Vec<User>,
+    text: Option<&str>,
+    sender: Option<UserId>,
+    members: &CommunityMembers,
+    personal_block_lists: &PersonalBlockLists,
+) -> UsersMentioned {
     let user_groups_mentioned = extract_user_groups_mentioned(text, members);
     let all_users_mentioned = mentioned
         .iter()
         .map(|u| u.user_id)
         .chain(user_groups_mentioned.iter().flat_map(|ug| ug.members.value.iter().copied()))
         .unique()
+        .filter(|u| sender.is_none_or(|s| !personal_block_lists.has_blocked(u, &s)))
         .collect();
 
     UsersMentioned {
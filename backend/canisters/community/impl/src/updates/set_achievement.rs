@@ -0,0 +1,84 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{jobs, mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::set_achievement::{Response::*, *};
+use utils::document::validate_avatar;
+use utils::text_validation::{validate_achievement_description, validate_achievement_name, StringLengthValidationError};
+
+const MAX_ACHIEVEMENTS: u32 = 100;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_achievement(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| set_achievement_impl(args, state))
+}
+
+fn set_achievement_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_manage_achievements(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    let name = args.name.trim().to_string();
+
+    if let Err(error) = validate_achievement_name(&name) {
+        return match error {
+            StringLengthValidationError::TooShort(s) => NameTooShort(s),
+            StringLengthValidationError::TooLong(l) => NameTooLong(l),
+        };
+    }
+
+    if let Err(error) = validate_achievement_description(&args.description) {
+        return DescriptionTooLong(error);
+    }
+
+    if let Err(error) = validate_avatar(args.image.as_ref()) {
+        return ImageTooBig(error);
+    }
+
+    if state.data.community_achievements.is_name_taken(&name, args.achievement_id) {
+        return NameTaken;
+    }
+
+    let now = state.env.now();
+
+    let achievement_id = if let Some(achievement_id) = args.achievement_id {
+        if !state
+            .data
+            .community_achievements
+            .update(achievement_id, name, args.description, args.image, args.criteria, now)
+        {
+            return AchievementNotFound;
+        }
+        achievement_id
+    } else {
+        if state.data.community_achievements.len() as u32 >= MAX_ACHIEVEMENTS {
+            return TooManyAchievements(MAX_ACHIEVEMENTS);
+        }
+
+        state
+            .data
+            .community_achievements
+            .create(name, args.description, args.image, args.criteria, state.env.rng(), now)
+    };
+
+    jobs::award_community_achievements::start_job_if_required(state);
+    handle_activity_notification(state);
+
+    Success(SuccessResult { achievement_id })
+}
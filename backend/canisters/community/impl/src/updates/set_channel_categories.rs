@@ -0,0 +1,110 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::channel_categories::CategoryUpdate;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::set_channel_categories::{Response::*, *};
+use std::collections::HashSet;
+use utils::text_validation::{validate_channel_category_name, StringLengthValidationError};
+
+const MAX_CATEGORIES: u32 = 50;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_channel_categories(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| set_channel_categories_impl(args, state))
+}
+
+fn set_channel_categories_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_update_details(&state.data.permissions) {
+        return NotAuthorized;
+    }
+
+    if args.categories.len() as u32 > MAX_CATEGORIES {
+        return TooManyCategories(MAX_CATEGORIES);
+    }
+
+    let user_id = member.user_id;
+    let mut names = HashSet::new();
+    let mut channel_ids = HashSet::new();
+
+    for category in args.categories.iter() {
+        if let Err(error) = validate_channel_category_name(&category.name) {
+            return match error {
+                StringLengthValidationError::TooShort(s) => NameTooShort(s),
+                StringLengthValidationError::TooLong(l) => NameTooLong(l),
+            };
+        }
+        if !names.insert(category.name.to_lowercase()) {
+            return NameTaken(category.name.clone());
+        }
+        if let Some(id) = category.id {
+            if !state.data.channel_categories.exists(id) {
+                return CategoryNotFound(id);
+            }
+        }
+        if let Some(permissions) = &category.default_permissions {
+            if !permissions.validate() {
+                return InvalidDefaultPermissions(category.name.clone());
+            }
+            // Default permissions are applied to new channels, so setting them requires permission to change permissions
+            let unchanged = category
+                .id
+                .is_some_and(|id| state.data.channel_categories.default_permissions(id) == Some(permissions));
+            if !unchanged && !member.role().can_change_permissions() {
+                return NotAuthorized;
+            }
+        }
+        for channel_id in category.channel_ids.iter() {
+            if !state.data.is_channel_visible(channel_id, Some(user_id)) {
+                return ChannelNotFound(*channel_id);
+            }
+            if !channel_ids.insert(*channel_id) {
+                return ChannelInMultipleCategories(*channel_id);
+            }
+        }
+    }
+
+    let updates = args
+        .categories
+        .into_iter()
+        .map(|c| CategoryUpdate {
+            id: c.id,
+            name: c.name,
+            channel_ids: c.channel_ids,
+            collapsed_by_default: c.collapsed_by_default,
+            default_permissions: c.default_permissions,
+        })
+        .collect();
+
+    let now = state.env.now();
+    let mut channel_categories = std::mem::take(&mut state.data.channel_categories);
+    channel_categories.set(
+        updates,
+        |channel_id| state.data.is_channel_visible(channel_id, Some(user_id)),
+        state.env.rng(),
+        now,
+    );
+    state.data.channel_categories = channel_categories;
+
+    handle_activity_notification(state);
+
+    Success(SuccessResult {
+        categories: state.data.channel_categories(Some(user_id)),
+    })
+}
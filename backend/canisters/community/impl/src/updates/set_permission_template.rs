@@ -0,0 +1,104 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::set_permission_template::{Response::*, *};
+use utils::text_validation::{validate_permission_template_name, StringLengthValidationError};
+
+const MAX_TEMPLATES: u32 = 50;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_permission_template(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| set_permission_template_impl(args, state))
+}
+
+fn set_permission_template_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_change_permissions() {
+        return NotAuthorized;
+    }
+
+    let user_id = member.user_id;
+    let name = args.name.trim().to_string();
+
+    if let Err(error) = validate_permission_template_name(&name) {
+        return match error {
+            StringLengthValidationError::TooShort(s) => NameTooShort(s),
+            StringLengthValidationError::TooLong(l) => NameTooLong(l),
+        };
+    }
+
+    if state.data.permission_templates.is_name_taken(&name, args.template_id) {
+        return NameTaken;
+    }
+
+    if !args.permissions.validate() {
+        return InvalidPermissions;
+    }
+
+    let now = state.env.now();
+
+    let (template_id, channels_updated) = if let Some(template_id) = args.template_id {
+        // Updating a template applies its permissions to each linked channel, so the caller must be a member of each
+        if let Some(channel_id) = state.data.permission_templates.channel_ids(template_id).and_then(|ids| {
+            ids.iter().find(|id| {
+                state
+                    .data
+                    .channels
+                    .get(id)
+                    .is_some_and(|c| c.chat.members.get(&user_id).is_none())
+            })
+        }) {
+            return UserNotInChannel(*channel_id);
+        }
+
+        let Some(channel_ids) = state
+            .data
+            .permission_templates
+            .update(template_id, name, args.permissions.clone(), now)
+        else {
+            return TemplateNotFound;
+        };
+
+        for channel_id in channel_ids.iter() {
+            if let Some(channel) = state.data.channels.get_mut(channel_id) {
+                channel.chat.set_permissions(user_id, args.permissions.clone(), now);
+            }
+        }
+
+        (template_id, channel_ids)
+    } else {
+        if state.data.permission_templates.len() as u32 >= MAX_TEMPLATES {
+            return TooManyTemplates(MAX_TEMPLATES);
+        }
+
+        let template_id = state
+            .data
+            .permission_templates
+            .create(name, args.permissions, state.env.rng(), now);
+
+        (template_id, Vec::new())
+    };
+
+    handle_activity_notification(state);
+
+    Success(SuccessResult {
+        template_id,
+        channels_updated,
+    })
+}
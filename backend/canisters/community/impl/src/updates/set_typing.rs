@@ -0,0 +1,46 @@
+use crate::{mutate_state, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::set_typing::{Response::*, *};
+use group_chat_core::VerifyMemberError;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_typing(args: Args) -> Response {
+    mutate_state(|state| set_typing_impl(args, state))
+}
+
+fn set_typing_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let user_id = match state.data.members.get(caller) {
+        Some(member) if member.suspended().value => return UserSuspended,
+        Some(member) if member.lapsed().value => return UserLapsed,
+        Some(member) => member.user_id,
+        None => return UserNotInCommunity,
+    };
+
+    let Some(channel) = state.data.channels.get(&args.channel_id) else {
+        return ChannelNotFound;
+    };
+
+    match channel.chat.members.get_verified_member(user_id) {
+        Ok(_) => {}
+        Err(VerifyMemberError::NotFound) => return UserNotInChannel,
+        Err(VerifyMemberError::Lapsed) => return UserLapsed,
+        Err(VerifyMemberError::Suspended) => return UserSuspended,
+    }
+
+    let now = state.env.now();
+    state.data.typing.set(
+        user_id,
+        (args.channel_id, args.thread_root_message_index),
+        args.is_typing,
+        now,
+    );
+
+    Success
+}
@@ -47,8 +47,10 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> Response {
         if let Some(member) = state.data.members.get(caller) {
             let now = state.env.now();
             let has_gate_config_updates = args.gate_config.has_update();
+            let has_permissions_updates = args.permissions_v2.is_some();
 
             let prev_gate_config = channel.chat.gate_config.value.clone();
+            let was_public = channel.chat.is_public.value;
 
             match channel.chat.update(
                 member.user_id,
@@ -65,6 +67,8 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> Response {
                 now,
             ) {
                 UpdateResult::Success(result) => {
+                    let visibility_changed = channel.chat.is_public.value != was_public;
+
                     if channel.chat.is_public.value && channel.chat.gate_config.is_none() {
                         // If the channel has just been made public or had its gate removed, add
                         // all existing community members to the channel, except those who have
@@ -84,11 +88,20 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> Response {
                         }
                     }
 
+                    if visibility_changed {
+                        state.data.channel_categories.mark_channel_updated(args.channel_id, now);
+                    }
+
                     if has_gate_config_updates {
                         state.data.update_member_expiry(Some(args.channel_id), &prev_gate_config, now);
                         jobs::expire_members::restart_job(state);
                     }
 
+                    if has_permissions_updates {
+                        // The channel's permissions have diverged from its template so unlink it
+                        state.data.permission_templates.unlink(&[args.channel_id], now);
+                    }
+
                     handle_activity_notification(state);
 
                     SuccessV2(SuccessResult {
@@ -0,0 +1,101 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::update_channel_permissions::{Response::*, *};
+use group_chat_core::GroupChatCore;
+
+const MAX_CHANNELS: u32 = 100;
+
+#[update(msgpack = true)]
+#[trace]
+fn update_channel_permissions(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| update_channel_permissions_impl(args, state))
+}
+
+fn update_channel_permissions_impl(mut args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.members.get(caller) else {
+        return UserNotInCommunity;
+    };
+
+    if member.suspended().value {
+        return UserSuspended;
+    } else if member.lapsed().value {
+        return UserLapsed;
+    } else if !member.role().can_change_permissions() {
+        return NotAuthorized;
+    }
+
+    args.channel_ids.sort();
+    args.channel_ids.dedup();
+
+    if args.channel_ids.len() as u32 > MAX_CHANNELS {
+        return TooManyChannels(MAX_CHANNELS);
+    }
+
+    let user_id = member.user_id;
+
+    // The caller must be a member of each channel whose permissions they change
+    for channel_id in args.channel_ids.iter() {
+        let Some(channel) = state.data.channels.get(channel_id) else {
+            return ChannelNotFound(*channel_id);
+        };
+        if channel.chat.members.get(&user_id).is_none() {
+            return UserNotInChannel(*channel_id);
+        }
+    }
+
+    let now = state.env.now();
+
+    match args.update {
+        PermissionsUpdate::Template(template_id) => {
+            let Some(permissions) = state
+                .data
+                .permission_templates
+                .link(template_id, &args.channel_ids, now)
+                .cloned()
+            else {
+                return TemplateNotFound;
+            };
+
+            for channel_id in args.channel_ids.iter() {
+                if let Some(channel) = state.data.channels.get_mut(channel_id) {
+                    channel.chat.set_permissions(user_id, permissions.clone(), now);
+                }
+            }
+        }
+        PermissionsUpdate::Permissions(permissions) => {
+            // Merge the changes into each channel's current permissions up front so that nothing
+            // is applied unless the result is valid for every channel
+            let mut merged = Vec::with_capacity(args.channel_ids.len());
+            for channel_id in args.channel_ids.iter() {
+                if let Some(channel) = state.data.channels.get(channel_id) {
+                    let new_permissions =
+                        GroupChatCore::merge_permissions(permissions.clone(), channel.chat.permissions.value.clone());
+                    if !new_permissions.validate() {
+                        return InvalidPermissions;
+                    }
+                    merged.push((*channel_id, new_permissions));
+                }
+            }
+
+            state.data.permission_templates.unlink(&args.channel_ids, now);
+
+            for (channel_id, new_permissions) in merged {
+                if let Some(channel) = state.data.channels.get_mut(&channel_id) {
+                    channel.chat.set_permissions(user_id, new_permissions, now);
+                }
+            }
+        }
+    }
+
+    handle_activity_notification(state);
+    Success
+}
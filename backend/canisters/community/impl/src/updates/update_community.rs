@@ -8,9 +8,9 @@ use community_canister::update_community::{Response::*, *};
 use group_index_canister::{c2c_make_community_private, c2c_update_community};
 use tracing::error;
 use types::{
-    AccessGateConfigInternal, AvatarChanged, BannerChanged, CanisterId, CommunityId, CommunityPermissions,
+    normalise_languages, AccessGateConfigInternal, AvatarChanged, BannerChanged, CanisterId, CommunityId, CommunityPermissions,
     CommunityPermissionsChanged, CommunityVisibilityChanged, Document, GroupDescriptionChanged, GroupNameChanged,
-    GroupRulesChanged, OptionUpdate, OptionalCommunityPermissions, PrimaryLanguageChanged, Timestamped, UserId,
+    GroupRulesChanged, OptionUpdate, OptionalCommunityPermissions, PrimaryLanguageChanged, Tag, Timestamped, UserId, MAX_TAGS,
 };
 use utils::document::{validate_avatar, validate_banner};
 use utils::text_validation::{
@@ -56,7 +56,9 @@ async fn update_community(mut args: Args) -> Response {
             || args.banner.has_update()
             || args.gate_config.has_update()
             || args.public == Some(true)
-            || args.primary_language.is_some())
+            || args.primary_language.is_some()
+            || args.tags.is_some()
+            || args.languages.is_some())
     {
         let c2c_update_community_args = c2c_update_community::Args {
             name: prepare_result.name,
@@ -66,6 +68,8 @@ async fn update_community(mut args: Args) -> Response {
             gate_config: prepare_result.gate_config.map(|gc| gc.into()),
             primary_language: prepare_result.primary_language,
             channel_count: prepare_result.channel_count,
+            tags: prepare_result.tags,
+            languages: prepare_result.languages,
         };
 
         match group_index_canister_c2c_client::c2c_update_community(group_index_canister_id, &c2c_update_community_args).await {
@@ -105,6 +109,8 @@ struct PrepareResult {
     gate_config: Option<AccessGateConfigInternal>,
     primary_language: String,
     channel_count: u32,
+    tags: Option<Vec<Tag>>,
+    languages: Option<Vec<String>>,
 }
 
 fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response> {
@@ -113,7 +119,7 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
     }
 
     if let OptionUpdate::SetToSome(gate_config) = &args.gate_config {
-        if !gate_config.validate(state.data.test_mode) {
+        if !gate_config.validate(state.data.test_mode) || gate_config.gate.requires_community_membership() {
             return Err(AccessGateInvalid);
         }
     }
@@ -166,6 +172,18 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
         }
     }
 
+    let tags = match args.tags.clone().map(Tag::normalise) {
+        Some(None) => return Err(TooManyTags(MAX_TAGS as u32)),
+        Some(Some(tags)) => Some(tags),
+        None => None,
+    };
+
+    let languages = match args.languages.clone().map(normalise_languages) {
+        Some(None) => return Err(InvalidLanguage),
+        Some(Some(languages)) => Some(languages),
+        None => None,
+    };
+
     if let Some(member) = state.data.members.get(caller) {
         if member.suspended().value {
             return Err(UserSuspended);
@@ -177,6 +195,7 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
         if !member.role().can_update_details(permissions)
             || (args.permissions.is_some() && !member.role().can_change_permissions())
             || (args.public.is_some() && !member.role().can_change_community_visibility())
+            || ((args.tags.is_some() || args.languages.is_some()) && !member.role().is_owner())
         {
             Err(NotAuthorized)
         } else {
@@ -192,6 +211,8 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
                 gate_config,
                 primary_language: args.primary_language.as_ref().unwrap_or(&state.data.primary_language).clone(),
                 channel_count: state.data.channels.public_channel_ids().len() as u32,
+                tags,
+                languages,
             })
         }
     } else {
@@ -401,5 +422,6 @@ fn merge_permissions(new: OptionalCommunityPermissions, old: &CommunityPermissio
         create_public_channel: new.create_public_channel.unwrap_or(old.create_public_channel),
         create_private_channel: new.create_private_channel.unwrap_or(old.create_private_channel),
         manage_user_groups: new.manage_user_groups.unwrap_or(old.manage_user_groups),
+        manage_achievements: new.manage_achievements.unwrap_or(old.manage_achievements),
     }
 }
@@ -65,21 +65,29 @@ fn main() {
     generate_ts_method!(group, events);
     generate_ts_method!(group, events_by_index);
     generate_ts_method!(group, events_window);
+    generate_ts_method!(group, export_events);
+    generate_ts_method!(group, export_members);
     generate_ts_method!(group, invite_code);
+    generate_ts_method!(group, join_requests);
     generate_ts_method!(group, local_user_index);
+    generate_ts_method!(group, message_edit_history);
+    generate_ts_method!(group, message_read_by);
     generate_ts_method!(group, messages_by_message_index);
     generate_ts_method!(group, thread_previews);
+    generate_ts_method!(group, typing);
     generate_ts_method!(group, public_summary);
     generate_ts_method!(group, rules);
     generate_ts_method!(group, search_messages);
     generate_ts_method!(group, selected_initial);
     generate_ts_method!(group, selected_updates_v2);
+    generate_ts_method!(group, subscription_payments);
     generate_ts_method!(group, summary);
     generate_ts_method!(group, summary_updates);
     generate_ts_method!(group, video_call_participants);
 
     generate_ts_method!(group, accept_p2p_swap);
     generate_ts_method!(group, add_reaction);
+    generate_ts_method!(group, approve_join_request);
     generate_ts_method!(group, block_user);
     generate_ts_method!(group, cancel_invites);
     generate_ts_method!(group, cancel_p2p_swap);
@@ -93,16 +101,21 @@ fn main() {
     generate_ts_method!(group, enable_invite_code);
     generate_ts_method!(group, follow_thread);
     generate_ts_method!(group, generate_bot_api_key);
+    generate_ts_method!(group, import_archived_events);
     generate_ts_method!(group, join_video_call);
+    generate_ts_method!(group, mark_read_up_to);
     generate_ts_method!(group, pin_message_v2);
     generate_ts_method!(group, register_poll_vote);
     generate_ts_method!(group, register_proposal_vote);
     generate_ts_method!(group, register_proposal_vote_v2);
+    generate_ts_method!(group, reject_join_request);
     generate_ts_method!(group, remove_participant);
     generate_ts_method!(group, remove_reaction);
     generate_ts_method!(group, report_message);
     generate_ts_method!(group, reset_invite_code);
     generate_ts_method!(group, send_message_v2);
+    generate_ts_method!(group, set_read_receipts_enabled);
+    generate_ts_method!(group, set_typing);
     generate_ts_method!(group, set_video_call_presence);
     generate_ts_method!(group, toggle_mute_notifications);
     generate_ts_method!(group, unblock_user);
@@ -0,0 +1,19 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChatArchiveCursor, ChatArchiveEventsPage};
+
+#[ts_export(group, export_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub after: Option<ChatArchiveCursor>,
+    pub max_events: u32,
+}
+
+#[ts_export(group, export_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatArchiveEventsPage),
+    NotAuthorized,
+    CallerNotInGroup,
+}
@@ -0,0 +1,19 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChatArchiveMembersPage, UserId};
+
+#[ts_export(group, export_members)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub after: Option<UserId>,
+    pub max_members: u32,
+}
+
+#[ts_export(group, export_members)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatArchiveMembersPage),
+    NotAuthorized,
+    CallerNotInGroup,
+}
@@ -0,0 +1,29 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageId, MessageIndex, MessageVersion};
+
+#[ts_export(group, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+}
+
+#[ts_export(group, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    CallerNotInGroup,
+    ThreadNotFound,
+    MessageNotFound,
+    UserSuspended,
+    UserLapsed,
+}
+
+#[ts_export(group, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // The previous versions of the message, oldest first
+    pub versions: Vec<MessageVersion>,
+}
@@ -0,0 +1,25 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageIndex, UserId};
+
+#[ts_export(group, message_read_by)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub message_index: MessageIndex,
+}
+
+#[ts_export(group, message_read_by)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    ReadReceiptsDisabled,
+    CallerNotInGroup,
+    MessageNotFound,
+}
+
+#[ts_export(group, message_read_by)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub read_by: Vec<UserId>,
+}
@@ -14,15 +14,22 @@ pub mod deleted_message;
 pub mod events;
 pub mod events_by_index;
 pub mod events_window;
+pub mod export_events;
+pub mod export_members;
 pub mod invite_code;
+pub mod join_requests;
 pub mod local_user_index;
+pub mod message_edit_history;
+pub mod message_read_by;
 pub mod messages_by_message_index;
 pub mod public_summary;
 pub mod rules;
 pub mod search_messages;
 pub mod selected_initial;
 pub mod selected_updates_v2;
+pub mod subscription_payments;
 pub mod summary;
 pub mod summary_updates;
 pub mod thread_previews;
+pub mod typing;
 pub mod video_call_participants;
@@ -0,0 +1,26 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{SubscriptionPayment, TimestampMillis, UserId};
+
+#[ts_export(group, subscription_payments)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // Only owners can view the payments of other members. Defaults to the caller.
+    pub user_id: Option<UserId>,
+}
+
+#[ts_export(group, subscription_payments)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    CallerNotInGroup,
+    NotAuthorized,
+}
+
+#[ts_export(group, subscription_payments)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub paid_until: Option<TimestampMillis>,
+    pub payments: Vec<SubscriptionPayment>,
+}
@@ -0,0 +1,25 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageIndex, UserId};
+
+#[ts_export(group, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_indexes: Vec<Option<MessageIndex>>,
+}
+
+// Only the main chat / threads in which someone other than the caller is typing are included
+#[ts_export(group, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<UsersTyping>),
+    CallerNotInGroup,
+}
+
+#[ts_export(group, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct UsersTyping {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub user_ids: Vec<UserId>,
+}
@@ -0,0 +1,25 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+use types::{TimestampMillis, UserId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub principal: Principal,
+    pub username: String,
+    pub answer: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AlreadyInGroup,
+    AlreadyInvited,
+    AlreadyRequested,
+    RecentlyRejected(TimestampMillis),
+    UserBlocked,
+    TooManyRequests(u32),
+    RateLimited(TimestampMillis),
+    AnswerTooLong(u32),
+    ChatFrozen,
+}
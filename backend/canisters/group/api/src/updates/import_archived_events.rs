@@ -0,0 +1,35 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::ArchivedEvent;
+
+#[ts_export(group, import_archived_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // None to start a new import, otherwise the session returned when the import was started
+    pub session_id: Option<u32>,
+    pub events: Vec<ArchivedEvent>,
+    pub format_version: u32,
+    pub finished: bool,
+}
+
+#[ts_export(group, import_archived_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UnsupportedFormatVersion(u32),
+    TooManyEvents(u32),
+    SessionNotFound,
+    NotAuthorized,
+    CallerNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    ChatFrozen,
+}
+
+#[ts_export(group, import_archived_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub session_id: u32,
+    pub messages_imported: u32,
+}
@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::MessageIndex;
+
+#[ts_export(group, mark_read_up_to)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub read_up_to: MessageIndex,
+    // If false, any previously shared read position is removed and the caller won't appear in
+    // `message_read_by` results
+    pub share_read_receipt: bool,
+}
+
+#[ts_export(group, mark_read_up_to)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    ReadReceiptsDisabled,
+    CallerNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    ChatFrozen,
+}
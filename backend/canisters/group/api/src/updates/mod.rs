@@ -1,5 +1,6 @@
 pub mod accept_p2p_swap;
 pub mod add_reaction;
+pub mod approve_join_request;
 pub mod block_user;
 pub mod c2c_bot_send_message;
 pub mod c2c_delete_group;
@@ -12,15 +13,19 @@ pub mod c2c_invite_users;
 pub mod c2c_join_group;
 pub mod c2c_leave_group;
 pub mod c2c_local_group_index;
+pub mod c2c_mark_read_up_to;
 pub mod c2c_notify_events;
 pub mod c2c_report_message_v2;
+pub mod c2c_request_to_join;
 pub mod c2c_send_message;
 pub mod c2c_set_user_suspended;
 pub mod c2c_start_import_into_community;
 pub mod c2c_tip_message;
 pub mod c2c_unfreeze_group;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_blocked_users;
 pub mod c2c_update_proposals;
+pub mod c2c_update_scoped_principals;
 pub mod c2c_update_user_principal;
 pub mod cancel_invites;
 pub mod cancel_p2p_swap;
@@ -35,16 +40,21 @@ pub mod enable_invite_code;
 pub mod end_video_call_v2;
 pub mod follow_thread;
 pub mod generate_bot_api_key;
+pub mod import_archived_events;
 pub mod join_video_call;
+pub mod mark_read_up_to;
 pub mod pin_message_v2;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
 pub mod register_proposal_vote_v2;
+pub mod reject_join_request;
 pub mod remove_participant;
 pub mod remove_reaction;
 pub mod report_message;
 pub mod reset_invite_code;
 pub mod send_message_v2;
+pub mod set_read_receipts_enabled;
+pub mod set_typing;
 pub mod set_video_call_presence;
 pub mod start_video_call_v2;
 pub mod toggle_mute_notifications;
@@ -0,0 +1,20 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export(group, set_read_receipts_enabled)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub enabled: bool,
+}
+
+#[ts_export(group, set_read_receipts_enabled)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    NotAuthorized,
+    CallerNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    ChatFrozen,
+}
@@ -0,0 +1,23 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::MessageIndex;
+
+// Clients should call this with `is_typing: true` every few seconds while the user is typing, since each signal
+// expires shortly after it is received
+#[ts_export(group, set_typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub is_typing: bool,
+}
+
+#[ts_export(group, set_typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    CallerNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    ChatFrozen,
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
 use types::{
     AccessGateConfig, Document, FieldTooLongResult, FieldTooShortResult, Milliseconds, OptionUpdate, OptionalGroupPermissions,
-    UpdatedRules, Version,
+    Tag, UpdatedRules, Version,
 };
 
 #[ts_export(group, update_group)]
@@ -21,6 +21,8 @@ pub struct Args {
     pub gate_config: OptionUpdate<AccessGateConfig>,
     pub public: Option<bool>,
     pub messages_visible_to_non_members: Option<bool>,
+    pub tags: Option<Vec<Tag>>,
+    pub languages: Option<Vec<String>>,
     pub correlation_id: u64,
 }
 
@@ -43,6 +45,8 @@ pub enum Response {
     UserLapsed,
     ChatFrozen,
     InternalError,
+    TooManyTags(u32),
+    InvalidLanguage,
 }
 
 #[ts_export(group, update_group)]
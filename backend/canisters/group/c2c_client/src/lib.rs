@@ -28,6 +28,7 @@ generate_c2c_call!(c2c_join_group);
 generate_c2c_call!(c2c_leave_group);
 generate_c2c_call!(c2c_notify_events);
 generate_c2c_call!(c2c_report_message_v2);
+generate_c2c_call!(c2c_request_to_join);
 generate_c2c_call!(c2c_send_message);
 generate_c2c_call!(c2c_set_user_suspended);
 generate_c2c_call!(c2c_start_import_into_community);
@@ -5,7 +5,9 @@ use fire_and_forget_handler::FireAndForgetHandler;
 use group_index_canister::c2c_mark_active;
 use msgpack::serialize_then_unwrap;
 use std::collections::HashSet;
-use types::{CanisterId, Milliseconds, PublicGroupActivity, TimestampMillis};
+use types::{CanisterId, Milliseconds, PublicGroupActivity, TimestampMillis, UserId};
+
+const MAX_ACTIVE_USERS: usize = 100;
 
 // If needed, notify the group index canister that there has been activity in this group
 pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
@@ -14,17 +16,23 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
     let now = state.env.now();
 
     if let Some(mark_active_duration) = state.data.activity_notification_state.notify_if_required(now) {
-        let public_group_activity = state.data.chat.is_public.then(|| extract_activity(now, &state.data));
+        let (public_group_activity, active_users) = if state.data.chat.is_public.value {
+            let (activity, active_users) = extract_activity(now, &state.data);
+            (Some(activity), active_users)
+        } else {
+            (None, Vec::new())
+        };
 
         call_group_index_canister(
             state.data.group_index_canister_id,
             mark_active_duration,
             public_group_activity,
+            active_users,
             &mut state.data.fire_and_forget_handler,
         );
     }
 
-    fn extract_activity(now: TimestampMillis, data: &Data) -> PublicGroupActivity {
+    fn extract_activity(now: TimestampMillis, data: &Data) -> (PublicGroupActivity, Vec<UserId>) {
         let one_hour_ago = now - HOUR_IN_MS;
         let one_day_ago = now - DAY_IN_MS;
 
@@ -36,6 +44,8 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
 
         let mut message_unique_users = HashSet::new();
         let mut reaction_unique_users = HashSet::new();
+        // Events are iterated newest first, so these are the users who have most recently sent messages
+        let mut active_users = Vec::new();
 
         for event in data
             .chat
@@ -56,6 +66,9 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
                 }
 
                 if message_unique_users.insert(m.sender) {
+                    if active_users.len() < MAX_ACTIVE_USERS {
+                        active_users.push(m.sender);
+                    }
                     activity.last_day.message_unique_users += 1;
                     if within_last_hour {
                         activity.last_hour.message_unique_users += 1;
@@ -73,18 +86,20 @@ pub(crate) fn handle_activity_notification(state: &mut RuntimeState) {
             }
         }
 
-        activity
+        (activity, active_users)
     }
 
     fn call_group_index_canister(
         canister_id: CanisterId,
         duration: Milliseconds,
         public_group_activity: Option<PublicGroupActivity>,
+        active_users: Vec<UserId>,
         fire_and_forget_handler: &mut FireAndForgetHandler,
     ) {
         let args = c2c_mark_active::Args {
             duration,
             public_group_activity,
+            active_users,
         };
 
         fire_and_forget_handler.send(
@@ -75,6 +75,10 @@ fn run() {
                     is_unique_person: false,
                     verified_credential_args: None,
                     referred_by_member: false,
+                    chit: None,
+                    user_index_canister_id: state.data.user_index_canister_id,
+                    community_member_since: None,
+                    community_message_count: 0,
                     now: state.env.now(),
                 };
 
@@ -1,10 +1,13 @@
 use super::expire_members;
 use crate::{activity_notifications::handle_activity_notification, mutate_state, read_state, RuntimeState};
 use gated_groups::{check_if_passes_gate, CheckGateArgs, CheckIfPassesGateResult};
-use group_community_common::{ExpiringMember, ExpiringMemberAction, ExpiringMemberActionDetails, Members};
+use group_community_common::{
+    ExpiringMember, ExpiringMemberAction, ExpiringMemberActionDetails, Members, SUBSCRIPTION_RENEWAL_RETRY_INTERVAL,
+};
 use ic_cdk_timers::TimerId;
 use local_user_index_canister_c2c_client::lookup_users;
 use std::cell::Cell;
+use std::cmp::min;
 use std::time::Duration;
 use tracing::trace;
 use types::{AccessGateConfigInternal, UserId};
@@ -96,6 +99,10 @@ fn prepare_gate_check(details: ExpiringMemberActionDetails, state: &RuntimeState
         is_unique_person,
         verified_credential_args: None,
         referred_by_member: false,
+        chit: None,
+        user_index_canister_id: state.data.user_index_canister_id,
+        community_member_since: None,
+        community_message_count: 0,
         now: state.env.now(),
     };
 
@@ -124,10 +131,27 @@ fn handle_gate_check_result(details: ExpiringMemberActionDetails, result: CheckI
 
     let now = state.env.now();
 
+    let grace_period = gate_config.gate().subscription_gate().map(|g| g.grace_period);
+
     // Determine if the gate expiry has increased since the action was added to the queue
     let expiry_increase = curr_gate_expiry.saturating_sub(details.original_gate_expiry);
 
     if matches!(result, CheckIfPassesGateResult::Failed(_)) && expiry_increase == 0 {
+        // If the subscription renewal failed but the member is within the grace period then retry later
+        if let Some(grace_period_end) = grace_period.and_then(|g| {
+            state
+                .data
+                .subscriptions
+                .renewal_failed(details.user_id, None, details.member_expires, g, now)
+        }) {
+            state.data.expiring_members.push(ExpiringMember {
+                expires: min(now + SUBSCRIPTION_RENEWAL_RETRY_INTERVAL, grace_period_end),
+                channel_id: None,
+                user_id: details.user_id,
+            });
+            return;
+        }
+
         // Membership lapsed
         state.data.chat.members.update_lapsed(details.user_id, true, now);
         handle_activity_notification(state);
@@ -137,6 +161,11 @@ fn handle_gate_check_result(details: ExpiringMemberActionDetails, result: CheckI
     // In all other cases re-queue the check
     let expiry = if result.success() { curr_gate_expiry } else { expiry_increase };
 
+    // Subscription gates charge the member each time their access is renewed
+    if let CheckIfPassesGateResult::Success(payments) = result {
+        state.handle_access_gate_payments(details.user_id, payments, now);
+    }
+
     state.data.expiring_members.push(ExpiringMember {
         expires: now + expiry,
         channel_id: None,
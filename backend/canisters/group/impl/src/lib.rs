@@ -16,10 +16,12 @@ use group_chat_core::{
 };
 use group_community_common::{
     Achievements, ExpiringMemberActions, ExpiringMembers, PaymentReceipts, PaymentRecipient, PendingPayment,
-    PendingPaymentReason, PendingPaymentsQueue, UserCache,
+    PendingPaymentReason, PendingPaymentsQueue, PersonalBlockLists, ScopedPrincipals, Subscriptions, Typing, UserCache,
 };
 use installed_bots::{BotApiKeys, InstalledBots};
 use instruction_counts_log::{InstructionCountEntry, InstructionCountFunctionId, InstructionCountsLog};
+use model::archive_imports::ArchiveImports;
+use model::read_receipts::ReadReceipts;
 use model::user_event_batch::UserEventBatch;
 use msgpack::serialize_then_unwrap;
 use notifications_canister::c2c_push_notification;
@@ -37,9 +39,9 @@ use timer_job_queues::GroupedTimerJobQueue;
 use types::{
     AccessGateConfigInternal, Achievement, BotAdded, BotCaller, BotEventsCaller, BotGroupConfig, BotInitiator, BotPermissions,
     BotRemoved, BotUpdated, BuildVersion, Caller, CanisterId, ChatId, ChatMetrics, CommunityId, Cryptocurrency, Cycles,
-    Document, Empty, EventIndex, EventsCaller, FrozenGroupInfo, GroupCanisterGroupChatSummary, GroupMembership,
-    GroupPermissions, GroupSubtype, IdempotentEnvelope, MessageIndex, Milliseconds, MultiUserChat, Notification, Rules,
-    TimestampMillis, Timestamped, UserId, UserType, MAX_THREADS_IN_SUMMARY, SNS_FEE_SHARE_PERCENT,
+    DelegationScope, Document, Empty, EventIndex, EventsCaller, FrozenGroupInfo, GroupCanisterGroupChatSummary,
+    GroupMembership, GroupPermissions, GroupSubtype, IdempotentEnvelope, MessageIndex, Milliseconds, MultiUserChat,
+    Notification, Rules, TimestampMillis, Timestamped, UserId, UserType, MAX_THREADS_IN_SUMMARY, SNS_FEE_SHARE_PERCENT,
 };
 use user_canister::GroupCanisterEvent;
 use utils::env::Environment;
@@ -112,6 +114,16 @@ impl RuntimeState {
         }
     }
 
+    // Scoped principals (see `DelegationScope`) are resolved to the member they belong to if their scope matches,
+    // otherwise the caller is returned unchanged
+    pub fn caller_with_scope(&self, scope: DelegationScope) -> Principal {
+        let caller = self.env.caller();
+        self.data
+            .scoped_principals
+            .resolve(&caller, scope, self.env.now())
+            .map_or(caller, |user_id| user_id.into())
+    }
+
     pub fn push_notification(&mut self, sender: Option<UserId>, recipients: Vec<UserId>, notification: Notification) {
         if !recipients.is_empty() {
             let args = c2c_push_notification::Args {
@@ -128,6 +140,31 @@ impl RuntimeState {
         }
     }
 
+    pub fn handle_access_gate_payments(&mut self, user_id: UserId, payments: Vec<GatePayment>, now: TimestampMillis) {
+        let subscription_period = self
+            .data
+            .chat
+            .gate_config
+            .value
+            .as_ref()
+            .and_then(|gc| gc.gate().subscription_gate())
+            .map(|g| g.period);
+
+        for payment in payments {
+            if let Some(period) = subscription_period {
+                self.data.subscriptions.record_payment(
+                    user_id,
+                    None,
+                    payment.ledger_canister_id,
+                    payment.amount,
+                    now + period,
+                    now,
+                );
+            }
+            self.queue_access_gate_payments(payment);
+        }
+    }
+
     pub fn queue_access_gate_payments(&mut self, payment: GatePayment) {
         // Queue a payment to each owner less the fee
         let owners = self.data.chat.members.owners();
@@ -208,7 +245,10 @@ impl RuntimeState {
             messages_visible_to_non_members: chat.messages_visible_to_non_members.value,
             min_visible_event_index,
             min_visible_message_index,
-            latest_message: main_events_reader.latest_message_event(Some(member.user_id())),
+            latest_message: self.data.personal_block_lists.latest_visible_message(
+                &member.user_id(),
+                main_events_reader.iter_latest_messages(Some(member.user_id())),
+            ),
             latest_event_index: main_events_reader.latest_event_index().unwrap_or_default(),
             latest_message_index: main_events_reader.latest_message_index(),
             joined: membership.joined,
@@ -231,6 +271,7 @@ impl RuntimeState {
             membership: Some(membership),
             video_call_in_progress: chat.events.video_call_in_progress().value.clone(),
             verified: self.data.verified.value,
+            read_receipts_enabled: self.data.read_receipts.enabled(),
         }
     }
 
@@ -450,13 +491,18 @@ impl RuntimeState {
     }
 
     pub fn verified_caller(&self, bot_caller: Option<BotCaller>) -> CallerResult {
+        self.verified_caller_with_scope(bot_caller, None)
+    }
+
+    // If a scope is provided, scoped principals with that scope are accepted in place of the member's own principal
+    pub fn verified_caller_with_scope(&self, bot_caller: Option<BotCaller>, scope: Option<DelegationScope>) -> CallerResult {
         use CallerResult::*;
 
         if let Some(bot_caller) = bot_caller {
             return Success(Caller::BotV2(bot_caller));
         }
 
-        let caller = self.env.caller();
+        let caller = scope.map_or_else(|| self.env.caller(), |s| self.caller_with_scope(s));
 
         if caller == self.data.user_index_canister_id {
             return Success(Caller::OCBot(OPENCHAT_BOT_USER_ID));
@@ -525,6 +571,18 @@ struct Data {
     message_ids_deduped: bool,
     #[serde(default)]
     idempotency_checker: IdempotencyChecker,
+    #[serde(default)]
+    pub archive_imports: ArchiveImports,
+    #[serde(default)]
+    pub read_receipts: ReadReceipts,
+    #[serde(default)]
+    subscriptions: Subscriptions,
+    #[serde(default)]
+    pub personal_block_lists: PersonalBlockLists,
+    #[serde(default)]
+    pub scoped_principals: ScopedPrincipals,
+    #[serde(skip)]
+    pub typing: Typing<Option<MessageIndex>>,
 }
 
 fn init_instruction_counts_log() -> InstructionCountsLog {
@@ -628,6 +686,12 @@ impl Data {
             bot_api_keys: BotApiKeys::default(),
             message_ids_deduped: true,
             idempotency_checker: IdempotencyChecker::default(),
+            archive_imports: ArchiveImports::default(),
+            read_receipts: ReadReceipts::default(),
+            subscriptions: Subscriptions::default(),
+            personal_block_lists: PersonalBlockLists::default(),
+            scoped_principals: ScopedPrincipals::default(),
+            typing: Typing::default(),
         }
     }
 
@@ -740,7 +804,12 @@ impl Data {
         self.expiring_members.remove_member(user_id, None);
         self.expiring_member_actions.remove_member(user_id, None);
         self.achievements.remove_user(&user_id);
+        self.read_receipts.remove(&user_id);
+        self.subscriptions.remove_member(user_id, None);
         self.user_cache.delete(user_id);
+        self.personal_block_lists.remove(&user_id);
+        self.scoped_principals.remove_user(&user_id);
+        self.typing.remove_user(&user_id);
     }
 
     pub fn get_caller_for_events(&self, caller: Principal, bot_initiator: Option<BotInitiator>) -> Option<EventsCaller> {
@@ -0,0 +1,111 @@
+use constants::DAY_IN_MS;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use types::{MessageIndex, Milliseconds, TimestampMillis, UserId};
+
+const SESSION_EXPIRY: Milliseconds = DAY_IN_MS;
+
+// Archives are imported over multiple batches. Each import session holds the mapping from message indexes in the
+// archive to message indexes in this chat, so that thread replies in later batches can be attached to their new root
+// messages. Each user has at most one session at a time and sessions expire if left idle.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ArchiveImports {
+    sessions: HashMap<u32, ArchiveImportSession>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ArchiveImportSession {
+    #[serde(rename = "u")]
+    pub user_id: UserId,
+    #[serde(rename = "m")]
+    pub message_index_map: BTreeMap<MessageIndex, MessageIndex>,
+    #[serde(rename = "l")]
+    pub last_updated: TimestampMillis,
+}
+
+impl ArchiveImports {
+    // Creates a new session for the user, abandoning any session they already had
+    pub fn start<R: RngCore>(&mut self, user_id: UserId, rng: &mut R, now: TimestampMillis) -> (u32, ArchiveImportSession) {
+        self.prune_expired(now);
+        self.sessions.retain(|_, s| s.user_id != user_id);
+
+        let session_id = loop {
+            let id = rng.next_u32();
+            if !self.sessions.contains_key(&id) {
+                break id;
+            }
+        };
+
+        (
+            session_id,
+            ArchiveImportSession {
+                user_id,
+                message_index_map: BTreeMap::new(),
+                last_updated: now,
+            },
+        )
+    }
+
+    // Removes the session so that it can be updated, it must then be put back unless the import has finished
+    pub fn take(&mut self, session_id: u32, user_id: UserId, now: TimestampMillis) -> Option<ArchiveImportSession> {
+        self.prune_expired(now);
+
+        if self.sessions.get(&session_id).is_some_and(|s| s.user_id == user_id) {
+            self.sessions.remove(&session_id)
+        } else {
+            None
+        }
+    }
+
+    pub fn put(&mut self, session_id: u32, mut session: ArchiveImportSession, now: TimestampMillis) {
+        session.last_updated = now;
+        self.sessions.insert(session_id, session);
+    }
+
+    fn prune_expired(&mut self, now: TimestampMillis) {
+        self.sessions
+            .retain(|_, s| now.saturating_sub(s.last_updated) < SESSION_EXPIRY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn sessions_are_scoped_to_user_and_expire() {
+        let mut imports = ArchiveImports::default();
+        let mut rng = rand::thread_rng();
+        let user1: UserId = Principal::from_slice(&[1]).into();
+        let user2: UserId = Principal::from_slice(&[2]).into();
+
+        let (session_id, mut session) = imports.start(user1, &mut rng, 0);
+        session.message_index_map.insert(1.into(), 10.into());
+        imports.put(session_id, session, 0);
+
+        assert!(imports.take(session_id, user2, 1).is_none());
+
+        let session = imports.take(session_id, user1, 1).unwrap();
+        assert_eq!(session.message_index_map.get(&1.into()), Some(&10.into()));
+        imports.put(session_id, session, 1);
+
+        assert!(imports.take(session_id, user1, 1 + SESSION_EXPIRY).is_none());
+    }
+
+    #[test]
+    fn starting_new_session_abandons_previous() {
+        let mut imports = ArchiveImports::default();
+        let mut rng = rand::thread_rng();
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+
+        let (session_id1, session) = imports.start(user_id, &mut rng, 0);
+        imports.put(session_id1, session, 0);
+        let (session_id2, session) = imports.start(user_id, &mut rng, 1);
+        imports.put(session_id2, session, 1);
+
+        assert!(imports.take(session_id1, user_id, 2).is_none());
+        assert!(imports.take(session_id2, user_id, 2).is_some());
+    }
+}
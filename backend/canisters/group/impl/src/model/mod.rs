@@ -1 +1,3 @@
+pub mod archive_imports;
+pub mod read_receipts;
 pub mod user_event_batch;
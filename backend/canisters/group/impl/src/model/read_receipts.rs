@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use types::{MessageIndex, TimestampMillis, Timestamped, UserId};
+
+// Tracks how far each member has read so that other members can see who has read a message.
+// Only members who have chosen to share their read receipts are included.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ReadReceipts {
+    enabled: Timestamped<bool>,
+    read_up_to: HashMap<UserId, MessageIndex>,
+}
+
+impl ReadReceipts {
+    pub fn enabled(&self) -> bool {
+        self.enabled.value
+    }
+
+    pub fn enabled_last_updated(&self) -> TimestampMillis {
+        self.enabled.timestamp
+    }
+
+    pub fn enabled_if_set_after(&self, since: TimestampMillis) -> Option<bool> {
+        self.enabled.if_set_after(since).copied()
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool, now: TimestampMillis) -> bool {
+        if self.enabled.value == enabled {
+            return false;
+        }
+
+        self.enabled = Timestamped::new(enabled, now);
+        if !enabled {
+            self.read_up_to.clear();
+        }
+        true
+    }
+
+    pub fn mark_read_up_to(&mut self, user_id: UserId, read_up_to: MessageIndex) {
+        self.read_up_to
+            .entry(user_id)
+            .and_modify(|i| *i = (*i).max(read_up_to))
+            .or_insert(read_up_to);
+    }
+
+    // Only advances the position of members who have already chosen to share their read receipts
+    pub fn advance(&mut self, user_id: UserId, read_up_to: MessageIndex) -> bool {
+        match self.read_up_to.get_mut(&user_id) {
+            Some(i) if *i < read_up_to => {
+                *i = read_up_to;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn remove(&mut self, user_id: &UserId) {
+        self.read_up_to.remove(user_id);
+    }
+
+    pub fn read_by(&self, message_index: MessageIndex) -> Vec<UserId> {
+        let mut users: Vec<_> = self
+            .read_up_to
+            .iter()
+            .filter(|(_, i)| **i >= message_index)
+            .map(|(u, _)| *u)
+            .collect();
+
+        users.sort();
+        users
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn read_by_only_includes_members_who_have_read_the_message() {
+        let mut read_receipts = ReadReceipts::default();
+        let user1: UserId = Principal::from_slice(&[1]).into();
+        let user2: UserId = Principal::from_slice(&[2]).into();
+
+        read_receipts.set_enabled(true, 1);
+        read_receipts.mark_read_up_to(user1, 5.into());
+        read_receipts.mark_read_up_to(user2, 10.into());
+
+        // Positions never move backwards
+        read_receipts.mark_read_up_to(user2, 3.into());
+
+        assert_eq!(read_receipts.read_by(5.into()).len(), 2);
+        assert_eq!(read_receipts.read_by(10.into()), vec![user2]);
+        assert!(read_receipts.read_by(11.into()).is_empty());
+
+        read_receipts.remove(&user2);
+        assert!(read_receipts.read_by(10.into()).is_empty());
+    }
+
+    #[test]
+    fn advance_only_updates_members_who_have_opted_in() {
+        let mut read_receipts = ReadReceipts::default();
+        let user1: UserId = Principal::from_slice(&[1]).into();
+        let user2: UserId = Principal::from_slice(&[2]).into();
+
+        read_receipts.set_enabled(true, 1);
+        read_receipts.mark_read_up_to(user1, 1.into());
+
+        assert!(read_receipts.advance(user1, 4.into()));
+        assert!(!read_receipts.advance(user1, 2.into()));
+        assert!(!read_receipts.advance(user2, 4.into()));
+        assert_eq!(read_receipts.read_by(4.into()), vec![user1]);
+    }
+
+    #[test]
+    fn disabling_clears_read_positions() {
+        let mut read_receipts = ReadReceipts::default();
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+
+        assert!(read_receipts.set_enabled(true, 1));
+        assert!(!read_receipts.set_enabled(true, 2));
+        read_receipts.mark_read_up_to(user_id, 1.into());
+
+        assert!(read_receipts.set_enabled(false, 3));
+        assert_eq!(read_receipts.enabled_if_set_after(2), Some(false));
+        assert_eq!(read_receipts.enabled_if_set_after(3), None);
+
+        read_receipts.set_enabled(true, 4);
+        assert!(read_receipts.read_by(0.into()).is_empty());
+    }
+}
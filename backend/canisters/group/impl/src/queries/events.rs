@@ -6,7 +6,7 @@ use canister_api_macros::query;
 use group_canister::c2c_events::Args as C2CArgs;
 use group_canister::events::{Response::*, *};
 use group_chat_core::EventsResult;
-use types::BotInitiator;
+use types::{BotInitiator, DelegationScope};
 
 #[query(candid = true, msgpack = true)]
 fn events(args: Args) -> Response {
@@ -28,20 +28,26 @@ fn events_impl(
         return ReplicaNotUpToDateV2(now);
     }
 
-    let caller = on_behalf_of.unwrap_or_else(|| state.env.caller());
+    let caller = on_behalf_of.unwrap_or_else(|| state.caller_with_scope(DelegationScope::ReadOnly));
     let Some(events_caller) = state.data.get_caller_for_events(caller, bot_initiator) else {
         return CallerNotInGroup;
     };
 
     match state.data.chat.events(
-        events_caller,
+        events_caller.clone(),
         args.thread_root_message_index,
         args.start_index,
         args.ascending,
         args.max_messages,
         args.max_events,
     ) {
-        EventsResult::Success(response) => Success(response),
+        EventsResult::Success(mut response) => {
+            state
+                .data
+                .personal_block_lists
+                .hide_blocked_users(&events_caller, &mut response);
+            Success(response)
+        }
         EventsResult::UserNotInGroup => CallerNotInGroup,
         EventsResult::ThreadNotFound => ThreadMessageNotFound,
         EventsResult::UserSuspended => UserSuspended,
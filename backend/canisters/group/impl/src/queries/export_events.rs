@@ -0,0 +1,28 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use group_canister::export_events::{Response::*, *};
+
+const MAX_EVENTS_PER_PAGE: u32 = 1000;
+
+#[query(msgpack = true)]
+fn export_events(args: Args) -> Response {
+    read_state(|state| export_events_impl(args, state))
+}
+
+fn export_events_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    if member.suspended().value || !member.role().is_owner() {
+        return NotAuthorized;
+    }
+
+    Success(
+        state
+            .data
+            .chat
+            .export_events(args.after, args.max_events.clamp(1, MAX_EVENTS_PER_PAGE)),
+    )
+}
@@ -0,0 +1,28 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use group_canister::export_members::{Response::*, *};
+
+const MAX_MEMBERS_PER_PAGE: u32 = 1000;
+
+#[query(msgpack = true)]
+fn export_members(args: Args) -> Response {
+    read_state(|state| export_members_impl(args, state))
+}
+
+fn export_members_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    if member.suspended().value || !member.role().is_owner() {
+        return NotAuthorized;
+    }
+
+    Success(
+        state
+            .data
+            .chat
+            .export_members(args.after, args.max_members.clamp(1, MAX_MEMBERS_PER_PAGE)),
+    )
+}
@@ -0,0 +1,22 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use group_canister::join_requests::{Response::*, *};
+use group_chat_core::JoinRequestsResult;
+
+#[query(msgpack = true)]
+fn join_requests(_args: Args) -> Response {
+    read_state(join_requests_impl)
+}
+
+fn join_requests_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    match state.data.chat.join_requests(member.user_id()) {
+        JoinRequestsResult::Success(requests) => Success(SuccessResult { requests }),
+        JoinRequestsResult::UserNotInGroup => CallerNotInGroup,
+        JoinRequestsResult::UserSuspended | JoinRequestsResult::UserLapsed | JoinRequestsResult::NotAuthorized => NotAuthorized,
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use group_canister::message_edit_history::{Response::*, *};
+use group_chat_core::MessageEditHistoryResult;
+use types::DelegationScope;
+
+#[query(msgpack = true)]
+fn message_edit_history(args: Args) -> Response {
+    read_state(|state| message_edit_history_impl(args, state))
+}
+
+fn message_edit_history_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
+    let Some(events_caller) = state.data.get_caller_for_events(caller, None) else {
+        return CallerNotInGroup;
+    };
+
+    match state
+        .data
+        .chat
+        .message_edit_history(events_caller, args.thread_root_message_index, args.message_id)
+    {
+        MessageEditHistoryResult::Success(versions) => Success(SuccessResult { versions }),
+        MessageEditHistoryResult::UserNotInGroup => CallerNotInGroup,
+        MessageEditHistoryResult::ThreadNotFound => ThreadNotFound,
+        MessageEditHistoryResult::MessageNotFound => MessageNotFound,
+        MessageEditHistoryResult::UserSuspended => UserSuspended,
+        MessageEditHistoryResult::UserLapsed => UserLapsed,
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use chat_events::Reader;
+use group_canister::message_read_by::{Response::*, *};
+
+#[query(msgpack = true)]
+fn message_read_by(args: Args) -> Response {
+    read_state(|state| message_read_by_impl(args, state))
+}
+
+fn message_read_by_impl(args: Args, state: &RuntimeState) -> Response {
+    if !state.data.read_receipts.enabled() {
+        return ReadReceiptsDisabled;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let Some(events_reader) = state
+        .data
+        .chat
+        .events
+        .events_reader(member.min_visible_event_index(), None, None)
+    else {
+        return MessageNotFound;
+    };
+
+    if events_reader.message_internal(args.message_index.into()).is_none() {
+        return MessageNotFound;
+    }
+
+    let read_by = state
+        .data
+        .read_receipts
+        .read_by(args.message_index)
+        .into_iter()
+        .filter(|u| state.data.chat.members.contains(u))
+        .collect();
+
+    Success(SuccessResult { read_by })
+}
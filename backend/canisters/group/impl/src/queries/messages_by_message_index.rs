@@ -3,7 +3,7 @@ use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use group_canister::messages_by_message_index::{Response::*, *};
 use group_chat_core::MessagesResult;
-use types::EventsCaller;
+use types::{DelegationScope, EventsCaller};
 
 #[query(candid = true, msgpack = true)]
 fn messages_by_message_index(args: Args) -> Response {
@@ -15,7 +15,7 @@ fn messages_by_message_index_impl(args: Args, state: &RuntimeState) -> Response
         return ReplicaNotUpToDateV2(now);
     }
 
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
     let user_id = state.data.lookup_user_id(caller);
     let events_caller = user_id.map_or(EventsCaller::Unknown, EventsCaller::User);
 
@@ -24,7 +24,15 @@ fn messages_by_message_index_impl(args: Args, state: &RuntimeState) -> Response
         .chat
         .messages_by_message_index(events_caller, args.thread_root_message_index, args.messages)
     {
-        MessagesResult::Success(response) => Success(response),
+        MessagesResult::Success(mut response) => {
+            if let Some(user_id) = user_id {
+                state
+                    .data
+                    .personal_block_lists
+                    .hide_blocked_users_in_messages(&user_id, &mut response.messages);
+            }
+            Success(response)
+        }
         MessagesResult::UserNotInGroup => CallerNotInGroup,
         MessagesResult::ThreadNotFound => ThreadMessageNotFound,
         MessagesResult::UserSuspended => UserSuspended,
@@ -11,18 +11,25 @@ mod deleted_message;
 mod events;
 mod events_by_index;
 mod events_window;
+mod export_events;
+mod export_members;
 mod http_request;
 mod invite_code;
+mod join_requests;
 mod local_user_index;
+mod message_edit_history;
+mod message_read_by;
 mod messages_by_message_index;
 mod public_summary;
 mod rules;
 mod search_messages;
 mod selected_initial;
 mod selected_updates;
+mod subscription_payments;
 mod summary;
 mod summary_updates;
 mod thread_previews;
+mod typing;
 mod video_call_participants;
 
 fn check_replica_up_to_date(latest_known_update: Option<TimestampMillis>, state: &RuntimeState) -> Result<(), TimestampMillis> {
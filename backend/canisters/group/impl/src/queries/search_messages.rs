@@ -1,7 +1,9 @@
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
+use chat_events::Reader;
 use group_canister::search_messages::{Response::*, *};
 use group_chat_core::SearchResults;
+use types::DelegationScope;
 
 #[query(msgpack = true)]
 fn search_messages(args: Args) -> Response {
@@ -9,7 +11,7 @@ fn search_messages(args: Args) -> Response {
 }
 
 fn search_messages_impl(args: Args, state: &RuntimeState) -> Response {
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
 
     if let Some(user_id) = state.data.lookup_user_id(caller) {
         match state
@@ -17,7 +19,15 @@ fn search_messages_impl(args: Args, state: &RuntimeState) -> Response {
             .chat
             .search(user_id, args.search_term, args.users, args.max_results)
         {
-            SearchResults::Success(matches) => Success(SuccessResult { matches }),
+            SearchResults::Success(mut matches) => {
+                let events_reader = state.data.chat.events.main_events_reader();
+                matches.retain(|m| {
+                    events_reader
+                        .message_internal(m.message_index.into())
+                        .is_some_and(|message| !state.data.personal_block_lists.has_blocked(&user_id, &message.sender))
+                });
+                Success(SuccessResult { matches })
+            }
             SearchResults::InvalidTerm => InvalidTerm,
             SearchResults::TermTooLong(v) => TermTooLong(v),
             SearchResults::TermTooShort(v) => TermTooShort(v),
@@ -2,7 +2,7 @@ use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use group_canister::selected_initial::{Response::*, *};
 use std::collections::HashSet;
-use types::{GroupMember, InstalledBotDetails};
+use types::{DelegationScope, GroupMember, InstalledBotDetails};
 
 #[query(candid = true, msgpack = true)]
 fn selected_initial(_args: Args) -> Response {
@@ -10,7 +10,7 @@ fn selected_initial(_args: Args) -> Response {
 }
 
 fn selected_initial_impl(state: &RuntimeState) -> Response {
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
     if let Some(member) = state.data.get_member(caller) {
         let min_visible_message_index = member.min_visible_message_index();
         let last_updated = state.data.details_last_updated();
@@ -3,7 +3,7 @@ use canister_api_macros::query;
 use group_canister::selected_updates_v2::{Response::*, *};
 use installed_bots::BotUpdate;
 use std::collections::HashSet;
-use types::InstalledBotDetails;
+use types::{DelegationScope, InstalledBotDetails};
 
 #[query(candid = true, msgpack = true)]
 fn selected_updates_v2(args: Args) -> Response {
@@ -17,7 +17,7 @@ fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
         return SuccessNoUpdates(last_updated);
     }
 
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
     let user_id = match state.data.lookup_user_id(caller) {
         Some(id) => id,
         None => return CallerNotInGroup,
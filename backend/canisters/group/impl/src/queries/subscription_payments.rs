@@ -0,0 +1,25 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use group_canister::subscription_payments::{Response::*, *};
+
+#[query(msgpack = true)]
+fn subscription_payments(args: Args) -> Response {
+    read_state(|state| subscription_payments_impl(args, state))
+}
+
+fn subscription_payments_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let user_id = args.user_id.unwrap_or(member.user_id());
+    if user_id != member.user_id() && !member.role().is_owner() {
+        return NotAuthorized;
+    }
+
+    Success(SuccessResult {
+        paid_until: state.data.subscriptions.paid_until(user_id, None),
+        payments: state.data.subscriptions.payments(user_id, None),
+    })
+}
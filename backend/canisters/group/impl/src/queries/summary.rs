@@ -3,6 +3,7 @@ use crate::RuntimeState;
 use candid::Principal;
 use canister_api_macros::query;
 use group_canister::summary::{Response::*, *};
+use types::DelegationScope;
 
 #[query(candid = true, msgpack = true)]
 fn summary(args: Args) -> Response {
@@ -19,7 +20,7 @@ fn summary_impl(on_behalf_of: Option<Principal>, state: &RuntimeState) -> Respon
         assert!(state.is_caller_local_user_index());
         principal
     } else {
-        state.env.caller()
+        state.caller_with_scope(DelegationScope::ReadOnly)
     };
 
     if let Some(member) = state.data.get_member(caller) {
@@ -1,9 +1,11 @@
 use crate::{read_state, RuntimeState};
 use candid::Principal;
 use canister_api_macros::query;
+use chat_events::Reader;
 use group_canister::summary_updates::{Response::*, *};
 use types::{
-    GroupCanisterGroupChatSummaryUpdates, GroupMembershipUpdates, OptionUpdate, TimestampMillis, MAX_THREADS_IN_SUMMARY,
+    DelegationScope, GroupCanisterGroupChatSummaryUpdates, GroupMembershipUpdates, OptionUpdate, TimestampMillis,
+    MAX_THREADS_IN_SUMMARY,
 };
 
 #[query(candid = true, msgpack = true)]
@@ -21,7 +23,7 @@ fn summary_updates_impl(updates_since: TimestampMillis, on_behalf_of: Option<Pri
         assert!(state.is_caller_local_user_index());
         principal
     } else {
-        state.env.caller()
+        state.caller_with_scope(DelegationScope::ReadOnly)
     };
 
     let member = match state.data.get_member(caller) {
@@ -34,6 +36,7 @@ fn summary_updates_impl(updates_since: TimestampMillis, on_behalf_of: Option<Pri
         chat.last_updated(Some(member.user_id())),
         state.data.verified.timestamp,
         state.data.bot_api_keys.last_updated(),
+        state.data.read_receipts.enabled_last_updated(),
     ]
     .into_iter()
     .max()
@@ -82,7 +85,21 @@ fn summary_updates_impl(updates_since: TimestampMillis, on_behalf_of: Option<Pri
             description: updates.description,
             subtype: updates.subtype,
             avatar_id: updates.avatar_id,
-            latest_message: updates.latest_message,
+            latest_message: updates.latest_message.and_then(|m| {
+                if state
+                    .data
+                    .personal_block_lists
+                    .has_blocked(&member.user_id(), &m.event.sender)
+                {
+                    let events_reader = chat.events.visible_main_events_reader(member.min_visible_event_index());
+                    state
+                        .data
+                        .personal_block_lists
+                        .latest_visible_message(&member.user_id(), events_reader.iter_latest_messages(Some(member.user_id())))
+                } else {
+                    Some(m)
+                }
+            }),
             latest_event_index: updates.latest_event_index,
             latest_message_index: updates.latest_message_index,
             participant_count: updates.member_count,
@@ -114,6 +131,7 @@ fn summary_updates_impl(updates_since: TimestampMillis, on_behalf_of: Option<Pri
             video_call_in_progress: updates.video_call_in_progress,
             any_updates_missed: updates.any_updates_missed,
             verified: state.data.verified.if_set_after(updates_since).copied(),
+            read_receipts_enabled: state.data.read_receipts.enabled_if_set_after(updates_since),
         },
     })
 }
@@ -3,6 +3,7 @@ use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use group_canister::thread_previews::{Response::*, *};
 use group_chat_core::ThreadPreviewsResult;
+use types::DelegationScope;
 
 #[query(msgpack = true)]
 fn thread_previews(args: Args) -> Response {
@@ -14,13 +15,20 @@ fn thread_previews_impl(args: Args, state: &RuntimeState) -> Response {
         return ReplicaNotUpToDate(now);
     }
 
-    let caller = state.env.caller();
+    let caller = state.caller_with_scope(DelegationScope::ReadOnly);
 
     if let Some(user_id) = state.data.lookup_user_id(caller) {
         let now = state.env.now();
 
         match state.data.chat.thread_previews(user_id, args.threads) {
-            ThreadPreviewsResult::Success(threads) => Success(SuccessResult { threads, timestamp: now }),
+            ThreadPreviewsResult::Success(mut threads) => {
+                state
+                    .data
+                    .personal_block_lists
+                    .hide_blocked_users_in_thread_previews(&user_id, &mut threads);
+
+                Success(SuccessResult { threads, timestamp: now })
+            }
             ThreadPreviewsResult::UserNotInGroup => CallerNotInGroup,
         }
     } else {
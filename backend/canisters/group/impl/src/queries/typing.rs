@@ -0,0 +1,40 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use group_canister::typing::{Response::*, *};
+
+const MAX_THREADS: usize = 10;
+
+#[query(msgpack = true)]
+fn typing(args: Args) -> Response {
+    read_state(|state| typing_impl(args, state))
+}
+
+fn typing_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let Some(user_id) = state.data.lookup_user_id(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let now = state.env.now();
+    let result = args
+        .thread_root_message_indexes
+        .into_iter()
+        .take(MAX_THREADS)
+        .filter_map(|thread_root_message_index| {
+            let user_ids: Vec<_> = state
+                .data
+                .typing
+                .get(user_id, &thread_root_message_index, now)
+                .into_iter()
+                .filter(|u| !state.data.personal_block_lists.has_blocked(&user_id, u))
+                .collect();
+
+            (!user_ids.is_empty()).then_some(UsersTyping {
+                thread_root_message_index,
+                user_ids,
+            })
+        })
+        .collect();
+
+    Success(result)
+}
@@ -0,0 +1,41 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::approve_join_request::{Response::*, *};
+use group_chat_core::ApproveJoinRequestResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn approve_join_request(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| approve_join_request_impl(args, state))
+}
+
+fn approve_join_request_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let now = state.env.now();
+
+    match state.data.chat.approve_join_request(member.user_id(), args.user_id, now) {
+        ApproveJoinRequestResult::Success(principal) => {
+            state.data.principal_to_user_id_map.insert(principal, args.user_id);
+            handle_activity_notification(state);
+            Success
+        }
+        ApproveJoinRequestResult::RequestNotFound => RequestNotFound,
+        ApproveJoinRequestResult::TooManyInvites(max) => TooManyInvites(max),
+        ApproveJoinRequestResult::UserNotInGroup => CallerNotInGroup,
+        ApproveJoinRequestResult::UserSuspended => UserSuspended,
+        ApproveJoinRequestResult::UserLapsed => UserLapsed,
+        ApproveJoinRequestResult::NotAuthorized => NotAuthorized,
+    }
+}
@@ -21,7 +21,14 @@ fn c2c_invite_users_impl(args: Args, state: &mut RuntimeState) -> Response {
 
     let now = state.env.now();
 
-    match state.data.invite_users(args.caller, args.users, now) {
+    // Users can't be invited by someone they have blocked
+    let users = args
+        .users
+        .into_iter()
+        .filter(|(user_id, _)| !state.data.personal_block_lists.has_blocked(user_id, &args.caller))
+        .collect();
+
+    match state.data.invite_users(args.caller, users, now) {
         InvitedUsersResult::Success(r) => {
             if !state.data.chat.is_public.value {
                 handle_activity_notification(state);
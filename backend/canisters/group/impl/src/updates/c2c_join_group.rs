@@ -74,6 +74,10 @@ fn is_permitted_to_join(
                         ii_origin: vc.ii_origin.clone(),
                     }),
                 referred_by_member: false,
+                chit: args.chit.clone(),
+                user_index_canister_id: state.data.user_index_canister_id,
+                community_member_since: None,
+                community_message_count: 0,
                 now: state.env.now(),
             },
         )
@@ -120,6 +124,7 @@ fn c2c_join_group_impl(args: Args, payments: Vec<GatePayment>, state: &mut Runti
     }) {
         AddResult::Success(result) => {
             let invitation = state.data.chat.invited_users.remove(&args.user_id, now);
+            state.data.chat.join_requests.remove(&args.user_id, now);
 
             let event = MemberJoinedInternal {
                 user_id: args.user_id,
@@ -136,15 +141,17 @@ fn c2c_join_group_impl(args: Args, payments: Vec<GatePayment>, state: &mut Runti
             let summary = state.summary(&result.member);
 
             // If there is a payment gate on this group then queue payments to owner(s) and treasury
-            for payment in payments {
-                state.queue_access_gate_payments(payment);
-            }
+            state.handle_access_gate_payments(args.user_id, payments, now);
 
             Success(Box::new(summary))
         }
         AddResult::AlreadyInGroup => {
             state.data.chat.members.update_lapsed(args.user_id, false, now);
 
+            // Lapsed members rejoining a payment or subscription gated group pay again, so their payment
+            // is distributed (and recorded against their subscription) in the same way as for new members
+            state.handle_access_gate_payments(args.user_id, payments, now);
+
             let member = state.data.chat.members.get(&args.user_id).unwrap();
             let summary = state.summary(&member);
             Success(Box::new(summary))
@@ -0,0 +1,30 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use chat_events::Reader;
+use group_canister::c2c_mark_read_up_to::{Response::*, *};
+
+// Called via the user's user canister whenever they mark messages as read
+#[update(msgpack = true)]
+#[trace]
+fn c2c_mark_read_up_to(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_mark_read_up_to_impl(args, state))
+}
+
+fn c2c_mark_read_up_to_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let user_id = state.env.caller().into();
+
+    if state.data.chat.members.get_verified_member(user_id).is_err() {
+        return CallerNotInGroup;
+    }
+
+    if state.data.read_receipts.enabled() {
+        let latest_message_index = state.data.chat.events.main_events_reader().latest_message_index();
+        if let Some(read_up_to) = latest_message_index.map(|i| i.min(args.read_up_to)) {
+            state.data.read_receipts.advance(user_id, read_up_to);
+        }
+    }
+    Success
+}
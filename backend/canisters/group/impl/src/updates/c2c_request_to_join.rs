@@ -0,0 +1,58 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::guards::caller_is_user_index_or_local_user_index;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::c2c_request_to_join::{Response::*, *};
+use group_chat_core::RequestToJoinResult;
+use types::{GroupJoinRequestedNotification, Notification};
+
+#[update(guard = "caller_is_user_index_or_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_request_to_join(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_request_to_join_impl(args, state))
+}
+
+fn c2c_request_to_join_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let now = state.env.now();
+
+    match state
+        .data
+        .chat
+        .request_to_join(args.user_id, args.principal, args.answer, now)
+    {
+        RequestToJoinResult::Success => {
+            // Only notify when the first request arrives, any further requests will be seen by the
+            // approvers when they go to review the pending requests
+            if state.data.chat.join_requests.len() == 1 {
+                let recipients = state.data.chat.join_request_approvers();
+
+                let notification = Notification::GroupJoinRequested(GroupJoinRequestedNotification {
+                    chat_id: state.env.canister_id().into(),
+                    group_name: state.data.chat.name.value.clone(),
+                    requested_by: args.user_id,
+                    requested_by_name: args.username,
+                    group_avatar_id: state.data.chat.avatar.as_ref().map(|d| d.id),
+                });
+                state.push_notification(Some(args.user_id), recipients, notification);
+            }
+
+            handle_activity_notification(state);
+            Success
+        }
+        RequestToJoinResult::AlreadyInGroup => AlreadyInGroup,
+        RequestToJoinResult::AlreadyInvited => AlreadyInvited,
+        RequestToJoinResult::AlreadyRequested => AlreadyRequested,
+        RequestToJoinResult::RecentlyRejected(until) => RecentlyRejected(until),
+        RequestToJoinResult::UserBlocked => UserBlocked,
+        RequestToJoinResult::TooManyRequests(max) => TooManyRequests(max),
+        RequestToJoinResult::RateLimited(until) => RateLimited(until),
+        RequestToJoinResult::AnswerTooLong(max) => AnswerTooLong(max),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::c2c_update_blocked_users::{Response::*, *};
+
+// Called via the user's user canister
+#[update(msgpack = true)]
+#[trace]
+fn c2c_update_blocked_users(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_update_blocked_users_impl(args, state))
+}
+
+fn c2c_update_blocked_users_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let user_id = state.env.caller().into();
+
+    if !state.data.chat.members.contains(&user_id) {
+        return CallerNotInGroup;
+    }
+
+    state.data.personal_block_lists.update(user_id, args.blocked, args.unblocked);
+
+    Success
+}
@@ -0,0 +1,26 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::c2c_update_scoped_principals::{Response::*, *};
+
+// Called via the user's user canister
+#[update(msgpack = true)]
+#[trace]
+fn c2c_update_scoped_principals(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_update_scoped_principals_impl(args, state))
+}
+
+fn c2c_update_scoped_principals_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let user_id = state.env.caller().into();
+
+    if !state.data.chat.members.contains(&user_id) {
+        return CallerNotInGroup;
+    }
+
+    let now = state.env.now();
+    state.data.scoped_principals.update(user_id, args.principals, now);
+
+    Success
+}
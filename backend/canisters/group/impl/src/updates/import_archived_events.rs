@@ -0,0 +1,70 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::import_archived_events::{Response::*, *};
+use group_chat_core::ImportArchivedEventsResult;
+use types::CHAT_ARCHIVE_FORMAT_VERSION;
+
+const MAX_EVENTS_PER_BATCH: u32 = 1000;
+
+#[update(msgpack = true)]
+#[trace]
+fn import_archived_events(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| import_archived_events_impl(args, state))
+}
+
+fn import_archived_events_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    if args.format_version != CHAT_ARCHIVE_FORMAT_VERSION {
+        return UnsupportedFormatVersion(CHAT_ARCHIVE_FORMAT_VERSION);
+    }
+
+    if args.events.len() > MAX_EVENTS_PER_BATCH as usize {
+        return TooManyEvents(MAX_EVENTS_PER_BATCH);
+    }
+
+    let caller = state.env.caller();
+    let Some(user_id) = state.data.lookup_user_id(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let now = state.env.now();
+    let (session_id, mut session) = match args.session_id {
+        Some(session_id) => match state.data.archive_imports.take(session_id, user_id, now) {
+            Some(session) => (session_id, session),
+            None => return SessionNotFound,
+        },
+        None => state.data.archive_imports.start(user_id, state.env.rng(), now),
+    };
+
+    let result = state
+        .data
+        .chat
+        .import_archived_events(user_id, args.events, &mut session.message_index_map, now);
+
+    if !args.finished && matches!(result, ImportArchivedEventsResult::Success(_)) {
+        state.data.archive_imports.put(session_id, session, now);
+    }
+
+    match result {
+        ImportArchivedEventsResult::Success(messages_imported) => {
+            if messages_imported > 0 {
+                handle_activity_notification(state);
+            }
+            Success(SuccessResult {
+                session_id,
+                messages_imported,
+            })
+        }
+        ImportArchivedEventsResult::NotAuthorized => NotAuthorized,
+        ImportArchivedEventsResult::UserNotInGroup => CallerNotInGroup,
+        ImportArchivedEventsResult::UserSuspended => UserSuspended,
+        ImportArchivedEventsResult::UserLapsed => UserLapsed,
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use chat_events::Reader;
+use group_canister::mark_read_up_to::{Response::*, *};
+use group_chat_core::VerifyMemberError;
+
+#[update(msgpack = true)]
+#[trace]
+fn mark_read_up_to(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| mark_read_up_to_impl(args, state))
+}
+
+fn mark_read_up_to_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    if !state.data.read_receipts.enabled() {
+        return ReadReceiptsDisabled;
+    }
+
+    let caller = state.env.caller();
+    let Some(user_id) = state.data.lookup_user_id(caller) else {
+        return CallerNotInGroup;
+    };
+
+    match state.data.chat.members.get_verified_member(user_id) {
+        Ok(_) => {}
+        Err(VerifyMemberError::NotFound) => return CallerNotInGroup,
+        Err(VerifyMemberError::Lapsed) => return UserLapsed,
+        Err(VerifyMemberError::Suspended) => return UserSuspended,
+    }
+
+    if args.share_read_receipt {
+        let latest_message_index = state.data.chat.events.main_events_reader().latest_message_index();
+        if let Some(read_up_to) = latest_message_index.map(|i| i.min(args.read_up_to)) {
+            state.data.read_receipts.mark_read_up_to(user_id, read_up_to);
+        }
+    } else {
+        state.data.read_receipts.remove(&user_id);
+    }
+    Success
+}
@@ -0,0 +1,39 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::reject_join_request::{Response::*, *};
+use group_chat_core::RejectJoinRequestResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn reject_join_request(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| reject_join_request_impl(args, state))
+}
+
+fn reject_join_request_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(member) = state.data.get_member(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let now = state.env.now();
+
+    match state.data.chat.reject_join_request(member.user_id(), args.user_id, now) {
+        RejectJoinRequestResult::Success => {
+            handle_activity_notification(state);
+            Success
+        }
+        RejectJoinRequestResult::RequestNotFound => RequestNotFound,
+        RejectJoinRequestResult::UserNotInGroup => CallerNotInGroup,
+        RejectJoinRequestResult::UserSuspended => UserSuspended,
+        RejectJoinRequestResult::UserLapsed => UserLapsed,
+        RejectJoinRequestResult::NotAuthorized => NotAuthorized,
+    }
+}
@@ -10,8 +10,8 @@ use group_canister::c2c_send_message::{Args as C2CArgs, Response as C2CResponse}
 use group_canister::send_message_v2::{Response::*, *};
 use group_chat_core::SendMessageResult;
 use types::{
-    Achievement, BotCaller, BotPermissions, Caller, Chat, ContentValidationError, EventIndex, EventWrapper,
-    GroupMessageNotification, Message, MessageContent, MessageIndex, Notification, TimestampMillis, User,
+    Achievement, BotCaller, BotPermissions, Caller, Chat, ContentValidationError, DelegationScope, EventIndex, EventWrapper,
+    GroupMessageNotification, Message, MessageContent, MessageIndex, Notification, TimestampMillis, User, UserId,
 };
 use user_canister::{GroupCanisterEvent, MessageActivity, MessageActivityEvent};
 
@@ -64,7 +64,7 @@ pub(crate) fn send_message_impl(args: Args, bot: Option<BotCaller>, finalised: b
         return NotAuthorized;
     }
 
-    let caller = match state.verified_caller(bot) {
+    let caller = match state.verified_caller_with_scope(bot, Some(DelegationScope::SendMessages)) {
         CallerResult::Success(caller) => caller,
         CallerResult::NotFound => return CallerNotInGroup,
         CallerResult::Suspended => return UserSuspended,
@@ -72,7 +72,7 @@ pub(crate) fn send_message_impl(args: Args, bot: Option<BotCaller>, finalised: b
     };
 
     let now = state.env.now();
-    let mentioned: Vec<_> = args.mentioned.iter().map(|u| u.user_id).collect();
+    let mentioned = users_to_mention(&args.mentioned, &caller, state);
 
     let content =
         match MessageContentInternal::validate_new_message(args.content, false, (&caller).into(), args.forwarding, now) {
@@ -135,7 +135,7 @@ fn c2c_send_message_impl(args: C2CArgs, state: &mut RuntimeState) -> C2CResponse
     }
 
     let now = state.env.now();
-    let mentioned: Vec<_> = args.mentioned.iter().map(|u| u.user_id).collect();
+    let mentioned = users_to_mention(&args.mentioned, &caller, state);
     let result = state.data.chat.send_message(
         &caller,
         args.thread_root_message_index,
@@ -229,16 +229,11 @@ fn process_send_message_result(
                     }
                 }
 
-                for user in mentioned {
-                    if caller.initiator().map(|i| i != user.user_id).unwrap_or_default()
-                        && state
-                            .data
-                            .chat
-                            .members
-                            .get(&user.user_id)
-                            .is_some_and(|m| !m.user_type().is_bot())
+                for user in users_to_mention(&mentioned, caller, state) {
+                    if caller.initiator().map(|i| i != user).unwrap_or_default()
+                        && state.data.chat.members.get(&user).is_some_and(|m| !m.user_type().is_bot())
                     {
-                        activity_events.push((user.user_id, MessageActivity::Mention));
+                        activity_events.push((user, MessageActivity::Mention));
                     }
                 }
 
@@ -358,3 +353,14 @@ fn register_timer_jobs(
         _ => {}
     }
 }
+
+// Users who have blocked the sender are not mentioned
+fn users_to_mention(mentioned: &[User], caller: &Caller, state: &RuntimeState) -> Vec<UserId> {
+    let sender = caller.initiator();
+
+    mentioned
+        .iter()
+        .map(|u| u.user_id)
+        .filter(|u| sender.is_none_or(|s| !state.data.personal_block_lists.has_blocked(u, &s)))
+        .collect()
+}
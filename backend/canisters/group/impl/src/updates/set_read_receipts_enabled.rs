@@ -0,0 +1,67 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use chat_events::ChatEventInternal;
+use group_canister::set_read_receipts_enabled::{Response::*, *};
+use group_chat_core::VerifyMemberError;
+use types::ReadReceiptsChanged;
+use user_canister::GroupCanisterEvent;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_read_receipts_enabled(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| set_read_receipts_enabled_impl(args, state))
+}
+
+fn set_read_receipts_enabled_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(user_id) = state.data.lookup_user_id(caller) else {
+        return CallerNotInGroup;
+    };
+
+    let member = match state.data.chat.members.get_verified_member(user_id) {
+        Ok(member) => member,
+        Err(VerifyMemberError::NotFound) => return CallerNotInGroup,
+        Err(VerifyMemberError::Lapsed) => return UserLapsed,
+        Err(VerifyMemberError::Suspended) => return UserSuspended,
+    };
+
+    if !member.role().can_update_group(&state.data.chat.permissions) {
+        return NotAuthorized;
+    }
+
+    let now = state.env.now();
+    if state.data.read_receipts.set_enabled(args.enabled, now) {
+        state.data.chat.events.push_main_event(
+            ChatEventInternal::ReadReceiptsChanged(Box::new(ReadReceiptsChanged {
+                enabled: args.enabled,
+                changed_by: user_id,
+            })),
+            0,
+            now,
+        );
+
+        // Members' user canisters only send their read positions while read receipts are enabled
+        let bots = state.data.chat.members.bots();
+        let members: Vec<_> = state
+            .data
+            .chat
+            .members
+            .member_ids()
+            .iter()
+            .filter(|user_id| !bots.contains_key(user_id))
+            .copied()
+            .collect();
+
+        for member in members {
+            state.push_event_to_user(member, GroupCanisterEvent::ReadReceiptsChanged(args.enabled), now);
+        }
+    }
+    Success
+}
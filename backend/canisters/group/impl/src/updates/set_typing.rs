@@ -0,0 +1,37 @@
+use crate::{mutate_state, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::set_typing::{Response::*, *};
+use group_chat_core::VerifyMemberError;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_typing(args: Args) -> Response {
+    mutate_state(|state| set_typing_impl(args, state))
+}
+
+fn set_typing_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    let Some(user_id) = state.data.lookup_user_id(caller) else {
+        return CallerNotInGroup;
+    };
+
+    match state.data.chat.members.get_verified_member(user_id) {
+        Ok(_) => {}
+        Err(VerifyMemberError::NotFound) => return CallerNotInGroup,
+        Err(VerifyMemberError::Lapsed) => return UserLapsed,
+        Err(VerifyMemberError::Suspended) => return UserSuspended,
+    }
+
+    let now = state.env.now();
+    state
+        .data
+        .typing
+        .set(user_id, args.thread_root_message_index, args.is_typing, now);
+
+    Success
+}
@@ -8,7 +8,10 @@ use group_chat_core::UpdateResult;
 use group_community_common::{ExpiringMember, Members};
 use group_index_canister::{c2c_make_private, c2c_update_group};
 use tracing::error;
-use types::{AccessGateConfigInternal, CanisterId, ChatId, Document, OptionUpdate, TimestampMillis, Timestamped, UserId};
+use types::{
+    normalise_languages, AccessGateConfigInternal, CanisterId, ChatId, Document, OptionUpdate, Tag, TimestampMillis,
+    Timestamped, UserId, MAX_TAGS,
+};
 
 #[update(msgpack = true)]
 #[trace]
@@ -42,13 +45,17 @@ async fn update_group_v2(mut args: Args) -> Response {
             || args.description.is_some()
             || args.avatar.has_update()
             || args.public == Some(true)
-            || args.gate_config.has_update())
+            || args.gate_config.has_update()
+            || args.tags.is_some()
+            || args.languages.is_some())
     {
         let c2c_update_group_args = c2c_update_group::Args {
             name: prepare_result.name,
             description: prepare_result.description,
             avatar_id: prepare_result.avatar_id,
             gate_config: prepare_result.gate_config.map(|gc| gc.into()),
+            tags: prepare_result.tags,
+            languages: prepare_result.languages,
         };
 
         match group_index_canister_c2c_client::c2c_update_group(group_index_canister_id, &c2c_update_group_args).await {
@@ -85,6 +92,8 @@ struct PrepareResult {
     description: String,
     avatar_id: Option<u128>,
     gate_config: Option<AccessGateConfigInternal>,
+    tags: Option<Vec<Tag>>,
+    languages: Option<Vec<String>>,
 }
 
 fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response> {
@@ -92,11 +101,23 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
         return Err(ChatFrozen);
     }
     if let OptionUpdate::SetToSome(gate_config) = &args.gate_config {
-        if !gate_config.validate(state.data.test_mode) {
+        if !gate_config.validate(state.data.test_mode) || gate_config.gate.requires_community_membership() {
             return Err(AccessGateInvalid);
         }
     }
 
+    let tags = match args.tags.clone().map(Tag::normalise) {
+        Some(None) => return Err(TooManyTags(MAX_TAGS as u32)),
+        Some(Some(tags)) => Some(tags),
+        None => None,
+    };
+
+    let languages = match args.languages.clone().map(normalise_languages) {
+        Some(None) => return Err(InvalidLanguage),
+        Some(Some(languages)) => Some(languages),
+        None => None,
+    };
+
     let caller = state.env.caller();
     let gate_config = args
         .gate_config
@@ -105,6 +126,11 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
         .apply_to(state.data.chat.gate_config.value.clone());
 
     if let Some(member) = state.data.get_member(caller) {
+        // Only owners can set the tags and languages used to discover the group
+        if (args.tags.is_some() || args.languages.is_some()) && !member.role().is_owner() {
+            return Err(NotAuthorized);
+        }
+
         let permissions = args.permissions_v2.as_ref();
 
         match state.data.chat.can_update(
@@ -128,6 +154,8 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
                     description: args.description.as_ref().unwrap_or(&state.data.chat.description).clone(),
                     avatar_id: avatar_update.map_or(Document::id(&state.data.chat.avatar), |avatar| avatar.map(|a| a.id)),
                     gate_config,
+                    tags,
+                    languages,
                 })
             }
             Err(result) => match result {
@@ -207,6 +235,13 @@ pub fn update_member_expiry(data: &mut Data, prev_gate_config: &Option<AccessGat
     let new_gate_config = data.chat.gate_config.value.as_ref();
     let new_gate_expiry = new_gate_config.and_then(|gc| gc.expiry());
 
+    // Subscriptions taken out under a different subscription gate no longer apply
+    if prev_gate_config.as_ref().and_then(|gc| gc.gate().subscription_gate())
+        != new_gate_config.and_then(|gc| gc.gate().subscription_gate())
+    {
+        data.subscriptions.remove_gate(None);
+    }
+
     if let Some(prev_gate_expiry) = prev_gate_expiry {
         if let Some(new_gate_expiry) = new_gate_expiry {
             // If there is also a new expiring gate then update the expiry schedule of members if necessary
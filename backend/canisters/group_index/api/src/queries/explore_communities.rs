@@ -1,13 +1,14 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::CommunityMatch;
+use types::{CommunityMatch, Tag, TagCount};
 
 #[ts_export(group_index, explore_communities)]
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
     pub search_term: Option<String>,
     pub languages: Vec<String>,
+    pub tags: Option<Vec<Tag>>,
     pub page_index: u32,
     pub page_size: u8,
     pub include_moderation_flags: u32,
@@ -28,4 +29,5 @@ pub enum Response {
 pub struct SuccessResult {
     pub matches: Vec<CommunityMatch>,
     pub total: u32,
+    pub tag_counts: Vec<TagCount>,
 }
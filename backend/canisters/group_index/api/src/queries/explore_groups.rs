@@ -1,12 +1,14 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::GroupMatch;
+use types::{GroupMatch, Tag, TagCount};
 
 #[ts_export(group_index, explore_groups)]
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
     pub search_term: Option<String>,
+    pub tags: Option<Vec<Tag>>,
+    pub languages: Option<Vec<String>>,
     pub page_index: u32,
     pub page_size: u8,
 }
@@ -25,4 +27,5 @@ pub enum Response {
 pub struct SuccessResult {
     pub matches: Vec<GroupMatch>,
     pub total: u32,
+    pub tag_counts: Vec<TagCount>,
 }
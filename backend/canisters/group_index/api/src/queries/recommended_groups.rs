@@ -1,13 +1,20 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{ChatId, PublicGroupSummary};
+use types::{ChatId, CommunityId, PublicGroupSummary};
 
 #[ts_export(group_index, recommended_groups)]
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
     pub count: u8,
     pub exclusions: Vec<ChatId>,
+    // The groups the user is already a member of. If provided, these are excluded and the recommendations are
+    // ranked by how similar each group is to the user's existing groups.
+    pub memberships: Option<Vec<ChatId>>,
+    // The communities the user is a member of. Groups similar to these communities are ranked higher.
+    pub community_memberships: Option<Vec<CommunityId>>,
+    // The languages the user speaks. Groups in these languages are ranked higher.
+    pub languages: Option<Vec<String>>,
 }
 
 #[ts_export(group_index, recommended_groups)]
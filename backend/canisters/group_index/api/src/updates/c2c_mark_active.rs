@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
-use types::{Milliseconds, PublicGroupActivity};
+use types::{Milliseconds, PublicGroupActivity, UserId};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
     pub duration: Milliseconds,
     pub public_group_activity: Option<PublicGroupActivity>,
+    #[serde(default)]
+    pub active_users: Vec<UserId>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
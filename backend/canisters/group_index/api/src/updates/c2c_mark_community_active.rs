@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
-use types::{Milliseconds, PublicCommunityActivity};
+use types::{Milliseconds, PublicCommunityActivity, UserId};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
     pub duration: Milliseconds,
     pub public_community_activity: Option<PublicCommunityActivity>,
+    #[serde(default)]
+    pub active_users: Vec<UserId>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
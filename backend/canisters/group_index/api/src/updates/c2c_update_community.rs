@@ -1,6 +1,6 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
-use types::AccessGateConfig;
+use types::{AccessGateConfig, Tag};
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
@@ -11,6 +11,10 @@ pub struct Args {
     pub gate_config: Option<AccessGateConfig>,
     pub primary_language: String,
     pub channel_count: u32,
+    #[serde(default)]
+    pub tags: Option<Vec<Tag>>,
+    #[serde(default)]
+    pub languages: Option<Vec<String>>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -1,6 +1,6 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
-use types::AccessGateConfig;
+use types::{AccessGateConfig, Tag};
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
@@ -8,6 +8,10 @@ pub struct Args {
     pub description: String,
     pub avatar_id: Option<u128>,
     pub gate_config: Option<AccessGateConfig>,
+    #[serde(default)]
+    pub tags: Option<Vec<Tag>>,
+    #[serde(default)]
+    pub languages: Option<Vec<String>>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
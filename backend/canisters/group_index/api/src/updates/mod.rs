@@ -1,5 +1,6 @@
 pub mod add_hot_group_exclusion;
 pub mod add_local_group_index_canister;
+pub mod c2c_chit_balances;
 pub mod c2c_convert_group_into_community;
 pub mod c2c_create_community;
 pub mod c2c_create_group;
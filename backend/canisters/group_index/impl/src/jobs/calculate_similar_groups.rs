@@ -0,0 +1,48 @@
+use crate::{mutate_state, read_state, RuntimeState};
+use constants::HOUR_IN_MS;
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::trace;
+use types::Milliseconds;
+use utils::canister_timers::run_now_then_interval;
+
+const SIMILAR_GROUPS_REFRESH_INTERVAL: Milliseconds = HOUR_IN_MS;
+const MAX_USERS_PER_BATCH: usize = 500;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub fn start_job() {
+    run_now_then_interval(Duration::from_millis(SIMILAR_GROUPS_REFRESH_INTERVAL), start_calculation);
+}
+
+fn start_calculation() {
+    mutate_state(|state| {
+        let now = state.env.now();
+        state.data.group_co_activity.start_calculation(now);
+    });
+    read_state(start_batch_job_if_required);
+}
+
+fn start_batch_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() && state.data.group_co_activity.calculation_in_progress() {
+        let timer_id = ic_cdk_timers::set_timer(Duration::ZERO, run_batch);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+fn run_batch() {
+    trace!("'calculate_similar_groups' job running");
+    TIMER_ID.set(None);
+
+    let completed = mutate_state(|state| state.data.group_co_activity.calculate_next_batch(MAX_USERS_PER_BATCH));
+
+    if !completed {
+        read_state(start_batch_job_if_required);
+    }
+}
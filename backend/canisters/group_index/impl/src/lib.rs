@@ -1,6 +1,7 @@
 use crate::model::cached_hot_groups::CachedHotGroups;
 use crate::model::deleted_communities::DeletedCommunities;
 use crate::model::deleted_groups::DeletedGroups;
+use crate::model::group_co_activity::GroupCoActivity;
 use crate::model::local_group_index_map::LocalGroupIndex;
 use crate::model::private_communities::PrivateCommunities;
 use crate::model::private_groups::PrivateGroups;
@@ -291,6 +292,8 @@ struct Data {
     pub test_mode: bool,
     pub total_cycles_spent_on_canisters: Cycles,
     pub cached_hot_groups: CachedHotGroups,
+    #[serde(default)]
+    pub group_co_activity: GroupCoActivity,
     pub cached_metrics: CachedMetrics,
     pub local_index_map: LocalGroupIndexMap,
     pub fire_and_forget_handler: FireAndForgetHandler,
@@ -337,6 +340,7 @@ impl Data {
             test_mode,
             total_cycles_spent_on_canisters: 0,
             cached_hot_groups: CachedHotGroups::default(),
+            group_co_activity: GroupCoActivity::default(),
             cached_metrics: CachedMetrics::default(),
             local_index_map: LocalGroupIndexMap::default(),
             fire_and_forget_handler: FireAndForgetHandler::default(),
@@ -445,6 +449,7 @@ impl Default for Data {
             test_mode: true,
             total_cycles_spent_on_canisters: 0,
             cached_hot_groups: CachedHotGroups::default(),
+            group_co_activity: GroupCoActivity::default(),
             cached_metrics: CachedMetrics::default(),
             local_index_map: LocalGroupIndexMap::default(),
             fire_and_forget_handler: FireAndForgetHandler::default(),
@@ -536,8 +541,14 @@ pub struct AccessGateMetrics {
     pub verified_credential: u32,
     pub sns_neuron: u32,
     pub payment: u32,
+    #[serde(default)]
+    pub subscription: u32,
     pub token_balance: u32,
     #[serde(default)]
+    pub nft: u32,
+    #[serde(default)]
+    pub chit: u32,
+    #[serde(default)]
     pub composite: u32,
     #[serde(default)]
     pub locked: u32,
@@ -554,7 +565,12 @@ impl AccessGateMetrics {
             AccessGate::VerifiedCredential(_) => self.verified_credential += 1,
             AccessGate::SnsNeuron(_) => self.sns_neuron += 1,
             AccessGate::Payment(_) => self.payment += 1,
+            AccessGate::Subscription(_) => self.subscription += 1,
             AccessGate::TokenBalance(_) => self.token_balance += 1,
+            AccessGate::Nft(_) => self.nft += 1,
+            AccessGate::Chit(_) => self.chit += 1,
+            // Only valid on channels, which aren't registered with the group index
+            AccessGate::CommunityTenure(_) | AccessGate::CommunityMessageCount(_) => {}
             AccessGate::Composite(_) => self.composite += 1,
             AccessGate::Locked => self.locked += 1,
             AccessGate::ReferredByMember => self.referred_by_member += 1,
@@ -0,0 +1,196 @@
+use constants::DAY_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use types::{CanisterId, Milliseconds, TimestampMillis, UserId};
+use utils::iterator_extensions::IteratorExtensions;
+
+const ACTIVE_USERS_TTL: Milliseconds = 7 * DAY_IN_MS;
+const MAX_GROUPS_PER_USER: usize = 50;
+const MAX_SIMILAR_GROUPS: usize = 20;
+
+// Tracks which users have recently been active in each public group and community so that we can
+// periodically calculate, for each of them, which other groups and communities share the most
+// active users with it. Groups and communities are keyed by their canister Ids.
+#[derive(Serialize, Deserialize, Default)]
+pub struct GroupCoActivity {
+    active_users: BTreeMap<CanisterId, ActiveUsers>,
+    similar_groups: HashMap<CanisterId, Vec<(CanisterId, u32)>>,
+    // Any calculation in progress during an upgrade is simply restarted by the next run of the job
+    #[serde(skip)]
+    calculation: Option<Calculation>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ActiveUsers {
+    #[serde(rename = "u")]
+    users: Vec<UserId>,
+    #[serde(rename = "t")]
+    timestamp: TimestampMillis,
+}
+
+// The groups each user has been active in, which are processed in batches to build up the number
+// of active users each pair of groups has in common
+struct Calculation {
+    groups_per_user: Vec<Vec<CanisterId>>,
+    next_index: usize,
+    overlaps: HashMap<CanisterId, HashMap<CanisterId, u32>>,
+}
+
+impl GroupCoActivity {
+    pub fn set_active_users(&mut self, canister_id: CanisterId, users: Vec<UserId>, now: TimestampMillis) {
+        if users.is_empty() {
+            self.active_users.remove(&canister_id);
+        } else {
+            self.active_users.insert(canister_id, ActiveUsers { users, timestamp: now });
+        }
+    }
+
+    pub fn remove(&mut self, canister_id: &CanisterId) {
+        self.active_users.remove(canister_id);
+        self.similar_groups.remove(canister_id);
+    }
+
+    // The groups and communities most similar to the given group or community along with their
+    // similarity scores (0 - 1000)
+    pub fn similar_groups(&self, canister_id: &CanisterId) -> &[(CanisterId, u32)] {
+        self.similar_groups.get(canister_id).map(|s| s.as_slice()).unwrap_or_default()
+    }
+
+    pub fn calculation_in_progress(&self) -> bool {
+        self.calculation.is_some()
+    }
+
+    // Starts a new calculation, discarding any calculation which is still in progress
+    pub fn start_calculation(&mut self, now: TimestampMillis) {
+        self.active_users.retain(|_, a| a.timestamp + ACTIVE_USERS_TTL > now);
+
+        // Iterating over the `BTreeMap` ensures that the groups kept for users who have been active
+        // in more than `MAX_GROUPS_PER_USER` groups are chosen deterministically
+        let mut groups_per_user: BTreeMap<UserId, Vec<CanisterId>> = BTreeMap::new();
+        for (canister_id, active) in self.active_users.iter() {
+            for user_id in active.users.iter() {
+                let groups = groups_per_user.entry(*user_id).or_default();
+                if groups.len() < MAX_GROUPS_PER_USER {
+                    groups.push(*canister_id);
+                }
+            }
+        }
+
+        self.calculation = Some(Calculation {
+            groups_per_user: groups_per_user.into_values().filter(|g| g.len() > 1).collect(),
+            next_index: 0,
+            overlaps: HashMap::new(),
+        });
+    }
+
+    // Processes the next `max_users` users of the calculation in progress. Once all users have been
+    // processed the similar groups are updated and this returns true.
+    pub fn calculate_next_batch(&mut self, max_users: usize) -> bool {
+        let Some(calculation) = self.calculation.as_mut() else {
+            return true;
+        };
+
+        let end_index = (calculation.next_index + max_users).min(calculation.groups_per_user.len());
+        for groups in &calculation.groups_per_user[calculation.next_index..end_index] {
+            for (i, a) in groups.iter().enumerate() {
+                for b in groups.iter().skip(i + 1) {
+                    *calculation.overlaps.entry(*a).or_default().entry(*b).or_default() += 1;
+                    *calculation.overlaps.entry(*b).or_default().entry(*a).or_default() += 1;
+                }
+            }
+        }
+        calculation.next_index = end_index;
+
+        if end_index < calculation.groups_per_user.len() {
+            return false;
+        }
+
+        let overlaps = self.calculation.take().map(|c| c.overlaps).unwrap_or_default();
+        let active_user_count =
+            |canister_id: &CanisterId| self.active_users.get(canister_id).map_or(0, |a| a.users.len()) as f64;
+
+        self.similar_groups = overlaps
+            .into_iter()
+            .map(|(canister_id, overlapping)| {
+                let count = active_user_count(&canister_id);
+                let similar = overlapping
+                    .into_iter()
+                    .map(|(other, overlap)| {
+                        // Cosine similarity between the two sets of active users
+                        let score = (1000.0 * overlap as f64 / (count * active_user_count(&other)).sqrt()) as u32;
+                        (other, score)
+                    })
+                    .filter(|(_, score)| *score > 0)
+                    .max_n_by(MAX_SIMILAR_GROUPS, |(_, score)| *score as usize)
+                    .collect();
+
+                (canister_id, similar)
+            })
+            .collect();
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    fn calculate(co_activity: &mut GroupCoActivity, now: TimestampMillis) {
+        co_activity.start_calculation(now);
+        while !co_activity.calculate_next_batch(1) {}
+        assert!(!co_activity.calculation_in_progress());
+    }
+
+    #[test]
+    fn groups_sharing_active_users_are_similar() {
+        let mut co_activity = GroupCoActivity::default();
+        let group = |i: u8| CanisterId::from_slice(&[i]);
+        let users = |ids: &[u8]| ids.iter().map(|i| UserId::from(Principal::from_slice(&[100, *i]))).collect();
+
+        co_activity.set_active_users(group(1), users(&[1, 2, 3, 4]), 0);
+        co_activity.set_active_users(group(2), users(&[1, 2, 3, 5]), 0);
+        co_activity.set_active_users(group(3), users(&[4, 6, 7, 8]), 0);
+        co_activity.set_active_users(group(4), users(&[9]), 0);
+
+        calculate(&mut co_activity, 1);
+
+        let similar = co_activity.similar_groups(&group(1));
+        assert_eq!(similar.iter().map(|(c, _)| *c).collect::<Vec<_>>(), vec![group(2), group(3)]);
+        assert_eq!(similar[0].1, 750);
+        assert_eq!(similar[1].1, 250);
+        assert!(co_activity.similar_groups(&group(4)).is_empty());
+    }
+
+    #[test]
+    fn stale_activity_is_ignored() {
+        let mut co_activity = GroupCoActivity::default();
+        let group = |i: u8| CanisterId::from_slice(&[i]);
+        let user = UserId::from(Principal::from_slice(&[100]));
+
+        co_activity.set_active_users(group(1), vec![user], 0);
+        co_activity.set_active_users(group(2), vec![user], ACTIVE_USERS_TTL);
+
+        calculate(&mut co_activity, ACTIVE_USERS_TTL + 1);
+
+        assert!(co_activity.similar_groups(&group(2)).is_empty());
+    }
+
+    #[test]
+    fn similar_groups_unchanged_until_calculation_completes() {
+        let mut co_activity = GroupCoActivity::default();
+        let group = |i: u8| CanisterId::from_slice(&[i]);
+        let users = |ids: &[u8]| ids.iter().map(|i| UserId::from(Principal::from_slice(&[100, *i]))).collect();
+
+        co_activity.set_active_users(group(1), users(&[1, 2]), 0);
+        co_activity.set_active_users(group(2), users(&[1, 2]), 0);
+
+        co_activity.start_calculation(1);
+        assert!(!co_activity.calculate_next_batch(1));
+        assert!(co_activity.similar_groups(&group(1)).is_empty());
+
+        assert!(co_activity.calculate_next_batch(1));
+        assert_eq!(co_activity.similar_groups(&group(1)), &[(group(2), 1000)]);
+    }
+}
@@ -4,7 +4,7 @@ use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
-use types::{CommunityId, FrozenCommunityInfo, TimestampMillis};
+use types::{CommunityId, FrozenCommunityInfo, Tag, TimestampMillis};
 
 #[derive(CandidType, Serialize, Deserialize, Default)]
 pub struct PrivateCommunities {
@@ -49,6 +49,11 @@ pub struct PrivateCommunityInfo {
     created: TimestampMillis,
     marked_active_until: TimestampMillis,
     frozen: Option<FrozenCommunityInfo>,
+    // The tags and languages are retained so that they are restored if the chat is made public again
+    #[serde(default)]
+    tags: Vec<Tag>,
+    #[serde(default)]
+    languages: Vec<String>,
 }
 
 impl PrivateCommunityInfo {
@@ -58,6 +63,8 @@ impl PrivateCommunityInfo {
             created: now,
             marked_active_until: now + MARK_ACTIVE_DURATION,
             frozen: None,
+            tags: Vec::new(),
+            languages: Vec::new(),
         }
     }
 
@@ -88,4 +95,20 @@ impl PrivateCommunityInfo {
     pub fn set_frozen(&mut self, info: Option<FrozenCommunityInfo>) {
         self.frozen = info;
     }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = tags;
+    }
+
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    pub fn set_languages(&mut self, languages: Vec<String>) {
+        self.languages = languages;
+    }
 }
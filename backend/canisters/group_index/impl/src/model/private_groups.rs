@@ -3,7 +3,7 @@ use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
-use types::{ChatId, FrozenGroupInfo, TimestampMillis};
+use types::{ChatId, FrozenGroupInfo, Tag, TimestampMillis};
 
 #[derive(CandidType, Serialize, Deserialize, Default)]
 pub struct PrivateGroups {
@@ -48,6 +48,11 @@ pub struct PrivateGroupInfo {
     created: TimestampMillis,
     marked_active_until: TimestampMillis,
     frozen: Option<FrozenGroupInfo>,
+    // The tags and languages are retained so that they are restored if the chat is made public again
+    #[serde(default)]
+    tags: Vec<Tag>,
+    #[serde(default)]
+    languages: Vec<String>,
 }
 
 impl PrivateGroupInfo {
@@ -57,6 +62,8 @@ impl PrivateGroupInfo {
             created: now,
             marked_active_until: now + MARK_ACTIVE_DURATION,
             frozen: None,
+            tags: Vec::new(),
+            languages: Vec::new(),
         }
     }
 
@@ -87,4 +94,20 @@ impl PrivateGroupInfo {
     pub fn set_frozen(&mut self, info: Option<FrozenGroupInfo>) {
         self.frozen = info;
     }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = tags;
+    }
+
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    pub fn set_languages(&mut self, languages: Vec<String>) {
+        self.languages = languages;
+    }
 }
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use types::{
     AccessGate, AccessGateConfig, AccessGateConfigInternal, CommunityId, CommunityMatch, FrozenCommunityInfo,
-    PublicCommunityActivity, TimestampMillis,
+    PublicCommunityActivity, Tag, TagCount, TimestampMillis,
 };
 
 #[derive(Serialize, Deserialize, Default)]
@@ -60,17 +60,22 @@ impl PublicCommunities {
         &self,
         search_term: Option<String>,
         include_moderation_flags: ModerationFlags,
-        languages: Vec<String>,
+        tags: &[Tag],
+        languages: &[String],
         page_index: u32,
         page_size: u8,
-    ) -> (Vec<CommunityMatch>, u32) {
+    ) -> (Vec<CommunityMatch>, u32, Vec<TagCount>) {
         let query = search_term.map(Query::parse);
 
         let mut matches: Vec<_> = self
             .iter()
             .filter(|c| !c.is_frozen())
             .filter(|c| include_moderation_flags.contains(*c.moderation_flags()))
-            .filter(|c| languages.is_empty() || languages.contains(&c.primary_language))
+            .filter(|c| {
+                languages.is_empty()
+                    || languages.contains(&c.primary_language)
+                    || c.languages.iter().any(|l| languages.contains(l))
+            })
             .map(|c| {
                 let score = if let Some(query) = &query {
                     let document: Document = c.into();
@@ -85,6 +90,13 @@ impl PublicCommunities {
             .filter(|(score, _)| *score > 0)
             .collect();
 
+        // The tag counts are calculated before filtering by tag so that they reflect how many results each tag would give
+        let tag_counts = Tag::count(matches.iter().flat_map(|(_, c)| c.tags.iter()));
+
+        if !tags.is_empty() {
+            matches.retain(|(_, c)| c.tags.iter().any(|t| tags.contains(t)));
+        }
+
         let total = matches.len() as u32;
 
         matches.sort_by_key(|(score, _)| *score);
@@ -97,7 +109,7 @@ impl PublicCommunities {
             .take(page_size as usize)
             .collect();
 
-        (matches, total)
+        (matches, total, tag_counts)
     }
 
     pub fn update_community(
@@ -159,6 +171,10 @@ pub struct PublicCommunityInfo {
     primary_language: String,
     #[serde(default)]
     verified: bool,
+    #[serde(default)]
+    tags: Vec<Tag>,
+    #[serde(default)]
+    languages: Vec<String>,
 }
 
 pub enum UpdateCommunityResult {
@@ -194,6 +210,8 @@ impl PublicCommunityInfo {
             moderation_flags: ModerationFlags::default(),
             primary_language,
             verified: false,
+            tags: Vec::new(),
+            languages: Vec::new(),
         }
     }
 
@@ -254,6 +272,22 @@ impl PublicCommunityInfo {
         self.hotness_score = hotness_score;
     }
 
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = tags;
+    }
+
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    pub fn set_languages(&mut self, languages: Vec<String>) {
+        self.languages = languages;
+    }
+
     pub fn moderation_flags(&self) -> &ModerationFlags {
         &self.moderation_flags
     }
@@ -281,6 +315,8 @@ impl PublicCommunityInfo {
             moderation_flags: self.moderation_flags.bits(),
             primary_language: self.primary_language.clone(),
             verified: self.verified,
+            tags: self.tags.clone(),
+            languages: self.languages.clone(),
         }
     }
 }
@@ -300,6 +336,8 @@ impl From<PublicCommunityInfo> for PrivateCommunityInfo {
         let mut private_community_info = PrivateCommunityInfo::new(public_community_info.id, public_community_info.created);
         private_community_info.mark_active(public_community_info.marked_active_until);
         private_community_info.set_frozen(public_community_info.frozen);
+        private_community_info.set_tags(public_community_info.tags);
+        private_community_info.set_languages(public_community_info.languages);
         private_community_info
     }
 }
@@ -8,7 +8,7 @@ use std::cmp;
 use std::collections::HashMap;
 use types::{
     AccessGate, AccessGateConfig, AccessGateConfigInternal, BuildVersion, ChatId, FrozenGroupInfo, GroupMatch, GroupSubtype,
-    PublicGroupActivity, PublicGroupSummary, TimestampMillis,
+    PublicGroupActivity, PublicGroupSummary, Tag, TagCount, TimestampMillis,
 };
 use utils::iterator_extensions::IteratorExtensions;
 
@@ -47,12 +47,20 @@ impl PublicGroups {
         );
     }
 
-    pub fn search(&self, search_term: Option<String>, page_index: u32, page_size: u8) -> (Vec<GroupMatch>, u32) {
+    pub fn search(
+        &self,
+        search_term: Option<String>,
+        tags: &[Tag],
+        languages: &[String],
+        page_index: u32,
+        page_size: u8,
+    ) -> (Vec<GroupMatch>, u32, Vec<TagCount>) {
         let query = search_term.map(Query::parse);
 
         let mut matches: Vec<_> = self
             .iter()
             .filter(|c| !c.is_frozen())
+            .filter(|c| languages.is_empty() || c.languages.iter().any(|l| languages.contains(l)))
             .map(|c| {
                 let score = if let Some(query) = &query {
                     let document: Document = c.into();
@@ -67,6 +75,13 @@ impl PublicGroups {
             .filter(|(score, _)| *score > 0)
             .collect();
 
+        // The tag counts are calculated before filtering by tag so that they reflect how many results each tag would give
+        let tag_counts = Tag::count(matches.iter().flat_map(|(_, c)| c.tags.iter()));
+
+        if !tags.is_empty() {
+            matches.retain(|(_, c)| c.tags.iter().any(|t| tags.contains(t)));
+        }
+
         let total = matches.len() as u32;
 
         matches.sort_by_key(|(score, _)| *score);
@@ -79,7 +94,7 @@ impl PublicGroups {
             .take(page_size as usize)
             .collect();
 
-        (matches, total)
+        (matches, total, tag_counts)
     }
 
     pub fn hydrate_cached_summary(&self, summary: CachedPublicGroupSummary) -> Option<PublicGroupSummary> {
@@ -172,6 +187,10 @@ pub struct PublicGroupInfo {
     gate_config: Option<AccessGateConfigInternal>,
     #[serde(default)]
     verified: bool,
+    #[serde(default)]
+    tags: Vec<Tag>,
+    #[serde(default)]
+    languages: Vec<String>,
 }
 
 pub enum UpdateGroupResult {
@@ -204,6 +223,8 @@ impl PublicGroupInfo {
             frozen: None,
             exclude_from_hotlist: false,
             verified: false,
+            tags: Vec::new(),
+            languages: Vec::new(),
         }
     }
 
@@ -219,6 +240,26 @@ impl PublicGroupInfo {
         self.created
     }
 
+    pub fn subtype(&self) -> Option<&GroupSubtype> {
+        self.subtype.as_ref()
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = tags;
+    }
+
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    pub fn set_languages(&mut self, languages: Vec<String>) {
+        self.languages = languages;
+    }
+
     pub fn marked_active_until(&self) -> TimestampMillis {
         self.marked_active_until
     }
@@ -288,6 +329,8 @@ impl From<&PublicGroupInfo> for GroupMatch {
             gate: group.gate_config.as_ref().map(|g| g.gate.clone()),
             subtype: group.subtype.clone(),
             verified: group.verified(),
+            tags: group.tags.clone(),
+            languages: group.languages.clone(),
         }
     }
 }
@@ -307,6 +350,8 @@ impl From<PublicGroupInfo> for PrivateGroupInfo {
         let mut private_group_info = PrivateGroupInfo::new(public_group_info.id, public_group_info.created);
         private_group_info.mark_active(public_group_info.marked_active_until);
         private_group_info.set_frozen(public_group_info.frozen);
+        private_group_info.set_tags(public_group_info.tags);
+        private_group_info.set_languages(public_group_info.languages);
         private_group_info
     }
 }
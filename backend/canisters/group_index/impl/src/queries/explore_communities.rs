@@ -1,6 +1,7 @@
 use crate::{model::moderation_flags::ModerationFlags, read_state, RuntimeState};
 use canister_api_macros::query;
 use group_index_canister::explore_communities::{Response::*, *};
+use types::languages_filter;
 
 const MIN_TERM_LENGTH: u8 = 2;
 const MAX_TERM_LENGTH: u8 = 20;
@@ -26,13 +27,18 @@ fn explore_communities_impl(args: Args, state: &RuntimeState) -> Response {
         None => return InvalidFlags,
     };
 
-    let (matches, total) = state.data.public_communities.search(
+    let (matches, total, tag_counts) = state.data.public_communities.search(
         args.search_term,
         include_moderation_flags,
-        args.languages,
+        &args.tags.unwrap_or_default(),
+        &languages_filter(args.languages),
         args.page_index,
         args.page_size,
     );
 
-    Success(SuccessResult { matches, total })
+    Success(SuccessResult {
+        matches,
+        total,
+        tag_counts,
+    })
 }
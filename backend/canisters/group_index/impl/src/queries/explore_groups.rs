@@ -1,6 +1,7 @@
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use group_index_canister::explore_groups::{Response::*, *};
+use types::languages_filter;
 
 const MIN_TERM_LENGTH: u8 = 2;
 const MAX_TERM_LENGTH: u8 = 20;
@@ -21,10 +22,17 @@ fn explore_groups_impl(args: Args, state: &RuntimeState) -> Response {
         }
     }
 
-    let (matches, total) = state
-        .data
-        .public_groups
-        .search(args.search_term, args.page_index, args.page_size);
+    let (matches, total, tag_counts) = state.data.public_groups.search(
+        args.search_term,
+        &args.tags.unwrap_or_default(),
+        &languages_filter(args.languages.unwrap_or_default()),
+        args.page_index,
+        args.page_size,
+    );
 
-    Success(SuccessResult { matches, total })
+    Success(SuccessResult {
+        matches,
+        total,
+        tag_counts,
+    })
 }
@@ -1,7 +1,17 @@
+use crate::model::cached_hot_groups::CachedPublicGroupSummary;
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use group_index_canister::recommended_groups::{Response::*, *};
-use std::collections::HashSet;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::mem::discriminant;
+use types::{languages_filter, CanisterId, ChatId, CommunityId};
+
+// The boosts given to groups which match the user's existing groups or languages, on the same scale as the
+// similarity scores (0 - 1000)
+const MATCHING_SUBTYPE_SCORE: u32 = 250;
+const MATCHING_TAG_SCORE: u32 = 100;
+const MATCHING_LANGUAGE_SCORE: u32 = 500;
 
 #[query(candid = true, msgpack = true)]
 fn recommended_groups(args: Args) -> Response {
@@ -9,14 +19,76 @@ fn recommended_groups(args: Args) -> Response {
 }
 
 fn recommended_groups_impl(args: Args, state: &RuntimeState) -> Response {
-    let exclusions: HashSet<_> = args.exclusions.into_iter().collect();
-    let groups = state
-        .data
-        .cached_hot_groups
-        .get(args.count as usize, &exclusions)
+    let memberships = args.memberships.unwrap_or_default();
+    let community_memberships = args.community_memberships.unwrap_or_default();
+    let languages = languages_filter(args.languages.unwrap_or_default());
+    let exclusions: HashSet<_> = args.exclusions.into_iter().chain(memberships.iter().copied()).collect();
+
+    let hot_groups = if memberships.is_empty() && community_memberships.is_empty() && languages.is_empty() {
+        state.data.cached_hot_groups.get(args.count as usize, &exclusions)
+    } else {
+        personalise(
+            state.data.cached_hot_groups.get(usize::MAX, &exclusions),
+            &memberships,
+            &community_memberships,
+            &languages,
+            args.count as usize,
+            state,
+        )
+    };
+
+    let groups = hot_groups
         .into_iter()
         .filter_map(|g| state.data.public_groups.hydrate_cached_summary(g))
         .collect();
 
     Success(SuccessResult { groups })
 }
+
+// Reorders the hot groups (which are already sorted by hotness) so that those most similar to the user's existing
+// groups and communities and in the user's languages come first, falling back to hotness for groups with equal scores
+fn personalise(
+    mut hot_groups: Vec<CachedPublicGroupSummary>,
+    memberships: &[ChatId],
+    community_memberships: &[CommunityId],
+    languages: &[String],
+    count: usize,
+    state: &RuntimeState,
+) -> Vec<CachedPublicGroupSummary> {
+    let mut scores: HashMap<CanisterId, u32> = HashMap::new();
+    let membership_canister_ids = memberships
+        .iter()
+        .map(|c| CanisterId::from(*c))
+        .chain(community_memberships.iter().map(|c| CanisterId::from(*c)));
+    for canister_id in membership_canister_ids {
+        for (similar, score) in state.data.group_co_activity.similar_groups(&canister_id) {
+            *scores.entry(*similar).or_default() += score;
+        }
+    }
+
+    let member_groups: Vec<_> = memberships
+        .iter()
+        .filter_map(|chat_id| state.data.public_groups.get(chat_id))
+        .collect();
+    let member_subtypes: HashSet<_> = member_groups.iter().filter_map(|g| g.subtype()).map(discriminant).collect();
+    let member_tags: HashSet<_> = member_groups.iter().flat_map(|g| g.tags().iter().copied()).collect();
+
+    let score = |chat_id: &ChatId| {
+        let mut score = scores.get(&CanisterId::from(*chat_id)).copied().unwrap_or_default();
+
+        if let Some(group) = state.data.public_groups.get(chat_id) {
+            if group.subtype().is_some_and(|s| member_subtypes.contains(&discriminant(s))) {
+                score += MATCHING_SUBTYPE_SCORE;
+            }
+            score += MATCHING_TAG_SCORE * group.tags().iter().filter(|t| member_tags.contains(t)).count() as u32;
+            if group.languages().iter().any(|l| languages.contains(l)) {
+                score += MATCHING_LANGUAGE_SCORE;
+            }
+        }
+        score
+    };
+
+    hot_groups.sort_by_cached_key(|g| Reverse(score(&g.chat_id)));
+    hot_groups.truncate(count);
+    hot_groups
+}
@@ -21,7 +21,10 @@ fn search_impl(args: Args, state: &RuntimeState) -> Response {
         return TermTooLong(MAX_TERM_LENGTH);
     }
 
-    let (matches, total) = state.data.public_groups.search(Some(args.search_term), 0, args.max_results);
+    let (matches, total, _) = state
+        .data
+        .public_groups
+        .search(Some(args.search_term), &[], &[], 0, args.max_results);
 
     Success(SuccessResult { matches, total })
 }
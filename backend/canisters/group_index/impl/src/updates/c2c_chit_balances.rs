@@ -0,0 +1,24 @@
+use crate::guards::caller_is_community_canister;
+use crate::read_state;
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_index_canister::c2c_chit_balances::{Response::*, *};
+
+// User index only serves CHIT balances to the group index, which checks here that the caller is a community
+#[update(guard = "caller_is_community_canister", msgpack = true)]
+#[trace]
+async fn c2c_chit_balances(args: Args) -> Response {
+    let user_index_canister_id = read_state(|state| state.data.user_index_canister_id);
+
+    match user_index_canister_c2c_client::c2c_chit_balances(
+        user_index_canister_id,
+        &user_index_canister::c2c_chit_balances::Args { users: args.users },
+    )
+    .await
+    {
+        Ok(user_index_canister::c2c_chit_balances::Response::Success(result)) => Success(SuccessResult {
+            balances: result.balances,
+        }),
+        Err(error) => InternalError(format!("{error:?}")),
+    }
+}
@@ -78,6 +78,7 @@ fn commit(community_id: CommunityId, deleted_by: UserId, name: String, members:
             .data
             .public_group_and_community_names
             .remove(community.name(), community_id.into());
+        state.data.group_co_activity.remove(&community_id.into());
         true
     } else {
         state.data.private_communities.delete(&community_id);
@@ -43,6 +43,7 @@ pub(crate) fn delete_group(
             .data
             .public_group_and_community_names
             .remove(group.name(), group_id.into());
+        state.data.group_co_activity.remove(&group_id.into());
         true
     } else {
         state.data.private_groups.delete(&group_id);
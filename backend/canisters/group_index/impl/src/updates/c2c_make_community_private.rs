@@ -19,6 +19,7 @@ fn c2c_make_community_private_impl(state: &mut RuntimeState) -> Response {
             .data
             .public_group_and_community_names
             .remove(community.name(), community_id.into());
+        state.data.group_co_activity.remove(&community_id.into());
         state.data.private_communities.add(community.into());
         Success
     } else {
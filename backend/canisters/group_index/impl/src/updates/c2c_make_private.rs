@@ -20,6 +20,7 @@ fn c2c_make_private_impl(state: &mut RuntimeState) -> Response {
             .public_group_and_community_names
             .remove(group.name(), chat_id.into());
         state.data.cached_hot_groups.remove(chat_id);
+        state.data.group_co_activity.remove(&chat_id.into());
         state.data.private_groups.add(group.into());
         Success
     } else {
@@ -19,6 +19,10 @@ fn c2c_mark_active_impl(args: Args, state: &mut RuntimeState) -> Response {
     } else if let Some(g) = state.data.public_groups.get_mut(&chat_id) {
         let activity = args.public_group_activity.unwrap_or_default();
         g.mark_active(now + args.duration, activity);
+        state
+            .data
+            .group_co_activity
+            .set_active_users(chat_id.into(), args.active_users, now);
     } else {
         return ChatNotFound;
     }
@@ -3,17 +3,20 @@ use crate::{mutate_state, RuntimeState};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_index_canister::c2c_mark_community_active::{Response::*, *};
-use types::{CommunityId, Milliseconds, PublicCommunityActivity};
+use types::{CommunityId, Milliseconds, PublicCommunityActivity, UserId};
 
 #[update(guard = "caller_is_community_canister", msgpack = true)]
 #[trace]
 fn c2c_mark_community_active(args: Args) -> Response {
-    mutate_state(|state| c2c_mark_community_active_impl(args.duration, args.public_community_activity, state))
+    mutate_state(|state| {
+        c2c_mark_community_active_impl(args.duration, args.public_community_activity, Some(args.active_users), state)
+    })
 }
 
 pub(crate) fn c2c_mark_community_active_impl(
     duration: Milliseconds,
     activity: Option<PublicCommunityActivity>,
+    active_users: Option<Vec<UserId>>,
     state: &mut RuntimeState,
 ) -> Response {
     let community_id = CommunityId::from(state.env.caller());
@@ -24,6 +27,12 @@ pub(crate) fn c2c_mark_community_active_impl(
     } else if let Some(c) = state.data.public_communities.get_mut(&community_id) {
         let activity = activity.unwrap_or_default();
         c.mark_active(now + duration, activity);
+        if let Some(active_users) = active_users {
+            state
+                .data
+                .group_co_activity
+                .set_active_users(community_id.into(), active_users, now);
+        }
     }
     Success
 }
@@ -17,7 +17,7 @@ fn c2c_mark_group_import_complete(args: Args) -> Response {
 fn c2c_mark_group_import_complete_impl(args: Args, state: &mut RuntimeState) -> Response {
     let community_id: CommunityId = state.env.caller().into();
 
-    c2c_mark_community_active_impl(args.mark_active_duration, args.public_community_activity, state);
+    c2c_mark_community_active_impl(args.mark_active_duration, args.public_community_activity, None, state);
 
     delete_group(
         args.group_id,
@@ -2,7 +2,7 @@ use crate::{mutate_state, RuntimeState};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_index_canister::c2c_update_community::{Response::*, *};
-use types::CommunityId;
+use types::{normalise_languages, CommunityId, Tag};
 
 #[update(msgpack = true)]
 #[trace]
@@ -34,6 +34,7 @@ fn c2c_update_community_impl(args: Args, state: &mut RuntimeState) -> Response {
             args.banner_id,
             args.gate_config,
         );
+        update_tags_and_languages(&community_id, args.tags, args.languages, state);
         Success
     } else if let Some(community) = state.data.private_communities.get(&community_id) {
         if state.data.public_group_and_community_names.is_name_taken(&args.name, now) {
@@ -41,6 +42,8 @@ fn c2c_update_community_impl(args: Args, state: &mut RuntimeState) -> Response {
         }
 
         let date_created = community.created();
+        let tags = args.tags.unwrap_or_else(|| community.tags().to_vec());
+        let languages = args.languages.unwrap_or_else(|| community.languages().to_vec());
 
         state.data.private_communities.delete(&community_id);
         state
@@ -59,8 +62,25 @@ fn c2c_update_community_impl(args: Args, state: &mut RuntimeState) -> Response {
             args.channel_count,
             date_created,
         );
+        update_tags_and_languages(&community_id, Some(tags), Some(languages), state);
         Success
     } else {
         CommunityNotFound
     }
 }
+
+fn update_tags_and_languages(
+    community_id: &CommunityId,
+    tags: Option<Vec<Tag>>,
+    languages: Option<Vec<String>>,
+    state: &mut RuntimeState,
+) {
+    if let Some(community) = state.data.public_communities.get_mut(community_id) {
+        if let Some(tags) = tags.and_then(Tag::normalise) {
+            community.set_tags(tags);
+        }
+        if let Some(languages) = languages.and_then(normalise_languages) {
+            community.set_languages(languages);
+        }
+    }
+}
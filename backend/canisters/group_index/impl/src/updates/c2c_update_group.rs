@@ -2,7 +2,7 @@ use crate::{mutate_state, RuntimeState};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_index_canister::c2c_update_group::{Response::*, *};
-use types::ChatId;
+use types::{normalise_languages, ChatId, Tag};
 
 #[update(msgpack = true)]
 #[trace]
@@ -30,6 +30,7 @@ fn c2c_update_group_impl(args: Args, state: &mut RuntimeState) -> Response {
             .data
             .public_groups
             .update_group(&chat_id, args.name, args.description, args.avatar_id, args.gate_config);
+        update_tags_and_languages(&chat_id, args.tags, args.languages, state);
         Success
     } else if let Some(group) = state.data.private_groups.get(&chat_id) {
         if state.data.public_group_and_community_names.is_name_taken(&args.name, now) {
@@ -37,6 +38,8 @@ fn c2c_update_group_impl(args: Args, state: &mut RuntimeState) -> Response {
         }
 
         let date_created = group.created();
+        let tags = args.tags.unwrap_or_else(|| group.tags().to_vec());
+        let languages = args.languages.unwrap_or_else(|| group.languages().to_vec());
 
         state.data.private_groups.delete(&chat_id);
         state.data.public_group_and_community_names.insert(&args.name, chat_id.into());
@@ -50,8 +53,25 @@ fn c2c_update_group_impl(args: Args, state: &mut RuntimeState) -> Response {
             args.gate_config,
             date_created,
         );
+        update_tags_and_languages(&chat_id, Some(tags), Some(languages), state);
         Success
     } else {
         ChatNotFound
     }
 }
+
+fn update_tags_and_languages(
+    chat_id: &ChatId,
+    tags: Option<Vec<Tag>>,
+    languages: Option<Vec<String>>,
+    state: &mut RuntimeState,
+) {
+    if let Some(group) = state.data.public_groups.get_mut(chat_id) {
+        if let Some(tags) = tags.and_then(Tag::normalise) {
+            group.set_tags(tags);
+        }
+        if let Some(languages) = languages.and_then(normalise_languages) {
+            group.set_languages(languages);
+        }
+    }
+}
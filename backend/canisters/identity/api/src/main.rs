@@ -5,14 +5,28 @@ fn main() {
     generate_candid_method!(identity, check_auth_principal, query);
     generate_candid_method!(identity, check_auth_principal_v2, query);
     generate_candid_method!(identity, get_delegation, query);
+    generate_candid_method!(identity, get_scoped_delegation, query);
     generate_candid_method!(identity, lookup_webauthn_pubkey, query);
+    generate_candid_method!(identity, recovery_contacts, query);
+    generate_candid_method!(identity, scoped_delegations, query);
+    generate_candid_method!(identity, webauthn_keys, query);
 
     generate_candid_method!(identity, approve_identity_link, update);
+    generate_candid_method!(identity, approve_recovery, update);
+    generate_candid_method!(identity, cancel_recovery, update);
+    generate_candid_method!(identity, complete_recovery, update);
     generate_candid_method!(identity, create_identity, update);
     generate_candid_method!(identity, generate_challenge, update);
     generate_candid_method!(identity, initiate_identity_link, update);
+    generate_candid_method!(identity, initiate_recovery, update);
     generate_candid_method!(identity, prepare_delegation, update);
+    generate_candid_method!(identity, prepare_scoped_delegation, update);
+    generate_candid_method!(identity, reject_recovery, update);
     generate_candid_method!(identity, remove_identity_link, update);
+    generate_candid_method!(identity, revoke_auth_principal, update);
+    generate_candid_method!(identity, revoke_scoped_delegation, update);
+    generate_candid_method!(identity, set_auth_principal_name, update);
+    generate_candid_method!(identity, set_recovery_contacts, update);
 
     candid::export_service!();
     std::print!("{}", __export_service());
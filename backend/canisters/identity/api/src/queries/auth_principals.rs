@@ -18,5 +18,7 @@ pub struct UserPrincipal {
     pub is_ii_principal: bool,
     pub is_current_identity: bool,
     pub webauthn_key: Option<WebAuthnKey>,
+    pub name: Option<String>,
+    pub created: TimestampMillis,
     pub last_used: TimestampMillis,
 }
@@ -0,0 +1,14 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+use types::SignedDelegation;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub principal: Principal,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SignedDelegation),
+    NotFound,
+}
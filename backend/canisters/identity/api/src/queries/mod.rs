@@ -2,4 +2,8 @@ pub mod auth_principals;
 pub mod check_auth_principal;
 pub mod check_auth_principal_v2;
 pub mod get_delegation;
+pub mod get_scoped_delegation;
 pub mod lookup_webauthn_pubkey;
+pub mod recovery_contacts;
+pub mod scoped_delegations;
+pub mod webauthn_keys;
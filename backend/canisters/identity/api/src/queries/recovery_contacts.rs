@@ -0,0 +1,27 @@
+use candid::{CandidType, Deserialize, Principal};
+use serde::Serialize;
+use types::{Empty, TimestampMillis, UserId};
+
+pub type Args = Empty;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotFound,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub contacts: Vec<UserId>,
+    pub threshold: u32,
+    pub recovery_in_progress: Option<RecoveryInProgress>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct RecoveryInProgress {
+    pub request_id: u64,
+    pub new_principal: Principal,
+    pub created: TimestampMillis,
+    pub approvals: Vec<UserId>,
+    pub completable_from: Option<TimestampMillis>,
+}
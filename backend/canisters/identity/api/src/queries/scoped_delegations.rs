@@ -0,0 +1,20 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+use types::{DelegationScope, Empty, TimestampMillis};
+
+pub type Args = Empty;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<ScopedDelegationDetails>),
+    UserNotFound,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ScopedDelegationDetails {
+    pub principal: Principal,
+    pub name: String,
+    pub scope: DelegationScope,
+    pub created: TimestampMillis,
+    pub expires: TimestampMillis,
+}
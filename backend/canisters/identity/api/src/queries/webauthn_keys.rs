@@ -0,0 +1,24 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+use types::{Empty, TimestampMillis};
+
+pub type Args = Empty;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<WebAuthnKeyDetails>),
+    NotFound,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct WebAuthnKeyDetails {
+    #[serde(with = "serde_bytes")]
+    pub credential_id: Vec<u8>,
+    pub auth_principal: Principal,
+    pub origin: String,
+    pub cross_platform: bool,
+    pub aaguid: [u8; 16],
+    pub name: Option<String>,
+    pub created: TimestampMillis,
+    pub last_used: TimestampMillis,
+}
@@ -0,0 +1,27 @@
+use candid::{CandidType, Deserialize, Principal};
+use serde::Serialize;
+use types::{TimestampMillis, UserId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub request_id: u64,
+    pub new_principal: Principal,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NotTrustedContact,
+    NoRecoveryInProgress,
+    RequestMismatch,
+    ApprovalWindowExpired,
+    CallerNotRecognised,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub approvals: u32,
+    pub threshold: u32,
+    pub completable_from: Option<TimestampMillis>,
+}
@@ -0,0 +1,17 @@
+use candid::{CandidType, Deserialize};
+use serde::Serialize;
+use types::{TimestampMillis, UserId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    NoRecoveryInProgress,
+    InsufficientApprovals,
+    WaitingPeriodNotElapsed(TimestampMillis),
+    AlreadyRegistered,
+}
@@ -0,0 +1,30 @@
+use crate::WebAuthnKey;
+use candid::{CandidType, Deserialize};
+use serde::Serialize;
+use types::{CanisterId, TimestampMillis, UserId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    #[serde(with = "serde_bytes")]
+    pub public_key: Vec<u8>,
+    pub webauthn_key: Option<WebAuthnKey>,
+    pub is_ii_principal: Option<bool>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    RecoveryNotConfigured,
+    RecoveryAlreadyInProgress,
+    RateLimited(TimestampMillis),
+    AlreadyRegistered,
+    PublicKeyInvalid(String),
+    OriginatingCanisterInvalid(CanisterId),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub request_id: u64,
+    pub approval_deadline: TimestampMillis,
+}
@@ -0,0 +1,28 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+use types::{DelegationScope, Nanoseconds, TimestampNanos};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    #[serde(with = "serde_bytes")]
+    pub session_key: Vec<u8>,
+    pub name: String,
+    pub scope: DelegationScope,
+    pub max_time_to_live: Option<Nanoseconds>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NameTooLong(u32),
+    TooManyScopedDelegations(u32),
+    UserNotFound,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub principal: Principal,
+    #[serde(with = "serde_bytes")]
+    pub user_key: Vec<u8>,
+    pub expiration: TimestampNanos,
+}
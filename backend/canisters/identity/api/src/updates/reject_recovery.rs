@@ -0,0 +1,18 @@
+use candid::{CandidType, Deserialize};
+use serde::Serialize;
+use types::UserId;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub request_id: u64,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    NotTrustedContact,
+    NoRecoveryInProgress,
+    RequestMismatch,
+    CallerNotRecognised,
+}
@@ -0,0 +1,22 @@
+use candid::{CandidType, Deserialize, Principal};
+use serde::Serialize;
+use types::TimestampNanos;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub auth_principal: Principal,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    CannotRevokeActivePrincipal,
+    AuthPrincipalNotFound,
+    UserNotFound,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // The latest expiry of the revoked delegations, any which were already fetched remain valid until then
+    pub revoked_delegations_valid_until: Option<TimestampNanos>,
+}
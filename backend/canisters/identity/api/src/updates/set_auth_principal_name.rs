@@ -0,0 +1,16 @@
+use candid::{CandidType, Deserialize, Principal};
+use serde::Serialize;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub auth_principal: Principal,
+    pub name: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    NameTooLong(u32),
+    AuthPrincipalNotFound,
+    UserNotFound,
+}
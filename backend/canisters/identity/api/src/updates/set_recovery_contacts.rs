@@ -0,0 +1,18 @@
+use candid::{CandidType, Deserialize};
+use serde::Serialize;
+use types::UserId;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub contacts: Vec<UserId>,
+    pub threshold: u32,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    InvalidThreshold,
+    TooManyContacts(u32),
+    CannotNominateSelf,
+    UserNotFound,
+}
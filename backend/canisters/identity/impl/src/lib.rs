@@ -1,12 +1,16 @@
+use crate::model::account_recovery::AccountRecovery;
 use crate::model::challenges::Challenges;
+use crate::model::delegations::Delegations;
 use crate::model::identity_link_requests::IdentityLinkRequests;
 use crate::model::salt::Salt;
+use crate::model::scoped_delegations::{ScopedDelegation, ScopedDelegations};
 use crate::model::user_principals::UserPrincipals;
 use crate::model::webauthn_keys::WebAuthnKeys;
 use candid::Principal;
 use canister_state_macros::canister_state;
-use ic_canister_sig_creation::signature_map::{SignatureMap, LABEL_SIG};
-use ic_canister_sig_creation::CanisterSigPublicKey;
+use fire_and_forget_handler::FireAndForgetHandler;
+use ic_canister_sig_creation::signature_map::{CanisterSigInputs, SignatureMap, LABEL_SIG};
+use ic_canister_sig_creation::{delegation_signature_msg, CanisterSigPublicKey, DELEGATION_SIG_DOMAIN};
 use ic_cdk::api::set_certified_data;
 use identity_canister::{WebAuthnKey, WEBAUTHN_ORIGINATING_CANISTER};
 use serde::{Deserialize, Serialize};
@@ -14,7 +18,8 @@ use serde_bytes::ByteBuf;
 use sha256::sha256;
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap, HashSet};
-use types::{BuildVersion, CanisterId, Cycles, TimestampMillis, Timestamped};
+use types::{BuildVersion, CanisterId, Cycles, DelegationScope, TimestampMillis, Timestamped, UserId};
+use user_index_canister::c2c_send_openchat_bot_messages::Message;
 use utils::env::Environment;
 use x509_parser::prelude::{FromDer, SubjectPublicKeyInfo};
 
@@ -120,6 +125,58 @@ impl RuntimeState {
         })
     }
 
+    pub fn send_openchat_bot_messages(&self, messages: Vec<Message>) {
+        if messages.is_empty() {
+            return;
+        }
+
+        self.data.fire_and_forget_handler.send(
+            self.data.user_index_canister_id,
+            "c2c_send_openchat_bot_messages_msgpack".to_string(),
+            msgpack::serialize_then_unwrap(user_index_canister::c2c_send_openchat_bot_messages::Args { messages }),
+        );
+    }
+
+    pub fn notify_scoped_principal_updated(
+        &self,
+        user_id: UserId,
+        principal: Principal,
+        scope: Option<DelegationScope>,
+        expires: TimestampMillis,
+    ) {
+        self.data.fire_and_forget_handler.send(
+            self.data.user_index_canister_id,
+            "c2c_update_scoped_principal_msgpack".to_string(),
+            msgpack::serialize_then_unwrap(user_index_canister::c2c_update_scoped_principal::Args {
+                user_id,
+                principal,
+                scope,
+                expires,
+            }),
+        );
+    }
+
+    // Removes the signatures of the scoped delegations and notifies each user's canister, which then stops accepting
+    // calls from the principals, covering any copies of the delegations which have already been retrieved
+    pub fn revoke_scoped_delegations(&mut self, delegations: Vec<(Principal, ScopedDelegation)>) {
+        if delegations.is_empty() {
+            return;
+        }
+
+        for (principal, delegation) in delegations {
+            let seed = self
+                .data
+                .calculate_scoped_seed(delegation.user_principal_index, delegation.nonce);
+            self.data.signature_map.delete(&CanisterSigInputs {
+                domain: DELEGATION_SIG_DOMAIN,
+                seed: &seed,
+                message: &delegation_signature_msg(&delegation.session_key, delegation.expiration, None),
+            });
+            self.notify_scoped_principal_updated(delegation.user_id, principal, None, delegation.expires());
+        }
+        self.data.update_root_hash();
+    }
+
     // All OC user keys are generated by this canister, so this function ensures that the key
     // is not an OC user key.
     pub fn assert_key_not_generated_by_this_canister(&self, public_key: &[u8]) {
@@ -161,6 +218,14 @@ struct Data {
     webauthn_keys: WebAuthnKeys,
     #[serde(skip)]
     signature_map: SignatureMap,
+    #[serde(default)]
+    delegations: Delegations,
+    #[serde(default)]
+    account_recovery: AccountRecovery,
+    #[serde(default)]
+    fire_and_forget_handler: FireAndForgetHandler,
+    #[serde(default)]
+    scoped_delegations: ScopedDelegations,
     #[serde(with = "serde_bytes")]
     ic_root_key: Vec<u8>,
     salt: Salt,
@@ -189,6 +254,10 @@ impl Data {
             identity_link_requests: IdentityLinkRequests::default(),
             webauthn_keys: WebAuthnKeys::default(),
             signature_map: SignatureMap::default(),
+            delegations: Delegations::default(),
+            account_recovery: AccountRecovery::default(),
+            fire_and_forget_handler: FireAndForgetHandler::default(),
+            scoped_delegations: ScopedDelegations::default(),
             ic_root_key,
             salt: Salt::default(),
             rng_seed: [0; 32],
@@ -212,6 +281,15 @@ impl Data {
         sha256(&bytes)
     }
 
+    // Scoped delegations each get their own principal, derived from the user's seed, so that canisters can tell them
+    // apart from the user's full-power principal
+    pub fn calculate_scoped_seed(&self, index: u32, nonce: u64) -> [u8; 32] {
+        let mut bytes = self.calculate_seed(index).to_vec();
+        bytes.extend_from_slice(&nonce.to_be_bytes());
+
+        sha256(&bytes)
+    }
+
     pub fn update_root_hash(&mut self) {
         let prefixed_root_hash = ic_certification::labeled_hash(LABEL_SIG, &self.signature_map.root_hash());
         set_certified_data(&prefixed_root_hash[..]);
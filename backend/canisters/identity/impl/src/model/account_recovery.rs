@@ -0,0 +1,226 @@
+use candid::Principal;
+use constants::DAY_IN_MS;
+use identity_canister::WebAuthnKey;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use types::{CanisterId, Milliseconds, TimestampMillis, UserId};
+
+// How long the trusted contacts have to approve a recovery request
+pub const APPROVAL_WINDOW: Milliseconds = 7 * DAY_IN_MS;
+// How long after the approval threshold is reached before the recovery can be completed, giving the original account
+// time to cancel it
+pub const WAITING_PERIOD: Milliseconds = 3 * DAY_IN_MS;
+// How long after a recovery is initiated before another can be initiated for the same user or by the same caller
+pub const INITIATION_COOLDOWN: Milliseconds = DAY_IN_MS;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct AccountRecovery {
+    configs: HashMap<UserId, RecoveryConfig>,
+    requests: HashMap<UserId, RecoveryRequest>, // Key is the user being recovered
+    #[serde(default)]
+    last_initiated_for: HashMap<UserId, TimestampMillis>,
+    #[serde(default)]
+    last_initiated_by: HashMap<Principal, TimestampMillis>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RecoveryConfig {
+    pub user_principal_index: u32,
+    pub contacts: Vec<UserId>,
+    pub threshold: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RecoveryRequest {
+    #[serde(default)]
+    pub request_id: u64,
+    pub initiated_by: Principal,
+    pub auth_principal: Principal,
+    pub webauthn_key: Option<WebAuthnKey>,
+    pub originating_canister: CanisterId,
+    pub is_ii_principal: bool,
+    pub created: TimestampMillis,
+    pub approvals: HashSet<UserId>,
+    pub threshold_reached: Option<TimestampMillis>,
+}
+
+impl RecoveryRequest {
+    pub fn completable_from(&self) -> Option<TimestampMillis> {
+        self.threshold_reached.map(|ts| ts + WAITING_PERIOD)
+    }
+
+    fn is_expired(&self, now: TimestampMillis) -> bool {
+        self.threshold_reached.is_none() && self.created + APPROVAL_WINDOW < now
+    }
+}
+
+pub enum InitiateRecoveryResult {
+    Success,
+    AlreadyInProgress,
+    RateLimited(TimestampMillis),
+}
+
+pub enum ApproveRecoveryResult {
+    Success {
+        approvals: u32,
+        threshold: u32,
+        completable_from: Option<TimestampMillis>,
+    },
+    NotTrustedContact,
+    NoRecoveryInProgress,
+    RequestMismatch,
+    ApprovalWindowExpired,
+}
+
+pub enum RejectRecoveryResult {
+    Success,
+    NotTrustedContact,
+    NoRecoveryInProgress,
+    RequestMismatch,
+}
+
+pub enum CompleteRecoveryResult {
+    Success(RecoveryRequest, u32),
+    NoRecoveryInProgress,
+    InsufficientApprovals,
+    WaitingPeriodNotElapsed(TimestampMillis),
+}
+
+impl AccountRecovery {
+    // Setting an empty list of contacts disables recovery. Any recovery in progress is cancelled.
+    pub fn set_config(&mut self, user_id: UserId, user_principal_index: u32, contacts: Vec<UserId>, threshold: u32) {
+        self.requests.remove(&user_id);
+
+        if contacts.is_empty() {
+            self.configs.remove(&user_id);
+        } else {
+            self.configs.insert(
+                user_id,
+                RecoveryConfig {
+                    user_principal_index,
+                    contacts,
+                    threshold,
+                },
+            );
+        }
+    }
+
+    pub fn config(&self, user_id: &UserId) -> Option<&RecoveryConfig> {
+        self.configs.get(user_id)
+    }
+
+    pub fn request(&self, user_id: &UserId, now: TimestampMillis) -> Option<&RecoveryRequest> {
+        self.requests.get(user_id).filter(|r| !r.is_expired(now))
+    }
+
+    pub fn initiate(&mut self, user_id: UserId, request: RecoveryRequest) -> InitiateRecoveryResult {
+        let now = request.created;
+        if self.request(&user_id, now).is_some() {
+            return InitiateRecoveryResult::AlreadyInProgress;
+        }
+
+        self.last_initiated_for.retain(|_, ts| *ts + INITIATION_COOLDOWN > now);
+        self.last_initiated_by.retain(|_, ts| *ts + INITIATION_COOLDOWN > now);
+
+        let next_allowed = [
+            self.last_initiated_for.get(&user_id),
+            self.last_initiated_by.get(&request.initiated_by),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|ts| *ts + INITIATION_COOLDOWN)
+        .max();
+
+        if let Some(next_allowed) = next_allowed {
+            return InitiateRecoveryResult::RateLimited(next_allowed);
+        }
+
+        self.last_initiated_for.insert(user_id, now);
+        self.last_initiated_by.insert(request.initiated_by, now);
+        self.requests.insert(user_id, request);
+        InitiateRecoveryResult::Success
+    }
+
+    // Approvals must specify the request Id and the principal being linked so that an approval can only ever apply to
+    // the request the trusted contact has confirmed with the user being recovered
+    pub fn approve(
+        &mut self,
+        user_id: UserId,
+        request_id: u64,
+        new_principal: Principal,
+        approver: UserId,
+        now: TimestampMillis,
+    ) -> ApproveRecoveryResult {
+        let Some(config) = self.configs.get(&user_id) else {
+            return ApproveRecoveryResult::NoRecoveryInProgress;
+        };
+        if !config.contacts.contains(&approver) {
+            return ApproveRecoveryResult::NotTrustedContact;
+        }
+        let Some(request) = self.requests.get_mut(&user_id) else {
+            return ApproveRecoveryResult::NoRecoveryInProgress;
+        };
+        if request.request_id != request_id || request.auth_principal != new_principal {
+            return ApproveRecoveryResult::RequestMismatch;
+        }
+        if request.is_expired(now) {
+            return ApproveRecoveryResult::ApprovalWindowExpired;
+        }
+
+        request.approvals.insert(approver);
+        let approvals = request.approvals.len() as u32;
+        if request.threshold_reached.is_none() && approvals >= config.threshold {
+            request.threshold_reached = Some(now);
+        }
+
+        ApproveRecoveryResult::Success {
+            approvals,
+            threshold: config.threshold,
+            completable_from: request.completable_from(),
+        }
+    }
+
+    // Any trusted contact can reject a request. The cooldown for the user being recovered is lifted so that a request
+    // made by someone else can't block the user from initiating their own recovery.
+    pub fn reject(&mut self, user_id: UserId, request_id: u64, rejecter: UserId, now: TimestampMillis) -> RejectRecoveryResult {
+        let Some(config) = self.configs.get(&user_id) else {
+            return RejectRecoveryResult::NoRecoveryInProgress;
+        };
+        if !config.contacts.contains(&rejecter) {
+            return RejectRecoveryResult::NotTrustedContact;
+        }
+        let Some(request) = self.request(&user_id, now) else {
+            return RejectRecoveryResult::NoRecoveryInProgress;
+        };
+        if request.request_id != request_id {
+            return RejectRecoveryResult::RequestMismatch;
+        }
+
+        self.requests.remove(&user_id);
+        self.last_initiated_for.remove(&user_id);
+        RejectRecoveryResult::Success
+    }
+
+    pub fn cancel(&mut self, user_id: &UserId) -> bool {
+        self.requests.remove(user_id).is_some()
+    }
+
+    pub fn complete(&mut self, user_id: UserId, caller: Principal, now: TimestampMillis) -> CompleteRecoveryResult {
+        let (Some(config), Some(request)) = (self.configs.get(&user_id), self.requests.get(&user_id)) else {
+            return CompleteRecoveryResult::NoRecoveryInProgress;
+        };
+        if request.initiated_by != caller || request.is_expired(now) {
+            return CompleteRecoveryResult::NoRecoveryInProgress;
+        }
+        let Some(completable_from) = request.completable_from() else {
+            return CompleteRecoveryResult::InsufficientApprovals;
+        };
+        if completable_from > now {
+            return CompleteRecoveryResult::WaitingPeriodNotElapsed(completable_from);
+        }
+
+        let user_principal_index = config.user_principal_index;
+        let request = self.requests.remove(&user_id).unwrap();
+        CompleteRecoveryResult::Success(request, user_principal_index)
+    }
+}
@@ -0,0 +1,56 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use serde_bytes::{ByteBuf, Bytes};
+use std::collections::HashMap;
+use types::{Nanoseconds, TimestampNanos};
+
+// The delegations which have been prepared for each auth principal, so that they can be revoked along with the auth
+// principal. Delegations which have already been retrieved can't be recalled, so the session keys of revoked
+// delegations are retained until they expire and checked before any delegation is signed or returned.
+#[derive(Serialize, Deserialize, Default)]
+pub struct Delegations {
+    map: HashMap<Principal, Vec<PreparedDelegation>>,
+    #[serde(default)]
+    revoked: HashMap<ByteBuf, TimestampNanos>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PreparedDelegation {
+    pub session_key: ByteBuf,
+    pub expiration: Nanoseconds,
+}
+
+impl Delegations {
+    pub fn push(&mut self, auth_principal: Principal, session_key: Vec<u8>, expiration: Nanoseconds, now_nanos: Nanoseconds) {
+        let delegations = self.map.entry(auth_principal).or_default();
+        delegations.retain(|d| d.expiration > now_nanos);
+        delegations.push(PreparedDelegation {
+            session_key: ByteBuf::from(session_key),
+            expiration,
+        });
+    }
+
+    pub fn revoke(&mut self, auth_principal: &Principal, now_nanos: TimestampNanos) -> Vec<PreparedDelegation> {
+        self.revoked.retain(|_, expiration| *expiration > now_nanos);
+
+        let delegations: Vec<_> = self
+            .map
+            .remove(auth_principal)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|d| d.expiration > now_nanos)
+            .collect();
+
+        for delegation in delegations.iter() {
+            self.revoked.insert(delegation.session_key.clone(), delegation.expiration);
+        }
+
+        delegations
+    }
+
+    pub fn is_revoked(&self, session_key: &[u8], now_nanos: TimestampNanos) -> bool {
+        self.revoked
+            .get(Bytes::new(session_key))
+            .is_some_and(|expiration| *expiration > now_nanos)
+    }
+}
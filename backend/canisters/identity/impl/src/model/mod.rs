@@ -1,5 +1,8 @@
+pub mod account_recovery;
 pub mod challenges;
+pub mod delegations;
 pub mod identity_link_requests;
 pub mod salt;
+pub mod scoped_delegations;
 pub mod user_principals;
 pub mod webauthn_keys;
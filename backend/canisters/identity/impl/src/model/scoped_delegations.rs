@@ -0,0 +1,87 @@
+use candid::Principal;
+use constants::NANOS_PER_MILLISECOND;
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use std::collections::HashMap;
+use types::{DelegationScope, TimestampMillis, TimestampNanos, UserId};
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ScopedDelegations {
+    map: HashMap<Principal, ScopedDelegation>, // Key is the scoped principal
+    next_nonce: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ScopedDelegation {
+    pub user_principal_index: u32,
+    pub auth_principal: Principal, // The auth principal which prepared the delegation
+    pub user_id: UserId,
+    pub nonce: u64,
+    pub name: String,
+    pub scope: DelegationScope,
+    pub session_key: ByteBuf,
+    pub expiration: TimestampNanos,
+    pub created: TimestampMillis,
+}
+
+impl ScopedDelegation {
+    pub fn expires(&self) -> TimestampMillis {
+        self.expiration / NANOS_PER_MILLISECOND
+    }
+}
+
+impl ScopedDelegations {
+    pub fn next_nonce(&mut self) -> u64 {
+        let nonce = self.next_nonce;
+        self.next_nonce += 1;
+        nonce
+    }
+
+    pub fn add(&mut self, principal: Principal, delegation: ScopedDelegation) {
+        self.prune_expired(delegation.created);
+        self.map.insert(principal, delegation);
+    }
+
+    pub fn get(&self, principal: &Principal, user_principal_index: u32) -> Option<&ScopedDelegation> {
+        self.map
+            .get(principal)
+            .filter(|d| d.user_principal_index == user_principal_index)
+    }
+
+    pub fn remove(&mut self, principal: &Principal, user_principal_index: u32) -> Option<ScopedDelegation> {
+        if self.get(principal, user_principal_index).is_some() {
+            self.map.remove(principal)
+        } else {
+            None
+        }
+    }
+
+    // Removes all of the scoped delegations prepared by the given auth principal, so that they are revoked along with it
+    pub fn remove_for_auth_principal(&mut self, auth_principal: &Principal) -> Vec<(Principal, ScopedDelegation)> {
+        let principals: Vec<_> = self
+            .map
+            .iter()
+            .filter(|(_, d)| d.auth_principal == *auth_principal)
+            .map(|(p, _)| *p)
+            .collect();
+
+        principals
+            .into_iter()
+            .filter_map(|p| self.map.remove(&p).map(|d| (p, d)))
+            .collect()
+    }
+
+    pub fn for_user(
+        &self,
+        user_principal_index: u32,
+        now: TimestampMillis,
+    ) -> impl Iterator<Item = (&Principal, &ScopedDelegation)> {
+        self.map
+            .iter()
+            .filter(move |(_, d)| d.user_principal_index == user_principal_index && d.expires() > now)
+    }
+
+    fn prune_expired(&mut self, now: TimestampMillis) {
+        self.map.retain(|_, d| d.expires() > now);
+    }
+}
@@ -1,5 +1,6 @@
 use candid::Principal;
 use identity_canister::remove_identity_link::Response as RemovePrincipalResponse;
+use identity_canister::set_auth_principal_name::Response as SetAuthPrincipalNameResponse;
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use std::collections::HashMap;
@@ -43,6 +44,10 @@ struct AuthPrincipalInternal {
     is_ii_principal: bool,
     #[serde(rename = "l", default, skip_serializing_if = "is_default")]
     last_used: TimestampMillis,
+    #[serde(rename = "c", default, skip_serializing_if = "is_default")]
+    created: TimestampMillis,
+    #[serde(rename = "n", default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -83,6 +88,8 @@ impl UserPrincipals {
                 webauthn_credential_id,
                 is_ii_principal,
                 last_used: now,
+                created: now,
+                name: None,
             },
         );
         self.incr_originating_canister(originating_canister);
@@ -129,6 +136,8 @@ impl UserPrincipals {
                     webauthn_credential_id,
                     is_ii_principal,
                     last_used: now,
+                    created: now,
+                    name: None,
                 },
             );
             self.incr_originating_canister(originating_canister);
@@ -148,6 +157,7 @@ impl UserPrincipals {
                 if user.auth_principals.contains(&linked_principal) {
                     user.auth_principals.retain(|&ap| ap != linked_principal);
                     self.auth_principals.remove(&linked_principal);
+                    self.temp_keys.retain(|_, k| k.auth_principal != linked_principal);
 
                     return RemovePrincipalResponse::Success;
                 }
@@ -159,6 +169,25 @@ impl UserPrincipals {
         }
     }
 
+    pub fn set_auth_principal_name(
+        &mut self,
+        caller: Principal,
+        auth_principal: Principal,
+        name: Option<String>,
+    ) -> SetAuthPrincipalNameResponse {
+        let Some(user_principal_index) = self.auth_principals.get(&caller).map(|a| a.user_principal_index) else {
+            return SetAuthPrincipalNameResponse::UserNotFound;
+        };
+
+        match self.auth_principals.get_mut(&auth_principal) {
+            Some(a) if a.user_principal_index == user_principal_index => {
+                a.name = name;
+                SetAuthPrincipalNameResponse::Success
+            }
+            _ => SetAuthPrincipalNameResponse::AuthPrincipalNotFound,
+        }
+    }
+
     pub fn next_index(&self) -> u32 {
         self.user_principals.len().try_into().unwrap()
     }
@@ -250,6 +279,8 @@ pub struct AuthPrincipal {
     pub webauthn_credential_id: Option<Vec<u8>>,
     pub is_ii_principal: bool,
     pub last_used: TimestampMillis,
+    pub created: TimestampMillis,
+    pub name: Option<String>,
 }
 
 impl From<&AuthPrincipalInternal> for AuthPrincipal {
@@ -260,6 +291,8 @@ impl From<&AuthPrincipalInternal> for AuthPrincipal {
             webauthn_credential_id: value.webauthn_credential_id.as_ref().map(|c| c.to_vec()),
             is_ii_principal: value.is_ii_principal,
             last_used: value.last_used,
+            created: value.created,
+            name: value.name.clone(),
         }
     }
 }
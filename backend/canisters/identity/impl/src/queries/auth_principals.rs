@@ -25,6 +25,8 @@ fn auth_principals_impl(state: &RuntimeState) -> Response {
                         webauthn_key: a
                             .webauthn_credential_id
                             .and_then(|id| state.data.webauthn_keys.get(id.clone()).map(|k| k.hydrate(id))),
+                        name: a.name,
+                        created: a.created,
                         last_used: a.last_used,
                     })
                 })
@@ -18,6 +18,10 @@ fn get_delegation_impl(args: Args, state: &RuntimeState) -> Response {
         panic!("Caller not recognised");
     };
 
+    if state.data.delegations.is_revoked(&args.session_key, state.env.now_nanos()) {
+        return NotFound;
+    }
+
     let seed = state.data.calculate_seed(user.index);
 
     if let Ok(signature) = state.data.signature_map.get_signature_as_cbor(
@@ -0,0 +1,44 @@
+use crate::{read_state, RuntimeState};
+use ic_canister_sig_creation::signature_map::CanisterSigInputs;
+use ic_canister_sig_creation::{delegation_signature_msg, DELEGATION_SIG_DOMAIN};
+use ic_cdk::query;
+use identity_canister::get_scoped_delegation::{Response::*, *};
+use types::{Delegation, SignedDelegation};
+
+#[query]
+fn get_scoped_delegation(args: Args) -> Response {
+    read_state(|state| get_scoped_delegation_impl(args, state))
+}
+
+fn get_scoped_delegation_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(user) = state.data.user_principals.get_by_auth_principal(&auth_principal) else {
+        return NotFound;
+    };
+
+    let Some(scoped_delegation) = state.data.scoped_delegations.get(&args.principal, user.index) else {
+        return NotFound;
+    };
+
+    let seed = state.data.calculate_scoped_seed(user.index, scoped_delegation.nonce);
+
+    if let Ok(signature) = state.data.signature_map.get_signature_as_cbor(
+        &CanisterSigInputs {
+            domain: DELEGATION_SIG_DOMAIN,
+            seed: &seed,
+            message: &delegation_signature_msg(&scoped_delegation.session_key, scoped_delegation.expiration, None),
+        },
+        None,
+    ) {
+        let delegation = Delegation {
+            pubkey: scoped_delegation.session_key.to_vec(),
+            expiration: scoped_delegation.expiration,
+        };
+
+        Success(SignedDelegation { delegation, signature })
+    } else {
+        NotFound
+    }
+}
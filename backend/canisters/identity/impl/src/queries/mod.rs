@@ -2,5 +2,9 @@ mod auth_principals;
 mod check_auth_principal;
 mod check_auth_principal_v2;
 mod get_delegation;
+mod get_scoped_delegation;
 mod http_request;
 mod lookup_webauthn_pubkey;
+mod recovery_contacts;
+mod scoped_delegations;
+mod webauthn_keys;
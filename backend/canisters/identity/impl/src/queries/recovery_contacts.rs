@@ -0,0 +1,46 @@
+use crate::{read_state, RuntimeState};
+use ic_cdk::query;
+use identity_canister::recovery_contacts::{Response::*, *};
+
+#[query]
+fn recovery_contacts() -> Response {
+    read_state(recovery_contacts_impl)
+}
+
+fn recovery_contacts_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(user_id) = state
+        .data
+        .user_principals
+        .get_by_auth_principal(&auth_principal)
+        .and_then(|u| u.user_id)
+    else {
+        return UserNotFound;
+    };
+
+    let Some(config) = state.data.account_recovery.config(&user_id) else {
+        return Success(SuccessResult {
+            contacts: Vec::new(),
+            threshold: 0,
+            recovery_in_progress: None,
+        });
+    };
+
+    Success(SuccessResult {
+        contacts: config.contacts.clone(),
+        threshold: config.threshold,
+        recovery_in_progress: state
+            .data
+            .account_recovery
+            .request(&user_id, state.env.now())
+            .map(|r| RecoveryInProgress {
+                request_id: r.request_id,
+                new_principal: r.auth_principal,
+                created: r.created,
+                approvals: r.approvals.iter().copied().collect(),
+                completable_from: r.completable_from(),
+            }),
+    })
+}
@@ -0,0 +1,32 @@
+use crate::{read_state, RuntimeState};
+use ic_cdk::query;
+use identity_canister::scoped_delegations::{Response::*, *};
+
+#[query]
+fn scoped_delegations() -> Response {
+    read_state(scoped_delegations_impl)
+}
+
+fn scoped_delegations_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(user) = state.data.user_principals.get_by_auth_principal(&auth_principal) else {
+        return UserNotFound;
+    };
+
+    Success(
+        state
+            .data
+            .scoped_delegations
+            .for_user(user.index, state.env.now())
+            .map(|(principal, d)| ScopedDelegationDetails {
+                principal: *principal,
+                name: d.name.clone(),
+                scope: d.scope,
+                created: d.created,
+                expires: d.expires(),
+            })
+            .collect(),
+    )
+}
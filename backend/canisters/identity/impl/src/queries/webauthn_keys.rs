@@ -0,0 +1,40 @@
+use crate::{read_state, RuntimeState};
+use ic_cdk::query;
+use identity_canister::webauthn_keys::{Response::*, *};
+
+#[query]
+fn webauthn_keys() -> Response {
+    read_state(webauthn_keys_impl)
+}
+
+fn webauthn_keys_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    if let Some(user_principal) = state.data.user_principals.get_by_auth_principal(&auth_principal) {
+        Success(
+            user_principal
+                .auth_principals
+                .into_iter()
+                .flat_map(|p| {
+                    let a = state.data.user_principals.get_auth_principal(&p)?;
+                    let credential_id = a.webauthn_credential_id?;
+                    let key = state.data.webauthn_keys.get(credential_id.clone())?;
+
+                    Some(WebAuthnKeyDetails {
+                        credential_id,
+                        auth_principal: p,
+                        origin: key.origin.clone(),
+                        cross_platform: key.cross_platform,
+                        aaguid: key.aaguid,
+                        name: a.name,
+                        created: key.created,
+                        last_used: a.last_used,
+                    })
+                })
+                .collect(),
+        )
+    } else {
+        NotFound
+    }
+}
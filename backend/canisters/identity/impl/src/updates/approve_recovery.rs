@@ -0,0 +1,66 @@
+use crate::model::account_recovery::{ApproveRecoveryResult, WAITING_PERIOD};
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use constants::DAY_IN_MS;
+use ic_cdk::update;
+use identity_canister::approve_recovery::{Response::*, *};
+use user_index_canister::c2c_send_openchat_bot_messages::Message;
+
+#[update]
+#[trace]
+fn approve_recovery(args: Args) -> Response {
+    mutate_state(|state| approve_recovery_impl(args, state))
+}
+
+fn approve_recovery_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(approver) = state
+        .data
+        .user_principals
+        .get_by_auth_principal(&auth_principal)
+        .and_then(|u| u.user_id)
+    else {
+        return CallerNotRecognised;
+    };
+
+    let now = state.env.now();
+    let threshold_previously_reached = state
+        .data
+        .account_recovery
+        .request(&args.user_id, now)
+        .is_some_and(|r| r.threshold_reached.is_some());
+
+    match state
+        .data
+        .account_recovery
+        .approve(args.user_id, args.request_id, args.new_principal, approver, now)
+    {
+        ApproveRecoveryResult::Success {
+            approvals,
+            threshold,
+            completable_from,
+        } => {
+            if completable_from.is_some() && !threshold_previously_reached {
+                state.send_openchat_bot_messages(vec![Message {
+                    recipient: args.user_id,
+                    text: format!(
+                        "Your trusted contacts have approved a request to recover your account. \
+The recovery can be completed in {} days. If this wasn't you, cancel the recovery from your account settings before then.",
+                        WAITING_PERIOD / DAY_IN_MS
+                    ),
+                }]);
+            }
+            Success(SuccessResult {
+                approvals,
+                threshold,
+                completable_from,
+            })
+        }
+        ApproveRecoveryResult::NotTrustedContact => NotTrustedContact,
+        ApproveRecoveryResult::NoRecoveryInProgress => NoRecoveryInProgress,
+        ApproveRecoveryResult::RequestMismatch => RequestMismatch,
+        ApproveRecoveryResult::ApprovalWindowExpired => ApprovalWindowExpired,
+    }
+}
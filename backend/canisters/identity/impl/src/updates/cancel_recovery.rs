@@ -0,0 +1,30 @@
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::cancel_recovery::{Response::*, *};
+
+#[update]
+#[trace]
+fn cancel_recovery(_args: Args) -> Response {
+    mutate_state(cancel_recovery_impl)
+}
+
+fn cancel_recovery_impl(state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(user_id) = state
+        .data
+        .user_principals
+        .get_by_auth_principal(&auth_principal)
+        .and_then(|u| u.user_id)
+    else {
+        return UserNotFound;
+    };
+
+    if state.data.account_recovery.cancel(&user_id) {
+        Success
+    } else {
+        NoRecoveryInProgress
+    }
+}
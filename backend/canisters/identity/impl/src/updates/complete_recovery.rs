@@ -0,0 +1,48 @@
+use crate::model::account_recovery::CompleteRecoveryResult;
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::complete_recovery::{Response::*, *};
+use user_index_canister::c2c_send_openchat_bot_messages::Message;
+
+#[update]
+#[trace]
+fn complete_recovery(args: Args) -> Response {
+    mutate_state(|state| complete_recovery_impl(args, state))
+}
+
+fn complete_recovery_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let now = state.env.now();
+
+    let (request, user_principal_index) = match state.data.account_recovery.complete(args.user_id, caller, now) {
+        CompleteRecoveryResult::Success(request, user_principal_index) => (request, user_principal_index),
+        CompleteRecoveryResult::NoRecoveryInProgress => return NoRecoveryInProgress,
+        CompleteRecoveryResult::InsufficientApprovals => return InsufficientApprovals,
+        CompleteRecoveryResult::WaitingPeriodNotElapsed(ts) => return WaitingPeriodNotElapsed(ts),
+    };
+
+    if !state.data.user_principals.link_auth_principal_with_existing_user(
+        request.auth_principal,
+        request.originating_canister,
+        request.webauthn_key.as_ref().map(|k| k.credential_id.clone().into()),
+        request.is_ii_principal,
+        user_principal_index,
+        now,
+    ) {
+        return AlreadyRegistered;
+    }
+
+    if let Some(webauthn_key) = request.webauthn_key {
+        state.data.webauthn_keys.add(webauthn_key, now);
+    }
+
+    state.send_openchat_bot_messages(vec![Message {
+        recipient: args.user_id,
+        text: "Your account has been recovered and a new sign-in method has been linked to it. \
+If this wasn't you, contact OpenChat support immediately."
+            .to_string(),
+    }]);
+
+    Success
+}
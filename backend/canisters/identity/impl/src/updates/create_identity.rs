@@ -64,7 +64,7 @@ fn create_identity_impl(args: Args, state: &mut RuntimeState) -> Response {
         args.is_ii_principal.unwrap_or_default(),
     );
 
-    let result = prepare_delegation_inner(seed, args.session_key, args.max_time_to_live, state);
+    let result = prepare_delegation_inner(seed, auth_principal, args.session_key, args.max_time_to_live, state);
 
     Success(SuccessResult {
         user_key: result.user_key,
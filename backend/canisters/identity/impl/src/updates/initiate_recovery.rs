@@ -0,0 +1,82 @@
+use crate::model::account_recovery::{InitiateRecoveryResult, RecoveryRequest, APPROVAL_WINDOW};
+use crate::{mutate_state, RuntimeState, VerifyNewIdentityArgs, VerifyNewIdentityError, VerifyNewIdentitySuccess};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::initiate_recovery::{Response::*, *};
+use rand::Rng;
+use std::collections::HashSet;
+use user_index_canister::c2c_send_openchat_bot_messages::Message;
+
+#[update]
+#[trace]
+fn initiate_recovery(args: Args) -> Response {
+    mutate_state(|state| initiate_recovery_impl(args, state))
+}
+
+fn initiate_recovery_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let VerifyNewIdentitySuccess {
+        caller,
+        auth_principal,
+        originating_canister,
+        webauthn_key,
+    } = match state.verify_new_identity(VerifyNewIdentityArgs {
+        public_key: args.public_key,
+        webauthn_key: args.webauthn_key,
+    }) {
+        Ok(ok) => ok,
+        Err(error) => {
+            return match error {
+                VerifyNewIdentityError::AlreadyRegistered => AlreadyRegistered,
+                VerifyNewIdentityError::PublicKeyInvalid(e) => PublicKeyInvalid(e),
+                VerifyNewIdentityError::OriginatingCanisterInvalid(c) => OriginatingCanisterInvalid(c),
+            }
+        }
+    };
+
+    let Some(contacts) = state.data.account_recovery.config(&args.user_id).map(|c| c.contacts.clone()) else {
+        return RecoveryNotConfigured;
+    };
+
+    let now = state.env.now();
+    let request_id = state.env.rng().gen();
+    match state.data.account_recovery.initiate(
+        args.user_id,
+        RecoveryRequest {
+            request_id,
+            initiated_by: caller,
+            auth_principal,
+            webauthn_key,
+            originating_canister,
+            is_ii_principal: args.is_ii_principal.unwrap_or_default(),
+            created: now,
+            approvals: HashSet::new(),
+            threshold_reached: None,
+        },
+    ) {
+        InitiateRecoveryResult::Success => {}
+        InitiateRecoveryResult::AlreadyInProgress => return RecoveryAlreadyInProgress,
+        InitiateRecoveryResult::RateLimited(ts) => return RateLimited(ts),
+    }
+
+    let mut messages = vec![Message {
+        recipient: args.user_id,
+        text: "A request has been made to recover your account using your trusted contacts. \
+If this wasn't you, cancel the recovery from your account settings."
+            .to_string(),
+    }];
+    messages.extend(contacts.into_iter().map(|recipient| Message {
+        recipient,
+        text: format!(
+            "@UserId({}) has requested to recover their account and you are one of their trusted contacts. \
+Only approve the recovery if you have confirmed with them that the request is genuine and that its Id ({request_id}) \
+and new principal ({auth_principal}) match theirs.",
+            args.user_id
+        ),
+    }));
+    state.send_openchat_bot_messages(messages);
+
+    Success(SuccessResult {
+        request_id,
+        approval_deadline: now + APPROVAL_WINDOW,
+    })
+}
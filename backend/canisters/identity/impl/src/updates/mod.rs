@@ -1,7 +1,17 @@
 pub mod approve_identity_link;
+pub mod approve_recovery;
 pub mod c2c_set_user_ids;
+pub mod cancel_recovery;
+pub mod complete_recovery;
 pub mod create_identity;
 pub mod generate_challenge;
 pub mod initiate_identity_link;
+pub mod initiate_recovery;
 pub mod prepare_delegation;
+pub mod prepare_scoped_delegation;
+pub mod reject_recovery;
 pub mod remove_identity_link;
+pub mod revoke_auth_principal;
+pub mod revoke_scoped_delegation;
+pub mod set_auth_principal_name;
+pub mod set_recovery_contacts;
@@ -1,4 +1,5 @@
 use crate::{mutate_state, RuntimeState};
+use candid::Principal;
 use canister_tracing_macros::trace;
 use constants::{DAY_IN_MS, NANOS_PER_MILLISECOND};
 use ic_canister_sig_creation::signature_map::CanisterSigInputs;
@@ -22,6 +23,9 @@ fn prepare_delegation_impl(args: Args, state: &mut RuntimeState) -> Response {
     let Some(user) = state.data.user_principals.get_by_auth_principal(&caller) else {
         return NotFound;
     };
+    if state.data.delegations.is_revoked(&args.session_key, state.env.now_nanos()) {
+        return NotFound;
+    }
     state.data.user_principals.bump_last_used(&caller, state.env.now());
 
     if args.is_ii_principal.unwrap_or_default() {
@@ -30,17 +34,25 @@ fn prepare_delegation_impl(args: Args, state: &mut RuntimeState) -> Response {
 
     let seed = state.data.calculate_seed(user.index);
 
-    Success(prepare_delegation_inner(seed, args.session_key, args.max_time_to_live, state))
+    Success(prepare_delegation_inner(
+        seed,
+        caller,
+        args.session_key,
+        args.max_time_to_live,
+        state,
+    ))
 }
 
 pub(crate) fn prepare_delegation_inner(
     seed: [u8; 32],
+    auth_principal: Principal,
     session_key: Vec<u8>,
     max_time_to_live: Option<Nanoseconds>,
     state: &mut RuntimeState,
 ) -> SuccessResult {
     let delta = Nanoseconds::min(max_time_to_live.unwrap_or(DEFAULT_EXPIRATION_PERIOD), MAX_EXPIRATION_PERIOD);
-    let expiration = state.env.now_nanos().saturating_add(delta);
+    let now_nanos = state.env.now_nanos();
+    let expiration = now_nanos.saturating_add(delta);
 
     state.data.signature_map.add_signature(&CanisterSigInputs {
         domain: DELEGATION_SIG_DOMAIN,
@@ -48,6 +60,10 @@ pub(crate) fn prepare_delegation_inner(
         message: &delegation_signature_msg(&session_key, expiration, None),
     });
     state.data.update_root_hash();
+    state
+        .data
+        .delegations
+        .push(auth_principal, session_key, expiration, now_nanos);
 
     SuccessResult {
         user_key: state.der_encode_canister_sig_key(seed),
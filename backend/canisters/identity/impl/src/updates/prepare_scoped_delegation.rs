@@ -0,0 +1,84 @@
+use crate::model::scoped_delegations::ScopedDelegation;
+use crate::{mutate_state, RuntimeState};
+use candid::Principal;
+use canister_tracing_macros::trace;
+use constants::{DAY_IN_MS, NANOS_PER_MILLISECOND};
+use ic_canister_sig_creation::signature_map::CanisterSigInputs;
+use ic_canister_sig_creation::{delegation_signature_msg, DELEGATION_SIG_DOMAIN};
+use ic_cdk::update;
+use identity_canister::prepare_scoped_delegation::{Response::*, *};
+use types::Nanoseconds;
+
+const DEFAULT_EXPIRATION_PERIOD: Nanoseconds = DAY_IN_MS * NANOS_PER_MILLISECOND;
+const MAX_EXPIRATION_PERIOD: Nanoseconds = 30 * DAY_IN_MS * NANOS_PER_MILLISECOND;
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_SCOPED_DELEGATIONS_PER_USER: usize = 10;
+
+#[update]
+#[trace]
+fn prepare_scoped_delegation(args: Args) -> Response {
+    mutate_state(|state| prepare_scoped_delegation_impl(args, state))
+}
+
+// Each scoped delegation is for a new principal derived from the user's seed. The identity canister notifies the
+// user's canister of the principal and its scope, so that the principal can only call the endpoints matching its scope.
+fn prepare_scoped_delegation_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some((index, user_id)) = state
+        .data
+        .user_principals
+        .get_by_auth_principal(&auth_principal)
+        .and_then(|u| u.user_id.map(|user_id| (u.index, user_id)))
+    else {
+        return UserNotFound;
+    };
+
+    if args.name.chars().count() > MAX_NAME_LENGTH {
+        return NameTooLong(MAX_NAME_LENGTH as u32);
+    }
+
+    let now = state.env.now();
+    if state.data.scoped_delegations.for_user(index, now).count() >= MAX_SCOPED_DELEGATIONS_PER_USER {
+        return TooManyScopedDelegations(MAX_SCOPED_DELEGATIONS_PER_USER as u32);
+    }
+
+    let nonce = state.data.scoped_delegations.next_nonce();
+    let seed = state.data.calculate_scoped_seed(index, nonce);
+    let user_key = state.der_encode_canister_sig_key(seed);
+    let principal = Principal::self_authenticating(&user_key);
+
+    let delta = Nanoseconds::min(
+        args.max_time_to_live.unwrap_or(DEFAULT_EXPIRATION_PERIOD),
+        MAX_EXPIRATION_PERIOD,
+    );
+    let expiration = state.env.now_nanos().saturating_add(delta);
+
+    state.data.signature_map.add_signature(&CanisterSigInputs {
+        domain: DELEGATION_SIG_DOMAIN,
+        seed: &seed,
+        message: &delegation_signature_msg(&args.session_key, expiration, None),
+    });
+    state.data.update_root_hash();
+
+    let scoped_delegation = ScopedDelegation {
+        user_principal_index: index,
+        auth_principal,
+        user_id,
+        nonce,
+        name: args.name,
+        scope: args.scope,
+        session_key: args.session_key.into(),
+        expiration,
+        created: now,
+    };
+    state.notify_scoped_principal_updated(user_id, principal, Some(args.scope), scoped_delegation.expires());
+    state.data.scoped_delegations.add(principal, scoped_delegation);
+
+    Success(SuccessResult {
+        principal,
+        user_key,
+        expiration,
+    })
+}
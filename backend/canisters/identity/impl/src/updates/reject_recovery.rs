@@ -0,0 +1,39 @@
+use crate::model::account_recovery::RejectRecoveryResult;
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::reject_recovery::{Response::*, *};
+
+#[update]
+#[trace]
+fn reject_recovery(args: Args) -> Response {
+    mutate_state(|state| reject_recovery_impl(args, state))
+}
+
+// Allows any of the user's trusted contacts to reject a recovery request which the user didn't make, so that someone
+// else initiating a recovery can't stop the user from recovering their account
+fn reject_recovery_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(rejecter) = state
+        .data
+        .user_principals
+        .get_by_auth_principal(&auth_principal)
+        .and_then(|u| u.user_id)
+    else {
+        return CallerNotRecognised;
+    };
+
+    let now = state.env.now();
+    match state
+        .data
+        .account_recovery
+        .reject(args.user_id, args.request_id, rejecter, now)
+    {
+        RejectRecoveryResult::Success => Success,
+        RejectRecoveryResult::NotTrustedContact => NotTrustedContact,
+        RejectRecoveryResult::NoRecoveryInProgress => NoRecoveryInProgress,
+        RejectRecoveryResult::RequestMismatch => RequestMismatch,
+    }
+}
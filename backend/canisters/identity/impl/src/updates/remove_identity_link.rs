@@ -1,7 +1,7 @@
 use crate::{mutate_state, RuntimeState};
 use canister_tracing_macros::trace;
 use ic_cdk::update;
-use identity_canister::remove_identity_link::{Args, Response};
+use identity_canister::remove_identity_link::{Response::*, *};
 
 #[update]
 #[trace]
@@ -13,8 +13,18 @@ fn remove_identity_link_impl(args: Args, state: &mut RuntimeState) -> Response {
     let caller = state.env.caller();
     let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
 
-    state
+    let response = state
         .data
         .user_principals
-        .remove_auth_principal(auth_principal, args.linked_principal)
+        .remove_auth_principal(auth_principal, args.linked_principal);
+
+    if matches!(response, Success) {
+        let scoped_delegations = state
+            .data
+            .scoped_delegations
+            .remove_for_auth_principal(&args.linked_principal);
+        state.revoke_scoped_delegations(scoped_delegations);
+    }
+
+    response
 }
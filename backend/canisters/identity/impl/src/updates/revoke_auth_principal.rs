@@ -0,0 +1,65 @@
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_canister_sig_creation::signature_map::CanisterSigInputs;
+use ic_canister_sig_creation::{delegation_signature_msg, DELEGATION_SIG_DOMAIN};
+use ic_cdk::update;
+use identity_canister::remove_identity_link::Response as RemovePrincipalResponse;
+use identity_canister::revoke_auth_principal::{Response::*, *};
+
+#[update]
+#[trace]
+fn revoke_auth_principal(args: Args) -> Response {
+    mutate_state(|state| revoke_auth_principal_impl(args, state))
+}
+
+// Unlinks the auth principal from the user, removes its WebAuthn key (if any) so that it can no longer be used to sign in,
+// and revokes any delegations prepared for it. Their signatures are removed, and their session keys are recorded so
+// that they are never signed or returned again, even if the signature map is rebuilt. Any scoped delegations prepared by
+// the auth principal are revoked too.
+fn revoke_auth_principal_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(user) = state.data.user_principals.get_by_auth_principal(&auth_principal) else {
+        return UserNotFound;
+    };
+    let revoked = state.data.user_principals.get_auth_principal(&args.auth_principal);
+
+    match state
+        .data
+        .user_principals
+        .remove_auth_principal(auth_principal, args.auth_principal)
+    {
+        RemovePrincipalResponse::Success => {}
+        RemovePrincipalResponse::CannotUnlinkActivePrincipal => return CannotRevokeActivePrincipal,
+        RemovePrincipalResponse::IdentityLinkNotFound => return AuthPrincipalNotFound,
+        RemovePrincipalResponse::UserNotFound => return UserNotFound,
+    }
+
+    if let Some(credential_id) = revoked.and_then(|a| a.webauthn_credential_id) {
+        state.data.webauthn_keys.remove(credential_id);
+    }
+
+    let delegations = state.data.delegations.revoke(&args.auth_principal, state.env.now_nanos());
+    let mut revoked_delegations_valid_until = delegations.iter().map(|d| d.expiration).max();
+    if !delegations.is_empty() {
+        let seed = state.data.calculate_seed(user.index);
+        for delegation in delegations {
+            state.data.signature_map.delete(&CanisterSigInputs {
+                domain: DELEGATION_SIG_DOMAIN,
+                seed: &seed,
+                message: &delegation_signature_msg(&delegation.session_key, delegation.expiration, None),
+            });
+        }
+        state.data.update_root_hash();
+    }
+
+    let scoped_delegations = state.data.scoped_delegations.remove_for_auth_principal(&args.auth_principal);
+    revoked_delegations_valid_until =
+        revoked_delegations_valid_until.max(scoped_delegations.iter().map(|(_, d)| d.expiration).max());
+    state.revoke_scoped_delegations(scoped_delegations);
+
+    Success(SuccessResult {
+        revoked_delegations_valid_until,
+    })
+}
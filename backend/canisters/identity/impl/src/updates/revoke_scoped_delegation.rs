@@ -0,0 +1,27 @@
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::revoke_scoped_delegation::{Response::*, *};
+
+#[update]
+#[trace]
+fn revoke_scoped_delegation(args: Args) -> Response {
+    mutate_state(|state| revoke_scoped_delegation_impl(args, state))
+}
+
+fn revoke_scoped_delegation_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some(user) = state.data.user_principals.get_by_auth_principal(&auth_principal) else {
+        return UserNotFound;
+    };
+
+    let Some(scoped_delegation) = state.data.scoped_delegations.remove(&args.principal, user.index) else {
+        return NotFound;
+    };
+
+    state.revoke_scoped_delegations(vec![(args.principal, scoped_delegation)]);
+
+    Success
+}
@@ -0,0 +1,27 @@
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::set_auth_principal_name::{Response::*, *};
+
+const MAX_NAME_LENGTH: u32 = 50;
+
+#[update]
+#[trace]
+fn set_auth_principal_name(args: Args) -> Response {
+    mutate_state(|state| set_auth_principal_name_impl(args, state))
+}
+
+fn set_auth_principal_name_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let name = args.name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if name.as_ref().is_some_and(|n| n.chars().count() > MAX_NAME_LENGTH as usize) {
+        return NameTooLong(MAX_NAME_LENGTH);
+    }
+
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    state
+        .data
+        .user_principals
+        .set_auth_principal_name(auth_principal, args.auth_principal, name)
+}
@@ -0,0 +1,48 @@
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use identity_canister::set_recovery_contacts::{Response::*, *};
+use std::collections::HashSet;
+
+const MAX_RECOVERY_CONTACTS: usize = 10;
+
+#[update]
+#[trace]
+fn set_recovery_contacts(args: Args) -> Response {
+    mutate_state(|state| set_recovery_contacts_impl(args, state))
+}
+
+fn set_recovery_contacts_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let auth_principal = state.data.user_principals.unwrap_temp_key_or(caller);
+
+    let Some((index, user_id)) = state
+        .data
+        .user_principals
+        .get_by_auth_principal(&auth_principal)
+        .and_then(|u| u.user_id.map(|user_id| (u.index, user_id)))
+    else {
+        return UserNotFound;
+    };
+
+    let mut contacts = args.contacts;
+    let mut unique = HashSet::new();
+    contacts.retain(|c| unique.insert(*c));
+
+    if contacts.len() > MAX_RECOVERY_CONTACTS {
+        return TooManyContacts(MAX_RECOVERY_CONTACTS as u32);
+    }
+    if contacts.contains(&user_id) {
+        return CannotNominateSelf;
+    }
+    if !contacts.is_empty() && (args.threshold == 0 || args.threshold as usize > contacts.len()) {
+        return InvalidThreshold;
+    }
+
+    state
+        .data
+        .account_recovery
+        .set_config(user_id, index, contacts, args.threshold);
+
+    Success
+}
@@ -3,10 +3,10 @@ use serde::{Deserialize, Serialize};
 use types::nns::CryptoAmount;
 use types::{
     is_default, AutonomousConfig, BotCommandDefinition, BotDefinition, BotInstallationLocation, CanisterId,
-    ChannelLatestMessageIndex, ChatId, ChitEarnedReason, CommunityId, Cryptocurrency, DiamondMembershipPlanDuration,
-    MessageContent, MessageContentInitial, MessageId, MessageIndex, NotifyChit, PhoneNumber, ReferralType, SuspensionDuration,
-    TimestampMillis, UniquePersonProof, UpdateUserPrincipalArgs, User, UserCanisterStreakInsuranceClaim,
-    UserCanisterStreakInsurancePayment, UserId, UserType,
+    ChannelLatestMessageIndex, ChatId, ChitEarnedReason, CommunityId, Cryptocurrency, DelegationScope,
+    DiamondMembershipPlanDuration, MessageContent, MessageContentInitial, MessageId, MessageIndex, NotifyChit, PhoneNumber,
+    ReferralType, SuspensionDuration, TimestampMillis, UniquePersonProof, UpdateUserPrincipalArgs, User,
+    UserCanisterStreakInsuranceClaim, UserCanisterStreakInsurancePayment, UserId, UserType,
 };
 
 mod lifecycle;
@@ -47,6 +47,7 @@ pub enum UserIndexEvent {
     AddCanisterToPool(CanisterId),
     ExternalAchievementAwarded(ExternalAchievementAwarded),
     SyncExistingUser(UserDetailsFull),
+    ScopedPrincipalUpdated(ScopedPrincipalUpdated),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -160,6 +161,8 @@ pub struct UserJoinedGroup {
     pub local_user_index_canister_id: CanisterId,
     pub latest_message_index: Option<MessageIndex>,
     pub group_canister_timestamp: TimestampMillis,
+    #[serde(default)]
+    pub read_receipts_enabled: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -244,6 +247,14 @@ pub struct ExternalAchievementAwarded {
     pub chit_reward: u32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScopedPrincipalUpdated {
+    pub user_id: UserId,
+    pub principal: Principal,
+    pub scope: Option<DelegationScope>,
+    pub expires: TimestampMillis,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct UserDetailsFull {
     #[serde(rename = "i")]
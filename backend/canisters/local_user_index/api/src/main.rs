@@ -6,6 +6,7 @@ fn main() {
     // generate_candid_method!(local_user_index, bot_chat_details, query);
     // generate_candid_method!(local_user_index, bot_chat_events, query);
 
+    generate_candid_method!(local_user_index, bot_award_achievement, update);
     generate_candid_method!(local_user_index, bot_create_channel, update);
     generate_candid_method!(local_user_index, bot_delete_channel, update);
     generate_candid_method!(local_user_index, bot_send_message, update);
@@ -23,6 +24,7 @@ fn main() {
     generate_ts_method!(local_user_index, chat_events);
     generate_ts_method!(local_user_index, group_and_community_summary_updates);
 
+    generate_ts_method!(local_user_index, bot_award_achievement);
     generate_ts_method!(local_user_index, bot_create_channel);
     generate_ts_method!(local_user_index, bot_delete_channel);
     generate_ts_method!(local_user_index, bot_send_message);
@@ -35,6 +37,8 @@ fn main() {
     generate_ts_method!(local_user_index, join_group);
     generate_ts_method!(local_user_index, register_user);
     generate_ts_method!(local_user_index, report_message_v2);
+    generate_ts_method!(local_user_index, request_to_join_community);
+    generate_ts_method!(local_user_index, request_to_join_group);
     generate_ts_method!(local_user_index, uninstall_bot);
     generate_ts_method!(local_user_index, withdraw_from_icpswap);
 }
@@ -0,0 +1,23 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{AuthToken, UserId};
+
+#[ts_export(local_user_index, bot_award_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Args {
+    pub achievement_id: u32,
+    pub user_id: UserId,
+    pub auth_token: AuthToken,
+}
+
+#[ts_export(local_user_index, bot_award_achievement)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    FailedAuthentication(String),
+    InvalidRequest(String),
+    NotAuthorized,
+    Frozen,
+    C2CError(i32, String),
+}
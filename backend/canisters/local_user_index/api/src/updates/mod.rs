@@ -1,3 +1,4 @@
+pub mod bot_award_achievement;
 pub mod bot_create_channel;
 pub mod bot_delete_channel;
 pub mod bot_send_message;
@@ -19,5 +20,7 @@ pub mod join_group;
 pub mod push_events;
 pub mod register_user;
 pub mod report_message_v2;
+pub mod request_to_join_community;
+pub mod request_to_join_group;
 pub mod uninstall_bot;
 pub mod withdraw_from_icpswap;
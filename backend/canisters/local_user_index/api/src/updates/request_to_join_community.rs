@@ -0,0 +1,28 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{CommunityId, TimestampMillis};
+
+#[ts_export(local_user_index, request_to_join_community)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub community_id: CommunityId,
+    pub answer: Option<String>,
+    pub caller_username: String,
+}
+
+#[ts_export(local_user_index, request_to_join_community)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AlreadyInCommunity,
+    AlreadyInvited,
+    AlreadyRequested,
+    RecentlyRejected(TimestampMillis),
+    UserBlocked,
+    TooManyRequests(u32),
+    RateLimited(TimestampMillis),
+    AnswerTooLong(u32),
+    CommunityFrozen,
+    InternalError(String),
+}
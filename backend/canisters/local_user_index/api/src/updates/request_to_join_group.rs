@@ -0,0 +1,28 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChatId, TimestampMillis};
+
+#[ts_export(local_user_index, request_to_join_group)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub chat_id: ChatId,
+    pub answer: Option<String>,
+    pub caller_username: String,
+}
+
+#[ts_export(local_user_index, request_to_join_group)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AlreadyInGroup,
+    AlreadyInvited,
+    AlreadyRequested,
+    RecentlyRejected(TimestampMillis),
+    Blocked,
+    TooManyRequests(u32),
+    RateLimited(TimestampMillis),
+    AnswerTooLong(u32),
+    ChatFrozen,
+    InternalError(String),
+}
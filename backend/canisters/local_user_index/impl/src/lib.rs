@@ -22,7 +22,7 @@ use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::time::Duration;
 use timer_job_queues::GroupedTimerJobQueue;
 use types::{
-    BuildVersion, CanisterId, ChannelLatestMessageIndex, ChatId, ChildCanisterWasms, CommunityCanisterChannelSummary,
+    BuildVersion, CanisterId, ChannelLatestMessageIndex, ChatId, ChildCanisterWasms, Chit, CommunityCanisterChannelSummary,
     CommunityCanisterCommunitySummary, CommunityId, Cycles, DiamondMembershipDetails, IdempotentEnvelope, MessageContent,
     ReferralType, TimestampMillis, Timestamped, User, UserId, VerifiedCredentialGateArgs,
 };
@@ -121,6 +121,12 @@ impl RuntimeState {
         user_details
     }
 
+    // CHIT is only known for users whose canisters are hosted by this local user index and whose user canisters
+    // have notified us of their CHIT. For all other users the group or community looks it up from the user index.
+    pub fn local_user_chit(&self, user_id: &UserId) -> Option<Chit> {
+        self.data.local_users.get(user_id).and_then(|u| u.chit(self.env.now()))
+    }
+
     pub fn is_caller_user_index_canister(&self) -> bool {
         let caller = self.env.caller();
         self.data.user_index_canister_id == caller
@@ -3,7 +3,8 @@ use constants::MINUTE_IN_MS;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
-use types::{BuildVersion, CyclesTopUp, TimestampMillis, UserId};
+use types::{BuildVersion, Chit, CyclesTopUp, NotifyChit, TimestampMillis, UserId};
+use utils::time::MonthKey;
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct LocalUserMap {
@@ -43,6 +44,12 @@ impl LocalUserMap {
         }
     }
 
+    pub fn set_chit(&mut self, user_id: &UserId, chit: &NotifyChit) {
+        if let Some(user) = self.users.get_mut(user_id) {
+            user.set_chit(chit);
+        }
+    }
+
     pub fn mark_registration_in_progress(&mut self, principal: Principal, now: TimestampMillis) -> bool {
         match self.registration_in_progress.entry(principal) {
             Vacant(e) => {
@@ -76,6 +83,14 @@ pub struct LocalUser {
     pub wasm_version: BuildVersion,
     pub upgrade_in_progress: bool,
     pub cycle_top_ups: Vec<CyclesTopUp>,
+    #[serde(default)]
+    pub chit_balance: i32,
+    #[serde(default)]
+    pub streak: u16,
+    #[serde(default)]
+    pub streak_ends: TimestampMillis,
+    #[serde(default)]
+    pub chit_updated: TimestampMillis,
 }
 
 impl LocalUser {
@@ -89,6 +104,26 @@ impl LocalUser {
     pub fn mark_cycles_top_up(&mut self, top_up: CyclesTopUp) {
         self.cycle_top_ups.push(top_up)
     }
+
+    pub fn set_chit(&mut self, chit: &NotifyChit) {
+        if chit.timestamp >= self.chit_updated {
+            self.chit_balance = chit.chit_balance;
+            self.streak = chit.streak;
+            self.streak_ends = chit.streak_ends;
+            self.chit_updated = chit.timestamp;
+        }
+    }
+
+    // Returns `None` if the user canister hasn't notified us of the user's CHIT during the current month, since
+    // CHIT balances are per month
+    pub fn chit(&self, now: TimestampMillis) -> Option<Chit> {
+        let up_to_date = self.chit_updated > 0 && MonthKey::from_timestamp(self.chit_updated) == MonthKey::from_timestamp(now);
+
+        up_to_date.then(|| Chit {
+            balance: self.chit_balance,
+            streak: if self.streak_ends > now { self.streak } else { 0 },
+        })
+    }
 }
 
 impl LocalUser {
@@ -98,6 +133,10 @@ impl LocalUser {
             wasm_version,
             upgrade_in_progress: false,
             cycle_top_ups: Vec::new(),
+            chit_balance: 0,
+            streak: 0,
+            streak_ends: 0,
+            chit_updated: 0,
         }
     }
 }
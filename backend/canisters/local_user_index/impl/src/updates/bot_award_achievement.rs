@@ -0,0 +1,43 @@
+use canister_api_macros::update;
+use local_user_index_canister::bot_award_achievement::*;
+use types::BotActionScope;
+
+use crate::{bots::extract_access_context, mutate_state};
+
+#[update(candid = true, json = true, msgpack = true)]
+async fn bot_award_achievement(args: Args) -> Response {
+    use Response::*;
+
+    let context = match mutate_state(|state| extract_access_context(&args.auth_token, state)) {
+        Ok(context) => context,
+        Err(error) => return FailedAuthentication(error),
+    };
+
+    let community_id = match context.scope {
+        BotActionScope::Chat(details) => match details.chat {
+            types::Chat::Channel(community_id, _) => community_id,
+            _ => return InvalidRequest("Must be community or channel scope".to_string()),
+        },
+        BotActionScope::Community(details) => details.community_id,
+    };
+
+    match community_canister_c2c_client::c2c_bot_award_achievement(
+        community_id.into(),
+        &community_canister::c2c_bot_award_achievement::Args {
+            bot_id: context.bot_id,
+            initiator: context.initiator,
+            achievement_id: args.achievement_id,
+            user_id: args.user_id,
+        },
+    )
+    .await
+    {
+        Ok(response) => match response {
+            community_canister::c2c_bot_award_achievement::Response::Success => Success,
+            community_canister::c2c_bot_award_achievement::Response::NotAuthorized => NotAuthorized,
+            community_canister::c2c_bot_award_achievement::Response::CommunityFrozen => Frozen,
+            community_canister::c2c_bot_award_achievement::Response::InvalidRequest(message) => InvalidRequest(message),
+        },
+        Err((code, message)) => C2CError(code as i32, message),
+    }
+}
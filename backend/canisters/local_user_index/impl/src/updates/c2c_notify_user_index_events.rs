@@ -14,8 +14,8 @@ use tracing::info;
 use types::{c2c_uninstall_bot, TimestampMillis};
 use user_canister::{
     DiamondMembershipPaymentReceived, DisplayNameChanged, ExternalAchievementAwarded, OpenChatBotMessageV2,
-    PhoneNumberConfirmed, ReferredUserRegistered, StorageUpgraded, UserJoinedCommunityOrChannel, UserJoinedGroup,
-    UserSuspended, UsernameChanged,
+    PhoneNumberConfirmed, ReferredUserRegistered, ScopedPrincipalUpdated, StorageUpgraded, UserJoinedCommunityOrChannel,
+    UserJoinedGroup, UserSuspended, UsernameChanged,
 };
 
 #[update(guard = "caller_is_user_index_canister", msgpack = true)]
@@ -137,6 +137,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
                     local_user_index_canister_id: ev.local_user_index_canister_id,
                     latest_message_index: ev.latest_message_index,
                     group_canister_timestamp: ev.group_canister_timestamp,
+                    read_receipts_enabled: ev.read_receipts_enabled,
                 })),
                 **now,
             );
@@ -279,6 +280,17 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
                 }),
             );
         }
+        UserIndexEvent::ScopedPrincipalUpdated(ev) => {
+            state.push_event_to_user(
+                ev.user_id,
+                UserEvent::ScopedPrincipalUpdated(Box::new(ScopedPrincipalUpdated {
+                    principal: ev.principal,
+                    scope: ev.scope,
+                    expires: ev.expires,
+                })),
+                **now,
+            );
+        }
     }
 }
 
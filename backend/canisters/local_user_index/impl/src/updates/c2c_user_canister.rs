@@ -51,6 +51,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
 ) {
     match event {
         UserEvent::NotifyChit(ev) => {
+            state.data.local_users.set_chit(&user_id, &ev);
             state.push_event_to_user_index(UserIndexEvent::NotifyChit(Box::new((user_id, ev))), **now);
         }
         UserEvent::NotifyStreakInsurancePayment(payment) => {
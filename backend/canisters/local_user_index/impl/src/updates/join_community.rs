@@ -7,8 +7,11 @@ use local_user_index_canister::join_community::{Response::*, *};
 #[update(guard = "caller_is_openchat_user", candid = true, msgpack = true)]
 #[trace]
 async fn join_community(args: Args) -> Response {
-    let user_details =
-        mutate_state(|state| state.get_calling_user_and_process_credentials(args.verified_credential_args.as_ref()));
+    let (user_details, chit) = mutate_state(|state| {
+        let user_details = state.get_calling_user_and_process_credentials(args.verified_credential_args.as_ref());
+        let chit = state.local_user_chit(&user_details.user_id);
+        (user_details, chit)
+    });
 
     let is_bot = user_details.user_type.is_bot();
 
@@ -24,6 +27,7 @@ async fn join_community(args: Args) -> Response {
         diamond_membership_expires_at: user_details.diamond_membership_expires_at,
         verified_credential_args: args.verified_credential_args,
         unique_person_proof: user_details.unique_person_proof.clone(),
+        chit,
     };
     match community_canister_c2c_client::c2c_join_community(args.community_id.into(), &c2c_args).await {
         Ok(response) => match response {
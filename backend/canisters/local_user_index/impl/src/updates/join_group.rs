@@ -8,8 +8,11 @@ use types::{ChatId, MessageIndex, TimestampMillis, UserId};
 #[update(guard = "caller_is_openchat_user", candid = true, msgpack = true)]
 #[trace]
 async fn join_group(args: Args) -> Response {
-    let user_details =
-        mutate_state(|state| state.get_calling_user_and_process_credentials(args.verified_credential_args.as_ref()));
+    let (user_details, chit) = mutate_state(|state| {
+        let user_details = state.get_calling_user_and_process_credentials(args.verified_credential_args.as_ref());
+        let chit = state.local_user_chit(&user_details.user_id);
+        (user_details, chit)
+    });
 
     let is_bot = user_details.user_type.is_bot();
 
@@ -25,6 +28,7 @@ async fn join_group(args: Args) -> Response {
         diamond_membership_expires_at: user_details.diamond_membership_expires_at,
         verified_credential_args: args.verified_credential_args.clone(),
         unique_person_proof: user_details.unique_person_proof.clone(),
+        chit,
     };
     match group_canister_c2c_client::c2c_join_group(args.chat_id.into(), &c2c_args).await {
         Ok(response) => match response {
@@ -37,6 +41,7 @@ async fn join_group(args: Args) -> Response {
                             args.chat_id,
                             s.latest_message.as_ref().map(|m| m.event.message_index),
                             s.last_updated,
+                            s.read_receipts_enabled,
                             state,
                         );
                     });
@@ -61,6 +66,7 @@ fn commit(
     chat_id: ChatId,
     latest_message_index: Option<MessageIndex>,
     group_canister_timestamp: TimestampMillis,
+    read_receipts_enabled: bool,
     state: &mut RuntimeState,
 ) {
     let local_user_index_canister_id = state.env.canister_id();
@@ -74,6 +80,7 @@ fn commit(
                 local_user_index_canister_id,
                 latest_message_index,
                 group_canister_timestamp,
+                read_receipts_enabled,
             })),
             now,
         );
@@ -85,6 +92,7 @@ fn commit(
                 local_user_index_canister_id,
                 latest_message_index,
                 group_canister_timestamp,
+                read_receipts_enabled,
             })),
             now,
         );
@@ -0,0 +1,34 @@
+use crate::guards::caller_is_openchat_user;
+use crate::read_state;
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use local_user_index_canister::request_to_join_community::{Response::*, *};
+
+#[update(guard = "caller_is_openchat_user", candid = true, msgpack = true)]
+#[trace]
+async fn request_to_join_community(args: Args) -> Response {
+    let user_details = read_state(|state| state.calling_user());
+
+    let c2c_args = community_canister::c2c_request_to_join::Args {
+        user_id: user_details.user_id,
+        principal: user_details.principal,
+        username: args.caller_username,
+        answer: args.answer,
+    };
+
+    match community_canister_c2c_client::c2c_request_to_join(args.community_id.into(), &c2c_args).await {
+        Ok(response) => match response {
+            community_canister::c2c_request_to_join::Response::Success => Success,
+            community_canister::c2c_request_to_join::Response::AlreadyInCommunity => AlreadyInCommunity,
+            community_canister::c2c_request_to_join::Response::AlreadyInvited => AlreadyInvited,
+            community_canister::c2c_request_to_join::Response::AlreadyRequested => AlreadyRequested,
+            community_canister::c2c_request_to_join::Response::RecentlyRejected(ts) => RecentlyRejected(ts),
+            community_canister::c2c_request_to_join::Response::UserBlocked => UserBlocked,
+            community_canister::c2c_request_to_join::Response::TooManyRequests(l) => TooManyRequests(l),
+            community_canister::c2c_request_to_join::Response::RateLimited(ts) => RateLimited(ts),
+            community_canister::c2c_request_to_join::Response::AnswerTooLong(l) => AnswerTooLong(l),
+            community_canister::c2c_request_to_join::Response::CommunityFrozen => CommunityFrozen,
+        },
+        Err(error) => InternalError(format!("Failed to call 'community::c2c_request_to_join': {error:?}")),
+    }
+}
@@ -1 +1,2 @@
+pub mod notifications_canisters;
 pub mod subscription_exists;
@@ -1,7 +1,8 @@
-use canister_client::generate_candid_update_call;
+use canister_client::{generate_candid_query_call, generate_candid_update_call};
 use notifications_index_canister::*;
 
 // Queries
+generate_candid_query_call!(notifications_canisters);
 
 // Updates
 generate_candid_update_call!(add_notifications_canister);
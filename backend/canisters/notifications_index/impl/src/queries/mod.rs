@@ -1,2 +1,3 @@
 mod http_request;
+mod notifications_canisters;
 mod subscription_exists;
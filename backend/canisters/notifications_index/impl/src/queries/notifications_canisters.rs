@@ -0,0 +1,15 @@
+use crate::guards::caller_is_push_service;
+use crate::{read_state, RuntimeState};
+use ic_cdk::query;
+use notifications_index_canister::notifications_canisters::{Response::*, *};
+
+#[query(guard = "caller_is_push_service")]
+fn notifications_canisters(_args: Args) -> Response {
+    read_state(notifications_canisters_impl)
+}
+
+fn notifications_canisters_impl(state: &RuntimeState) -> Response {
+    let mut canister_ids: Vec<_> = state.data.notifications_canisters.keys().copied().collect();
+    canister_ids.sort();
+    Success(canister_ids)
+}
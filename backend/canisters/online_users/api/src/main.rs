@@ -15,4 +15,8 @@ fn main() {
 
     generate_ts_method!(online_users, last_online);
     generate_ts_method!(online_users, mark_as_online);
+    generate_ts_method!(online_users, presence);
+    generate_ts_method!(online_users, set_presence);
+    generate_ts_method!(online_users, set_typing);
+    generate_ts_method!(online_users, typing);
 }
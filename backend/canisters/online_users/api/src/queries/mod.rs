@@ -1 +1,3 @@
 pub mod last_online;
+pub mod presence;
+pub mod typing;
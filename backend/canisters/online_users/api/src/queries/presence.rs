@@ -0,0 +1,17 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{UserId, UserPresence};
+
+#[ts_export(online_users, presence)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_ids: Vec<UserId>,
+}
+
+// Users who have never been online, or who have hidden their presence from the caller, are omitted
+#[ts_export(online_users, presence)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<UserPresence>),
+}
@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageIndex, UserId};
+
+#[ts_export(online_users, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub chats: Vec<DirectChatOrThread>,
+}
+
+#[ts_export(online_users, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectChatOrThread {
+    pub user_id: UserId,
+    pub thread_root_message_index: Option<MessageIndex>,
+}
+
+// Only the direct chats / threads in which the other user is typing are included
+#[ts_export(online_users, typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<DirectChatOrThread>),
+}
@@ -1,2 +1,4 @@
 pub mod c2c_remove_user;
 pub mod mark_as_online;
+pub mod set_presence;
+pub mod set_typing;
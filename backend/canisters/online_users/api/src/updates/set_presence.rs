@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{CustomStatus, FieldTooLongResult, OptionUpdate, PresenceStatus, PresenceVisibility};
+
+#[ts_export(online_users, set_presence)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub status: Option<PresenceStatus>,
+    #[ts(as = "types::OptionUpdateCustomStatus")]
+    pub custom_status: OptionUpdate<CustomStatus>,
+    pub visibility: Option<PresenceVisibility>,
+}
+
+#[ts_export(online_users, set_presence)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    CustomStatusTooLong(FieldTooLongResult),
+    EmojiTooLong(FieldTooLongResult),
+    TooManyContacts(u32),
+    UserNotFound,
+    InternalError(String),
+}
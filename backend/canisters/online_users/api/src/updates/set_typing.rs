@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageIndex, UserId};
+
+// Typing indicators for direct chats only, those for groups and channels are handled by the group and community
+// canisters so that they can be restricted to members.
+// Clients should call this with `is_typing: true` every few seconds while the user is typing, since each signal
+// expires shortly after it is received.
+#[ts_export(online_users, set_typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub is_typing: bool,
+}
+
+#[ts_export(online_users, set_typing)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    UserNotFound,
+    InternalError(String),
+}
@@ -1,13 +1,18 @@
 use crate::model::last_online_dates::LastOnlineDates;
+use crate::model::presence::Presence;
+use candid::Principal;
 use canister_state_macros::canister_state;
+use constants::MINUTE_IN_MS;
 use event_store_producer::{EventStoreClient, EventStoreClientBuilder, EventStoreClientInfo};
 use event_store_producer_cdk_runtime::CdkRuntime;
+use group_community_common::Typing;
 use principal_to_user_id_map::PrincipalToUserIdMap;
 use serde::{Deserialize, Serialize};
+use stable_memory_map::StableMemoryMap;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::time::Duration;
-use types::{BuildVersion, CanisterId, Cycles, TimestampMillis, Timestamped};
+use types::{BuildVersion, CanisterId, Cycles, MessageIndex, Milliseconds, TimestampMillis, Timestamped, UserId};
 use utils::env::Environment;
 
 mod guards;
@@ -18,6 +23,9 @@ mod model;
 mod queries;
 mod updates;
 
+// Users are considered offline if they haven't marked themselves as online within this duration
+const ONLINE_THRESHOLD: Milliseconds = 5 * MINUTE_IN_MS;
+
 thread_local! {
     static WASM_VERSION: RefCell<Timestamped<BuildVersion>> = RefCell::default();
 }
@@ -39,6 +47,13 @@ impl RuntimeState {
         self.data.user_index_canister_id == caller
     }
 
+    pub fn is_online(&self, user_id: UserId) -> bool {
+        self.data
+            .last_online_dates
+            .get(user_id)
+            .is_some_and(|ts| self.env.now().saturating_sub(ts) < ONLINE_THRESHOLD)
+    }
+
     pub fn metrics(&self) -> Metrics {
         let event_store_client_info = self.data.event_store_client.info();
         let event_store_canister_id = event_store_client_info.event_store_canister_id;
@@ -66,6 +81,11 @@ impl RuntimeState {
 #[derive(Serialize, Deserialize)]
 struct Data {
     pub last_online_dates: LastOnlineDates,
+    #[serde(default)]
+    pub presence: Presence,
+    // Keyed by the user being typed to and the thread, if any
+    #[serde(skip)]
+    pub typing: Typing<(UserId, Option<MessageIndex>)>,
     pub principal_to_user_id_map: PrincipalToUserIdMap,
     pub user_index_canister_id: CanisterId,
     pub cycles_dispenser_canister_id: CanisterId,
@@ -85,6 +105,8 @@ impl Data {
     ) -> Data {
         Data {
             last_online_dates: LastOnlineDates::default(),
+            presence: Presence::default(),
+            typing: Typing::default(),
             principal_to_user_id_map: PrincipalToUserIdMap::default(),
             user_index_canister_id,
             cycles_dispenser_canister_id,
@@ -99,6 +121,36 @@ impl Data {
     }
 }
 
+// Looks up the caller's UserId locally, falling back to querying the UserIndex canister if not found.
+// Returns `Ok(None)` if the caller is not a registered user.
+async fn lookup_caller_user_id() -> Result<Option<UserId>, String> {
+    match read_state(try_get_caller_user_id_locally) {
+        Ok(user_id) => Ok(Some(user_id)),
+        Err((principal, user_index_canister_id)) => {
+            let c2c_args = user_index_canister::c2c_lookup_user::Args {
+                user_id_or_principal: principal,
+            };
+            match user_index_canister_c2c_client::c2c_lookup_user(user_index_canister_id, &c2c_args).await {
+                Ok(user_index_canister::c2c_lookup_user::Response::Success(result)) => {
+                    mutate_state(|state| state.data.principal_to_user_id_map.insert(principal, result.user_id));
+                    Ok(Some(result.user_id))
+                }
+                Ok(_) => Ok(None),
+                Err(error) => Err(format!("{error:?}")),
+            }
+        }
+    }
+}
+
+fn try_get_caller_user_id_locally(state: &RuntimeState) -> Result<UserId, (Principal, CanisterId)> {
+    let caller = state.env.caller();
+    state
+        .data
+        .principal_to_user_id_map
+        .get(&caller)
+        .ok_or((caller, state.data.user_index_canister_id))
+}
+
 #[derive(Serialize, Debug)]
 pub struct Metrics {
     pub now: TimestampMillis,
@@ -1 +1,2 @@
 pub mod last_online_dates;
+pub mod presence;
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use types::{is_default, CustomStatus, EffectivePresence, PresenceStatus, PresenceVisibility, TimestampMillis, UserId};
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Presence {
+    users: HashMap<UserId, PresenceSettings>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct PresenceSettings {
+    #[serde(rename = "s", default, skip_serializing_if = "is_default")]
+    status: PresenceStatus,
+    #[serde(rename = "c", default, skip_serializing_if = "Option::is_none")]
+    custom_status: Option<CustomStatus>,
+    #[serde(rename = "v", default, skip_serializing_if = "is_default")]
+    visibility: Visibility,
+}
+
+#[derive(Serialize, Deserialize, Default, PartialEq)]
+enum Visibility {
+    #[default]
+    Everyone,
+    Contacts(HashSet<UserId>),
+    Nobody,
+}
+
+impl From<PresenceVisibility> for Visibility {
+    fn from(value: PresenceVisibility) -> Self {
+        match value {
+            PresenceVisibility::Everyone => Visibility::Everyone,
+            PresenceVisibility::Contacts(user_ids) => Visibility::Contacts(user_ids.into_iter().collect()),
+            PresenceVisibility::Nobody => Visibility::Nobody,
+        }
+    }
+}
+
+impl PresenceSettings {
+    fn is_default(&self) -> bool {
+        self.status == PresenceStatus::default() && self.custom_status.is_none() && self.visibility == Visibility::Everyone
+    }
+}
+
+impl Presence {
+    pub fn set(
+        &mut self,
+        user_id: UserId,
+        status: Option<PresenceStatus>,
+        custom_status: Option<Option<CustomStatus>>,
+        visibility: Option<PresenceVisibility>,
+    ) {
+        let settings = self.users.entry(user_id).or_default();
+
+        if let Some(status) = status {
+            settings.status = status;
+        }
+        if let Some(custom_status) = custom_status {
+            settings.custom_status = custom_status;
+        }
+        if let Some(visibility) = visibility {
+            settings.visibility = visibility.into();
+        }
+
+        if settings.is_default() {
+            self.users.remove(&user_id);
+        }
+    }
+
+    pub fn remove(&mut self, user_id: &UserId) {
+        self.users.remove(user_id);
+    }
+
+    // Returns false if the user has chosen to hide their presence from the caller (who may be anonymous)
+    pub fn is_visible_to(&self, user_id: &UserId, caller: Option<UserId>) -> bool {
+        if caller == Some(*user_id) {
+            return true;
+        }
+
+        let Some(settings) = self.users.get(user_id) else {
+            return true;
+        };
+
+        settings.status != PresenceStatus::Invisible
+            && match &settings.visibility {
+                Visibility::Everyone => true,
+                Visibility::Contacts(user_ids) => caller.is_some_and(|c| user_ids.contains(&c)),
+                Visibility::Nobody => false,
+            }
+    }
+
+    pub fn get(&self, user_id: &UserId, is_online: bool, now: TimestampMillis) -> (EffectivePresence, Option<CustomStatus>) {
+        let settings = self.users.get(user_id);
+
+        let presence = if !is_online {
+            EffectivePresence::Offline
+        } else {
+            match settings.map(|s| s.status).unwrap_or_default() {
+                PresenceStatus::Online => EffectivePresence::Online,
+                PresenceStatus::Away => EffectivePresence::Away,
+                PresenceStatus::DoNotDisturb => EffectivePresence::DoNotDisturb,
+                PresenceStatus::Invisible => EffectivePresence::Offline,
+            }
+        };
+
+        let custom_status = settings
+            .and_then(|s| s.custom_status.as_ref())
+            .filter(|c| !c.is_expired(now))
+            .cloned();
+
+        (presence, custom_status)
+    }
+}
@@ -1,20 +1,24 @@
-use crate::{read_state, RuntimeState};
+use crate::{lookup_caller_user_id, read_state, RuntimeState};
 use canister_api_macros::query;
 use canister_tracing_macros::trace;
 use online_users_canister::last_online::{Response::*, *};
+use types::UserId;
 
-#[query(candid = true, msgpack = true)]
+#[query(composite = true, candid = true, msgpack = true)]
 #[trace]
-fn last_online(args: Args) -> Response {
-    read_state(|state| last_online_impl(args, state))
+async fn last_online(args: Args) -> Response {
+    let caller = lookup_caller_user_id().await.ok().flatten();
+
+    read_state(|state| last_online_impl(args, caller, state))
 }
 
-fn last_online_impl(args: Args, state: &RuntimeState) -> Response {
+fn last_online_impl(args: Args, caller: Option<UserId>, state: &RuntimeState) -> Response {
     let now = state.env.now();
 
     let result = args
         .user_ids
         .into_iter()
+        .filter(|u| state.data.presence.is_visible_to(u, caller))
         .filter_map(|u| {
             state.data.last_online_dates.get(u).map(|ts| UserLastOnline {
                 user_id: u,
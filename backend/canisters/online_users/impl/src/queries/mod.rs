@@ -1,2 +1,4 @@
 mod http_request;
 mod last_online;
+mod presence;
+mod typing;
@@ -0,0 +1,39 @@
+use crate::{lookup_caller_user_id, read_state, RuntimeState};
+use canister_api_macros::query;
+use online_users_canister::presence::{Response::*, *};
+use types::{UserId, UserPresence};
+
+const MAX_USERS: usize = 1000;
+
+// This is a composite query so that callers whose principal isn't yet known to this canister (eg. when using a new
+// device) can still be looked up, otherwise they'd be unable to see presences which are only visible to contacts
+#[query(composite = true, msgpack = true)]
+async fn presence(args: Args) -> Response {
+    let caller = lookup_caller_user_id().await.ok().flatten();
+
+    read_state(|state| presence_impl(args, caller, state))
+}
+
+fn presence_impl(args: Args, caller: Option<UserId>, state: &RuntimeState) -> Response {
+    let now = state.env.now();
+
+    let result = args
+        .user_ids
+        .into_iter()
+        .take(MAX_USERS)
+        .filter(|u| state.data.presence.is_visible_to(u, caller))
+        .filter_map(|u| {
+            state.data.last_online_dates.get(u).map(|ts| {
+                let (presence, custom_status) = state.data.presence.get(&u, state.is_online(u), now);
+                UserPresence {
+                    user_id: u,
+                    presence,
+                    custom_status,
+                    duration_since_last_online: now.saturating_sub(ts),
+                }
+            })
+        })
+        .collect();
+
+    Success(result)
+}
@@ -0,0 +1,34 @@
+use crate::{lookup_caller_user_id, read_state, RuntimeState};
+use canister_api_macros::query;
+use online_users_canister::typing::{Response::*, *};
+use types::UserId;
+
+const MAX_CHATS: usize = 100;
+
+#[query(composite = true, msgpack = true)]
+async fn typing(args: Args) -> Response {
+    let Some(caller) = lookup_caller_user_id().await.ok().flatten() else {
+        return Success(Vec::new());
+    };
+
+    read_state(|state| typing_impl(args, caller, state))
+}
+
+fn typing_impl(args: Args, caller: UserId, state: &RuntimeState) -> Response {
+    let now = state.env.now();
+
+    let result = args
+        .chats
+        .into_iter()
+        .take(MAX_CHATS)
+        .filter(|c| {
+            state
+                .data
+                .typing
+                .get(caller, &(caller, c.thread_root_message_index), now)
+                .contains(&c.user_id)
+        })
+        .collect();
+
+    Success(result)
+}
@@ -19,6 +19,7 @@ fn c2c_remove_user_impl(args: Args, state: &mut RuntimeState) -> Response {
         .map(|v| v.into_value())
     {
         state.data.last_online_dates.remove(user_id);
+        state.data.presence.remove(&user_id);
     }
     Success
 }
@@ -1,3 +1,5 @@
 mod c2c_remove_user;
 mod mark_as_online;
+mod set_presence;
+mod set_typing;
 mod wallet_receive;
@@ -0,0 +1,55 @@
+use crate::{lookup_caller_user_id, mutate_state, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use online_users_canister::set_presence::{Response::*, *};
+use types::{FieldTooLongResult, OptionUpdate, PresenceVisibility, UserId};
+
+const MAX_CUSTOM_STATUS_LENGTH: u32 = 100;
+const MAX_EMOJI_LENGTH: u32 = 16;
+const MAX_CONTACTS: u32 = 200;
+
+#[update(msgpack = true)]
+#[trace]
+async fn set_presence(args: Args) -> Response {
+    let user_id = match lookup_caller_user_id().await {
+        Ok(Some(u)) => u,
+        Ok(None) => return UserNotFound,
+        Err(error) => return InternalError(error),
+    };
+
+    mutate_state(|state| set_presence_impl(user_id, args, state))
+}
+
+fn set_presence_impl(user_id: UserId, args: Args, state: &mut RuntimeState) -> Response {
+    if let OptionUpdate::SetToSome(custom_status) = &args.custom_status {
+        let length = custom_status.text.chars().count() as u32;
+        if length > MAX_CUSTOM_STATUS_LENGTH {
+            return CustomStatusTooLong(FieldTooLongResult {
+                length_provided: length,
+                max_length: MAX_CUSTOM_STATUS_LENGTH,
+            });
+        }
+        if let Some(emoji) = &custom_status.emoji {
+            let length = emoji.chars().count() as u32;
+            if length > MAX_EMOJI_LENGTH {
+                return EmojiTooLong(FieldTooLongResult {
+                    length_provided: length,
+                    max_length: MAX_EMOJI_LENGTH,
+                });
+            }
+        }
+    }
+
+    if let Some(PresenceVisibility::Contacts(user_ids)) = &args.visibility {
+        if user_ids.len() as u32 > MAX_CONTACTS {
+            return TooManyContacts(MAX_CONTACTS);
+        }
+    }
+
+    state
+        .data
+        .presence
+        .set(user_id, args.status, args.custom_status.expand(), args.visibility);
+
+    Success
+}
@@ -0,0 +1,24 @@
+use crate::{lookup_caller_user_id, mutate_state};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use online_users_canister::set_typing::{Response::*, *};
+
+#[update(msgpack = true)]
+#[trace]
+async fn set_typing(args: Args) -> Response {
+    let user_id = match lookup_caller_user_id().await {
+        Ok(Some(u)) => u,
+        Ok(None) => return UserNotFound,
+        Err(error) => return InternalError(error),
+    };
+
+    mutate_state(|state| {
+        let now = state.env.now();
+        state
+            .data
+            .typing
+            .set(user_id, (args.user_id, args.thread_root_message_index), args.is_typing, now);
+    });
+
+    Success
+}
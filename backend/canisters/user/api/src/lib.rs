@@ -1,9 +1,9 @@
-use candid::CandidType;
+use candid::{CandidType, Principal};
 use chat_events::MessageContentInternal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use types::{
-    Achievement, CanisterId, ChannelId, ChannelLatestMessageIndex, Chat, ChatId, CommunityId, Cryptocurrency,
+    Achievement, CanisterId, ChannelId, ChannelLatestMessageIndex, Chat, ChatId, CommunityId, Cryptocurrency, DelegationScope,
     DiamondMembershipPlanDuration, EventIndex, MessageContent, MessageContentInitial, MessageId, MessageIndex, Milliseconds,
     P2PSwapStatus, PhoneNumber, Reaction, ReferralStatus, SuspensionDuration, TimestampMillis, UniquePersonProof, User, UserId,
 };
@@ -107,6 +107,7 @@ pub enum LocalUserIndexEvent {
     DiamondMembershipPaymentReceived(Box<DiamondMembershipPaymentReceived>),
     NotifyUniquePersonProof(Box<UniquePersonProof>),
     ExternalAchievementAwarded(Box<ExternalAchievementAwarded>),
+    ScopedPrincipalUpdated(Box<ScopedPrincipalUpdated>),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -160,6 +161,8 @@ pub struct UserJoinedGroup {
     pub local_user_index_canister_id: CanisterId,
     pub latest_message_index: Option<MessageIndex>,
     pub group_canister_timestamp: TimestampMillis,
+    #[serde(default)]
+    pub read_receipts_enabled: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -170,6 +173,13 @@ pub struct UserJoinedCommunityOrChannel {
     pub community_canister_timestamp: TimestampMillis,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScopedPrincipalUpdated {
+    pub principal: Principal,
+    pub scope: Option<DelegationScope>,
+    pub expires: TimestampMillis,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct DiamondMembershipPaymentReceived {
     pub timestamp: TimestampMillis,
@@ -414,4 +424,5 @@ pub enum CommunityCanisterEvent {
 pub enum GroupCanisterEvent {
     MessageActivity(MessageActivityEvent),
     Achievement(Achievement),
+    ReadReceiptsChanged(bool),
 }
@@ -17,12 +17,15 @@ fn main() {
     generate_ts_method!(user, events);
     generate_ts_method!(user, events_by_index);
     generate_ts_method!(user, events_window);
+    generate_ts_method!(user, export_direct_chat_events);
     generate_ts_method!(user, hot_group_exclusions);
     generate_ts_method!(user, initial_state);
     generate_ts_method!(user, local_user_index);
     generate_ts_method!(user, message_activity_feed);
+    generate_ts_method!(user, message_edit_history);
     generate_ts_method!(user, messages_by_message_index);
     generate_ts_method!(user, public_profile);
+    generate_ts_method!(user, recommended_groups);
     generate_ts_method!(user, search_messages);
     generate_ts_method!(user, saved_crypto_accounts);
     generate_ts_method!(user, token_swap_status);
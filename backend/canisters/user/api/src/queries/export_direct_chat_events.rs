@@ -0,0 +1,19 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChatArchiveCursor, ChatArchiveEventsPage, UserId};
+
+#[ts_export(user, export_direct_chat_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub after: Option<ChatArchiveCursor>,
+    pub max_events: u32,
+}
+
+#[ts_export(user, export_direct_chat_events)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatArchiveEventsPage),
+    ChatNotFound,
+}
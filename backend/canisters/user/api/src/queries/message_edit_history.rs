@@ -0,0 +1,28 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageId, MessageIndex, MessageVersion, UserId};
+
+#[ts_export(user, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+}
+
+#[ts_export(user, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    ChatNotFound,
+    ThreadNotFound,
+    MessageNotFound,
+}
+
+#[ts_export(user, message_edit_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // The previous versions of the message, oldest first
+    pub versions: Vec<MessageVersion>,
+}
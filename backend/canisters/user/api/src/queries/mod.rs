@@ -11,12 +11,15 @@ pub mod deleted_message;
 pub mod events;
 pub mod events_by_index;
 pub mod events_window;
+pub mod export_direct_chat_events;
 pub mod hot_group_exclusions;
 pub mod initial_state;
 pub mod local_user_index;
 pub mod message_activity_feed;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
 pub mod public_profile;
+pub mod recommended_groups;
 pub mod saved_crypto_accounts;
 pub mod search_messages;
 pub mod token_swap_status;
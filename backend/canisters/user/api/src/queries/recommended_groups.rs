@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::PublicGroupSummary;
+
+#[ts_export(user, recommended_groups)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub count: u8,
+    pub languages: Option<Vec<String>>,
+}
+
+#[ts_export(user, recommended_groups)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    InternalError(String),
+}
+
+#[ts_export(user, recommended_groups)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub groups: Vec<PublicGroupSummary>,
+}
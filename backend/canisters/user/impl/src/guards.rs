@@ -1,4 +1,5 @@
 use crate::read_state;
+use types::DelegationScope;
 
 pub fn caller_is_owner() -> Result<(), String> {
     if read_state(|state| state.is_caller_owner()) {
@@ -8,6 +9,32 @@ pub fn caller_is_owner() -> Result<(), String> {
     }
 }
 
+pub fn caller_is_owner_or_read_only_principal() -> Result<(), String> {
+    if read_state(|state| state.is_caller_owner_or_scoped_principal(DelegationScope::ReadOnly)) {
+        Ok(())
+    } else {
+        Err("Caller is not the canister owner or a read only principal".to_owned())
+    }
+}
+
+pub fn caller_is_owner_or_local_user_index_or_read_only_principal() -> Result<(), String> {
+    if read_state(|state| {
+        state.is_caller_local_user_index() || state.is_caller_owner_or_scoped_principal(DelegationScope::ReadOnly)
+    }) {
+        Ok(())
+    } else {
+        Err("Caller is not the canister owner, the local user index or a read only principal".to_owned())
+    }
+}
+
+pub fn caller_is_owner_or_send_messages_principal() -> Result<(), String> {
+    if read_state(|state| state.is_caller_owner_or_scoped_principal(DelegationScope::SendMessages)) {
+        Ok(())
+    } else {
+        Err("Caller is not the canister owner or a send messages principal".to_owned())
+    }
+}
+
 pub fn caller_is_user_index() -> Result<(), String> {
     if read_state(|state| state.is_caller_user_index()) {
         Ok(())
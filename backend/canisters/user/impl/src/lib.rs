@@ -7,6 +7,7 @@ use crate::model::hot_group_exclusions::HotGroupExclusions;
 use crate::model::local_user_index_event_batch::LocalUserIndexEventBatch;
 use crate::model::p2p_swaps::P2PSwaps;
 use crate::model::pin_number::PinNumber;
+use crate::model::scoped_principals::ScopedPrincipals;
 use crate::model::token_swaps::TokenSwaps;
 use crate::model::user_canister_event_batch::UserCanisterEventBatch;
 use crate::timer_job_types::{ClaimChitInsuranceJob, DeleteFileReferencesJob, RemoveExpiredEventsJob, TimerJob};
@@ -38,9 +39,9 @@ use std::time::Duration;
 use timer_job_queues::GroupedTimerJobQueue;
 use types::{
     Achievement, BotInitiator, BotPermissions, BuildVersion, CanisterId, Chat, ChatId, ChatMetrics, ChitEarned,
-    ChitEarnedReason, CommunityId, Cryptocurrency, Cycles, Document, IdempotentEnvelope, Milliseconds, Notification,
-    NotifyChit, TimestampMillis, Timestamped, UniquePersonProof, UserCanisterStreakInsuranceClaim,
-    UserCanisterStreakInsurancePayment, UserId,
+    ChitEarnedReason, CommunityId, Cryptocurrency, Cycles, DelegationScope, Document, IdempotentEnvelope, Milliseconds,
+    Notification, NotifyChit, ScopedPrincipalUpdate, TimestampMillis, Timestamped, UniquePersonProof,
+    UserCanisterStreakInsuranceClaim, UserCanisterStreakInsurancePayment, UserId,
 };
 use user_canister::{MessageActivityEvent, NamedAccount, UserCanisterEvent, WalletConfig};
 use utils::env::Environment;
@@ -88,6 +89,11 @@ impl RuntimeState {
         self.env.caller() == self.data.owner
     }
 
+    pub fn is_caller_owner_or_scoped_principal(&self, scope: DelegationScope) -> bool {
+        let caller = self.env.caller();
+        caller == self.data.owner || self.data.scoped_principals.is_permitted(&caller, scope, self.env.now())
+    }
+
     pub fn is_caller_user_index(&self) -> bool {
         self.env.caller() == self.data.user_index_canister_id
     }
@@ -292,6 +298,7 @@ impl RuntimeState {
         if self.data.blocked_users.value.insert(user_id) {
             self.data.blocked_users.timestamp = now;
             self.push_local_user_index_canister_event(LocalUserIndexEvent::UserBlocked(user_id), now);
+            self.sync_blocked_users_to_all_chats(vec![user_id], Vec::new());
         }
     }
 
@@ -299,7 +306,71 @@ impl RuntimeState {
         if self.data.blocked_users.value.remove(&user_id) {
             self.data.blocked_users.timestamp = now;
             self.push_local_user_index_canister_event(LocalUserIndexEvent::UserUnblocked(user_id), now);
+            self.sync_blocked_users_to_all_chats(Vec::new(), vec![user_id]);
+        }
+    }
+
+    // Groups and communities hold a copy of each member's blocked users so that they can hide the blocked users'
+    // messages, reactions and mentions from the member. Only the changes are sent, other than when joining a group or
+    // community, at which point all of the user's blocked users are sent.
+    pub fn sync_blocked_users_to_all_chats(&mut self, blocked: Vec<UserId>, unblocked: Vec<UserId>) {
+        let group_ids: Vec<_> = self.data.group_chats.iter().map(|g| g.chat_id).collect();
+        let community_ids: Vec<_> = self.data.communities.iter().map(|c| c.community_id).collect();
+
+        for chat_id in group_ids {
+            self.sync_blocked_users_to_group(chat_id, blocked.clone(), unblocked.clone());
         }
+        for community_id in community_ids {
+            self.sync_blocked_users_to_community(community_id, blocked.clone(), unblocked.clone());
+        }
+    }
+
+    pub fn sync_blocked_users_to_group(&mut self, chat_id: ChatId, blocked: Vec<UserId>, unblocked: Vec<UserId>) {
+        let args = group_canister::c2c_update_blocked_users::Args { blocked, unblocked };
+        self.data.fire_and_forget_handler.send(
+            chat_id.into(),
+            "c2c_update_blocked_users_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn sync_blocked_users_to_community(&mut self, community_id: CommunityId, blocked: Vec<UserId>, unblocked: Vec<UserId>) {
+        let args = community_canister::c2c_update_blocked_users::Args { blocked, unblocked };
+        self.data.fire_and_forget_handler.send(
+            community_id.into(),
+            "c2c_update_blocked_users_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn sync_scoped_principals_to_all_chats(&mut self, principals: Vec<ScopedPrincipalUpdate>) {
+        let group_ids: Vec<_> = self.data.group_chats.iter().map(|g| g.chat_id).collect();
+        let community_ids: Vec<_> = self.data.communities.iter().map(|c| c.community_id).collect();
+
+        for chat_id in group_ids {
+            self.sync_scoped_principals_to_group(chat_id, principals.clone());
+        }
+        for community_id in community_ids {
+            self.sync_scoped_principals_to_community(community_id, principals.clone());
+        }
+    }
+
+    pub fn sync_scoped_principals_to_group(&mut self, chat_id: ChatId, principals: Vec<ScopedPrincipalUpdate>) {
+        let args = group_canister::c2c_update_scoped_principals::Args { principals };
+        self.data.fire_and_forget_handler.send(
+            chat_id.into(),
+            "c2c_update_scoped_principals_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn sync_scoped_principals_to_community(&mut self, community_id: CommunityId, principals: Vec<ScopedPrincipalUpdate>) {
+        let args = community_canister::c2c_update_scoped_principals::Args { principals };
+        self.data.fire_and_forget_handler.send(
+            community_id.into(),
+            "c2c_update_scoped_principals_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
     }
 
     pub fn metrics(&self) -> Metrics {
@@ -399,6 +470,10 @@ struct Data {
     pub bots: InstalledBots,
     #[serde(default)]
     bot_api_keys: BotApiKeys,
+    #[serde(default)]
+    pub scoped_principals: ScopedPrincipals,
+    #[serde(default)]
+    pub blocked_users_synced_to_chats: bool,
 }
 
 impl Data {
@@ -471,6 +546,8 @@ impl Data {
             idempotency_checker: IdempotencyChecker::default(),
             bots: InstalledBots::default(),
             bot_api_keys: BotApiKeys::default(),
+            scoped_principals: ScopedPrincipals::default(),
+            blocked_users_synced_to_chats: true,
         }
     }
 
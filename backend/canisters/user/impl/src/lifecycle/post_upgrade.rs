@@ -33,6 +33,16 @@ fn post_upgrade(args: Args) {
     DedupeMessageIdsJob::default().execute();
 
     mutate_state(|state| {
+        // Send the user's blocked users to each of their groups and communities which joined before personal block
+        // lists were introduced
+        if !state.data.blocked_users_synced_to_chats {
+            let blocked_users: Vec<_> = state.data.blocked_users.value.iter().copied().collect();
+            if !blocked_users.is_empty() {
+                state.sync_blocked_users_to_all_chats(blocked_users, Vec::new());
+            }
+            state.data.blocked_users_synced_to_chats = true;
+        }
+
         let blocked_users = state.data.blocked_users.value.clone();
         if !blocked_users.is_empty() {
             let now = state.env.now();
@@ -13,6 +13,9 @@ pub struct GroupChat {
     pub messages_read: GroupMessagesRead,
     pub last_changed_for_my_data: TimestampMillis,
     pub archived: Timestamped<bool>,
+    // Kept in sync by the group so that we only tell it how far we have read when it will be used
+    #[serde(default)]
+    pub read_receipts_enabled: bool,
 }
 
 impl GroupChat {
@@ -20,6 +23,7 @@ impl GroupChat {
         chat_id: ChatId,
         local_user_index_canister_id: CanisterId,
         read_up_to: Option<MessageIndex>,
+        read_receipts_enabled: bool,
         now: TimestampMillis,
     ) -> GroupChat {
         GroupChat {
@@ -32,6 +36,7 @@ impl GroupChat {
             },
             last_changed_for_my_data: now,
             archived: Timestamped::new(false, now),
+            read_receipts_enabled,
         }
     }
 
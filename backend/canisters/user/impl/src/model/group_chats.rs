@@ -55,7 +55,7 @@ impl GroupChats {
     }
 
     pub fn create(&mut self, chat_id: ChatId, local_user_index_canister_id: CanisterId, now: TimestampMillis) -> bool {
-        self.join(chat_id, local_user_index_canister_id, None, now);
+        self.join(chat_id, local_user_index_canister_id, None, false, now);
         self.groups_created += 1;
         true
     }
@@ -65,11 +65,18 @@ impl GroupChats {
         chat_id: ChatId,
         local_user_index_canister_id: CanisterId,
         read_up_to: Option<MessageIndex>,
+        read_receipts_enabled: bool,
         now: TimestampMillis,
     ) -> bool {
         match self.group_chats.entry(chat_id) {
             Vacant(e) => {
-                e.insert(GroupChat::new(chat_id, local_user_index_canister_id, read_up_to, now));
+                e.insert(GroupChat::new(
+                    chat_id,
+                    local_user_index_canister_id,
+                    read_up_to,
+                    read_receipts_enabled,
+                    now,
+                ));
                 self.removed.retain(|g| g.chat_id != chat_id);
                 true
             }
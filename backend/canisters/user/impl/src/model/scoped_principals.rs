@@ -0,0 +1,52 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use types::{DelegationScope, ScopedPrincipalUpdate, TimestampMillis};
+
+// Principals derived from the owner's identity which may only call the endpoints matching their scope. These are
+// created by the identity canister when the owner prepares a scoped delegation for a third-party client, and are
+// forwarded on to each of the owner's groups and communities.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ScopedPrincipals {
+    map: HashMap<Principal, ScopedPrincipal>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ScopedPrincipal {
+    scope: DelegationScope,
+    expires: TimestampMillis,
+}
+
+impl ScopedPrincipals {
+    pub fn update(
+        &mut self,
+        principal: Principal,
+        scope: Option<DelegationScope>,
+        expires: TimestampMillis,
+        now: TimestampMillis,
+    ) {
+        self.map.retain(|_, p| p.expires > now);
+
+        if let Some(scope) = scope.filter(|_| expires > now) {
+            self.map.insert(principal, ScopedPrincipal { scope, expires });
+        } else {
+            self.map.remove(&principal);
+        }
+    }
+
+    pub fn current(&self, now: TimestampMillis) -> Vec<ScopedPrincipalUpdate> {
+        self.map
+            .iter()
+            .filter(|(_, p)| p.expires > now)
+            .map(|(principal, p)| ScopedPrincipalUpdate {
+                principal: *principal,
+                scope: Some(p.scope),
+                expires: p.expires,
+            })
+            .collect()
+    }
+
+    pub fn is_permitted(&self, principal: &Principal, scope: DelegationScope, now: TimestampMillis) -> bool {
+        self.map.get(principal).is_some_and(|p| p.scope == scope && p.expires > now)
+    }
+}
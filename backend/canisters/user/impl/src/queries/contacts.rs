@@ -1,9 +1,9 @@
-use crate::guards::caller_is_owner;
+use crate::guards::caller_is_owner_or_read_only_principal;
 use crate::read_state;
 use canister_api_macros::query;
 use user_canister::contacts::{Response::*, *};
 
-#[query(guard = "caller_is_owner", msgpack = true)]
+#[query(guard = "caller_is_owner_or_read_only_principal", msgpack = true)]
 fn contacts(_args: Args) -> Response {
     read_state(|state| {
         Success(SuccessResult {
@@ -1,4 +1,4 @@
-use crate::guards::caller_is_owner_or_local_user_index;
+use crate::guards::caller_is_owner_or_local_user_index_or_read_only_principal;
 use crate::model::direct_chat::DirectChat;
 use crate::queries::check_replica_up_to_date;
 use crate::{read_state, RuntimeState};
@@ -7,7 +7,7 @@ use chat_events::{ChatEventsListReader, Reader};
 use types::{EventIndex, EventOrExpiredRange, EventsResponse, MessageIndex, TimestampMillis, UserId};
 use user_canister::events::{Response::*, *};
 
-#[query(guard = "caller_is_owner_or_local_user_index", msgpack = true)]
+#[query(guard = "caller_is_owner_or_local_user_index_or_read_only_principal", msgpack = true)]
 fn events(args: Args) -> Response {
     read_state(|state| {
         read_events(
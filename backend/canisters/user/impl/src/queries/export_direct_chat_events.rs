@@ -0,0 +1,30 @@
+use crate::guards::caller_is_owner;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use types::{ChatArchiveEventsPage, CHAT_ARCHIVE_FORMAT_VERSION};
+use user_canister::export_direct_chat_events::{Response::*, *};
+
+const MAX_EVENTS_PER_PAGE: u32 = 1000;
+
+#[query(guard = "caller_is_owner", msgpack = true)]
+fn export_direct_chat_events(args: Args) -> Response {
+    read_state(|state| export_direct_chat_events_impl(args, state))
+}
+
+fn export_direct_chat_events_impl(args: Args, state: &RuntimeState) -> Response {
+    let Some(direct_chat) = state.data.direct_chats.get(&args.user_id.into()) else {
+        return ChatNotFound;
+    };
+
+    let my_user_id = state.env.canister_id().into();
+    let max_events = args.max_events.clamp(1, MAX_EVENTS_PER_PAGE);
+    let events = direct_chat
+        .events
+        .export_events(args.after.map(|c| c.into()), max_events as usize, Some(my_user_id));
+
+    Success(ChatArchiveEventsPage {
+        format_version: CHAT_ARCHIVE_FORMAT_VERSION,
+        finished: events.len() < max_events as usize,
+        events,
+    })
+}
@@ -1,11 +1,11 @@
-use crate::guards::caller_is_owner;
+use crate::guards::caller_is_owner_or_read_only_principal;
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use types::{InstalledBotDetails, UserId};
 use user_canister::initial_state::{Response::*, *};
 use utils::time::{today, tomorrow};
 
-#[query(guard = "caller_is_owner", msgpack = true)]
+#[query(guard = "caller_is_owner_or_read_only_principal", msgpack = true)]
 fn initial_state(_args: Args) -> Response {
     read_state(initial_state_impl)
 }
@@ -1,9 +1,9 @@
 use crate::read_state;
-use crate::{guards::caller_is_owner, RuntimeState};
+use crate::{guards::caller_is_owner_or_read_only_principal, RuntimeState};
 use canister_api_macros::query;
 use user_canister::message_activity_feed::{Response::*, *};
 
-#[query(guard = "caller_is_owner", msgpack = true)]
+#[query(guard = "caller_is_owner_or_read_only_principal", msgpack = true)]
 fn message_activity_feed(args: Args) -> Response {
     read_state(|state| message_activity_feed_impl(args, state))
 }
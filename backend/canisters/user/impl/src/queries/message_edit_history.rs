@@ -0,0 +1,37 @@
+use crate::guards::caller_is_owner_or_read_only_principal;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use chat_events::Reader;
+use types::EventIndex;
+use user_canister::message_edit_history::{Response::*, *};
+
+#[query(guard = "caller_is_owner_or_read_only_principal", msgpack = true)]
+fn message_edit_history(args: Args) -> Response {
+    read_state(|state| message_edit_history_impl(args, state))
+}
+
+fn message_edit_history_impl(args: Args, state: &RuntimeState) -> Response {
+    let my_user_id = state.env.canister_id().into();
+
+    let Some(chat) = state.data.direct_chats.get(&args.user_id.into()) else {
+        return ChatNotFound;
+    };
+
+    let Some(events_reader) = chat
+        .events
+        .events_reader(EventIndex::default(), args.thread_root_message_index, None)
+    else {
+        return ThreadNotFound;
+    };
+
+    match events_reader.message_internal(args.message_id.into()) {
+        Some(message) if message.deleted_by.is_none() => Success(SuccessResult {
+            versions: message
+                .edit_history
+                .into_iter()
+                .map(|v| v.hydrate(Some(my_user_id)))
+                .collect(),
+        }),
+        _ => MessageNotFound,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::guards::caller_is_owner;
+use crate::guards::caller_is_owner_or_read_only_principal;
 use crate::queries::check_replica_up_to_date;
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
@@ -6,7 +6,7 @@ use chat_events::Reader;
 use types::{EventIndex, MessagesResponse};
 use user_canister::messages_by_message_index::{Response::*, *};
 
-#[query(guard = "caller_is_owner", msgpack = true)]
+#[query(guard = "caller_is_owner_or_read_only_principal", msgpack = true)]
 fn messages_by_message_index(args: Args) -> Response {
     read_state(|state| messages_by_message_index_impl(args, state))
 }
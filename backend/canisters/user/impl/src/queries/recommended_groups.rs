@@ -0,0 +1,33 @@
+use crate::guards::caller_is_owner;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use types::CanisterId;
+use user_canister::recommended_groups::{Response::*, *};
+
+// Recommends groups based on the user's existing groups and communities, excluding those the user is
+// already in, has left or has dismissed
+#[query(composite = true, guard = "caller_is_owner", msgpack = true)]
+async fn recommended_groups(args: Args) -> Response {
+    let (group_index_canister_id, c2c_args) = read_state(|state| prepare(args, state));
+
+    match group_index_canister_c2c_client::recommended_groups(group_index_canister_id, &c2c_args).await {
+        Ok(group_index_canister::recommended_groups::Response::Success(result)) => {
+            Success(SuccessResult { groups: result.groups })
+        }
+        Err(error) => InternalError(format!("Failed to call 'group_index::recommended_groups': {error:?}")),
+    }
+}
+
+fn prepare(args: Args, state: &RuntimeState) -> (CanisterId, group_index_canister::recommended_groups::Args) {
+    let now = state.env.now();
+
+    let c2c_args = group_index_canister::recommended_groups::Args {
+        count: args.count,
+        exclusions: state.data.hot_group_exclusions.get_all(now).copied().collect(),
+        memberships: Some(state.data.group_chats.iter().map(|g| g.chat_id).collect()),
+        community_memberships: Some(state.data.communities.iter().map(|c| c.community_id).collect()),
+        languages: args.languages,
+    };
+
+    (state.data.group_index_canister_id, c2c_args)
+}
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::guards::caller_is_owner;
+use crate::guards::caller_is_owner_or_read_only_principal;
 use crate::{read_state, RuntimeState};
 use canister_api_macros::query;
 use installed_bots::BotUpdate;
@@ -8,7 +8,7 @@ use types::{InstalledBotDetails, OptionUpdate, TimestampMillis, UserId};
 use user_canister::updates::{Response::*, *};
 use utils::time::{today, tomorrow};
 
-#[query(guard = "caller_is_owner", msgpack = true)]
+#[query(guard = "caller_is_owner_or_read_only_principal", msgpack = true)]
 fn updates(args: Args) -> Response {
     read_state(|state| updates_impl(args.updates_since, state))
 }
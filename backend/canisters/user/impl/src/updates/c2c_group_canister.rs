@@ -44,6 +44,11 @@ fn c2c_notify_group_canister_events_impl(args: Args, state: &mut RuntimeState) -
                 GroupCanisterEvent::Achievement(achievement) => {
                     awarded_achievement |= state.data.award_achievement(achievement, now);
                 }
+                GroupCanisterEvent::ReadReceiptsChanged(enabled) => {
+                    if let Some(group_chat) = state.data.group_chats.get_mut(&caller.into()) {
+                        group_chat.read_receipts_enabled = enabled;
+                    }
+                }
             }
         }
     }
@@ -2,7 +2,9 @@ use crate::guards::caller_is_local_user_index;
 use crate::{mutate_state, openchat_bot, RuntimeState};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
-use types::{Achievement, DiamondMembershipPlanDuration, MessageContentInitial, ReferralStatus, Timestamped};
+use types::{
+    Achievement, DiamondMembershipPlanDuration, MessageContentInitial, ReferralStatus, ScopedPrincipalUpdate, Timestamped,
+};
 use user_canister::c2c_local_user_index::{Response::*, *};
 use user_canister::mark_read::ChannelMessagesRead;
 use user_canister::{LocalUserIndexEvent, UserCanisterEvent};
@@ -81,13 +83,26 @@ fn process_event(event: LocalUserIndexEvent, state: &mut RuntimeState) {
                 .removed_since(ev.group_canister_timestamp)
                 .contains(&ev.chat_id)
             {
-                state
-                    .data
-                    .group_chats
-                    .join(ev.chat_id, ev.local_user_index_canister_id, ev.latest_message_index, now);
+                state.data.group_chats.join(
+                    ev.chat_id,
+                    ev.local_user_index_canister_id,
+                    ev.latest_message_index,
+                    ev.read_receipts_enabled,
+                    now,
+                );
 
                 state.data.hot_group_exclusions.remove(&ev.chat_id, now);
                 state.award_achievement_and_notify(Achievement::JoinedGroup, now);
+
+                let blocked_users: Vec<_> = state.data.blocked_users.value.iter().copied().collect();
+                if !blocked_users.is_empty() {
+                    state.sync_blocked_users_to_group(ev.chat_id, blocked_users, Vec::new());
+                }
+
+                let scoped_principals = state.data.scoped_principals.current(now);
+                if !scoped_principals.is_empty() {
+                    state.sync_scoped_principals_to_group(ev.chat_id, scoped_principals);
+                }
             }
         }
         LocalUserIndexEvent::UserJoinedCommunityOrChannel(ev) => {
@@ -116,6 +131,16 @@ fn process_event(event: LocalUserIndexEvent, state: &mut RuntimeState) {
                     now,
                 );
                 state.award_achievement_and_notify(Achievement::JoinedCommunity, now);
+
+                let blocked_users: Vec<_> = state.data.blocked_users.value.iter().copied().collect();
+                if !blocked_users.is_empty() {
+                    state.sync_blocked_users_to_community(ev.community_id, blocked_users, Vec::new());
+                }
+
+                let scoped_principals = state.data.scoped_principals.current(now);
+                if !scoped_principals.is_empty() {
+                    state.sync_scoped_principals_to_community(ev.community_id, scoped_principals);
+                }
             }
         }
         LocalUserIndexEvent::DiamondMembershipPaymentReceived(ev) => {
@@ -163,5 +188,13 @@ fn process_event(event: LocalUserIndexEvent, state: &mut RuntimeState) {
         LocalUserIndexEvent::ExternalAchievementAwarded(ev) => {
             state.award_external_achievement(ev.name, ev.chit_reward, now);
         }
+        LocalUserIndexEvent::ScopedPrincipalUpdated(ev) => {
+            state.data.scoped_principals.update(ev.principal, ev.scope, ev.expires, now);
+            state.sync_scoped_principals_to_all_chats(vec![ScopedPrincipalUpdate {
+                principal: ev.principal,
+                scope: ev.scope,
+                expires: ev.expires,
+            }]);
+        }
     }
 }
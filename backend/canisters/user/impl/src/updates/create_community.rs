@@ -94,7 +94,7 @@ fn prepare(args: Args, state: &RuntimeState) -> Result<PrepareResult, Response>
     } else if args
         .gate_config
         .as_ref()
-        .map(|g| !g.validate(state.data.test_mode))
+        .map(|g| !g.validate(state.data.test_mode) || g.gate.requires_community_membership())
         .unwrap_or_default()
     {
         Err(AccessGateInvalid)
@@ -3,6 +3,7 @@ use crate::{mutate_state, run_regular_jobs, RuntimeState};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use constants::OPENCHAT_BOT_USER_ID;
+use msgpack::serialize_then_unwrap;
 use user_canister::mark_read::{Response::*, *};
 use user_canister::{MarkMessagesReadArgs, UserCanisterEvent};
 
@@ -19,12 +20,26 @@ fn mark_read_impl(args: Args, state: &mut RuntimeState) -> Response {
 
     for chat_messages_read in args.messages_read {
         if let Some(group_chat) = state.data.group_chats.get_mut(&chat_messages_read.chat_id) {
+            let previous_read_up_to = group_chat.messages_read.read_by_me_up_to.value;
             group_chat.mark_read(
                 chat_messages_read.read_up_to,
                 chat_messages_read.threads,
                 chat_messages_read.date_read_pinned,
                 now,
             );
+
+            // Let the group know how far we have read so that it can be shown to other members, but only
+            // if the group has read receipts enabled, otherwise it would just ignore the call
+            if let Some(read_up_to) = chat_messages_read
+                .read_up_to
+                .filter(|i| group_chat.read_receipts_enabled && Some(*i) > previous_read_up_to)
+            {
+                state.data.fire_and_forget_handler.send(
+                    chat_messages_read.chat_id.into(),
+                    "c2c_mark_read_up_to_msgpack".to_string(),
+                    serialize_then_unwrap(group_canister::c2c_mark_read_up_to::Args { read_up_to }),
+                );
+            }
         } else if let Some(direct_chat) = state.data.direct_chats.get_mut(&chat_messages_read.chat_id) {
             if let Some(read_up_to) = chat_messages_read.read_up_to {
                 if read_up_to
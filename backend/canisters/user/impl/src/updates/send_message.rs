@@ -1,6 +1,6 @@
 use crate::crypto::process_transaction_without_caller_check;
 use crate::guards::caller_is_local_user_index;
-use crate::guards::caller_is_owner;
+use crate::guards::caller_is_owner_or_send_messages_principal;
 use crate::model::pin_number::VerifyPinError;
 use crate::timer_job_types::{DeleteFileReferencesJob, MarkP2PSwapExpiredJob, NotifyEscrowCanisterOfDepositJob};
 use crate::updates::send_message_with_transfer::set_up_p2p_swap;
@@ -31,7 +31,7 @@ use user_canister::{C2CReplyContext, SendMessageArgs, SendMessagesArgs, UserCani
 use super::c2c_send_messages::handle_message_impl;
 use super::c2c_send_messages::HandleMessageArgs;
 
-#[update(guard = "caller_is_owner", msgpack = true)]
+#[update(guard = "caller_is_owner_or_send_messages_principal", msgpack = true)]
 #[trace]
 async fn send_message_v2(args: Args) -> Response {
     run_regular_jobs();
@@ -379,6 +379,12 @@ fn prepare(args: &Args, is_v2_bot: bool, state: &RuntimeState) -> Result<Prepare
         )));
     }
 
+    if !is_v2_bot && args.content.contains_crypto_transfer() && !state.is_caller_owner() {
+        return Err(Box::new(InvalidRequest(
+            "Only the owner can send messages containing crypto transfers".to_string(),
+        )));
+    }
+
     let my_user_id = state.env.canister_id().into();
     let maybe_recipient_type = if let Some(chat) = state.data.direct_chats.get(&args.recipient.into()) {
         if chat
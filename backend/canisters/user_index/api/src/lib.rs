@@ -49,6 +49,8 @@ pub struct UserJoinedGroup {
     pub local_user_index_canister_id: CanisterId,
     pub latest_message_index: Option<MessageIndex>,
     pub group_canister_timestamp: TimestampMillis,
+    #[serde(default)]
+    pub read_receipts_enabled: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
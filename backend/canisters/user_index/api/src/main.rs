@@ -17,9 +17,11 @@ fn main() {
     generate_candid_method!(user_index, referral_metrics, query);
     generate_candid_method!(user_index, search, query);
     generate_candid_method!(user_index, user, query);
+    generate_candid_method!(user_index, user_directory, query);
     generate_candid_method!(user_index, user_registration_canister, query);
     generate_candid_method!(user_index, users, query);
     generate_candid_method!(user_index, users_chit, query);
+    generate_candid_method!(user_index, username_history, query);
 
     generate_candid_method!(user_index, award_external_achievement, update);
     generate_candid_method!(user_index, register_external_achievement, update);
@@ -49,17 +51,22 @@ fn main() {
     generate_ts_method!(user_index, public_key);
     generate_ts_method!(user_index, referral_metrics);
     generate_ts_method!(user_index, reported_messages);
+    generate_ts_method!(user_index, reserved_usernames);
     generate_ts_method!(user_index, search);
     generate_ts_method!(user_index, suspected_bots);
     generate_ts_method!(user_index, user);
+    generate_ts_method!(user_index, user_directory);
     generate_ts_method!(user_index, user_registration_canister);
     generate_ts_method!(user_index, users);
     generate_ts_method!(user_index, users_chit);
+    generate_ts_method!(user_index, username_history);
     generate_ts_method!(user_index, update_diamond_membership_subscription);
 
+    generate_ts_method!(user_index, add_reserved_usernames);
     generate_ts_method!(user_index, pay_for_diamond_membership);
     generate_ts_method!(user_index, register_bot);
     generate_ts_method!(user_index, remove_bot);
+    generate_ts_method!(user_index, remove_reserved_usernames);
     generate_ts_method!(user_index, set_diamond_membership_fees);
     generate_ts_method!(user_index, set_display_name);
     generate_ts_method!(user_index, set_user_upgrade_concurrency);
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use types::{ChitBalances, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub users: Vec<UserId>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub balances: Vec<ChitBalances>,
+}
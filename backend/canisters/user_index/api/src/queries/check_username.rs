@@ -17,4 +17,6 @@ pub enum Response {
     UsernameInvalid,
     UsernameTooShort(u16),
     UsernameTooLong(u16),
+    UsernameReserved,
+    UsernameTooSimilar,
 }
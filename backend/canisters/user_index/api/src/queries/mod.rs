@@ -1,4 +1,5 @@
 pub mod bot_updates;
+pub mod c2c_chit_balances;
 pub mod c2c_lookup_user;
 pub mod check_username;
 pub mod chit_leaderboard;
@@ -12,9 +13,12 @@ pub mod platform_operators;
 pub mod public_key;
 pub mod referral_metrics;
 pub mod reported_messages;
+pub mod reserved_usernames;
 pub mod search;
 pub mod suspected_bots;
 pub mod user;
+pub mod user_directory;
 pub mod user_registration_canister;
+pub mod username_history;
 pub mod users;
 pub mod users_chit;
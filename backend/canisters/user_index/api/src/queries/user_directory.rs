@@ -0,0 +1,48 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{TimestampMillis, UserId, UserSummary};
+
+#[ts_export(user_index, user_directory)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub filter: UserDirectoryFilter,
+    pub after: Option<UserDirectoryCursor>,
+    pub page_size: u16,
+}
+
+#[ts_export(user_index, user_directory)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Default)]
+pub struct UserDirectoryFilter {
+    pub bots: Option<bool>,
+    pub diamond: Option<bool>,
+    pub unique_person: Option<bool>,
+    pub registered_from: Option<TimestampMillis>,
+    pub registered_to: Option<TimestampMillis>,
+    pub min_chit: Option<i32>,
+    pub max_chit: Option<i32>,
+}
+
+#[ts_export(user_index, user_directory)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UserDirectoryCursor {
+    pub registered: TimestampMillis,
+    pub user_id: UserId,
+    // Only set when the users are being paged through in order of total CHIT
+    pub total_chit: Option<i32>,
+}
+
+#[ts_export(user_index, user_directory)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+}
+
+#[ts_export(user_index, user_directory)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub users: Vec<UserSummary>,
+    // If set, there may be further matches, which can be retrieved by passing this in as `after`
+    pub next: Option<UserDirectoryCursor>,
+    pub timestamp: TimestampMillis,
+}
@@ -0,0 +1,30 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{TimestampMillis, UserId};
+
+#[ts_export(user_index, username_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+}
+
+#[ts_export(user_index, username_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotFound,
+}
+
+#[ts_export(user_index, username_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub usernames: Vec<PreviousUsername>,
+}
+
+#[ts_export(user_index, username_history)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct PreviousUsername {
+    pub username: String,
+    pub replaced: TimestampMillis,
+}
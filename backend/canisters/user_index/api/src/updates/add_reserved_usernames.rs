@@ -0,0 +1,15 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export(user_index, add_reserved_usernames)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub usernames: Vec<String>,
+}
+
+#[ts_export(user_index, add_reserved_usernames)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+}
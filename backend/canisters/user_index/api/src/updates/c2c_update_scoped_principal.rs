@@ -0,0 +1,17 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use types::{DelegationScope, TimestampMillis, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub principal: Principal,
+    // None if the scoped principal has been revoked
+    pub scope: Option<DelegationScope>,
+    pub expires: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+}
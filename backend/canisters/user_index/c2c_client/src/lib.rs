@@ -1,9 +1,11 @@
 use candid::Principal;
 use canister_client::generate_c2c_call;
-use types::{CanisterId, UserDetails};
+use types::{CanisterId, Chit, TimestampMillis, UserDetails, UserId};
 use user_index_canister::*;
+use utils::time::MonthKey;
 
 // Queries
+generate_c2c_call!(c2c_chit_balances);
 generate_c2c_call!(c2c_lookup_user);
 generate_c2c_call!(platform_moderators_group);
 generate_c2c_call!(user);
@@ -37,3 +39,18 @@ pub async fn lookup_user(
         Err(error) => Err(LookupUserError::InternalError(format!("{error:?}"))),
     }
 }
+
+// Looks up the user's CHIT balance for the current month along with their current streak
+pub async fn lookup_chit(user_id: UserId, user_index_canister_id: CanisterId, now: TimestampMillis) -> Result<Chit, String> {
+    let month_key = MonthKey::from_timestamp(now);
+    let args = users_chit::Args {
+        users: vec![user_id],
+        year: month_key.year() as u16,
+        month: month_key.month(),
+    };
+
+    match crate::users_chit(user_index_canister_id, &args).await {
+        Ok(users_chit::Response::Success(result)) => Ok(result.chit.into_iter().next().unwrap_or_default()),
+        Err(error) => Err(format!("{error:?}")),
+    }
+}
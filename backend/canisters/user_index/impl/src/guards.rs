@@ -72,11 +72,19 @@ pub fn caller_is_user_canister_or_group_index() -> Result<(), String> {
     }
 }
 
-pub fn caller_is_translations_canister() -> Result<(), String> {
-    if read_state(|state| state.is_caller_translations_canister()) {
+pub fn caller_is_identity_canister() -> Result<(), String> {
+    if read_state(|state| state.is_caller_identity_canister()) {
         Ok(())
     } else {
-        Err("Caller is not the translations canister".to_string())
+        Err("Caller is not the identity canister".to_string())
+    }
+}
+
+pub fn caller_is_translations_or_identity_canister() -> Result<(), String> {
+    if read_state(|state| state.is_caller_translations_canister() || state.is_caller_identity_canister()) {
+        Ok(())
+    } else {
+        Err("Caller is not the translations canister or the identity canister".to_string())
     }
 }
 
@@ -26,7 +26,9 @@ pub fn run() {
     TIMER_ID.set(None);
 
     mutate_state(|state| {
-        state.data.chit_leaderboard.switch_months(state.env.now());
+        let now = state.env.now();
+        state.data.chit_leaderboard.switch_months(now);
+        state.data.recent_chit.prune(now);
         start_job_if_required(state);
     });
 }
@@ -19,8 +19,11 @@ use model::external_achievements::{ExternalAchievementMetrics, ExternalAchieveme
 use model::local_user_index_map::LocalUserIndexMap;
 use model::pending_modclub_submissions_queue::{PendingModclubSubmission, PendingModclubSubmissionsQueue};
 use model::pending_payments_queue::{PendingPayment, PendingPaymentsQueue};
+use model::recent_chit::RecentChit;
 use model::reported_messages::{ReportedMessages, ReportingMetrics};
+use model::reserved_usernames::ReservedUsernames;
 use model::user::SuspensionDetails;
+use model::username_history::UsernameHistory;
 use p256_key_pair::P256KeyPair;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
@@ -37,6 +40,7 @@ use utils::canister::{CanistersRequiringUpgrade, FailedUpgradeCount};
 use utils::canister_event_sync_queue::CanisterEventSyncQueue;
 use utils::env::Environment;
 use utils::idempotency_checker::IdempotencyChecker;
+use utils::text_validation::username_skeleton;
 use utils::time::MonthKey;
 
 mod guards;
@@ -101,6 +105,11 @@ impl RuntimeState {
         caller == self.data.translations_canister_id
     }
 
+    pub fn is_caller_identity_canister(&self) -> bool {
+        let caller = self.env.caller();
+        caller == self.data.identity_canister_id
+    }
+
     pub fn is_caller_platform_moderator(&self) -> bool {
         let caller = self.env.caller();
         if let Some(user) = self.data.users.get_by_principal(&caller) {
@@ -180,6 +189,7 @@ impl RuntimeState {
     pub fn delete_user(&mut self, user_id: UserId, triggered_by_user: bool) {
         let now = self.env.now();
         if let Some(user) = self.data.users.delete_user(user_id, now) {
+            self.data.username_history.record_deletion(user_id, &user.username, now);
             self.data.local_index_map.remove_user(&user_id);
             self.data.empty_users.remove(&user_id);
 
@@ -406,6 +416,12 @@ struct Data {
     pub streak_insurance_logs: StreakInsuranceLogs,
     #[serde(default)]
     pub idempotency_checker: IdempotencyChecker,
+    #[serde(default)]
+    pub username_history: UsernameHistory,
+    #[serde(default)]
+    pub reserved_usernames: ReservedUsernames,
+    #[serde(default)]
+    pub recent_chit: RecentChit,
 }
 
 impl Data {
@@ -488,6 +504,9 @@ impl Data {
             upload_wasm_chunks_whitelist: Vec::new(),
             streak_insurance_logs: StreakInsuranceLogs::default(),
             idempotency_checker: IdempotencyChecker::default(),
+            username_history: UsernameHistory::default(),
+            reserved_usernames: ReservedUsernames::default(),
+            recent_chit: RecentChit::default(),
         };
 
         // Register the ProposalsBot
@@ -543,6 +562,69 @@ impl Data {
 
         bands
     }
+
+    // Checks whether `username` can be taken by `user_id`, or by a new user if `user_id` is None
+    pub fn check_username_available(
+        &self,
+        username: &str,
+        user_id: Option<UserId>,
+        now: TimestampMillis,
+    ) -> Result<(), UsernameUnavailableReason> {
+        let current_username = user_id
+            .and_then(|u| self.users.get_by_user_id(&u))
+            .map(|u| u.username.as_str());
+
+        // Users may always switch to a variant of their current username
+        if current_username.is_some_and(|u| username_skeleton(u) == username_skeleton(username)) {
+            return Ok(());
+        }
+
+        if self.users.get_by_username(username).is_some() {
+            Err(UsernameUnavailableReason::Taken)
+        } else if self.reserved_usernames.contains(username) || self.username_history.is_cooling_off(username, user_id, now) {
+            Err(UsernameUnavailableReason::Reserved)
+        } else if self.users.is_username_confusable(username, user_id) {
+            Err(UsernameUnavailableReason::TooSimilar)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Bot names only need to be unique amongst bots, but are subject to the same reserved and confusable checks as
+    // usernames so that bots can't be used to impersonate users
+    pub fn check_bot_name_available(&self, name: &str, now: TimestampMillis) -> Result<(), UsernameUnavailableReason> {
+        if self.users.does_username_exist(name, true) {
+            Err(UsernameUnavailableReason::Taken)
+        } else if self.reserved_usernames.contains(name) || self.username_history.is_cooling_off(name, None, now) {
+            Err(UsernameUnavailableReason::Reserved)
+        } else if self.users.is_username_confusable(name, None) {
+            Err(UsernameUnavailableReason::TooSimilar)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn ensure_username_available(&self, username: &str, now: TimestampMillis) -> Result<(), String> {
+        if self.check_username_available(username, None, now).is_ok() {
+            return Ok(());
+        }
+
+        // Append the lowest number (starting from 2) which will make this username available
+        let mut suffix = 2;
+        loop {
+            let u = format!("{username}{suffix}");
+            if self.check_username_available(&u, None, now).is_ok() {
+                return Err(u);
+            }
+            suffix += 1;
+        }
+    }
+}
+
+pub enum UsernameUnavailableReason {
+    Taken,
+    Reserved,
+    TooSimilar,
 }
 
 #[cfg(test)]
@@ -602,6 +684,9 @@ impl Default for Data {
             upload_wasm_chunks_whitelist: Vec::new(),
             streak_insurance_logs: StreakInsuranceLogs::default(),
             idempotency_checker: IdempotencyChecker::default(),
+            username_history: UsernameHistory::default(),
+            reserved_usernames: ReservedUsernames::default(),
+            recent_chit: RecentChit::default(),
         }
     }
 }
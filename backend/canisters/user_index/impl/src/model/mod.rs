@@ -6,12 +6,16 @@ pub mod local_user_index_map;
 pub mod notifications_index_event_batch;
 pub mod pending_modclub_submissions_queue;
 pub mod pending_payments_queue;
+pub mod recent_chit;
 pub mod reported_messages;
+pub mod reserved_usernames;
 pub mod storage_index_user_config_batch;
 pub mod storage_index_users_to_remove_batch;
 pub mod streak_insurance_logs;
 pub mod user;
+pub mod user_directory;
 pub mod user_map;
+pub mod username_history;
 
 pub const MAX_AVATAR_SIZE: usize = 250_000;
 pub const MAX_DESCRIPTION_LEN: usize = 10_000;
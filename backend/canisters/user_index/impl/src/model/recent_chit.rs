@@ -0,0 +1,68 @@
+use constants::DAY_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use types::{TimestampMillis, UserId};
+
+const DAYS_RETAINED: u64 = 7;
+
+// The CHIT earned by each user per day over the last week, so that weekly leaderboards can be calculated
+#[derive(Serialize, Deserialize, Default)]
+pub struct RecentChit {
+    users: HashMap<UserId, BTreeMap<u64, i32>>,
+}
+
+impl RecentChit {
+    pub fn record(&mut self, user_id: UserId, amount: i32, timestamp: TimestampMillis, now: TimestampMillis) {
+        let day = timestamp / DAY_IN_MS;
+        if amount == 0 || day < first_day_retained(now) {
+            return;
+        }
+
+        let days = self.users.entry(user_id).or_default();
+        *days.entry(day).or_default() += amount;
+        days.retain(|d, _| *d >= first_day_retained(now));
+    }
+
+    pub fn last_week(&self, user_id: &UserId, now: TimestampMillis) -> i32 {
+        self.users
+            .get(user_id)
+            .map(|days| days.range(first_day_retained(now)..).map(|(_, amount)| *amount).sum())
+            .unwrap_or_default()
+    }
+
+    pub fn prune(&mut self, now: TimestampMillis) {
+        let first_day = first_day_retained(now);
+        self.users.retain(|_, days| {
+            days.retain(|d, _| *d >= first_day);
+            !days.is_empty()
+        });
+    }
+}
+
+fn first_day_retained(now: TimestampMillis) -> u64 {
+    (now / DAY_IN_MS).saturating_sub(DAYS_RETAINED - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn only_last_seven_days_included() {
+        let mut recent_chit = RecentChit::default();
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+        let now = 100 * DAY_IN_MS;
+
+        recent_chit.record(user_id, 100, now - 7 * DAY_IN_MS, now);
+        recent_chit.record(user_id, 200, now - 6 * DAY_IN_MS, now);
+        recent_chit.record(user_id, 300, now, now);
+
+        assert_eq!(recent_chit.last_week(&user_id, now), 500);
+        assert_eq!(recent_chit.last_week(&user_id, now + DAY_IN_MS), 300);
+
+        recent_chit.prune(now + 7 * DAY_IN_MS);
+        assert_eq!(recent_chit.last_week(&user_id, now + 7 * DAY_IN_MS), 0);
+        assert!(recent_chit.users.is_empty());
+    }
+}
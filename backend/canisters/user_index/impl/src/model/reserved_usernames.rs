@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utils::text_validation::username_skeleton;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ReservedUsernames {
+    usernames: BTreeMap<String, String>, // Keyed by the skeleton of the reserved username
+}
+
+impl ReservedUsernames {
+    pub fn add(&mut self, username: String) -> bool {
+        self.usernames.insert(username_skeleton(&username), username).is_none()
+    }
+
+    pub fn remove(&mut self, username: &str) -> bool {
+        self.usernames.remove(&username_skeleton(username)).is_some()
+    }
+
+    pub fn contains(&self, username: &str) -> bool {
+        self.usernames.contains_key(&username_skeleton(username))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.usernames.values()
+    }
+}
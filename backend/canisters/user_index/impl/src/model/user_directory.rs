@@ -0,0 +1,139 @@
+use crate::model::user::User;
+use candid::Principal;
+use std::collections::BTreeSet;
+use std::ops::Bound::{Excluded, Included, Unbounded};
+use types::{TimestampMillis, UserId};
+use user_index_canister::user_directory::{UserDirectoryCursor, UserDirectoryFilter};
+
+type Key = (TimestampMillis, UserId);
+
+// Secondary indexes over the users, each ordered by registration date so that any of them can be used to
+// serve a page of the user directory
+#[derive(Default)]
+pub struct UserDirectory {
+    all: BTreeSet<Key>,
+    bots: BTreeSet<Key>,
+    unique_persons: BTreeSet<Key>,
+    // Users who have ever been Diamond members, whether or not their membership is still active
+    diamond_members: BTreeSet<Key>,
+    by_total_chit: BTreeSet<(i32, Key)>,
+}
+
+impl UserDirectory {
+    pub fn add(&mut self, user: &User) {
+        let key = key(user);
+        self.all.insert(key);
+        if user.user_type.is_bot() {
+            self.bots.insert(key);
+        }
+        if user.unique_person_proof.is_some() {
+            self.unique_persons.insert(key);
+        }
+        if !user.diamond_membership_details.has_never_been_diamond_member() {
+            self.diamond_members.insert(key);
+        }
+        self.by_total_chit.insert((user.total_chit_earned(), key));
+    }
+
+    pub fn remove(&mut self, user: &User) {
+        let key = key(user);
+        self.all.remove(&key);
+        self.bots.remove(&key);
+        self.unique_persons.remove(&key);
+        self.diamond_members.remove(&key);
+        self.by_total_chit.remove(&(user.total_chit_earned(), key));
+    }
+
+    pub fn mark_unique_person(&mut self, user: &User) {
+        self.unique_persons.insert(key(user));
+    }
+
+    pub fn mark_diamond_member(&mut self, user: &User) {
+        self.diamond_members.insert(key(user));
+    }
+
+    pub fn update_total_chit(&mut self, user: &User, previous_total_chit: i32) {
+        let key = key(user);
+        self.by_total_chit.remove(&(previous_total_chit, key));
+        self.by_total_chit.insert((user.total_chit_earned(), key));
+    }
+
+    // Returns the users which may match the filter, starting after `after`. If filtering by CHIT and none of the
+    // other indexes apply, users are returned in order of total CHIT, otherwise in order of registration date.
+    // Picks whichever index narrows down the candidates the most, callers must still check each user against
+    // the filter.
+    pub fn candidates<'a>(
+        &'a self,
+        filter: &UserDirectoryFilter,
+        after: Option<UserDirectoryCursor>,
+    ) -> Box<dyn Iterator<Item = UserDirectoryCursor> + 'a> {
+        let min_user_id: UserId = Principal::from_slice(&[]).into();
+        let registered_to = filter.registered_to.unwrap_or(TimestampMillis::MAX);
+
+        let narrowest_index = [
+            (filter.bots == Some(true), &self.bots),
+            (filter.unique_person == Some(true), &self.unique_persons),
+            (filter.diamond == Some(true), &self.diamond_members),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, index)| index)
+        .min_by_key(|index| index.len());
+
+        if narrowest_index.is_none() && (filter.min_chit.is_some() || filter.max_chit.is_some()) {
+            let max_chit = filter.max_chit.unwrap_or(i32::MAX);
+            let lower = match after.and_then(|c| c.total_chit.map(|chit| (chit, (c.registered, c.user_id)))) {
+                Some(start) => Excluded(start),
+                None => Included((filter.min_chit.unwrap_or(i32::MIN), (0, min_user_id))),
+            };
+
+            // The registration date bounds are left to `matches_filter` so that every user skipped over counts
+            // towards the caller's scan limit
+            return Box::new(
+                self.by_total_chit
+                    .range((lower, Unbounded))
+                    .take_while(move |(chit, _)| *chit <= max_chit)
+                    .map(|(chit, (registered, user_id))| UserDirectoryCursor {
+                        registered: *registered,
+                        user_id: *user_id,
+                        total_chit: Some(*chit),
+                    }),
+            );
+        }
+
+        let lower = match after {
+            Some(cursor) => Excluded((cursor.registered, cursor.user_id)),
+            None => Included((filter.registered_from.unwrap_or_default(), min_user_id)),
+        };
+
+        Box::new(
+            narrowest_index
+                .unwrap_or(&self.all)
+                .range((lower, Unbounded))
+                .take_while(move |(registered, _)| *registered <= registered_to)
+                .map(|(registered, user_id)| UserDirectoryCursor {
+                    registered: *registered,
+                    user_id: *user_id,
+                    total_chit: None,
+                }),
+        )
+    }
+}
+
+pub fn matches_filter(user: &User, filter: &UserDirectoryFilter, now: TimestampMillis) -> bool {
+    let total_chit = user.total_chit_earned();
+
+    filter.bots.is_none_or(|b| user.user_type.is_bot() == b)
+        && filter
+            .diamond
+            .is_none_or(|d| user.diamond_membership_details.is_active(now) == d)
+        && filter.unique_person.is_none_or(|u| user.unique_person_proof.is_some() == u)
+        && filter.registered_from.is_none_or(|from| user.date_created >= from)
+        && filter.registered_to.is_none_or(|to| user.date_created <= to)
+        && filter.min_chit.is_none_or(|min| total_chit >= min)
+        && filter.max_chit.is_none_or(|max| total_chit <= max)
+}
+
+fn key(user: &User) -> Key {
+    (user.date_created, user.user_id)
+}
@@ -1,6 +1,7 @@
 use super::user::SuspensionDetails;
 use crate::model::diamond_membership_details::DiamondMembershipDetailsInternal;
 use crate::model::user::User;
+use crate::model::user_directory::{matches_filter, UserDirectory};
 use crate::DiamondMembershipUserMetrics;
 use candid::Principal;
 use search::weighted::{Document as SearchDocument, Query};
@@ -13,7 +14,9 @@ use types::{
     Document, Milliseconds, SuspensionDuration, TimestampMillis, UniquePersonProof, UserId, UserType,
 };
 use user_index_canister::bot_updates::BotDetails;
+use user_index_canister::user_directory::{UserDirectoryCursor, UserDirectoryFilter};
 use utils::case_insensitive_hash_map::CaseInsensitiveHashMap;
+use utils::text_validation::username_skeleton;
 use utils::time::MonthKey;
 
 #[derive(Serialize, Deserialize, Default)]
@@ -32,6 +35,10 @@ pub struct UserMap {
     #[serde(skip)]
     botname_to_user_id: CaseInsensitiveHashMap<UserId>,
     #[serde(skip)]
+    username_skeletons: HashMap<String, Vec<UserId>>,
+    #[serde(skip)]
+    directory: UserDirectory,
+    #[serde(skip)]
     principal_to_user_id: HashMap<Principal, UserId>,
     #[serde(skip)]
     user_referrals: HashMap<UserId, Vec<UserId>>,
@@ -128,20 +135,11 @@ impl UserMap {
         map.contains_key(username)
     }
 
-    pub fn ensure_unique_username(&self, username: &str, is_bot: bool) -> Result<(), String> {
-        if !self.does_username_exist(username, is_bot) {
-            return Ok(());
-        }
-
-        // Append the lowest number (starting from 2) which will make this username unique
-        let mut suffix = 2;
-        loop {
-            let u = format!("{username}{suffix}");
-            if !self.username_to_user_id.contains_key(&u) {
-                return Err(u);
-            }
-            suffix += 1;
-        }
+    // Returns true if the username is visually confusable with that of any user other than `user_id`
+    pub fn is_username_confusable(&self, username: &str, user_id: Option<UserId>) -> bool {
+        self.username_skeletons
+            .get(&username_skeleton(username))
+            .is_some_and(|user_ids| user_ids.iter().any(|u| Some(*u) != user_id))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -161,6 +159,7 @@ impl UserMap {
         } else {
             self.username_to_user_id.insert(&username, bot_id);
         }
+        add_username_skeleton(&mut self.username_skeletons, &username, bot_id);
 
         let avatar_id = bot.as_ref().and_then(|b| b.avatar.as_ref().map(|a| a.id));
 
@@ -177,6 +176,7 @@ impl UserMap {
             avatar_id,
         );
 
+        self.directory.add(&user);
         self.users.insert(bot_id, user);
 
         if let Some(bot) = bot {
@@ -237,12 +237,16 @@ impl UserMap {
                     self.username_to_user_id.remove(previous_username);
                     self.username_to_user_id.insert(username, user_id);
                 }
+                remove_username_skeleton(&mut self.username_skeletons, previous_username, user_id);
+                add_username_skeleton(&mut self.username_skeletons, username, user_id);
             }
 
             if previous.display_name != user.display_name {
                 user.display_name_upper = user.display_name.as_ref().map(|s| s.to_uppercase());
             }
 
+            self.directory.remove(previous);
+            self.directory.add(&user);
             self.users.insert(user_id, user);
 
             if let Some(bot) = bot {
@@ -303,6 +307,8 @@ impl UserMap {
         if self.username_to_user_id.get(&user.username) == Some(&user_id) {
             self.username_to_user_id.remove(&user.username);
         }
+        remove_username_skeleton(&mut self.username_skeletons, &user.username, user_id);
+        self.directory.remove(&user);
         self.deleted_users.insert(user_id, now);
         Some(user)
     }
@@ -353,6 +359,12 @@ impl UserMap {
         self.users.get_mut(user_id).map(|u| &mut u.diamond_membership_details)
     }
 
+    pub fn mark_diamond_member(&mut self, user_id: &UserId) {
+        if let Some(user) = self.users.get(user_id) {
+            self.directory.mark_diamond_member(user);
+        }
+    }
+
     pub fn mark_updated(&mut self, user_id: &UserId, now: TimestampMillis) {
         if let Some(user) = self.users.get_mut(user_id) {
             user.date_updated = now;
@@ -377,6 +389,7 @@ impl UserMap {
         }
     }
 
+    // Returns the change in the user's CHIT balance, or None if the update was not applied
     pub fn set_chit(
         &mut self,
         user_id: &UserId,
@@ -385,10 +398,8 @@ impl UserMap {
         streak: u16,
         streak_ends: TimestampMillis,
         now: TimestampMillis,
-    ) -> bool {
-        let Some(user) = self.users.get_mut(user_id) else {
-            return false;
-        };
+    ) -> Option<i32> {
+        let user = self.users.get_mut(user_id)?;
 
         let chit_event_month = MonthKey::from_timestamp(chit_event_timestamp);
 
@@ -407,13 +418,15 @@ impl UserMap {
             if chit_event_month == previous_month && chit_event_timestamp >= user.latest_chit_event_previous_month {
                 user.latest_chit_event_previous_month = chit_event_timestamp;
             } else {
-                return false;
+                return None;
             }
         }
 
+        let previous_total_chit = user.total_chit_earned();
         user.chit_updated = now;
-        user.chit_per_month.insert(chit_event_month, chit_balance);
-        true
+        let previous_balance = user.chit_per_month.insert(chit_event_month, chit_balance).unwrap_or_default();
+        self.directory.update_total_chit(user, previous_total_chit);
+        Some(chit_balance - previous_balance)
     }
 
     pub fn suspend_user(
@@ -484,6 +497,35 @@ impl UserMap {
         })
     }
 
+    // Returns a page of users matching the filter along with a cursor from which to continue if there may be
+    // further matches. At most `max_scanned` candidates are checked per call.
+    pub fn directory_page(
+        &self,
+        filter: &UserDirectoryFilter,
+        after: Option<UserDirectoryCursor>,
+        page_size: usize,
+        max_scanned: usize,
+        now: TimestampMillis,
+    ) -> (Vec<&User>, Option<UserDirectoryCursor>) {
+        let mut candidates = self.directory.candidates(filter, after);
+        let mut matches = Vec::new();
+        let mut last_scanned = None;
+
+        for cursor in candidates.by_ref().take(max_scanned) {
+            last_scanned = Some(cursor);
+            if let Some(user) = self.users.get(&cursor.user_id).filter(|u| matches_filter(u, filter, now)) {
+                matches.push(user);
+                if matches.len() >= page_size {
+                    break;
+                }
+            }
+        }
+
+        let next = if candidates.next().is_some() { last_scanned } else { None };
+
+        (matches, next)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &User> {
         self.users.values()
     }
@@ -569,6 +611,7 @@ impl UserMap {
             }
             user.unique_person_proof = Some(proof);
             user.date_updated = now;
+            self.directory.mark_unique_person(user);
             true
         } else {
             false
@@ -647,6 +690,23 @@ impl UserMap {
     }
 }
 
+fn add_username_skeleton(username_skeletons: &mut HashMap<String, Vec<UserId>>, username: &str, user_id: UserId) {
+    username_skeletons
+        .entry(username_skeleton(username))
+        .or_default()
+        .push(user_id);
+}
+
+fn remove_username_skeleton(username_skeletons: &mut HashMap<String, Vec<UserId>>, username: &str, user_id: UserId) {
+    let skeleton = username_skeleton(username);
+    if let Some(user_ids) = username_skeletons.get_mut(&skeleton) {
+        user_ids.retain(|u| *u != user_id);
+        if user_ids.is_empty() {
+            username_skeletons.remove(&skeleton);
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdateUserResult {
     Success,
@@ -682,6 +742,8 @@ impl From<UserMapTrimmed> for UserMap {
             bots: value.bots,
             username_to_user_id: CaseInsensitiveHashMap::default(),
             botname_to_user_id: CaseInsensitiveHashMap::default(),
+            username_skeletons: HashMap::default(),
+            directory: UserDirectory::default(),
             principal_to_user_id: HashMap::default(),
             user_referrals: HashMap::default(),
             users_with_duplicate_usernames: Vec::default(),
@@ -704,6 +766,9 @@ impl From<UserMapTrimmed> for UserMap {
                 }
             }
 
+            add_username_skeleton(&mut user_map.username_skeletons, &user.username, *user_id);
+            user_map.directory.add(user);
+
             if let Some(other_user_id) = user_map.principal_to_user_id.insert(user.principal, *user_id) {
                 user_map.users_with_duplicate_principals.push((*user_id, other_user_id));
             }
@@ -0,0 +1,87 @@
+use constants::DAY_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use types::{Milliseconds, TimestampMillis, UserId};
+use utils::text_validation::username_skeleton;
+
+// Once released, a username can only be reclaimed by its previous owner until this period has passed
+pub const COOLING_OFF_PERIOD: Milliseconds = 30 * DAY_IN_MS;
+// Only the most recent usernames are retained for each user
+const MAX_HISTORY_PER_USER: usize = 20;
+// Only the most recently released usernames are held for each user, so that repeatedly renaming can't be used to
+// squat on a large number of usernames
+const MAX_RELEASED_PER_USER: usize = 3;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct UsernameHistory {
+    history: HashMap<UserId, Vec<PreviousUsername>>,
+    released: HashMap<String, ReleasedUsername>, // Keyed by the skeleton of the released username
+    #[serde(default)]
+    released_by_user: HashMap<UserId, VecDeque<String>>, // The skeletons each user holds, oldest first
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PreviousUsername {
+    pub username: String,
+    pub replaced: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ReleasedUsername {
+    user_id: UserId,
+    released: TimestampMillis,
+}
+
+impl UsernameHistory {
+    pub fn record_change(&mut self, user_id: UserId, previous_username: String, now: TimestampMillis) {
+        self.release(user_id, &previous_username, now);
+        let history = self.history.entry(user_id).or_default();
+        history.push(PreviousUsername {
+            username: previous_username,
+            replaced: now,
+        });
+        if history.len() > MAX_HISTORY_PER_USER {
+            history.drain(..history.len() - MAX_HISTORY_PER_USER);
+        }
+    }
+
+    pub fn record_deletion(&mut self, user_id: UserId, username: &str, now: TimestampMillis) {
+        self.release(user_id, username, now);
+        self.history.remove(&user_id);
+    }
+
+    pub fn get(&self, user_id: &UserId) -> &[PreviousUsername] {
+        self.history.get(user_id).map(|h| h.as_slice()).unwrap_or_default()
+    }
+
+    // Returns true if the username (or one confusable with it) was released by a different user within the
+    // cooling-off period
+    pub fn is_cooling_off(&self, username: &str, user_id: Option<UserId>, now: TimestampMillis) -> bool {
+        self.released
+            .get(&username_skeleton(username))
+            .is_some_and(|r| Some(r.user_id) != user_id && now < r.released + COOLING_OFF_PERIOD)
+    }
+
+    fn release(&mut self, user_id: UserId, username: &str, now: TimestampMillis) {
+        self.released.retain(|_, r| now < r.released + COOLING_OFF_PERIOD);
+        self.released_by_user.retain(|_, skeletons| {
+            skeletons.retain(|s| self.released.contains_key(s));
+            !skeletons.is_empty()
+        });
+
+        let skeleton = username_skeleton(username);
+        self.released
+            .insert(skeleton.clone(), ReleasedUsername { user_id, released: now });
+
+        let held = self.released_by_user.entry(user_id).or_default();
+        held.retain(|s| *s != skeleton);
+        held.push_back(skeleton);
+        while held.len() > MAX_RELEASED_PER_USER {
+            if let Some(oldest) = held.pop_front() {
+                if self.released.get(&oldest).is_some_and(|r| r.user_id == user_id) {
+                    self.released.remove(&oldest);
+                }
+            }
+        }
+    }
+}
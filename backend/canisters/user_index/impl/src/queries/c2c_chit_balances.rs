@@ -0,0 +1,28 @@
+use crate::guards::caller_is_group_index;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use types::ChitBalances;
+use user_index_canister::c2c_chit_balances::{Response::*, *};
+
+#[query(guard = "caller_is_group_index", msgpack = true)]
+fn c2c_chit_balances(args: Args) -> Response {
+    read_state(|state| c2c_chit_balances_impl(args, state))
+}
+
+fn c2c_chit_balances_impl(args: Args, state: &RuntimeState) -> Response {
+    let now = state.env.now();
+
+    let balances = args
+        .users
+        .into_iter()
+        .filter_map(|user_id| state.data.users.get_by_user_id(&user_id))
+        .map(|user| ChitBalances {
+            user_id: user.user_id,
+            week: state.data.recent_chit.last_week(&user.user_id, now),
+            month: user.current_chit_balance(now),
+            all_time: user.total_chit_earned(),
+        })
+        .collect();
+
+    Success(SuccessResult { balances })
+}
@@ -1,4 +1,4 @@
-use crate::{read_state, RuntimeState};
+use crate::{read_state, RuntimeState, UsernameUnavailableReason};
 use canister_api_macros::query;
 use user_index_canister::check_username::{Response::*, *};
 use utils::text_validation::{validate_username, UsernameValidationError};
@@ -10,7 +10,8 @@ fn check_username(args: Args) -> Response {
 
 fn check_username_impl(args: Args, state: &RuntimeState) -> Response {
     let caller = state.env.caller();
-    if let Some(user) = state.data.users.get(&caller) {
+    let user = state.data.users.get(&caller);
+    if let Some(user) = user {
         if user.username.eq_ignore_ascii_case(&args.username) {
             return Success;
         }
@@ -18,10 +19,20 @@ fn check_username_impl(args: Args, state: &RuntimeState) -> Response {
 
     match validate_username(&args.username) {
         Ok(_) => {
-            if state.data.users.does_username_exist(&args.username, args.is_bot) {
-                UsernameTaken
+            let now = state.env.now();
+            let result = if args.is_bot {
+                state.data.check_bot_name_available(&args.username, now)
             } else {
-                Success
+                state
+                    .data
+                    .check_username_available(&args.username, user.map(|u| u.user_id), now)
+            };
+
+            match result {
+                Ok(_) => Success,
+                Err(UsernameUnavailableReason::Taken) => UsernameTaken,
+                Err(UsernameUnavailableReason::Reserved) => UsernameReserved,
+                Err(UsernameUnavailableReason::TooSimilar) => UsernameTooSimilar,
             }
         }
         Err(UsernameValidationError::TooShort(s)) => UsernameTooShort(s.min_length as u16),
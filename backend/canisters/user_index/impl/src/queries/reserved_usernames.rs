@@ -0,0 +1,15 @@
+use crate::guards::caller_is_platform_operator;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use user_index_canister::reserved_usernames::{Response::*, *};
+
+#[query(guard = "caller_is_platform_operator", msgpack = true)]
+fn reserved_usernames(_args: Args) -> Response {
+    read_state(reserved_usernames_impl)
+}
+
+fn reserved_usernames_impl(state: &RuntimeState) -> Response {
+    Success(SuccessResult {
+        usernames: state.data.reserved_usernames.iter().cloned().collect(),
+    })
+}
@@ -0,0 +1,207 @@
+use crate::guards::caller_is_openchat_user;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use user_index_canister::user_directory::{Response::*, *};
+
+const MAX_PAGE_SIZE: u16 = 100;
+const MAX_USERS_SCANNED: usize = 10_000;
+
+#[query(guard = "caller_is_openchat_user", candid = true, msgpack = true)]
+fn user_directory(args: Args) -> Response {
+    read_state(|state| user_directory_impl(args, state))
+}
+
+fn user_directory_impl(args: Args, state: &RuntimeState) -> Response {
+    let now = state.env.now();
+    let page_size = args.page_size.clamp(1, MAX_PAGE_SIZE) as usize;
+
+    let (users, next) = state
+        .data
+        .users
+        .directory_page(&args.filter, args.after, page_size, MAX_USERS_SCANNED, now);
+
+    Success(SuccessResult {
+        users: users.into_iter().map(|u| u.to_summary(now)).collect(),
+        next,
+        timestamp: now,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::user::{PhoneStatus, User};
+    use crate::Data;
+    use candid::Principal;
+    use types::{PhoneNumber, UniquePersonProof, UniquePersonProofProvider, UserType};
+    use utils::env::test::TestEnv;
+
+    #[test]
+    fn pages_through_all_users_in_registration_order() {
+        let state = setup_runtime_state();
+
+        let mut usernames = Vec::new();
+        let mut after = None;
+        loop {
+            let Success(result) = user_directory_impl(
+                Args {
+                    filter: UserDirectoryFilter::default(),
+                    after,
+                    page_size: 3,
+                },
+                &state,
+            );
+            usernames.extend(result.users.into_iter().map(|u| u.username));
+            after = result.next;
+            if after.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(
+            usernames,
+            vec!["user0", "user1", "user2", "user3", "user4", "user5", "user6", "user7", "user8", "user9"]
+        );
+    }
+
+    #[test]
+    fn filter_by_user_type() {
+        let state = setup_runtime_state();
+
+        let Success(bots) = user_directory_impl(
+            filtered(UserDirectoryFilter {
+                bots: Some(true),
+                ..Default::default()
+            }),
+            &state,
+        );
+        let Success(humans) = user_directory_impl(
+            filtered(UserDirectoryFilter {
+                bots: Some(false),
+                ..Default::default()
+            }),
+            &state,
+        );
+
+        assert_eq!(usernames(&bots.users), vec!["user0", "user3", "user6", "user9"]);
+        assert_eq!(humans.users.len(), 6);
+        assert!(bots.next.is_none());
+    }
+
+    #[test]
+    fn filter_by_unique_person_and_registration_date() {
+        let state = setup_runtime_state();
+
+        let Success(result) = user_directory_impl(
+            filtered(UserDirectoryFilter {
+                unique_person: Some(true),
+                registered_from: Some(12000),
+                registered_to: Some(18000),
+                ..Default::default()
+            }),
+            &state,
+        );
+
+        assert_eq!(usernames(&result.users), vec!["user2", "user4", "user6", "user8"]);
+    }
+
+    #[test]
+    fn filter_by_chit_range() {
+        let state = setup_runtime_state();
+
+        let Success(result) = user_directory_impl(
+            filtered(UserDirectoryFilter {
+                min_chit: Some(300),
+                max_chit: Some(600),
+                ..Default::default()
+            }),
+            &state,
+        );
+
+        assert_eq!(usernames(&result.users), vec!["user3", "user4", "user5", "user6"]);
+    }
+
+    #[test]
+    fn pages_through_chit_range_in_chit_order() {
+        let mut state = setup_runtime_state();
+        let user1 = state.data.users.get_by_username("user1").unwrap().user_id;
+        state.data.users.set_chit(&user1, state.env.now(), 900, 0, 0, state.env.now());
+
+        let mut usernames = Vec::new();
+        let mut after = None;
+        loop {
+            let Success(result) = user_directory_impl(
+                Args {
+                    filter: UserDirectoryFilter {
+                        min_chit: Some(200),
+                        registered_to: Some(18000),
+                        ..Default::default()
+                    },
+                    after,
+                    page_size: 2,
+                },
+                &state,
+            );
+            usernames.extend(result.users.into_iter().map(|u| u.username));
+            after = result.next;
+            if after.is_none() {
+                break;
+            }
+            assert!(after.unwrap().total_chit.is_some());
+        }
+
+        assert_eq!(
+            usernames,
+            vec!["user2", "user3", "user4", "user5", "user6", "user7", "user8", "user1"]
+        );
+    }
+
+    fn filtered(filter: UserDirectoryFilter) -> Args {
+        Args {
+            filter,
+            after: None,
+            page_size: 100,
+        }
+    }
+
+    fn usernames(users: &[types::UserSummary]) -> Vec<&str> {
+        users.iter().map(|u| u.username.as_str()).collect()
+    }
+
+    // Registers 10 users one second apart, every 3rd being a bot, every 2nd being a unique person, and with
+    // user N having earned N * 100 CHIT
+    fn setup_runtime_state() -> RuntimeState {
+        let mut env = TestEnv::default();
+        let mut data = Data::default();
+
+        for index in 0..10u8 {
+            let p = Principal::from_slice(&[index, 1]);
+            let user_id = p.into();
+
+            data.users.add_test_user(User {
+                principal: p,
+                user_id,
+                username: format!("user{index}"),
+                date_created: env.now,
+                date_updated: env.now,
+                user_type: if index % 3 == 0 { UserType::Bot } else { UserType::User },
+                phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, format!("+44 1111 111 11{index}"))),
+                ..Default::default()
+            });
+            if index % 2 == 0 {
+                data.users.record_proof_of_unique_personhood(
+                    user_id,
+                    UniquePersonProof {
+                        timestamp: env.now,
+                        provider: UniquePersonProofProvider::DecideAI,
+                    },
+                    env.now,
+                );
+            }
+            data.users.set_chit(&user_id, env.now, index as i32 * 100, 0, 0, env.now);
+            env.now += 1000;
+        }
+
+        RuntimeState::new(Box::new(env), data)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query;
+use user_index_canister::username_history::{Response::*, *};
+
+#[query(candid = true, msgpack = true)]
+fn username_history(args: Args) -> Response {
+    read_state(|state| username_history_impl(args, state))
+}
+
+fn username_history_impl(args: Args, state: &RuntimeState) -> Response {
+    if state.data.users.get_by_user_id(&args.user_id).is_none() {
+        return UserNotFound;
+    }
+
+    let usernames = state
+        .data
+        .username_history
+        .get(&args.user_id)
+        .iter()
+        .rev()
+        .map(|u| PreviousUsername {
+            username: u.username.clone(),
+            replaced: u.replaced,
+        })
+        .collect();
+
+    Success(SuccessResult { usernames })
+}
@@ -0,0 +1,19 @@
+use crate::guards::caller_is_platform_operator;
+use crate::mutate_state;
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use tracing::info;
+use user_index_canister::add_reserved_usernames::{Response::*, *};
+
+#[update(guard = "caller_is_platform_operator", msgpack = true)]
+#[trace]
+fn add_reserved_usernames(args: Args) -> Response {
+    mutate_state(|state| {
+        for username in args.usernames {
+            info!(%username, "Username reserved");
+            state.data.reserved_usernames.add(username);
+        }
+    });
+
+    Success
+}
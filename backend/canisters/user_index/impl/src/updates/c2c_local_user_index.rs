@@ -70,6 +70,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
                     local_user_index_canister_id: ev.local_user_index_canister_id,
                     latest_message_index: ev.latest_message_index,
                     group_canister_timestamp: ev.group_canister_timestamp,
+                    read_receipts_enabled: ev.read_receipts_enabled,
                 }),
             );
         }
@@ -126,7 +127,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
         LocalUserIndexEvent::NotifyChit(ev) => {
             let (user_id, chit) = *ev;
 
-            if state.data.users.set_chit(
+            if let Some(chit_earned) = state.data.users.set_chit(
                 &user_id,
                 chit.timestamp,
                 chit.chit_balance,
@@ -134,6 +135,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
                 chit.streak_ends,
                 **now,
             ) {
+                state.data.recent_chit.record(user_id, chit_earned, chit.timestamp, **now);
                 if let Some(user) = state.data.users.get_by_user_id(&user_id) {
                     state.data.chit_leaderboard.update_position(
                         user_id,
@@ -179,7 +181,7 @@ fn process_new_user(
     let now = state.env.now();
 
     let mut original_username = None;
-    let username = match state.data.users.ensure_unique_username(&username, false) {
+    let username = match state.data.ensure_username_available(&username, now) {
         Ok(_) => username,
         Err(new_username) => {
             original_username = Some(username);
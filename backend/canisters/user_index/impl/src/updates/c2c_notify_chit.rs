@@ -14,7 +14,7 @@ fn c2c_notify_chit_impl(args: Args, state: &mut RuntimeState) -> Response {
     let now = state.env.now();
     let user_id: UserId = state.env.caller().into();
 
-    if state.data.users.set_chit(
+    if let Some(chit_earned) = state.data.users.set_chit(
         &user_id,
         args.timestamp,
         args.chit_balance,
@@ -22,6 +22,7 @@ fn c2c_notify_chit_impl(args: Args, state: &mut RuntimeState) -> Response {
         args.streak_ends,
         now,
     ) {
+        state.data.recent_chit.record(user_id, chit_earned, args.timestamp, now);
         if let Some(user) = state.data.users.get_by_user_id(&user_id) {
             state.data.chit_leaderboard.update_position(
                 user_id,
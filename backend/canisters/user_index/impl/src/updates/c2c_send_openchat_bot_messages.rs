@@ -1,11 +1,11 @@
-use crate::{guards::caller_is_translations_canister, mutate_state, RuntimeState};
+use crate::{guards::caller_is_translations_or_identity_canister, mutate_state, RuntimeState};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use local_user_index_canister::{OpenChatBotMessage, UserIndexEvent};
 use types::{MessageContent, TextContent};
 use user_index_canister::c2c_send_openchat_bot_messages::{Response::*, *};
 
-#[update(guard = "caller_is_translations_canister", msgpack = true)]
+#[update(guard = "caller_is_translations_or_identity_canister", msgpack = true)]
 #[trace]
 fn c2c_send_openchat_bot_messages(args: Args) -> Response {
     mutate_state(|state| c2c_send_openchat_bot_messages_impl(args, state))
@@ -0,0 +1,25 @@
+use crate::{guards::caller_is_identity_canister, mutate_state, RuntimeState};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use local_user_index_canister::{ScopedPrincipalUpdated, UserIndexEvent};
+use user_index_canister::c2c_update_scoped_principal::{Response::*, *};
+
+#[update(guard = "caller_is_identity_canister", msgpack = true)]
+#[trace]
+fn c2c_update_scoped_principal(args: Args) -> Response {
+    mutate_state(|state| c2c_update_scoped_principal_impl(args, state))
+}
+
+fn c2c_update_scoped_principal_impl(args: Args, state: &mut RuntimeState) -> Response {
+    state.push_event_to_local_user_index(
+        args.user_id,
+        UserIndexEvent::ScopedPrincipalUpdated(ScopedPrincipalUpdated {
+            user_id: args.user_id,
+            principal: args.principal,
+            scope: args.scope,
+            expires: args.expires,
+        }),
+    );
+
+    Success
+}
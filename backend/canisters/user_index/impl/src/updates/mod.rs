@@ -1,6 +1,7 @@
 pub mod add_local_user_index_canister;
 pub mod add_platform_moderator;
 pub mod add_platform_operator;
+pub mod add_reserved_usernames;
 pub mod assign_platform_moderators_group;
 pub mod award_external_achievement;
 pub mod c2c_local_user_index;
@@ -11,6 +12,7 @@ pub mod c2c_report_message;
 pub mod c2c_send_openchat_bot_messages;
 pub mod c2c_set_avatar;
 pub mod c2c_suspend_users;
+pub mod c2c_update_scoped_principal;
 pub mod delete_user;
 pub mod mark_local_user_index_full;
 pub mod mark_suspected_bot;
@@ -22,6 +24,7 @@ pub mod register_external_achievement;
 pub mod remove_bot;
 pub mod remove_platform_moderator;
 pub mod remove_platform_operator;
+pub mod remove_reserved_usernames;
 pub mod set_diamond_membership_fees;
 pub mod set_display_name;
 pub mod set_max_concurrent_user_canister_upgrades;
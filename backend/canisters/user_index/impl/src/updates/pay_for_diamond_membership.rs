@@ -128,6 +128,7 @@ fn process_charge(
         let result = diamond_membership.hydrate(now).unwrap();
 
         state.data.users.mark_updated(&user_id, now);
+        state.data.users.mark_diamond_member(&user_id);
         state.push_event_to_all_local_user_indexes(
             UserIndexEvent::DiamondMembershipPaymentReceived(DiamondMembershipPaymentReceived {
                 user_id,
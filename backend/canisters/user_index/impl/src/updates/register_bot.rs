@@ -1,7 +1,7 @@
 use crate::guards::caller_is_openchat_user;
 use crate::model::user_map::Bot;
 use crate::model::{MAX_AVATAR_SIZE, MAX_COMMANDS, MAX_DESCRIPTION_LEN};
-use crate::{mutate_state, RuntimeState};
+use crate::{mutate_state, RuntimeState, UsernameUnavailableReason};
 use candid::Principal;
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
@@ -164,8 +164,11 @@ fn validate_request(args: &Args, owner_id: UserId, state: &RuntimeState) -> Resu
         return Err(format!("owner must not be suspended {owner_id}"));
     }
 
-    if state.data.users.does_username_exist(&args.name, true) {
-        return Err("bot name already exists".to_string());
+    match state.data.check_bot_name_available(&args.name, state.env.now()) {
+        Ok(_) => {}
+        Err(UsernameUnavailableReason::Taken) => return Err("bot name already exists".to_string()),
+        Err(UsernameUnavailableReason::Reserved) => return Err("bot name reserved".to_string()),
+        Err(UsernameUnavailableReason::TooSimilar) => return Err("bot name too similar to an existing name".to_string()),
     }
 
     Ok(())
@@ -0,0 +1,20 @@
+use crate::guards::caller_is_platform_operator;
+use crate::mutate_state;
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use tracing::info;
+use user_index_canister::remove_reserved_usernames::{Response::*, *};
+
+#[update(guard = "caller_is_platform_operator", msgpack = true)]
+#[trace]
+fn remove_reserved_usernames(args: Args) -> Response {
+    mutate_state(|state| {
+        for username in args.usernames {
+            if state.data.reserved_usernames.remove(&username) {
+                info!(%username, "Username no longer reserved");
+            }
+        }
+    });
+
+    Success
+}
@@ -1,6 +1,6 @@
 use crate::guards::caller_is_openchat_user;
 use crate::model::user_map::UpdateUserResult;
-use crate::{mutate_state, RuntimeState};
+use crate::{mutate_state, RuntimeState, UsernameUnavailableReason};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use local_user_index_canister::{UserIndexEvent, UsernameChanged};
@@ -18,21 +18,34 @@ fn set_username_impl(args: Args, state: &mut RuntimeState) -> Response {
 
     if let Some(user) = state.data.users.get_by_principal(&caller) {
         let username = args.username;
-        if !username.eq_ignore_ascii_case(&user.username) {
+        let user_id = user.user_id;
+        let now = state.env.now();
+        let username_changed = !username.eq_ignore_ascii_case(&user.username);
+        if username_changed {
             match validate_username(&username) {
                 Ok(_) => {}
                 Err(UsernameValidationError::TooShort(s)) => return UsernameTooShort(s.min_length as u16),
                 Err(UsernameValidationError::TooLong(l)) => return UsernameTooLong(l.max_length as u16),
                 Err(UsernameValidationError::Invalid) => return UsernameInvalid,
             };
+
+            match state.data.check_username_available(&username, Some(user_id), now) {
+                Ok(_) => {}
+                Err(UsernameUnavailableReason::Taken) => return UsernameTaken,
+                Err(UsernameUnavailableReason::Reserved) => return UsernameReserved,
+                Err(UsernameUnavailableReason::TooSimilar) => return UsernameTooSimilar,
+            }
         }
 
+        let previous_username = user.username.clone();
         let mut user_to_update = user.clone();
         user_to_update.username.clone_from(&username);
-        let user_id = user.user_id;
-        let now = state.env.now();
         match state.data.users.update(user_to_update, now, false, None) {
             UpdateUserResult::Success => {
+                if username_changed {
+                    state.data.username_history.record_change(user_id, previous_username, now);
+                }
+
                 state.push_event_to_local_user_index(
                     user_id,
                     UserIndexEvent::UsernameChanged(UsernameChanged { user_id, username }),
@@ -82,6 +95,10 @@ mod tests {
         let user = state.data.users.get_by_username("vwxyz").unwrap();
 
         assert_eq!(&user.username, "vwxyz");
+
+        let history = state.data.username_history.get(&user.user_id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(&history[0].username, "abcdef");
     }
 
     #[test]
@@ -202,4 +219,168 @@ mod tests {
         let result = set_username_impl(args, &mut state);
         assert!(matches!(result, Response::UsernameTooLong(_)));
     }
+
+    #[test]
+    fn username_too_similar() {
+        let env = TestEnv::default();
+        let mut data = Data::default();
+        data.users.add_test_user(User {
+            principal: env.caller,
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "1111 111 111".to_owned())),
+            user_id: Principal::from_slice(&[1]).into(),
+            username: "abcdef".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        data.users.add_test_user(User {
+            principal: Principal::from_slice(&[2]),
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "2222 222 222".to_owned())),
+            user_id: Principal::from_slice(&[2]).into(),
+            username: "johnsmith".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        let mut state = RuntimeState::new(Box::new(env), data);
+
+        let args = Args {
+            username: "J0hn_Sm1th".to_string(),
+        };
+        let result = set_username_impl(args, &mut state);
+        assert!(matches!(result, Response::UsernameTooSimilar));
+    }
+
+    #[test]
+    fn username_reserved() {
+        let env = TestEnv::default();
+        let mut data = Data::default();
+        data.users.add_test_user(User {
+            principal: env.caller,
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "1111 111 111".to_owned())),
+            user_id: Principal::from_slice(&[1]).into(),
+            username: "abcdef".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        data.reserved_usernames.add("openchat_team".to_string());
+        let mut state = RuntimeState::new(Box::new(env), data);
+
+        let args = Args {
+            username: "OpenChatTeam".to_string(),
+        };
+        let result = set_username_impl(args, &mut state);
+        assert!(matches!(result, Response::UsernameReserved));
+    }
+
+    #[test]
+    fn recently_released_username_reserved() {
+        let env = TestEnv::default();
+        let mut data = Data::default();
+        data.users.add_test_user(User {
+            principal: env.caller,
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "1111 111 111".to_owned())),
+            user_id: Principal::from_slice(&[1]).into(),
+            username: "abcdef".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        data.username_history
+            .record_change(Principal::from_slice(&[2]).into(), "vwxyz".to_string(), env.now);
+        let mut state = RuntimeState::new(Box::new(env), data);
+
+        let args = Args {
+            username: "vwxyz".to_string(),
+        };
+        let result = set_username_impl(args, &mut state);
+        assert!(matches!(result, Response::UsernameReserved));
+    }
+
+    #[test]
+    fn variant_of_own_username_succeeds() {
+        let env = TestEnv::default();
+        let mut data = Data::default();
+        data.users.add_test_user(User {
+            principal: env.caller,
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "1111 111 111".to_owned())),
+            user_id: Principal::from_slice(&[1]).into(),
+            username: "john_smith".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        data.reserved_usernames.add("johnsmith".to_string());
+        let mut state = RuntimeState::new(Box::new(env), data);
+
+        let args = Args {
+            username: "JohnSmith".to_string(),
+        };
+        let result = set_username_impl(args, &mut state);
+        assert!(matches!(result, Response::Success));
+    }
+
+    #[test]
+    fn released_usernames_held_per_user_capped() {
+        let env = TestEnv::default();
+        let now = env.now;
+        let mut data = Data::default();
+        let user_id = Principal::from_slice(&[1]).into();
+        data.users.add_test_user(User {
+            principal: env.caller,
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "1111 111 111".to_owned())),
+            user_id,
+            username: "username_start".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        let mut state = RuntimeState::new(Box::new(env), data);
+
+        for i in 0..10 {
+            let args = Args {
+                username: format!("username_{i}"),
+            };
+            let result = set_username_impl(args, &mut state);
+            assert!(matches!(result, Response::Success));
+        }
+
+        // Only the 3 most recently released usernames are still held by the user
+        let history = &state.data.username_history;
+        assert!(!history.is_cooling_off("username_start", None, now));
+        assert!(!history.is_cooling_off("username_5", None, now));
+        assert!(history.is_cooling_off("username_6", None, now));
+        assert!(history.is_cooling_off("username_8", None, now));
+    }
+
+    #[test]
+    fn username_history_capped() {
+        let env = TestEnv::default();
+        let mut data = Data::default();
+        let user_id = Principal::from_slice(&[1]).into();
+        data.users.add_test_user(User {
+            principal: env.caller,
+            phone_status: PhoneStatus::Confirmed(PhoneNumber::new(44, "1111 111 111".to_owned())),
+            user_id,
+            username: "username_start".to_string(),
+            date_created: env.now,
+            date_updated: env.now,
+            ..Default::default()
+        });
+        let mut state = RuntimeState::new(Box::new(env), data);
+
+        for i in 0..30 {
+            let args = Args {
+                username: format!("username_{i}"),
+            };
+            let result = set_username_impl(args, &mut state);
+            assert!(matches!(result, Response::Success));
+        }
+
+        let history = state.data.username_history.get(&user_id);
+        assert_eq!(history.len(), 20);
+        assert_eq!(&history[0].username, "username_9");
+        assert_eq!(&history[19].username, "username_28");
+    }
 }
@@ -0,0 +1,5 @@
+use candid::Nat;
+use icrc_ledger_types::icrc1::account::Account;
+
+pub type Args = Vec<Account>;
+pub type Response = Vec<Nat>;
@@ -0,0 +1,6 @@
+use candid::Nat;
+use icrc_ledger_types::icrc1::account::Account;
+
+// (account, prev, take)
+pub type Args = (Account, Option<Nat>, Option<Nat>);
+pub type Response = Vec<Nat>;
@@ -0,0 +1,2 @@
+pub mod icrc7_balance_of;
+pub mod icrc7_tokens_of;
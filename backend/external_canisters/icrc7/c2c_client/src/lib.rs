@@ -0,0 +1,21 @@
+use canister_client::generate_candid_c2c_call;
+use icrc7_canister::*;
+
+// Queries
+generate_candid_c2c_call!(icrc7_balance_of);
+
+// 'icrc7_tokens_of' takes multiple arguments so can't be generated using the macro
+pub async fn icrc7_tokens_of(
+    canister_id: ::types::CanisterId,
+    args: &icrc7_tokens_of::Args,
+) -> ::ic_cdk::api::call::CallResult<icrc7_tokens_of::Response> {
+    let method_name = "icrc7_tokens_of";
+    canister_client::make_c2c_call(
+        canister_id,
+        method_name,
+        args,
+        |(account, prev, take)| ::candid::encode_args((account, prev, take)),
+        |r| ::candid::decode_one(r),
+    )
+    .await
+}
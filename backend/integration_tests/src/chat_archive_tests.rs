@@ -0,0 +1,134 @@
+use crate::env::ENV;
+use crate::{client, TestEnv, User};
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use testing::rng::random_string;
+use types::{ArchivedEvent, ChatEvent, ChatId};
+
+#[test]
+fn exported_events_can_be_imported_into_another_group() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user = client::register_user(env, canister_ids);
+    let group1 = client::user::happy_path::create_group(env, &user, &random_string(), false, true);
+
+    let root_message_index = client::group::happy_path::send_text_message(env, &user, group1, None, "root", None).message_index;
+    for i in 0..4 {
+        client::group::happy_path::send_text_message(env, &user, group1, None, format!("message {i}"), None);
+    }
+    for i in 0..2 {
+        client::group::happy_path::send_text_message(env, &user, group1, Some(root_message_index), format!("reply {i}"), None);
+    }
+
+    let events = export_all_events(env, &user, group1, 3);
+    assert_eq!(count_messages(&events, false), 5);
+    assert_eq!(count_messages(&events, true), 2);
+
+    // Requesting 0 events is treated as requesting 1 so that paging always makes progress
+    let response = client::group::export_events(
+        env,
+        user.principal,
+        group1.into(),
+        &group_canister::export_events::Args {
+            after: None,
+            max_events: 0,
+        },
+    );
+    match response {
+        group_canister::export_events::Response::Success(page) => assert_eq!(page.events.len(), 1),
+        response => panic!("'export_events' error: {response:?}"),
+    }
+
+    let group2 = client::user::happy_path::create_group(env, &user, &random_string(), false, true);
+
+    // Thread replies are exported after the main events so they land in the second batch, which relies on the
+    // session to map their root message indexes
+    let (first_batch, second_batch) = events.split_at(events.len() / 2);
+    let session_id = import(env, &user, group2, None, first_batch.to_vec(), false).session_id;
+    let result = import(env, &user, group2, Some(session_id), second_batch.to_vec(), true);
+    assert_eq!(result.session_id, session_id);
+
+    let imported = export_all_events(env, &user, group2, 100);
+    assert_eq!(count_messages(&imported, false), 5);
+    assert_eq!(count_messages(&imported, true), 2);
+
+    // The session is removed once the import has finished
+    let response = client::group::import_archived_events(
+        env,
+        user.principal,
+        group2.into(),
+        &group_canister::import_archived_events::Args {
+            session_id: Some(session_id),
+            events: Vec::new(),
+            format_version: types::CHAT_ARCHIVE_FORMAT_VERSION,
+            finished: true,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::import_archived_events::Response::SessionNotFound
+    ));
+}
+
+fn export_all_events(env: &PocketIc, user: &User, group_id: ChatId, page_size: u32) -> Vec<ArchivedEvent> {
+    let mut events = Vec::new();
+    let mut after = None;
+
+    loop {
+        let response = client::group::export_events(
+            env,
+            user.principal,
+            group_id.into(),
+            &group_canister::export_events::Args {
+                after,
+                max_events: page_size,
+            },
+        );
+
+        let page = match response {
+            group_canister::export_events::Response::Success(page) => page,
+            response => panic!("'export_events' error: {response:?}"),
+        };
+
+        after = page.events.last().map(|e| e.cursor()).or(after);
+        events.extend(page.events);
+
+        if page.finished {
+            return events;
+        }
+    }
+}
+
+fn import(
+    env: &mut PocketIc,
+    user: &User,
+    group_id: ChatId,
+    session_id: Option<u32>,
+    events: Vec<ArchivedEvent>,
+    finished: bool,
+) -> group_canister::import_archived_events::SuccessResult {
+    let response = client::group::import_archived_events(
+        env,
+        user.principal,
+        group_id.into(),
+        &group_canister::import_archived_events::Args {
+            session_id,
+            events,
+            format_version: types::CHAT_ARCHIVE_FORMAT_VERSION,
+            finished,
+        },
+    );
+
+    match response {
+        group_canister::import_archived_events::Response::Success(result) => result,
+        response => panic!("'import_archived_events' error: {response:?}"),
+    }
+}
+
+fn count_messages(events: &[ArchivedEvent], in_threads: bool) -> usize {
+    events
+        .iter()
+        .filter(|e| e.thread_root_message_index.is_some() == in_threads && matches!(e.event.event, ChatEvent::Message(_)))
+        .count()
+}
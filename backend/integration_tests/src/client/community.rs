@@ -5,21 +5,28 @@ use ic_stable_structures::memory_manager::MemoryId;
 pub const STABLE_MEMORY_MAP_MEMORY_ID: MemoryId = MemoryId::new(3);
 
 // Queries
+generate_msgpack_query_call!(achievements);
 generate_msgpack_query_call!(channel_summary);
+generate_msgpack_query_call!(community_analytics);
 generate_msgpack_query_call!(events);
 generate_msgpack_query_call!(events_by_index);
 generate_msgpack_query_call!(local_user_index);
+generate_msgpack_query_call!(permission_templates);
 generate_msgpack_query_call!(search_channel);
+generate_msgpack_query_call!(search_community);
 generate_msgpack_query_call!(selected_channel_initial);
 generate_msgpack_query_call!(selected_channel_updates_v2);
 generate_msgpack_query_call!(selected_initial);
 generate_msgpack_query_call!(selected_updates_v2);
+generate_msgpack_query_call!(subscription_payments);
 generate_msgpack_query_call!(summary);
 generate_msgpack_query_call!(summary_updates);
+generate_msgpack_query_call!(typing);
 
 // Updates
 generate_msgpack_update_call!(accept_p2p_swap);
 generate_msgpack_update_call!(add_reaction);
+generate_msgpack_update_call!(award_achievement);
 generate_msgpack_update_call!(block_user);
 generate_msgpack_update_call!(cancel_invites);
 generate_msgpack_update_call!(cancel_p2p_swap);
@@ -28,8 +35,10 @@ generate_msgpack_update_call!(change_role);
 generate_msgpack_update_call!(claim_prize);
 generate_msgpack_update_call!(create_channel);
 generate_msgpack_update_call!(create_user_group);
+generate_msgpack_update_call!(delete_achievement);
 generate_msgpack_update_call!(delete_channel);
 generate_msgpack_update_call!(delete_messages);
+generate_msgpack_update_call!(delete_permission_template);
 generate_msgpack_update_call!(delete_user_groups);
 generate_msgpack_update_call!(edit_message);
 generate_msgpack_update_call!(enable_invite_code);
@@ -40,11 +49,17 @@ generate_msgpack_update_call!(register_poll_vote);
 generate_msgpack_update_call!(remove_member);
 generate_msgpack_update_call!(remove_member_from_channel);
 generate_msgpack_update_call!(remove_reaction);
+generate_msgpack_update_call!(revoke_achievement);
 generate_msgpack_update_call!(send_message);
+generate_msgpack_update_call!(set_achievement);
+generate_msgpack_update_call!(set_channel_categories);
+generate_msgpack_update_call!(set_permission_template);
+generate_msgpack_update_call!(set_typing);
 generate_msgpack_update_call!(unblock_user);
 generate_msgpack_update_call!(undelete_messages);
 generate_msgpack_update_call!(update_bot);
 generate_msgpack_update_call!(update_channel);
+generate_msgpack_update_call!(update_channel_permissions);
 generate_msgpack_update_call!(update_community);
 generate_msgpack_update_call!(update_user_group);
 
@@ -84,6 +99,7 @@ pub mod happy_path {
                 events_ttl: None,
                 gate_config: None,
                 external_url: None,
+                category_id: None,
             },
         );
 
@@ -118,6 +134,7 @@ pub mod happy_path {
                 events_ttl: None,
                 gate_config: Some(gate.into()),
                 external_url: None,
+                category_id: None,
             },
         );
 
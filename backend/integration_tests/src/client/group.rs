@@ -8,16 +8,22 @@ pub const CHAT_EVENTS_MEMORY_ID: MemoryId = MemoryId::new(3);
 generate_msgpack_query_call!(events);
 generate_msgpack_query_call!(events_by_index);
 generate_msgpack_query_call!(events_window);
+generate_msgpack_query_call!(export_events);
+generate_msgpack_query_call!(join_requests);
 generate_msgpack_query_call!(local_user_index);
+generate_msgpack_query_call!(message_read_by);
 generate_msgpack_query_call!(public_summary);
 generate_msgpack_query_call!(selected_initial);
 generate_msgpack_query_call!(selected_updates_v2);
+generate_msgpack_query_call!(subscription_payments);
 generate_msgpack_query_call!(summary);
 generate_msgpack_query_call!(summary_updates);
+generate_msgpack_query_call!(typing);
 
 // Updates
 generate_msgpack_update_call!(accept_p2p_swap);
 generate_msgpack_update_call!(add_reaction);
+generate_msgpack_update_call!(approve_join_request);
 generate_msgpack_update_call!(block_user);
 generate_msgpack_update_call!(cancel_p2p_swap);
 generate_msgpack_update_call!(change_role);
@@ -27,13 +33,18 @@ generate_msgpack_update_call!(delete_messages);
 generate_msgpack_update_call!(edit_message_v2);
 generate_msgpack_update_call!(enable_invite_code);
 generate_update_call!(end_video_call_v2);
+generate_msgpack_update_call!(import_archived_events);
 generate_msgpack_update_call!(generate_bot_api_key);
 generate_msgpack_update_call!(join_video_call);
+generate_msgpack_update_call!(mark_read_up_to);
 generate_msgpack_update_call!(pin_message_v2);
 generate_msgpack_update_call!(register_poll_vote);
+generate_msgpack_update_call!(reject_join_request);
 generate_msgpack_update_call!(remove_participant);
 generate_msgpack_update_call!(remove_reaction);
 generate_msgpack_update_call!(send_message_v2);
+generate_msgpack_update_call!(set_read_receipts_enabled);
+generate_msgpack_update_call!(set_typing);
 generate_update_call!(start_video_call_v2);
 generate_msgpack_update_call!(toggle_mute_notifications);
 generate_msgpack_update_call!(unblock_user);
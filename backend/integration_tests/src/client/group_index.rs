@@ -35,6 +35,7 @@ pub mod happy_path {
             &group_index_canister::explore_communities::Args {
                 search_term: None,
                 languages: Vec::new(),
+                tags: None,
                 page_index: 0,
                 page_size: 50,
                 include_moderation_flags: 0,
@@ -55,6 +56,8 @@ pub mod happy_path {
             group_index_canister_id,
             &group_index_canister::explore_groups::Args {
                 search_term: None,
+                tags: None,
+                languages: None,
                 page_index: 0,
                 page_size: 50,
             },
@@ -0,0 +1,38 @@
+use crate::generate_update_call;
+use candid::CandidType;
+use icrc_ledger_types::icrc1::account::Account;
+use serde::Deserialize;
+
+// Updates
+generate_update_call!(mint);
+
+pub mod mint {
+    use super::*;
+
+    #[derive(CandidType, Deserialize, Debug)]
+    pub struct Args {
+        pub token_id: u128,
+        pub owner: Account,
+    }
+
+    pub type Response = u128;
+}
+
+pub mod happy_path {
+    use super::*;
+    use candid::Principal;
+    use pocket_ic::PocketIc;
+    use types::CanisterId;
+
+    pub fn mint(env: &mut PocketIc, collection_canister_id: CanisterId, owner: impl Into<Account>, token_id: u128) {
+        super::mint(
+            env,
+            Principal::anonymous(),
+            collection_canister_id,
+            &super::mint::Args {
+                token_id,
+                owner: owner.into(),
+            },
+        );
+    }
+}
@@ -5,13 +5,26 @@ use identity_canister::*;
 generate_query_call!(auth_principals);
 generate_query_call!(check_auth_principal);
 generate_query_call!(get_delegation);
+generate_query_call!(get_scoped_delegation);
+generate_query_call!(recovery_contacts);
+generate_query_call!(scoped_delegations);
 
 // Updates
 generate_update_call!(approve_identity_link);
+generate_update_call!(approve_recovery);
+generate_update_call!(cancel_recovery);
+generate_update_call!(complete_recovery);
 generate_update_call!(create_identity);
 generate_update_call!(initiate_identity_link);
+generate_update_call!(initiate_recovery);
 generate_update_call!(prepare_delegation);
+generate_update_call!(prepare_scoped_delegation);
+generate_update_call!(reject_recovery);
 generate_update_call!(remove_identity_link);
+generate_update_call!(revoke_auth_principal);
+generate_update_call!(revoke_scoped_delegation);
+generate_update_call!(set_auth_principal_name);
+generate_update_call!(set_recovery_contacts);
 
 pub mod happy_path {
     use candid::Principal;
@@ -20,6 +20,7 @@ pub mod escrow;
 pub mod event_store;
 pub mod group;
 pub mod group_index;
+pub mod icrc7_ledger;
 pub mod identity;
 pub mod ledger;
 pub mod local_user_index;
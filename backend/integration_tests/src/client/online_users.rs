@@ -3,9 +3,13 @@ use online_users_canister::*;
 
 // Queries
 generate_msgpack_query_call!(last_online);
+generate_msgpack_query_call!(presence);
+generate_msgpack_query_call!(typing);
 
 // Updates
 generate_msgpack_update_call!(mark_as_online);
+generate_msgpack_update_call!(set_presence);
+generate_msgpack_update_call!(set_typing);
 
 pub mod happy_path {
     use candid::Principal;
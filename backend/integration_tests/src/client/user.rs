@@ -7,6 +7,7 @@ generate_msgpack_query_call!(events);
 generate_msgpack_query_call!(events_by_index);
 generate_msgpack_query_call!(initial_state);
 generate_msgpack_query_call!(message_activity_feed);
+generate_msgpack_query_call!(message_edit_history);
 generate_msgpack_query_call!(saved_crypto_accounts);
 generate_msgpack_query_call!(updates);
 
@@ -11,8 +11,8 @@ use std::time::Duration;
 use test_case::test_case;
 use testing::rng::random_string;
 use types::{
-    AccessGate, AccessGateConfig, AccessGateNonComposite, ChannelId, ChatId, CommunityId, CompositeGate,
-    DiamondMembershipPlanDuration, OptionUpdate, TokenBalanceGate, UserId,
+    AccessGate, AccessGateConfig, AccessGateNonComposite, CanisterId, ChannelId, ChatId, ChitGate, CommunityId, CompositeGate,
+    DiamondMembershipPlanDuration, OptionUpdate, SubscriptionGate, TokenBalanceGate, UserId,
 };
 
 const DAY_IN_MS: Milliseconds = 24 * 60 * 60 * 1000;
@@ -347,6 +347,48 @@ fn member_lapses_from_token_balance_gate_and_rejoins_successfully(container_type
     }
 }
 
+#[test_case(ContainerType::Community)]
+#[test_case(ContainerType::Channel)]
+#[test_case(ContainerType::Group)]
+fn member_lapses_from_chit_gate_once_streak_ends(container_type: ContainerType) {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let gate_config = AccessGateConfig {
+        gate: AccessGate::Chit(ChitGate {
+            min_balance: None,
+            min_streak: Some(1),
+        }),
+        expiry: Some(DAY_IN_MS),
+    };
+
+    let TestData { owner, container, .. } =
+        init_test_data(env, canister_ids, *controller, gate_config, 0, container_type, false);
+
+    let user = client::register_user(env, canister_ids);
+    client::user::happy_path::claim_daily_chit(env, &user);
+    tick_many(env, 3);
+
+    join_container(env, &owner, &user, canister_ids, &container, false);
+
+    // The gate is re-checked on expiry, looking up the user's CHIT from the user index, and the
+    // user's streak lasts until the end of the day after they claimed so they remain a member
+    env.advance_time(Duration::from_millis(DAY_IN_MS + 60_000));
+    tick_many(env, 5);
+
+    assert!(!has_user_lapsed(env, &user, &container));
+
+    // Once the streak has ended the user lapses at the next expiry
+    env.advance_time(Duration::from_millis(3 * DAY_IN_MS));
+    tick_many(env, 5);
+
+    assert!(has_user_lapsed(env, &user, &container));
+}
+
 #[test_case(ContainerType::Community)]
 #[test_case(ContainerType::Channel)]
 #[test_case(ContainerType::Group)]
@@ -505,6 +547,164 @@ fn invited_users_pass_composite_gate_then_expire_later(container_type: Container
     assert!(has_user_lapsed(env, user2, &container));
 }
 
+#[test_case(ContainerType::Community)]
+#[test_case(ContainerType::Channel)]
+#[test_case(ContainerType::Group)]
+fn subscription_renews_then_lapses_after_grace_period(container_type: ContainerType) {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let amount = 1_0000_0000;
+    let fee = 10_000;
+    let grace_period = DAY_IN_MS / 2;
+
+    let gate_config = AccessGateConfig {
+        gate: AccessGate::Subscription(SubscriptionGate {
+            ledger_canister_id: canister_ids.icp_ledger,
+            amount,
+            fee,
+            period: DAY_IN_MS,
+            grace_period,
+        }),
+        expiry: None,
+    };
+
+    let TestData { owner, container, .. } =
+        init_test_data(env, canister_ids, *controller, gate_config, 0, container_type, false);
+
+    let user = client::register_user(env, canister_ids);
+
+    // Give the user enough funds and allowance to cover joining plus a single renewal
+    client::ledger::happy_path::transfer(env, *controller, canister_ids.icp_ledger, user.user_id, 3 * amount);
+    client::ledger::happy_path::approve(
+        env,
+        user.user_id.into(),
+        canister_ids.icp_ledger,
+        container_canister_id(&container),
+        2 * (amount - fee),
+    );
+
+    join_container(env, &owner, &user, canister_ids, &container, false);
+    assert!(!has_user_lapsed(env, &user, &container));
+    assert_eq!(subscription_payment_count(env, &owner, user.user_id, &container), 1);
+
+    // The first renewal succeeds
+    env.advance_time(Duration::from_millis((11 * DAY_IN_MS) / 10));
+    tick_many(env, 10);
+
+    assert!(!has_user_lapsed(env, &user, &container));
+    assert_eq!(subscription_payment_count(env, &owner, user.user_id, &container), 2);
+
+    // The second renewal fails but the member is within the grace period
+    env.advance_time(Duration::from_millis((11 * DAY_IN_MS) / 10));
+    tick_many(env, 10);
+
+    assert!(!has_user_lapsed(env, &user, &container));
+    assert_eq!(subscription_payment_count(env, &owner, user.user_id, &container), 2);
+
+    // Once the grace period has passed the member lapses
+    env.advance_time(Duration::from_millis(grace_period));
+    tick_many(env, 10);
+
+    assert!(has_user_lapsed(env, &user, &container));
+}
+
+#[test_case(ContainerType::Community)]
+#[test_case(ContainerType::Channel)]
+#[test_case(ContainerType::Group)]
+fn subscription_removed_when_member_leaves(container_type: ContainerType) {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let amount = 1_0000_0000;
+    let fee = 10_000;
+
+    let gate_config = AccessGateConfig {
+        gate: AccessGate::Subscription(SubscriptionGate {
+            ledger_canister_id: canister_ids.icp_ledger,
+            amount,
+            fee,
+            period: DAY_IN_MS,
+            grace_period: DAY_IN_MS / 2,
+        }),
+        expiry: None,
+    };
+
+    let TestData { owner, container, .. } =
+        init_test_data(env, canister_ids, *controller, gate_config, 0, container_type, false);
+
+    let user = client::register_user(env, canister_ids);
+
+    client::ledger::happy_path::transfer(env, *controller, canister_ids.icp_ledger, user.user_id, 2 * amount);
+    client::ledger::happy_path::approve(
+        env,
+        user.user_id.into(),
+        canister_ids.icp_ledger,
+        container_canister_id(&container),
+        amount - fee,
+    );
+
+    join_container(env, &owner, &user, canister_ids, &container, false);
+    assert_eq!(subscription_payment_count(env, &owner, user.user_id, &container), 1);
+
+    match container {
+        Container::Community(community_id) => client::user::happy_path::leave_community(env, &user, community_id),
+        Container::Channel(community_id, channel_id) => {
+            client::community::happy_path::leave_channel(env, user.principal, community_id, channel_id)
+        }
+        Container::Group(group_id) => client::user::happy_path::leave_group(env, &user, group_id),
+    }
+    tick_many(env, 3);
+
+    assert_eq!(subscription_payment_count(env, &owner, user.user_id, &container), 0);
+}
+
+fn container_canister_id(container: &Container) -> CanisterId {
+    match container {
+        Container::Community(community_id) | Container::Channel(community_id, _) => (*community_id).into(),
+        Container::Group(group_id) => (*group_id).into(),
+    }
+}
+
+fn subscription_payment_count(env: &PocketIc, sender: &User, user_id: UserId, container: &Container) -> usize {
+    match container {
+        Container::Community(community_id) | Container::Channel(community_id, _) => {
+            let channel_id = if let Container::Channel(_, channel_id) = container { Some(*channel_id) } else { None };
+            match client::community::subscription_payments(
+                env,
+                sender.principal,
+                (*community_id).into(),
+                &community_canister::subscription_payments::Args {
+                    channel_id,
+                    user_id: Some(user_id),
+                },
+            ) {
+                community_canister::subscription_payments::Response::Success(result) => result.payments.len(),
+                response => panic!("'subscription_payments' error: {response:?}"),
+            }
+        }
+        Container::Group(group_id) => {
+            match client::group::subscription_payments(
+                env,
+                sender.principal,
+                (*group_id).into(),
+                &group_canister::subscription_payments::Args { user_id: Some(user_id) },
+            ) {
+                group_canister::subscription_payments::Response::Success(result) => result.payments.len(),
+                response => panic!("'subscription_payments' error: {response:?}"),
+            }
+        }
+    }
+}
+
 fn has_user_lapsed(env: &mut PocketIc, user: &User, container: &Container) -> bool {
     match container {
         Container::Community(community_id) => {
@@ -609,6 +809,8 @@ fn update_container_gate(
                 gate_config: gate_config_update,
                 public: None,
                 primary_language: None,
+                tags: None,
+                languages: None,
             };
 
             client::community::happy_path::update_community(env, principal, *community_id, &args);
@@ -642,6 +844,8 @@ fn update_container_gate(
                 events_ttl: OptionUpdate::NoChange,
                 messages_visible_to_non_members: None,
                 correlation_id: 0,
+                tags: None,
+                languages: None,
             };
 
             client::group::happy_path::update_group(env, principal, *group_id, &args);
@@ -0,0 +1,120 @@
+use crate::env::ENV;
+use crate::{client, TestEnv};
+use std::ops::Deref;
+use testing::rng::random_string;
+use types::CommunityAchievementCriteria;
+
+#[test]
+fn admins_can_award_and_revoke_achievements() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["general".to_string()]);
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+
+    let set_achievement_response = client::community::set_achievement(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::set_achievement::Args {
+            achievement_id: None,
+            name: "Helper".to_string(),
+            description: "Helped out a fellow member".to_string(),
+            image: None,
+            criteria: CommunityAchievementCriteria::Manual,
+        },
+    );
+
+    let achievement_id = match set_achievement_response {
+        community_canister::set_achievement::Response::Success(result) => result.achievement_id,
+        response => panic!("'set_achievement' error: {response:?}"),
+    };
+
+    // Regular members cannot award achievements
+    let award_args = community_canister::award_achievement::Args {
+        achievement_id,
+        user_id: user2.user_id,
+    };
+    let award_response = client::community::award_achievement(env, user2.principal, community_id.into(), &award_args);
+    assert!(matches!(
+        award_response,
+        community_canister::award_achievement::Response::NotAuthorized
+    ));
+
+    let award_response = client::community::award_achievement(env, user1.principal, community_id.into(), &award_args);
+    assert!(matches!(
+        award_response,
+        community_canister::award_achievement::Response::Success
+    ));
+
+    let award_response = client::community::award_achievement(env, user1.principal, community_id.into(), &award_args);
+    assert!(matches!(
+        award_response,
+        community_canister::award_achievement::Response::AlreadyAwarded
+    ));
+
+    let achievements = match client::community::achievements(
+        env,
+        user2.principal,
+        community_id.into(),
+        &community_canister::achievements::Args {
+            user_id: Some(user2.user_id),
+        },
+    ) {
+        community_canister::achievements::Response::Success(result) => result,
+        response => panic!("'achievements' error: {response:?}"),
+    };
+    assert_eq!(achievements.achievements.len(), 1);
+    assert_eq!(achievements.achievements[0].awarded_count, 1);
+    assert_eq!(achievements.awarded.len(), 1);
+    assert_eq!(achievements.awarded[0].achievement_id, achievement_id);
+    assert_eq!(achievements.awarded[0].awarded_by, Some(user1.user_id));
+
+    // The awarded achievements are shown on the member's profile and in their community summary
+    let summary = client::community::happy_path::summary(env, user2.principal, community_id);
+    assert_eq!(summary.membership.unwrap().achievements, vec![achievement_id]);
+
+    let selected_initial = client::community::happy_path::selected_initial(env, user1.principal, community_id);
+    let member = selected_initial
+        .members
+        .iter()
+        .find(|m| m.user_id == user2.user_id)
+        .expect("Member with achievements should not be a basic member");
+    assert_eq!(member.achievements, vec![achievement_id]);
+
+    let revoke_response = client::community::revoke_achievement(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::revoke_achievement::Args {
+            achievement_id,
+            user_id: user2.user_id,
+        },
+    );
+    assert!(matches!(
+        revoke_response,
+        community_canister::revoke_achievement::Response::Success
+    ));
+
+    let achievements = match client::community::achievements(
+        env,
+        user2.principal,
+        community_id.into(),
+        &community_canister::achievements::Args {
+            user_id: Some(user2.user_id),
+        },
+    ) {
+        community_canister::achievements::Response::Success(result) => result,
+        response => panic!("'achievements' error: {response:?}"),
+    };
+    assert_eq!(achievements.achievements[0].awarded_count, 0);
+    assert!(achievements.awarded.is_empty());
+}
@@ -0,0 +1,225 @@
+use crate::env::ENV;
+use crate::{client, TestEnv};
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::random_string;
+use types::{CustomPermission, GroupPermissionRole, GroupPermissions, OptionUpdate, Rules};
+
+#[test]
+fn channel_categories_included_in_summary_and_inherited_by_new_channels() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let community_id = client::user::happy_path::create_community(
+        env,
+        &user1,
+        &random_string(),
+        true,
+        vec!["general".to_string(), "random".to_string()],
+    );
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+
+    let summary = client::community::happy_path::summary(env, user1.principal, community_id);
+    let general = summary.channels.iter().find(|c| c.name == "general").unwrap().channel_id;
+    let random = summary.channels.iter().find(|c| c.name == "random").unwrap().channel_id;
+    assert!(summary.channel_categories.is_empty());
+
+    let default_permissions = GroupPermissions {
+        pin_messages: GroupPermissionRole::Owner,
+        ..Default::default()
+    };
+
+    env.advance_time(Duration::from_secs(1));
+
+    let set_categories_response = client::community::set_channel_categories(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::set_channel_categories::Args {
+            categories: vec![
+                community_canister::set_channel_categories::ChannelCategoryArgs {
+                    id: None,
+                    name: "Announcements".to_string(),
+                    channel_ids: vec![general],
+                    collapsed_by_default: false,
+                    default_permissions: Some(default_permissions.clone()),
+                },
+                community_canister::set_channel_categories::ChannelCategoryArgs {
+                    id: None,
+                    name: "Off-topic".to_string(),
+                    channel_ids: vec![random],
+                    collapsed_by_default: true,
+                    default_permissions: None,
+                },
+            ],
+        },
+    );
+
+    let categories = match set_categories_response {
+        community_canister::set_channel_categories::Response::Success(result) => result.categories,
+        response => panic!("'set_channel_categories' error: {response:?}"),
+    };
+    assert_eq!(categories.len(), 2);
+    let announcements_id = categories[0].id;
+
+    let summary_updates =
+        client::community::happy_path::summary_updates(env, user2.principal, community_id, summary.last_updated).unwrap();
+    let categories = summary_updates.channel_categories.unwrap();
+    assert_eq!(
+        categories.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        vec!["Announcements", "Off-topic"]
+    );
+    assert_eq!(categories[0].channel_ids, vec![general]);
+    assert!(categories[1].collapsed_by_default);
+
+    let create_channel_response = client::community::create_channel(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::create_channel::Args {
+            is_public: true,
+            name: "news".to_string(),
+            description: "news_description".to_string(),
+            rules: Rules::default(),
+            subtype: None,
+            avatar: None,
+            history_visible_to_new_joiners: true,
+            messages_visible_to_non_members: None,
+            permissions_v2: None,
+            events_ttl: None,
+            gate_config: None,
+            external_url: None,
+            category_id: Some(announcements_id),
+        },
+    );
+
+    let channel_id = match create_channel_response {
+        community_canister::create_channel::Response::Success(result) => result.channel_id,
+        response => panic!("'create_channel' error: {response:?}"),
+    };
+
+    let channel = client::community::happy_path::channel_summary(env, &user1, community_id, channel_id);
+    assert_eq!(channel.permissions_v2.pin_messages, GroupPermissionRole::Owner);
+
+    let summary = client::community::happy_path::summary(env, user2.principal, community_id);
+    assert_eq!(summary.channel_categories[0].channel_ids, vec![general, channel_id]);
+}
+
+#[test]
+fn channel_categories_resent_when_channel_visibility_changes() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["general".to_string()]);
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+
+    let channel_id = client::community::happy_path::create_channel(env, user1.principal, community_id, false, random_string());
+
+    let response = client::community::set_channel_categories(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::set_channel_categories::Args {
+            categories: vec![community_canister::set_channel_categories::ChannelCategoryArgs {
+                id: None,
+                name: "Private".to_string(),
+                channel_ids: vec![channel_id],
+                collapsed_by_default: false,
+                default_permissions: None,
+            }],
+        },
+    );
+    assert!(matches!(
+        response,
+        community_canister::set_channel_categories::Response::Success(_)
+    ));
+
+    let summary = client::community::happy_path::summary(env, user2.principal, community_id);
+    assert!(summary.channel_categories[0].channel_ids.is_empty());
+
+    env.advance_time(Duration::from_secs(1));
+
+    client::community::happy_path::update_channel(
+        env,
+        user1.principal,
+        community_id,
+        &community_canister::update_channel::Args {
+            channel_id,
+            name: None,
+            description: None,
+            rules: None,
+            avatar: OptionUpdate::NoChange,
+            permissions_v2: None,
+            events_ttl: OptionUpdate::NoChange,
+            gate_config: OptionUpdate::NoChange,
+            public: Some(true),
+            messages_visible_to_non_members: None,
+            external_url: OptionUpdate::NoChange,
+        },
+    );
+
+    let summary_updates =
+        client::community::happy_path::summary_updates(env, user2.principal, community_id, summary.last_updated).unwrap();
+    assert_eq!(summary_updates.channel_categories.unwrap()[0].channel_ids, vec![channel_id]);
+}
+
+#[test]
+fn default_permissions_validated() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let community_id =
+        client::user::happy_path::create_community(env, &user, &random_string(), true, vec!["general".to_string()]);
+
+    let mut default_permissions = GroupPermissions::default();
+    default_permissions.message_permissions.custom = vec![
+        CustomPermission {
+            subtype: "x".to_string(),
+            role: GroupPermissionRole::Admins,
+        },
+        CustomPermission {
+            subtype: "x".to_string(),
+            role: GroupPermissionRole::Members,
+        },
+    ];
+
+    let response = client::community::set_channel_categories(
+        env,
+        user.principal,
+        community_id.into(),
+        &community_canister::set_channel_categories::Args {
+            categories: vec![community_canister::set_channel_categories::ChannelCategoryArgs {
+                id: None,
+                name: "Announcements".to_string(),
+                channel_ids: Vec::new(),
+                collapsed_by_default: false,
+                default_permissions: Some(default_permissions),
+            }],
+        },
+    );
+    assert!(matches!(
+        response,
+        community_canister::set_channel_categories::Response::InvalidDefaultPermissions(name) if name == "Announcements"
+    ));
+}
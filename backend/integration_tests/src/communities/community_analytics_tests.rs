@@ -0,0 +1,38 @@
+use crate::env::ENV;
+use crate::{client, TestEnv};
+use std::ops::Deref;
+use testing::rng::random_string;
+use types::AnalyticsWindow;
+
+#[test]
+fn only_admins_can_view_community_analytics() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["general".to_string()]);
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+
+    let args = community_canister::community_analytics::Args {
+        window: AnalyticsWindow::Week,
+    };
+
+    let response = client::community::community_analytics(env, user1.principal, community_id.into(), &args);
+    let community_canister::community_analytics::Response::Success(result) = response else {
+        panic!("'community_analytics' error: {response:?}");
+    };
+    assert_eq!(result.daily_activity.len(), 7);
+
+    let response = client::community::community_analytics(env, user2.principal, community_id.into(), &args);
+    assert!(matches!(
+        response,
+        community_canister::community_analytics::Response::NotAuthorized
+    ));
+}
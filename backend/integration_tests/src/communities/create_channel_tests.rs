@@ -68,6 +68,7 @@ fn existing_users_joined_to_new_public_channel() {
             events_ttl: None,
             gate_config: None,
             external_url: None,
+            category_id: None,
         },
     );
 
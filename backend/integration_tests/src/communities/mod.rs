@@ -1,5 +1,8 @@
 mod access_gate_expiry_tests;
+mod achievement_tests;
 mod cancel_invites_tests;
+mod channel_category_tests;
+mod community_analytics_tests;
 mod convert_group_into_community_tests;
 mod create_channel_tests;
 mod delete_channel_tests;
@@ -9,6 +12,7 @@ mod join_channel_tests;
 mod join_community_tests;
 mod leave_community_tests;
 mod p2p_swap_tests;
+mod permission_template_tests;
 mod promote_member_tests;
 mod remove_member_from_channel_tests;
 mod remove_member_tests;
@@ -0,0 +1,182 @@
+use crate::env::ENV;
+use crate::{client, TestEnv};
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use testing::rng::random_string;
+use types::{ChannelId, ChatEvent, CommunityRole, GroupPermissionRole, GroupPermissions};
+
+#[test]
+fn updating_template_applies_permissions_to_linked_channels() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let community_id = client::user::happy_path::create_community(
+        env,
+        &user,
+        &random_string(),
+        true,
+        vec!["general".to_string(), "random".to_string(), "other".to_string()],
+    );
+
+    let summary = client::community::happy_path::summary(env, user.principal, community_id);
+    let channel_id = |name: &str| summary.channels.iter().find(|c| c.name == name).unwrap().channel_id;
+    let general = channel_id("general");
+    let random = channel_id("random");
+    let other = channel_id("other");
+
+    let set_template = |env: &mut PocketIc, template_id: Option<u32>, permissions: GroupPermissions| {
+        let response = client::community::set_permission_template(
+            env,
+            user.principal,
+            community_id.into(),
+            &community_canister::set_permission_template::Args {
+                template_id,
+                name: "Read only".to_string(),
+                permissions,
+            },
+        );
+
+        match response {
+            community_canister::set_permission_template::Response::Success(result) => result,
+            response => panic!("'set_permission_template' error: {response:?}"),
+        }
+    };
+
+    let template_id = set_template(
+        env,
+        None,
+        GroupPermissions {
+            pin_messages: GroupPermissionRole::Owner,
+            ..Default::default()
+        },
+    )
+    .template_id;
+
+    let update_response = client::community::update_channel_permissions(
+        env,
+        user.principal,
+        community_id.into(),
+        &community_canister::update_channel_permissions::Args {
+            channel_ids: vec![general, random],
+            update: community_canister::update_channel_permissions::PermissionsUpdate::Template(template_id),
+        },
+    );
+    assert!(matches!(
+        update_response,
+        community_canister::update_channel_permissions::Response::Success
+    ));
+
+    let result = set_template(
+        env,
+        Some(template_id),
+        GroupPermissions {
+            pin_messages: GroupPermissionRole::Owner,
+            react_to_messages: GroupPermissionRole::Admins,
+            ..Default::default()
+        },
+    );
+    assert_eq!(result.channels_updated.len(), 2);
+
+    for channel_id in [general, random] {
+        let channel = client::community::happy_path::channel_summary(env, &user, community_id, channel_id);
+        assert_eq!(channel.permissions_v2.pin_messages, GroupPermissionRole::Owner);
+        assert_eq!(channel.permissions_v2.react_to_messages, GroupPermissionRole::Admins);
+
+        let events = client::community::happy_path::events(
+            env,
+            &user,
+            community_id,
+            channel_id,
+            channel.latest_event_index,
+            false,
+            1,
+            1,
+        );
+        assert!(matches!(events.events[0].event, ChatEvent::PermissionsChanged(_)));
+    }
+
+    let channel = client::community::happy_path::channel_summary(env, &user, community_id, other);
+    assert_eq!(channel.permissions_v2.react_to_messages, GroupPermissionRole::Members);
+
+    let templates_response =
+        client::community::permission_templates(env, user.principal, community_id.into(), &types::Empty {});
+    let community_canister::permission_templates::Response::Success(result) = templates_response else {
+        panic!("'permission_templates' error: {templates_response:?}");
+    };
+    assert_eq!(result.templates.len(), 1);
+    assert_eq!(result.templates[0].channel_ids.len(), 2);
+}
+
+#[test]
+fn applying_template_requires_channel_membership_and_skips_unchanged_channels() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["general".to_string()]);
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+    client::community::happy_path::change_role(env, user1.principal, community_id, user2.user_id, CommunityRole::Owner);
+
+    let summary = client::community::happy_path::summary(env, user1.principal, community_id);
+    let general = summary.channels[0].channel_id;
+    let private_channel =
+        client::community::happy_path::create_channel(env, user2.principal, community_id, false, random_string());
+
+    let response = client::community::set_permission_template(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::set_permission_template::Args {
+            template_id: None,
+            name: "Default".to_string(),
+            permissions: GroupPermissions::default(),
+        },
+    );
+    let community_canister::set_permission_template::Response::Success(result) = response else {
+        panic!("'set_permission_template' error: {response:?}");
+    };
+
+    let apply_template = |env: &mut PocketIc, channel_ids: Vec<ChannelId>| {
+        client::community::update_channel_permissions(
+            env,
+            user1.principal,
+            community_id.into(),
+            &community_canister::update_channel_permissions::Args {
+                channel_ids,
+                update: community_canister::update_channel_permissions::PermissionsUpdate::Template(result.template_id),
+            },
+        )
+    };
+
+    let response = apply_template(env, vec![general, private_channel]);
+    assert!(matches!(
+        response,
+        community_canister::update_channel_permissions::Response::UserNotInChannel(c) if c == private_channel
+    ));
+
+    let response = apply_template(env, vec![general]);
+    assert!(matches!(
+        response,
+        community_canister::update_channel_permissions::Response::Success
+    ));
+
+    // The channel's permissions already match the template so no event should be pushed
+    let latest_event_index =
+        client::community::happy_path::channel_summary(env, &user1, community_id, general).latest_event_index;
+    apply_template(env, vec![general]);
+    let channel = client::community::happy_path::channel_summary(env, &user1, community_id, general);
+    assert_eq!(channel.latest_event_index, latest_event_index);
+}
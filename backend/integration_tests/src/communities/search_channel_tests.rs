@@ -3,8 +3,9 @@ use crate::{client, CanisterIds, TestEnv, User};
 use candid::Principal;
 use pocket_ic::PocketIc;
 use std::ops::Deref;
+use std::time::Duration;
 use testing::rng::random_string;
-use types::{ChannelId, CommunityId, MessageIndex};
+use types::{ChannelId, CommunityId, MessageContent, MessageIndex};
 
 #[test]
 fn search_channel_returns_expected_message() {
@@ -44,6 +45,78 @@ fn search_channel_returns_expected_message() {
     assert_eq!(matches[0].message_index, MessageIndex::from(1));
 }
 
+#[test]
+fn search_community_returns_matches_across_channels() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let community_id = client::user::happy_path::create_community(
+        env,
+        &user1,
+        &random_string(),
+        true,
+        vec!["general".to_string(), "random".to_string()],
+    );
+
+    let summary = client::community::happy_path::summary(env, user1.principal, community_id);
+    let general = summary.channels.iter().find(|c| c.name == "general").unwrap().channel_id;
+    let random = summary.channels.iter().find(|c| c.name == "random").unwrap().channel_id;
+
+    client::community::happy_path::send_text_message(
+        env,
+        &user1,
+        community_id,
+        general,
+        None,
+        "Hello, cruel cruel world!",
+        None,
+    );
+    env.advance_time(Duration::from_secs(1));
+    client::community::happy_path::send_text_message(env, &user1, community_id, random, None, "Goodbye, cruel world!", None);
+    client::community::happy_path::send_text_message(env, &user1, community_id, random, None, "Something else", None);
+
+    let search = |channel_ids: Option<Vec<ChannelId>>| {
+        let response = client::community::search_community(
+            env,
+            user1.principal,
+            community_id.into(),
+            &community_canister::search_community::Args {
+                search_term: "cruel".to_string(),
+                max_results: 10,
+                channel_ids,
+                users: None,
+                from: None,
+                to: None,
+            },
+        );
+
+        match response {
+            community_canister::search_community::Response::Success(result) => result.matches,
+            response => panic!("'search_community' error: {response:?}"),
+        }
+    };
+
+    // Matches are ranked by score and then by recency
+    let matches = search(None);
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].channel_id, general);
+    assert_eq!(matches[0].score, 2);
+    assert_eq!(matches[0].sender, user1.user_id);
+    assert!(matches!(&matches[0].content, MessageContent::Text(t) if t.text == "Hello, cruel cruel world!"));
+    assert_eq!(matches[1].channel_id, random);
+    assert_eq!(matches[1].score, 1);
+
+    let matches = search(Some(vec![general]));
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].channel_id, general);
+}
+
 fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal) -> TestData {
     let user1 = client::register_diamond_user(env, canister_ids, controller);
     let user2 = client::register_user(env, canister_ids);
@@ -738,6 +738,8 @@ fn set_community_rules(env: &mut PocketIc, sender: Principal, community_id: Comm
         gate_config: OptionUpdate::NoChange,
         public: None,
         primary_language: None,
+        tags: None,
+        languages: None,
     };
 
     client::community::happy_path::update_community(env, sender, community_id, &args);
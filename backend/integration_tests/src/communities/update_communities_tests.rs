@@ -41,6 +41,8 @@ fn change_casing_of_community_name_succeeds() {
             gate_config: OptionUpdate::NoChange,
             public: None,
             primary_language: None,
+            tags: None,
+            languages: None,
         },
     );
 
@@ -87,10 +89,13 @@ fn update_permissions_succeeds() {
             create_public_channel: Some(CommunityPermissionRole::Owners),
             create_private_channel: None,
             manage_user_groups: None,
+            manage_achievements: None,
         }),
         gate_config: OptionUpdate::NoChange,
         public: None,
         primary_language: None,
+        tags: None,
+        languages: None,
     };
 
     client::community::happy_path::update_community(env, user1.principal, community_id, &args);
@@ -138,10 +143,13 @@ fn update_permissions_summary_updates_succeeds() {
             create_public_channel: Some(CommunityPermissionRole::Owners),
             create_private_channel: None,
             manage_user_groups: None,
+            manage_achievements: None,
         }),
         gate_config: OptionUpdate::NoChange,
         public: None,
         primary_language: None,
+        tags: None,
+        languages: None,
     };
 
     client::community::happy_path::update_community(env, user1.principal, community_id, &args);
@@ -188,6 +196,8 @@ fn make_private_community_public_succeeds() {
         gate_config: OptionUpdate::NoChange,
         public: Some(true),
         primary_language: None,
+        tags: None,
+        languages: None,
     };
 
     client::community::happy_path::update_community(env, user.principal, community_id, &args);
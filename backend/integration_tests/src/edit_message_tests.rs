@@ -43,6 +43,42 @@ fn edit_message_succeeds() {
     assert_eq!(m2.content.text().unwrap(), new_text);
 }
 
+#[test]
+fn edit_history_retained() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+
+    let message_id = random_from_u128();
+
+    client::user::happy_path::send_text_message(env, &user1, user2.user_id, "TEXT", Some(message_id));
+    client::user::happy_path::edit_text_message(env, &user1, user2.user_id, message_id, "TEXT!", None);
+    client::user::happy_path::edit_text_message(env, &user1, user2.user_id, message_id, "TEXT!!", None);
+
+    env.tick();
+
+    for (user, other) in [(&user1, &user2), (&user2, &user1)] {
+        let user_canister::message_edit_history::Response::Success(result) = client::user::message_edit_history(
+            env,
+            user.principal,
+            user.canister(),
+            &user_canister::message_edit_history::Args {
+                user_id: other.user_id,
+                thread_root_message_index: None,
+                message_id,
+            },
+        ) else {
+            panic!()
+        };
+
+        let texts: Vec<_> = result.versions.iter().map(|v| v.content.text().unwrap()).collect();
+        assert_eq!(texts, vec!["TEXT", "TEXT!"]);
+        assert!(result.versions[0].replaced_at <= result.versions[1].timestamp);
+    }
+}
+
 #[test_case(true)]
 #[test_case(false)]
 fn update_block_level_markdown_succeeds(starting_value: bool) {
@@ -1,11 +1,15 @@
 use crate::env::ENV;
 use crate::utils::tick_many;
-use crate::{client, TestEnv};
+use crate::{client, wasms, TestEnv};
 use candid::Principal;
 use std::ops::Deref;
+use std::time::Duration;
 use test_case::test_case;
 use testing::rng::random_string;
-use types::{AccessGate, AccessGateNonComposite, CompositeGate, GateCheckFailedReason, PaymentGate, Rules, TokenBalanceGate};
+use types::{
+    AccessGate, AccessGateConfig, AccessGateNonComposite, ChitGate, CommunityMessageCountGate, CommunityTenureGate,
+    CompositeGate, GateCheckFailedReason, NftGate, PaymentGate, Rules, TokenBalanceGate,
+};
 
 #[test_case(true, false; "diamond_member")]
 #[test_case(false, false; "not_diamond_member")]
@@ -162,6 +166,92 @@ fn public_group_token_balance_gate_check(has_sufficient_balance: bool) {
     }
 }
 
+#[test_case(true, None; "owns_nft")]
+#[test_case(false, None; "doesnt_own_nft")]
+#[test_case(true, Some(vec![1, 2]); "owns_required_nft")]
+#[test_case(false, Some(vec![1, 2]); "doesnt_own_required_nft")]
+fn public_group_nft_gate_check(owns_nft: bool, token_ids: Option<Vec<u128>>) {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+
+    let restricted_to_token_ids = token_ids.is_some();
+    let collection_canister_id = client::create_canister(env, *controller);
+    client::install_canister(env, *controller, collection_canister_id, wasms::TEST_ICRC7_LEDGER.clone(), ());
+
+    let group_name = random_string();
+
+    let group_id = match client::user::create_group(
+        env,
+        user1.principal,
+        user1.user_id.into(),
+        &user_canister::create_group::Args {
+            is_public: true,
+            name: group_name.clone(),
+            description: format!("{group_name}_description"),
+            avatar: None,
+            history_visible_to_new_joiners: true,
+            permissions_v2: None,
+            rules: Rules::default(),
+            events_ttl: None,
+            gate_config: Some(
+                AccessGate::Nft(NftGate {
+                    collection_canister_id,
+                    token_ids,
+                    min_count: None,
+                })
+                .into(),
+            ),
+            messages_visible_to_non_members: None,
+        },
+    ) {
+        user_canister::create_group::Response::Success(result) => result.chat_id,
+        response => panic!("'create_group' error: {response:?}"),
+    };
+
+    if restricted_to_token_ids {
+        // Tokens outside of the gate's set shouldn't count towards the gate
+        client::icrc7_ledger::happy_path::mint(env, collection_canister_id, user2.user_id, 3);
+    }
+    if owns_nft {
+        client::icrc7_ledger::happy_path::mint(env, collection_canister_id, user2.user_id, 2);
+    }
+
+    let join_group_response = client::local_user_index::join_group(
+        env,
+        user2.principal,
+        canister_ids.local_user_index(env, group_id),
+        &local_user_index_canister::join_group::Args {
+            chat_id: group_id,
+            invite_code: None,
+            verified_credential_args: None,
+            correlation_id: 0,
+        },
+    );
+
+    if owns_nft {
+        assert!(matches!(
+            join_group_response,
+            local_user_index_canister::join_group::Response::Success(_)
+        ));
+    } else {
+        assert!(
+            matches!(
+                join_group_response,
+                local_user_index_canister::join_group::Response::GateCheckFailed(GateCheckFailedReason::InsufficientNfts(0))
+            ),
+            "{join_group_response:?}"
+        );
+    }
+}
+
 #[test_case(true, true, true)]
 #[test_case(true, true, false)]
 #[test_case(true, false, true)]
@@ -255,6 +345,209 @@ fn public_group_composite_gate_check(is_diamond: bool, has_sufficient_balance: b
     }
 }
 
+#[test]
+fn channel_community_message_count_gate_check() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["General".to_string()]);
+
+    let gated_channel_id = client::community::happy_path::create_gated_channel(
+        env,
+        user1.principal,
+        community_id,
+        true,
+        random_string(),
+        AccessGate::CommunityMessageCount(CommunityMessageCountGate { min_messages: 2 }),
+    );
+
+    let community_summary = client::community::happy_path::join_community(env, user2.principal, community_id);
+    let general_channel_id = community_summary
+        .channels
+        .iter()
+        .find(|c| c.channel_id != gated_channel_id)
+        .unwrap()
+        .channel_id;
+
+    let local_user_index = canister_ids.local_user_index(env, community_id);
+    let join_channel_args = local_user_index_canister::join_channel::Args {
+        community_id,
+        channel_id: gated_channel_id,
+        invite_code: None,
+        referred_by: None,
+        verified_credential_args: None,
+    };
+
+    let join_channel_response =
+        client::local_user_index::join_channel(env, user2.principal, local_user_index, &join_channel_args);
+
+    assert!(matches!(
+        join_channel_response,
+        local_user_index_canister::join_channel::Response::GateCheckFailed(
+            GateCheckFailedReason::InsufficientCommunityMessages(0)
+        )
+    ));
+
+    for i in 0..2 {
+        client::community::happy_path::send_text_message(env, &user2, community_id, general_channel_id, None, i, None);
+    }
+
+    let join_channel_response =
+        client::local_user_index::join_channel(env, user2.principal, local_user_index, &join_channel_args);
+
+    assert!(matches!(
+        join_channel_response,
+        local_user_index_canister::join_channel::Response::Success(_)
+    ));
+}
+
+#[test]
+fn public_group_chit_gate_check() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+
+    let group_name = random_string();
+
+    let group_id = match client::user::create_group(
+        env,
+        user1.principal,
+        user1.user_id.into(),
+        &user_canister::create_group::Args {
+            is_public: true,
+            name: group_name.clone(),
+            description: format!("{group_name}_description"),
+            avatar: None,
+            history_visible_to_new_joiners: true,
+            permissions_v2: None,
+            rules: Rules::default(),
+            events_ttl: None,
+            gate_config: Some(
+                AccessGate::Chit(ChitGate {
+                    min_balance: Some(200),
+                    min_streak: None,
+                })
+                .into(),
+            ),
+            messages_visible_to_non_members: None,
+        },
+    ) {
+        user_canister::create_group::Response::Success(result) => result.chat_id,
+        response => panic!("'create_group' error: {response:?}"),
+    };
+
+    let join_group_args = local_user_index_canister::join_group::Args {
+        chat_id: group_id,
+        invite_code: None,
+        verified_credential_args: None,
+        correlation_id: 0,
+    };
+    let local_user_index = canister_ids.local_user_index(env, group_id);
+
+    let join_group_response = client::local_user_index::join_group(env, user2.principal, local_user_index, &join_group_args);
+
+    assert!(
+        matches!(
+            join_group_response,
+            local_user_index_canister::join_group::Response::GateCheckFailed(GateCheckFailedReason::InsufficientChit(0))
+        ),
+        "{join_group_response:?}"
+    );
+
+    client::user::happy_path::claim_daily_chit(env, &user2);
+    tick_many(env, 3);
+
+    let join_group_response = client::local_user_index::join_group(env, user2.principal, local_user_index, &join_group_args);
+
+    assert!(
+        matches!(
+            join_group_response,
+            local_user_index_canister::join_group::Response::Success(_)
+        ),
+        "{join_group_response:?}"
+    );
+}
+
+#[test]
+fn channel_community_tenure_gate_check() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["General".to_string()]);
+
+    let min_membership_duration = 24 * 60 * 60 * 1000;
+    let gated_channel_id = client::community::happy_path::create_gated_channel(
+        env,
+        user1.principal,
+        community_id,
+        true,
+        random_string(),
+        AccessGate::CommunityTenure(CommunityTenureGate { min_membership_duration }),
+    );
+
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+
+    let local_user_index = canister_ids.local_user_index(env, community_id);
+    let join_channel_args = local_user_index_canister::join_channel::Args {
+        community_id,
+        channel_id: gated_channel_id,
+        invite_code: None,
+        referred_by: None,
+        verified_credential_args: None,
+    };
+
+    let join_channel_response =
+        client::local_user_index::join_channel(env, user2.principal, local_user_index, &join_channel_args);
+
+    assert!(
+        matches!(
+            join_channel_response,
+            local_user_index_canister::join_channel::Response::GateCheckFailed(
+                GateCheckFailedReason::InsufficientCommunityTenure(tenure)
+            ) if tenure < min_membership_duration
+        ),
+        "{join_channel_response:?}"
+    );
+
+    env.advance_time(Duration::from_millis(min_membership_duration));
+
+    let join_channel_response =
+        client::local_user_index::join_channel(env, user2.principal, local_user_index, &join_channel_args);
+
+    assert!(
+        matches!(
+            join_channel_response,
+            local_user_index_canister::join_channel::Response::Success(_)
+        ),
+        "{join_channel_response:?}"
+    );
+}
+
 #[test_case(true)]
 #[test_case(false)]
 fn owner_receives_transfer_after_user_joins_via_payment_gate(composite_gate: bool) {
@@ -330,3 +623,78 @@ fn owner_receives_transfer_after_user_joins_via_payment_gate(composite_gate: boo
 
     assert_eq!(balance - original_balance, (amount * 98) / 100);
 }
+
+#[test]
+fn owner_receives_transfer_when_lapsed_member_rejoins_via_payment_gate() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+
+    let original_balance = client::ledger::happy_path::balance_of(env, canister_ids.icp_ledger, Principal::from(user1.user_id));
+
+    let group_name = random_string();
+    let amount = 1_0000_0000;
+    let fee = 10_000;
+    let expiry = 24 * 60 * 60 * 1000;
+
+    let group_id = match client::user::create_group(
+        env,
+        user1.principal,
+        user1.user_id.into(),
+        &user_canister::create_group::Args {
+            is_public: true,
+            name: group_name.clone(),
+            description: format!("{group_name}_description"),
+            avatar: None,
+            history_visible_to_new_joiners: true,
+            permissions_v2: None,
+            rules: Rules::default(),
+            events_ttl: None,
+            gate_config: Some(AccessGateConfig {
+                gate: AccessGate::Payment(PaymentGate {
+                    ledger_canister_id: canister_ids.icp_ledger,
+                    amount,
+                    fee,
+                }),
+                expiry: Some(expiry),
+            }),
+            messages_visible_to_non_members: None,
+        },
+    ) {
+        user_canister::create_group::Response::Success(result) => result.chat_id,
+        response => panic!("'create_group' error: {response:?}"),
+    };
+
+    client::ledger::happy_path::transfer(env, *controller, canister_ids.icp_ledger, user2.user_id, 2 * amount);
+
+    for _ in 0..2 {
+        client::ledger::happy_path::approve(
+            env,
+            user2.user_id.into(),
+            canister_ids.icp_ledger,
+            Principal::from(group_id),
+            amount - fee,
+        );
+        client::group::happy_path::join_group(env, user2.principal, group_id);
+        tick_many(env, 3);
+
+        // Move the time forward so that the member lapses
+        env.advance_time(Duration::from_millis(2 * expiry));
+        tick_many(env, 5);
+
+        let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+        assert!(summary.membership.is_some_and(|m| m.lapsed));
+    }
+
+    let balance = client::ledger::happy_path::balance_of(env, canister_ids.icp_ledger, Principal::from(user1.user_id));
+
+    // The payment made when rejoining is distributed in the same way as the original payment
+    assert_eq!(balance - original_balance, 2 * ((amount * 98) / 100));
+}
@@ -1,14 +1,15 @@
 use crate::env::ENV;
-use crate::{client, CanisterIds, TestEnv};
+use crate::utils::tick_many;
+use crate::{client, CanisterIds, TestEnv, User};
 use candid::Principal;
-use constants::NANOS_PER_MILLISECOND;
-use pocket_ic::PocketIc;
+use constants::{DAY_IN_MS, NANOS_PER_MILLISECOND};
+use pocket_ic::{PocketIc, RejectResponse};
 use rand::random;
 use std::ops::Deref;
 use std::time::Duration;
 use test_case::test_case;
-use testing::rng::{random_internet_identity_principal, random_string};
-use types::{Delegation, Empty, SignedDelegation};
+use testing::rng::{random_from_u128, random_internet_identity_principal, random_string};
+use types::{Delegation, DelegationScope, Empty, MessageContentInitial, SignedDelegation, TextContent, UserId};
 
 #[test_case(false)]
 #[test_case(true)]
@@ -135,6 +136,535 @@ fn flag_ii_principal(is_ii_principal: bool) {
     assert_eq!(auth_principals_response.first().unwrap().is_ii_principal, is_ii_principal);
 }
 
+#[test]
+fn name_and_revoke_auth_principal() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let (auth_principal1, public_key1, delegation1) = sign_in_with_email(env, canister_ids);
+    let (auth_principal2, public_key2) = random_internet_identity_principal();
+
+    client::identity::happy_path::create_identity(
+        env,
+        auth_principal1,
+        canister_ids.identity,
+        public_key1.clone(),
+        random::<[u8; 32]>().to_vec(),
+        false,
+    );
+
+    client::identity::happy_path::initiate_identity_link(
+        env,
+        auth_principal2,
+        canister_ids.identity,
+        public_key2,
+        true,
+        auth_principal1,
+    );
+    client::identity::happy_path::approve_identity_link(
+        env,
+        auth_principal1,
+        canister_ids.identity,
+        delegation1,
+        public_key1,
+        auth_principal2,
+    );
+    let session_key2 = random::<[u8; 32]>().to_vec();
+    let delegation2 =
+        client::identity::happy_path::prepare_delegation(env, auth_principal2, canister_ids.identity, session_key2.clone());
+
+    let set_name_response = client::identity::set_auth_principal_name(
+        env,
+        auth_principal1,
+        canister_ids.identity,
+        &identity_canister::set_auth_principal_name::Args {
+            auth_principal: auth_principal2,
+            name: Some("Laptop".to_string()),
+        },
+    );
+    assert!(matches!(
+        set_name_response,
+        identity_canister::set_auth_principal_name::Response::Success
+    ));
+
+    let auth_principals = client::identity::happy_path::auth_principals(env, auth_principal2, canister_ids.identity);
+    let laptop = auth_principals.iter().find(|p| p.principal == auth_principal2).unwrap();
+    assert_eq!(laptop.name.as_deref(), Some("Laptop"));
+    assert!(laptop.is_current_identity);
+    assert!(laptop.created > 0);
+
+    let revoke_response = client::identity::revoke_auth_principal(
+        env,
+        auth_principal2,
+        canister_ids.identity,
+        &identity_canister::revoke_auth_principal::Args {
+            auth_principal: auth_principal2,
+        },
+    );
+    assert!(matches!(
+        revoke_response,
+        identity_canister::revoke_auth_principal::Response::CannotRevokeActivePrincipal
+    ));
+
+    let revoke_response = client::identity::revoke_auth_principal(
+        env,
+        auth_principal1,
+        canister_ids.identity,
+        &identity_canister::revoke_auth_principal::Args {
+            auth_principal: auth_principal2,
+        },
+    );
+    assert!(matches!(
+        revoke_response,
+        identity_canister::revoke_auth_principal::Response::Success(_)
+    ));
+
+    let response = client::identity::check_auth_principal(env, auth_principal2, canister_ids.identity, &Empty {});
+    assert!(matches!(
+        response,
+        identity_canister::check_auth_principal::Response::NotFound
+    ));
+
+    let auth_principals = client::identity::happy_path::auth_principals(env, auth_principal1, canister_ids.identity);
+    assert_eq!(auth_principals.len(), 1);
+
+    // The revoked session key can no longer be retrieved or signed, even via the remaining auth principal
+    let get_delegation_response = client::identity::get_delegation(
+        env,
+        auth_principal1,
+        canister_ids.identity,
+        &identity_canister::get_delegation::Args {
+            session_key: session_key2.clone(),
+            expiration: delegation2.expiration,
+        },
+    );
+    assert!(matches!(
+        get_delegation_response,
+        identity_canister::get_delegation::Response::NotFound
+    ));
+
+    let prepare_delegation_response = client::identity::prepare_delegation(
+        env,
+        auth_principal1,
+        canister_ids.identity,
+        &identity_canister::prepare_delegation::Args {
+            session_key: session_key2,
+            is_ii_principal: None,
+            max_time_to_live: None,
+        },
+    );
+    assert!(matches!(
+        prepare_delegation_response,
+        identity_canister::prepare_delegation::Response::NotFound
+    ));
+}
+
+#[test]
+fn recover_account_via_trusted_contacts() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let (owner_auth_principal, User { user_id: owner, .. }) = register_user_with_auth_principal(env, canister_ids);
+    let (contact1_auth_principal, User { user_id: contact1, .. }) = register_user_with_auth_principal(env, canister_ids);
+    let (contact2_auth_principal, User { user_id: contact2, .. }) = register_user_with_auth_principal(env, canister_ids);
+    let (_, User { user_id: contact3, .. }) = register_user_with_auth_principal(env, canister_ids);
+
+    let set_contacts_response = client::identity::set_recovery_contacts(
+        env,
+        owner_auth_principal,
+        canister_ids.identity,
+        &identity_canister::set_recovery_contacts::Args {
+            contacts: vec![contact1, contact2, contact3],
+            threshold: 2,
+        },
+    );
+    assert!(matches!(
+        set_contacts_response,
+        identity_canister::set_recovery_contacts::Response::Success
+    ));
+
+    let (new_auth_principal, new_public_key) = random_internet_identity_principal();
+    let initiate_response = client::identity::initiate_recovery(
+        env,
+        new_auth_principal,
+        canister_ids.identity,
+        &identity_canister::initiate_recovery::Args {
+            user_id: owner,
+            public_key: new_public_key,
+            webauthn_key: None,
+            is_ii_principal: Some(true),
+        },
+    );
+    let identity_canister::initiate_recovery::Response::Success(initiate_result) = initiate_response else {
+        panic!("{initiate_response:?}");
+    };
+    let request_id = initiate_result.request_id;
+
+    // Approvals which don't match the request's Id and new principal are rejected
+    let approve_response = client::identity::approve_recovery(
+        env,
+        contact1_auth_principal,
+        canister_ids.identity,
+        &identity_canister::approve_recovery::Args {
+            user_id: owner,
+            request_id,
+            new_principal: contact1_auth_principal,
+        },
+    );
+    assert!(matches!(
+        approve_response,
+        identity_canister::approve_recovery::Response::RequestMismatch
+    ));
+
+    let approve_response = client::identity::approve_recovery(
+        env,
+        contact1_auth_principal,
+        canister_ids.identity,
+        &identity_canister::approve_recovery::Args {
+            user_id: owner,
+            request_id,
+            new_principal: new_auth_principal,
+        },
+    );
+    let identity_canister::approve_recovery::Response::Success(result) = approve_response else {
+        panic!("{approve_response:?}");
+    };
+    assert_eq!(result.approvals, 1);
+    assert!(result.completable_from.is_none());
+
+    let complete_response = client::identity::complete_recovery(
+        env,
+        new_auth_principal,
+        canister_ids.identity,
+        &identity_canister::complete_recovery::Args { user_id: owner },
+    );
+    assert!(matches!(
+        complete_response,
+        identity_canister::complete_recovery::Response::InsufficientApprovals
+    ));
+
+    let approve_response = client::identity::approve_recovery(
+        env,
+        contact2_auth_principal,
+        canister_ids.identity,
+        &identity_canister::approve_recovery::Args {
+            user_id: owner,
+            request_id,
+            new_principal: new_auth_principal,
+        },
+    );
+    let identity_canister::approve_recovery::Response::Success(result) = approve_response else {
+        panic!("{approve_response:?}");
+    };
+    assert_eq!(result.approvals, 2);
+    assert!(result.completable_from.is_some());
+
+    let recovery_contacts = client::identity::recovery_contacts(env, owner_auth_principal, canister_ids.identity, &Empty {});
+    let identity_canister::recovery_contacts::Response::Success(recovery_contacts) = recovery_contacts else {
+        panic!("{recovery_contacts:?}");
+    };
+    assert_eq!(recovery_contacts.recovery_in_progress.unwrap().approvals.len(), 2);
+
+    let complete_response = client::identity::complete_recovery(
+        env,
+        new_auth_principal,
+        canister_ids.identity,
+        &identity_canister::complete_recovery::Args { user_id: owner },
+    );
+    assert!(matches!(
+        complete_response,
+        identity_canister::complete_recovery::Response::WaitingPeriodNotElapsed(_)
+    ));
+
+    env.advance_time(Duration::from_millis(3 * DAY_IN_MS + 1));
+    env.tick();
+
+    let complete_response = client::identity::complete_recovery(
+        env,
+        new_auth_principal,
+        canister_ids.identity,
+        &identity_canister::complete_recovery::Args { user_id: owner },
+    );
+    assert!(matches!(
+        complete_response,
+        identity_canister::complete_recovery::Response::Success
+    ));
+
+    let auth_principals = client::identity::happy_path::auth_principals(env, new_auth_principal, canister_ids.identity);
+    assert_eq!(auth_principals.len(), 2);
+    assert!(auth_principals.iter().any(|p| p.principal == owner_auth_principal));
+
+    // Once a recovery is cancelled, another can't be initiated for the same user until the cooldown has passed
+    let (other_auth_principal, other_public_key) = random_internet_identity_principal();
+    let initiate_response = client::identity::initiate_recovery(
+        env,
+        other_auth_principal,
+        canister_ids.identity,
+        &identity_canister::initiate_recovery::Args {
+            user_id: owner,
+            public_key: other_public_key,
+            webauthn_key: None,
+            is_ii_principal: Some(true),
+        },
+    );
+    assert!(matches!(
+        initiate_response,
+        identity_canister::initiate_recovery::Response::Success(_)
+    ));
+
+    let cancel_response = client::identity::cancel_recovery(env, owner_auth_principal, canister_ids.identity, &Empty {});
+    assert!(matches!(
+        cancel_response,
+        identity_canister::cancel_recovery::Response::Success
+    ));
+
+    let (another_auth_principal, another_public_key) = random_internet_identity_principal();
+    let initiate_response = client::identity::initiate_recovery(
+        env,
+        another_auth_principal,
+        canister_ids.identity,
+        &identity_canister::initiate_recovery::Args {
+            user_id: owner,
+            public_key: another_public_key,
+            webauthn_key: None,
+            is_ii_principal: Some(true),
+        },
+    );
+    assert!(matches!(
+        initiate_response,
+        identity_canister::initiate_recovery::Response::RateLimited(_)
+    ));
+}
+
+#[test]
+fn trusted_contact_can_reject_recovery_initiated_by_someone_else() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let (owner_auth_principal, User { user_id: owner, .. }) = register_user_with_auth_principal(env, canister_ids);
+    let (contact1_auth_principal, User { user_id: contact1, .. }) = register_user_with_auth_principal(env, canister_ids);
+    let (_, User { user_id: contact2, .. }) = register_user_with_auth_principal(env, canister_ids);
+    let (non_contact_auth_principal, _) = register_user_with_auth_principal(env, canister_ids);
+
+    client::identity::set_recovery_contacts(
+        env,
+        owner_auth_principal,
+        canister_ids.identity,
+        &identity_canister::set_recovery_contacts::Args {
+            contacts: vec![contact1, contact2],
+            threshold: 2,
+        },
+    );
+
+    // Someone other than the owner initiates a recovery, which blocks the owner from initiating one
+    let (attacker_auth_principal, attacker_public_key) = random_internet_identity_principal();
+    let initiate_response = client::identity::initiate_recovery(
+        env,
+        attacker_auth_principal,
+        canister_ids.identity,
+        &identity_canister::initiate_recovery::Args {
+            user_id: owner,
+            public_key: attacker_public_key,
+            webauthn_key: None,
+            is_ii_principal: Some(true),
+        },
+    );
+    let identity_canister::initiate_recovery::Response::Success(initiate_result) = initiate_response else {
+        panic!("{initiate_response:?}");
+    };
+
+    let (new_auth_principal, new_public_key) = random_internet_identity_principal();
+    let initiate_args = identity_canister::initiate_recovery::Args {
+        user_id: owner,
+        public_key: new_public_key,
+        webauthn_key: None,
+        is_ii_principal: Some(true),
+    };
+    let initiate_response = client::identity::initiate_recovery(env, new_auth_principal, canister_ids.identity, &initiate_args);
+    assert!(
+        !matches!(initiate_response, identity_canister::initiate_recovery::Response::Success(_)),
+        "{initiate_response:?}"
+    );
+
+    // Only trusted contacts can reject the request
+    let reject_args = identity_canister::reject_recovery::Args {
+        user_id: owner,
+        request_id: initiate_result.request_id,
+    };
+    let reject_response =
+        client::identity::reject_recovery(env, non_contact_auth_principal, canister_ids.identity, &reject_args);
+    assert!(matches!(
+        reject_response,
+        identity_canister::reject_recovery::Response::NotTrustedContact
+    ));
+
+    let reject_response = client::identity::reject_recovery(env, contact1_auth_principal, canister_ids.identity, &reject_args);
+    assert!(matches!(
+        reject_response,
+        identity_canister::reject_recovery::Response::Success
+    ));
+
+    // Once rejected, the owner can initiate their own recovery straight away
+    let initiate_response = client::identity::initiate_recovery(env, new_auth_principal, canister_ids.identity, &initiate_args);
+    assert!(
+        matches!(initiate_response, identity_canister::initiate_recovery::Response::Success(_)),
+        "{initiate_response:?}"
+    );
+}
+
+#[test]
+fn scoped_delegations_restricted_to_scope() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let (auth_principal, user) = register_user_with_auth_principal(env, canister_ids);
+    let recipient = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user, &random_string(), false, true);
+
+    let read_only_principal = prepare_scoped_delegation(env, auth_principal, canister_ids, DelegationScope::ReadOnly);
+    let send_messages_principal = prepare_scoped_delegation(env, auth_principal, canister_ids, DelegationScope::SendMessages);
+    tick_many(env, 5);
+
+    let scoped_delegations = client::identity::scoped_delegations(env, auth_principal, canister_ids.identity, &Empty {});
+    let identity_canister::scoped_delegations::Response::Success(scoped_delegations) = scoped_delegations else {
+        panic!("{scoped_delegations:?}");
+    };
+    assert_eq!(scoped_delegations.len(), 2);
+
+    let read_only_user = User {
+        principal: read_only_principal,
+        user_id: user.user_id,
+        public_key: Vec::new(),
+        local_user_index: user.local_user_index,
+    };
+    let send_messages_user = User {
+        principal: send_messages_principal,
+        user_id: user.user_id,
+        public_key: Vec::new(),
+        local_user_index: user.local_user_index,
+    };
+
+    // The read only principal can read but can't send messages
+    client::user::happy_path::initial_state(env, &read_only_user);
+    assert!(send_text_message(env, &read_only_user, recipient.user_id).is_err());
+
+    // The send messages principal can send messages but can't read
+    assert!(send_text_message(env, &send_messages_user, recipient.user_id).is_ok());
+    assert!(initial_state(env, &send_messages_user).is_err());
+
+    // The user's groups recognise the scoped principals too
+    client::group::happy_path::events(env, &read_only_user, group_id, 0.into(), true, 10, 100);
+    client::group::happy_path::send_text_message(env, &send_messages_user, group_id, None, random_string(), None);
+    let events_response = client::group::events(
+        env,
+        send_messages_principal,
+        group_id.into(),
+        &group_canister::events::Args {
+            thread_root_message_index: None,
+            start_index: 0.into(),
+            ascending: true,
+            max_messages: 10,
+            max_events: 100,
+            latest_known_update: None,
+        },
+    );
+    assert!(matches!(events_response, group_canister::events::Response::CallerNotInGroup));
+
+    let revoke_response = client::identity::revoke_scoped_delegation(
+        env,
+        auth_principal,
+        canister_ids.identity,
+        &identity_canister::revoke_scoped_delegation::Args {
+            principal: read_only_principal,
+        },
+    );
+    assert!(matches!(
+        revoke_response,
+        identity_canister::revoke_scoped_delegation::Response::Success
+    ));
+    tick_many(env, 5);
+
+    assert!(initial_state(env, &read_only_user).is_err());
+    client::user::happy_path::initial_state(env, &user);
+}
+
+fn prepare_scoped_delegation(
+    env: &mut PocketIc,
+    auth_principal: Principal,
+    canister_ids: &CanisterIds,
+    scope: DelegationScope,
+) -> Principal {
+    let response = client::identity::prepare_scoped_delegation(
+        env,
+        auth_principal,
+        canister_ids.identity,
+        &identity_canister::prepare_scoped_delegation::Args {
+            session_key: random::<[u8; 32]>().to_vec(),
+            name: random_string(),
+            scope,
+            max_time_to_live: None,
+        },
+    );
+    let identity_canister::prepare_scoped_delegation::Response::Success(result) = response else {
+        panic!("{response:?}");
+    };
+    result.principal
+}
+
+fn send_text_message(env: &mut PocketIc, sender: &User, recipient: UserId) -> Result<Vec<u8>, RejectResponse> {
+    env.update_call(
+        sender.canister(),
+        sender.principal,
+        "send_message_v2_msgpack",
+        msgpack::serialize_then_unwrap(&user_canister::send_message_v2::Args {
+            recipient,
+            thread_root_message_index: None,
+            message_id: random_from_u128(),
+            content: MessageContentInitial::Text(TextContent { text: random_string() }),
+            replies_to: None,
+            forwarding: false,
+            block_level_markdown: false,
+            message_filter_failed: None,
+            pin: None,
+            correlation_id: 0,
+        }),
+    )
+}
+
+fn initial_state(env: &PocketIc, sender: &User) -> Result<Vec<u8>, RejectResponse> {
+    env.query_call(
+        sender.canister(),
+        sender.principal,
+        "initial_state_msgpack",
+        msgpack::serialize_then_unwrap(&user_canister::initial_state::Args {}),
+    )
+}
+
+fn register_user_with_auth_principal(env: &mut PocketIc, canister_ids: &CanisterIds) -> (Principal, User) {
+    let (auth_principal, public_key) = random_internet_identity_principal();
+    let create_identity_result = client::identity::happy_path::create_identity(
+        env,
+        auth_principal,
+        canister_ids.identity,
+        public_key,
+        random::<[u8; 32]>().to_vec(),
+        true,
+    );
+
+    let local_user_index = client::user_index::happy_path::user_registration_canister(env, canister_ids.user_index);
+    let user = client::local_user_index::happy_path::register_user(
+        env,
+        Principal::self_authenticating(&create_identity_result.user_key),
+        local_user_index,
+        create_identity_result.user_key,
+    );
+
+    // Wait for the user index to sync the new user's userId to the identity canister
+    tick_many(env, 5);
+
+    (auth_principal, user)
+}
+
 fn sign_in_with_email(env: &mut PocketIc, canister_ids: &CanisterIds) -> (Principal, Vec<u8>, SignedDelegation) {
     let email = format!("{}@test.com", random_string());
     let session_key = random::<[u8; 32]>().to_vec();
@@ -2,11 +2,12 @@ use crate::env::ENV;
 use crate::utils::now_millis;
 use crate::{client, CanisterIds, TestEnv, User};
 use candid::Principal;
+use constants::DAY_IN_MS;
 use pocket_ic::PocketIc;
 use std::ops::Deref;
 use std::time::Duration;
 use testing::rng::random_string;
-use types::ChatId;
+use types::{ChatEvent, ChatId, Empty};
 
 #[test]
 fn join_public_group_succeeds() {
@@ -149,6 +150,188 @@ fn join_leave_group_triggers_correct_updates() {
     assert!(updates.unwrap().group_chats.removed.contains(&group_id));
 }
 
+#[test]
+fn join_private_group_with_approved_join_request_succeeds() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller, false);
+
+    let request_response = client::local_user_index::request_to_join_group(
+        env,
+        user2.principal,
+        canister_ids.local_user_index(env, group_id),
+        &local_user_index_canister::request_to_join_group::Args {
+            chat_id: group_id,
+            answer: Some("I'd like to help out".to_string()),
+            caller_username: user2.username(),
+        },
+    );
+
+    assert!(
+        matches!(
+            request_response,
+            local_user_index_canister::request_to_join_group::Response::Success
+        ),
+        "{request_response:?}",
+    );
+
+    let join_requests_response = client::group::join_requests(env, user1.principal, group_id.into(), &Empty {});
+
+    let group_canister::join_requests::Response::Success(result) = join_requests_response else {
+        panic!("'join_requests' error: {join_requests_response:?}");
+    };
+    assert_eq!(result.requests.len(), 1);
+    assert_eq!(result.requests[0].user_id, user2.user_id);
+    assert_eq!(result.requests[0].answer.as_deref(), Some("I'd like to help out"));
+
+    let approve_response = client::group::approve_join_request(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::approve_join_request::Args { user_id: user2.user_id },
+    );
+
+    assert!(
+        matches!(approve_response, group_canister::approve_join_request::Response::Success),
+        "{approve_response:?}",
+    );
+
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    env.tick();
+
+    let initial_state = client::user::happy_path::initial_state(env, &user2);
+
+    assert!(initial_state.group_chats.summaries.iter().any(|c| c.chat_id == group_id));
+}
+
+#[test]
+fn rejected_join_request_is_removed() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller, false);
+
+    client::local_user_index::request_to_join_group(
+        env,
+        user2.principal,
+        canister_ids.local_user_index(env, group_id),
+        &local_user_index_canister::request_to_join_group::Args {
+            chat_id: group_id,
+            answer: None,
+            caller_username: user2.username(),
+        },
+    );
+
+    let reject_response = client::group::reject_join_request(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::reject_join_request::Args { user_id: user2.user_id },
+    );
+
+    assert!(
+        matches!(reject_response, group_canister::reject_join_request::Response::Success),
+        "{reject_response:?}",
+    );
+
+    let join_requests_response = client::group::join_requests(env, user1.principal, group_id.into(), &Empty {});
+
+    let group_canister::join_requests::Response::Success(result) = join_requests_response else {
+        panic!("'join_requests' error: {join_requests_response:?}");
+    };
+    assert!(result.requests.is_empty());
+
+    // The user can't request to join again until the rejection cooldown has passed
+    let request_args = local_user_index_canister::request_to_join_group::Args {
+        chat_id: group_id,
+        answer: None,
+        caller_username: user2.username(),
+    };
+    let request_response = client::local_user_index::request_to_join_group(
+        env,
+        user2.principal,
+        canister_ids.local_user_index(env, group_id),
+        &request_args,
+    );
+
+    assert!(
+        matches!(
+            request_response,
+            local_user_index_canister::request_to_join_group::Response::RecentlyRejected(_)
+        ),
+        "{request_response:?}",
+    );
+
+    env.advance_time(Duration::from_millis(8 * DAY_IN_MS));
+
+    let request_response = client::local_user_index::request_to_join_group(
+        env,
+        user2.principal,
+        canister_ids.local_user_index(env, group_id),
+        &request_args,
+    );
+
+    assert!(
+        matches!(
+            request_response,
+            local_user_index_canister::request_to_join_group::Response::Success
+        ),
+        "{request_response:?}",
+    );
+}
+
+#[test]
+fn join_request_events_only_visible_to_approvers() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller, true);
+    let user3 = client::register_user(env, canister_ids);
+    client::group::happy_path::join_group(env, user3.principal, group_id);
+
+    client::local_user_index::request_to_join_group(
+        env,
+        user2.principal,
+        canister_ids.local_user_index(env, group_id),
+        &local_user_index_canister::request_to_join_group::Args {
+            chat_id: group_id,
+            answer: None,
+            caller_username: user2.username(),
+        },
+    );
+
+    let summary = client::group::happy_path::summary(env, user1.principal, group_id);
+
+    let owner_events = client::group::happy_path::events(env, &user1, group_id, summary.latest_event_index, false, 1, 1);
+    assert!(matches!(
+        owner_events.events.first().map(|e| &e.event),
+        Some(ChatEvent::JoinRequested(r)) if r.user_id == user2.user_id
+    ));
+
+    let member_events = client::group::happy_path::events(env, &user3, group_id, summary.latest_event_index, false, 1, 1);
+    assert!(matches!(
+        member_events.events.first().map(|e| &e.event),
+        Some(ChatEvent::Empty)
+    ));
+}
+
 fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal, public: bool) -> TestData {
     let user1 = client::register_diamond_user(env, canister_ids, controller);
     let user2 = client::register_user(env, canister_ids);
@@ -11,6 +11,7 @@ mod airdrop_bot_tests;
 mod batched_summary_and_event_tests;
 mod bot_tests;
 mod change_group_role_tests;
+mod chat_archive_tests;
 mod chit_tests;
 mod client;
 mod communities;
@@ -35,10 +36,13 @@ mod mentions_tests;
 mod message_activity_tests;
 mod notification_tests;
 mod p2p_swap_tests;
+mod personal_block_list_tests;
 mod pin_number_tests;
 // mod platform_moderator_tests;
 mod poll_tests;
+mod presence_tests;
 mod prize_message_tests;
+mod read_receipts_tests;
 mod register_user_tests;
 mod registry_tests;
 mod remove_from_group_tests;
@@ -52,6 +56,7 @@ mod storage;
 mod storage_tests;
 mod suspend_user_tests;
 mod tip_message_tests;
+mod typing_tests;
 mod update_group_tests;
 mod update_profile_tests;
 mod utils;
@@ -0,0 +1,84 @@
+use crate::env::ENV;
+use crate::utils::tick_many;
+use crate::{client, TestEnv};
+use std::ops::Deref;
+use testing::rng::{random_from_u128, random_string};
+use types::{ChatEvent, MessageContentInitial, TextContent};
+
+#[test]
+fn blocked_users_messages_and_mentions_hidden_in_groups() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+    client::group::happy_path::join_group(env, user3.principal, group_id);
+
+    client::user::happy_path::block_user(env, &user1, user2.user_id);
+    tick_many(env, 3);
+
+    let blocked_message = client::group::send_message_v2(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::send_message_v2::Args {
+            thread_root_message_index: None,
+            message_id: random_from_u128(),
+            content: MessageContentInitial::Text(TextContent {
+                text: format!("Hello @UserId({})", user1.user_id),
+            }),
+            sender_name: user2.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: vec![(&user1).into()],
+            forwarding: false,
+            block_level_markdown: false,
+            rules_accepted: None,
+            message_filter_failed: None,
+            new_achievement: false,
+            correlation_id: 0,
+        },
+    );
+    let group_canister::send_message_v2::Response::Success(blocked_message) = blocked_message else {
+        panic!("'send_message_v2' error: {blocked_message:?}");
+    };
+
+    let message_id = random_from_u128();
+    let message = client::group::happy_path::send_text_message(env, &user3, group_id, None, random_string(), Some(message_id));
+    client::group::happy_path::add_reaction(env, &user2, group_id, "1", message_id);
+
+    let summary = client::group::happy_path::summary(env, user1.principal, group_id);
+    assert!(summary.mentions.is_empty());
+
+    let events = client::group::happy_path::events(env, &user1, group_id, 0.into(), true, 10, 100);
+    assert!(!events.unauthorized.contains(&blocked_message.event_index));
+    assert!(events
+        .events
+        .iter()
+        .any(|e| e.index == blocked_message.event_index && matches!(e.event, ChatEvent::Empty)));
+
+    let ChatEvent::Message(m) = &events.events.iter().find(|e| e.index == message.event_index).unwrap().event else {
+        panic!();
+    };
+    assert!(m.reactions.is_empty());
+
+    let user3_events = client::group::happy_path::events(env, &user3, group_id, 0.into(), true, 10, 100);
+    assert!(user3_events.events.iter().any(|e| e.index == blocked_message.event_index));
+
+    client::user::happy_path::unblock_user(env, &user1, user2.user_id);
+    tick_many(env, 3);
+
+    let events = client::group::happy_path::events(env, &user1, group_id, 0.into(), true, 10, 100);
+    assert!(events
+        .events
+        .iter()
+        .any(|e| e.index == blocked_message.event_index && matches!(e.event, ChatEvent::Message(_))));
+}
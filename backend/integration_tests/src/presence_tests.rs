@@ -0,0 +1,69 @@
+use crate::env::ENV;
+use crate::{client, TestEnv};
+use candid::Principal;
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use types::{CustomStatus, EffectivePresence, OptionUpdate, PresenceStatus, PresenceVisibility};
+
+#[test]
+fn presence_only_visible_to_permitted_users() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+
+    // Only user1 marks themselves as online, so the other users' principals are unknown to the OnlineUsers canister
+    // until they are looked up, as would be the case when using a new device
+    client::online_users::happy_path::mark_as_online(env, user1.principal, canister_ids.online_users);
+
+    let set_presence_response = client::online_users::set_presence(
+        env,
+        user1.principal,
+        canister_ids.online_users,
+        &online_users_canister::set_presence::Args {
+            status: Some(PresenceStatus::DoNotDisturb),
+            custom_status: OptionUpdate::SetToSome(CustomStatus {
+                text: "In a meeting".to_string(),
+                emoji: Some("📅".to_string()),
+                expires_at: None,
+            }),
+            visibility: Some(PresenceVisibility::Contacts(vec![user2.user_id])),
+        },
+    );
+    assert!(matches!(
+        set_presence_response,
+        online_users_canister::set_presence::Response::Success
+    ));
+
+    let presence = |env: &PocketIc, sender: Principal| {
+        let online_users_canister::presence::Response::Success(users) = client::online_users::presence(
+            env,
+            sender,
+            canister_ids.online_users,
+            &online_users_canister::presence::Args {
+                user_ids: vec![user1.user_id],
+            },
+        );
+        users
+    };
+
+    let users = presence(env, user2.principal);
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].presence, EffectivePresence::DoNotDisturb);
+    assert_eq!(users[0].custom_status.as_ref().unwrap().text, "In a meeting");
+
+    assert!(presence(env, user3.principal).is_empty());
+
+    let last_online = client::online_users::last_online(
+        env,
+        user3.principal,
+        canister_ids.online_users,
+        &online_users_canister::last_online::Args {
+            user_ids: vec![user1.user_id],
+        },
+    );
+    let online_users_canister::last_online::Response::Success(users) = last_online;
+    assert!(users.is_empty());
+}
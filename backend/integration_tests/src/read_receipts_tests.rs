@@ -0,0 +1,156 @@
+use crate::env::ENV;
+use crate::utils::tick_many;
+use crate::{client, TestEnv, User};
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::random_string;
+use types::{ChatEvent, ChatId, MessageIndex, UserId};
+use user_canister::mark_read::ChatMessagesRead;
+
+#[test]
+fn read_receipts_shared_by_opted_in_members() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+    client::group::happy_path::join_group(env, user3.principal, group_id);
+
+    set_read_receipts_enabled(env, &user1, group_id, true);
+    // Give the group time to let the members' user canisters know that read receipts are enabled
+    tick_many(env, 3);
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert!(summary.read_receipts_enabled);
+
+    let events = client::group::happy_path::events(env, &user2, group_id, summary.latest_event_index, false, 1, 1);
+    assert!(matches!(
+        events.events.first().map(|e| &e.event),
+        Some(ChatEvent::ReadReceiptsChanged(c)) if c.enabled && c.changed_by == user1.user_id
+    ));
+
+    for _ in 0..3 {
+        client::group::happy_path::send_text_message(env, &user1, group_id, None, random_string(), None);
+    }
+
+    mark_read_up_to(env, &user2, group_id, 0.into(), true);
+    mark_read_up_to(env, &user3, group_id, 2.into(), false);
+
+    assert_eq!(message_read_by(env, &user1, group_id, 0.into()), vec![user2.user_id]);
+    assert!(message_read_by(env, &user1, group_id, 1.into()).is_empty());
+
+    // Marking messages as read via the user canister advances the position of members who have opted in
+    mark_read_via_user_canister(env, &user2, group_id, 2.into());
+    mark_read_via_user_canister(env, &user3, group_id, 2.into());
+    tick_many(env, 3);
+
+    assert_eq!(message_read_by(env, &user1, group_id, 2.into()), vec![user2.user_id]);
+
+    // Read positions are removed once a member leaves
+    client::user::happy_path::leave_group(env, &user2, group_id);
+    tick_many(env, 3);
+
+    assert!(message_read_by(env, &user1, group_id, 0.into()).is_empty());
+}
+
+#[test]
+fn read_receipts_cleared_when_disabled() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let response = client::group::mark_read_up_to(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::mark_read_up_to::Args {
+            read_up_to: 0.into(),
+            share_read_receipt: true,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::mark_read_up_to::Response::ReadReceiptsDisabled
+    ));
+
+    set_read_receipts_enabled(env, &user1, group_id, true);
+    client::group::happy_path::send_text_message(env, &user1, group_id, None, random_string(), None);
+    mark_read_up_to(env, &user2, group_id, 0.into(), true);
+    assert_eq!(message_read_by(env, &user1, group_id, 0.into()), vec![user2.user_id]);
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    env.advance_time(Duration::from_millis(1));
+    set_read_receipts_enabled(env, &user1, group_id, false);
+
+    let updates = client::group::happy_path::summary_updates(env, user2.principal, group_id, summary.last_updated).unwrap();
+    assert_eq!(updates.read_receipts_enabled, Some(false));
+
+    // Re-enabling read receipts doesn't restore the positions shared before they were disabled
+    set_read_receipts_enabled(env, &user1, group_id, true);
+    assert!(message_read_by(env, &user1, group_id, 0.into()).is_empty());
+}
+
+fn set_read_receipts_enabled(env: &mut PocketIc, user: &User, group_id: ChatId, enabled: bool) {
+    let response = client::group::set_read_receipts_enabled(
+        env,
+        user.principal,
+        group_id.into(),
+        &group_canister::set_read_receipts_enabled::Args { enabled },
+    );
+    assert!(matches!(
+        response,
+        group_canister::set_read_receipts_enabled::Response::Success
+    ));
+}
+
+fn mark_read_up_to(env: &mut PocketIc, user: &User, group_id: ChatId, read_up_to: MessageIndex, share_read_receipt: bool) {
+    let response = client::group::mark_read_up_to(
+        env,
+        user.principal,
+        group_id.into(),
+        &group_canister::mark_read_up_to::Args {
+            read_up_to,
+            share_read_receipt,
+        },
+    );
+    assert!(matches!(response, group_canister::mark_read_up_to::Response::Success));
+}
+
+fn mark_read_via_user_canister(env: &mut PocketIc, user: &User, group_id: ChatId, read_up_to: MessageIndex) {
+    client::user::mark_read(
+        env,
+        user.principal,
+        user.user_id.into(),
+        &user_canister::mark_read::Args {
+            messages_read: vec![ChatMessagesRead {
+                chat_id: group_id,
+                read_up_to: Some(read_up_to),
+                threads: Vec::new(),
+                date_read_pinned: None,
+            }],
+            community_messages_read: Vec::new(),
+        },
+    );
+}
+
+fn message_read_by(env: &PocketIc, user: &User, group_id: ChatId, message_index: MessageIndex) -> Vec<UserId> {
+    let response = client::group::message_read_by(
+        env,
+        user.principal,
+        group_id.into(),
+        &group_canister::message_read_by::Args { message_index },
+    );
+
+    match response {
+        group_canister::message_read_by::Response::Success(result) => result.read_by,
+        response => panic!("'message_read_by' error: {response:?}"),
+    }
+}
@@ -0,0 +1,193 @@
+use crate::env::ENV;
+use crate::{client, TestEnv};
+use candid::Principal;
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::random_string;
+use types::{ChannelId, ChatId, CommunityId, UserId};
+
+#[test]
+fn typing_in_direct_chat_visible_to_recipient_until_expired() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let online_users = canister_ids.online_users;
+
+    let set_typing_response = client::online_users::set_typing(
+        env,
+        user1.principal,
+        online_users,
+        &online_users_canister::set_typing::Args {
+            user_id: user2.user_id,
+            thread_root_message_index: None,
+            is_typing: true,
+        },
+    );
+    assert!(matches!(
+        set_typing_response,
+        online_users_canister::set_typing::Response::Success
+    ));
+
+    let is_typing = |env: &PocketIc, sender: Principal, other: UserId| {
+        let online_users_canister::typing::Response::Success(result) = client::online_users::typing(
+            env,
+            sender,
+            online_users,
+            &online_users_canister::typing::Args {
+                chats: vec![online_users_canister::typing::DirectChatOrThread {
+                    user_id: other,
+                    thread_root_message_index: None,
+                }],
+            },
+        );
+        !result.is_empty()
+    };
+
+    assert!(is_typing(env, user2.principal, user1.user_id));
+    assert!(!is_typing(env, user3.principal, user1.user_id));
+    assert!(!is_typing(env, user1.principal, user2.user_id));
+
+    env.advance_time(Duration::from_secs(6));
+
+    assert!(!is_typing(env, user2.principal, user1.user_id));
+}
+
+#[test]
+fn typing_in_group_only_visible_to_members() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), false, true);
+    client::local_user_index::happy_path::invite_users_to_group(
+        env,
+        &user1,
+        canister_ids.local_user_index(env, group_id),
+        group_id,
+        vec![user2.user_id],
+    );
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let set_typing = |env: &mut PocketIc, sender: Principal| {
+        client::group::set_typing(
+            env,
+            sender,
+            group_id.into(),
+            &group_canister::set_typing::Args {
+                thread_root_message_index: None,
+                is_typing: true,
+            },
+        )
+    };
+
+    assert!(matches!(
+        set_typing(env, user1.principal),
+        group_canister::set_typing::Response::Success
+    ));
+    assert!(matches!(
+        set_typing(env, user3.principal),
+        group_canister::set_typing::Response::CallerNotInGroup
+    ));
+
+    assert_eq!(group_typing(env, user2.principal, group_id), Some(vec![user1.user_id]));
+    assert_eq!(group_typing(env, user3.principal, group_id), None);
+    assert_eq!(group_typing(env, Principal::anonymous(), group_id), None);
+}
+
+#[test]
+fn typing_in_channel_only_visible_to_channel_members() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["general".to_string()]);
+    let channel_id = client::community::happy_path::create_channel(env, user1.principal, community_id, false, random_string());
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+    client::community::happy_path::join_community(env, user3.principal, community_id);
+    client::local_user_index::happy_path::invite_users_to_channel(
+        env,
+        &user1,
+        canister_ids.local_user_index(env, community_id),
+        community_id,
+        channel_id,
+        vec![user2.user_id],
+    );
+    client::community::happy_path::join_channel(env, user2.principal, community_id, channel_id);
+
+    let set_typing = |env: &mut PocketIc, sender: Principal| {
+        client::community::set_typing(
+            env,
+            sender,
+            community_id.into(),
+            &community_canister::set_typing::Args {
+                channel_id,
+                thread_root_message_index: None,
+                is_typing: true,
+            },
+        )
+    };
+
+    assert!(matches!(
+        set_typing(env, user1.principal),
+        community_canister::set_typing::Response::Success
+    ));
+    assert!(matches!(
+        set_typing(env, user3.principal),
+        community_canister::set_typing::Response::UserNotInChannel
+    ));
+
+    assert_eq!(
+        channel_typing(env, user2.principal, community_id, channel_id),
+        vec![user1.user_id]
+    );
+    assert!(channel_typing(env, user3.principal, community_id, channel_id).is_empty());
+}
+
+fn group_typing(env: &PocketIc, sender: Principal, group_id: ChatId) -> Option<Vec<UserId>> {
+    let response = client::group::typing(
+        env,
+        sender,
+        group_id.into(),
+        &group_canister::typing::Args {
+            thread_root_message_indexes: vec![None],
+        },
+    );
+
+    match response {
+        group_canister::typing::Response::Success(result) => Some(result.into_iter().flat_map(|r| r.user_ids).collect()),
+        group_canister::typing::Response::CallerNotInGroup => None,
+    }
+}
+
+fn channel_typing(env: &PocketIc, sender: Principal, community_id: CommunityId, channel_id: ChannelId) -> Vec<UserId> {
+    let response = client::community::typing(
+        env,
+        sender,
+        community_id.into(),
+        &community_canister::typing::Args {
+            channels: vec![community_canister::typing::ChannelOrThread {
+                channel_id,
+                thread_root_message_index: None,
+            }],
+        },
+    );
+
+    match response {
+        community_canister::typing::Response::Success(result) => result.into_iter().flat_map(|r| r.user_ids).collect(),
+        response => panic!("'typing' error: {response:?}"),
+    }
+}
@@ -5,7 +5,7 @@ use candid::Principal;
 use pocket_ic::PocketIc;
 use std::ops::Deref;
 use testing::rng::random_string;
-use types::{ChatId, OptionUpdate::*};
+use types::{ChatId, OptionUpdate::*, Tag};
 
 #[test]
 fn update_group_name_succeeds() {
@@ -36,6 +36,8 @@ fn update_group_name_succeeds() {
             correlation_id: 0,
             gate_config: NoChange,
             messages_visible_to_non_members: None,
+            tags: None,
+            languages: None,
         },
     );
 
@@ -80,6 +82,8 @@ fn change_casing_of_group_name_succeeds() {
             correlation_id: 0,
             gate_config: NoChange,
             messages_visible_to_non_members: None,
+            tags: None,
+            languages: None,
         },
     );
 
@@ -127,6 +131,8 @@ fn update_group_privacy_succeeds() {
             correlation_id: 0,
             gate_config: NoChange,
             messages_visible_to_non_members: None,
+            tags: None,
+            languages: None,
         },
     );
 
@@ -176,6 +182,8 @@ fn make_private_group_public_succeeds() {
             public: Some(true),
             correlation_id: 0,
             messages_visible_to_non_members: None,
+            tags: None,
+            languages: None,
         },
     );
 
@@ -188,6 +196,136 @@ fn make_private_group_public_succeeds() {
     assert_eq!(group_summary.min_visible_message_index, 5.into());
 }
 
+#[test]
+fn owner_can_set_tags_and_languages_for_discovery() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller, &random_string());
+
+    let args = group_canister::update_group_v2::Args {
+        tags: Some(vec![Tag::Music, Tag::Art, Tag::Music]),
+        languages: Some(vec!["EN".to_string()]),
+        ..Default::default()
+    };
+
+    // Only owners can set the tags
+    let response = client::group::update_group_v2(env, user2.principal, group_id.into(), &args);
+    assert!(matches!(response, group_canister::update_group_v2::Response::NotAuthorized));
+
+    client::group::happy_path::update_group(env, user1.principal, group_id, &args);
+
+    tick_many(env, 3);
+
+    let response = client::group_index::explore_groups(
+        env,
+        user2.principal,
+        canister_ids.group_index,
+        &group_index_canister::explore_groups::Args {
+            search_term: None,
+            tags: Some(vec![Tag::Music]),
+            languages: Some(vec!["en".to_string()]),
+            page_index: 0,
+            page_size: 50,
+        },
+    );
+
+    let group_index_canister::explore_groups::Response::Success(result) = response else {
+        panic!("'explore_groups' error: {response:?}");
+    };
+
+    let group = result.matches.iter().find(|m| m.id == group_id).expect("Group not found");
+    assert_eq!(group.tags, vec![Tag::Art, Tag::Music]);
+    assert_eq!(group.languages, vec!["en".to_string()]);
+    assert!(result.matches.iter().all(|m| m.tags.contains(&Tag::Music)));
+    assert!(result.tag_counts.iter().any(|c| c.tag == Tag::Art && c.count >= 1));
+}
+
+#[test]
+fn tags_and_languages_validated_and_retained_while_private() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, group_id, .. } = init_test_data(env, canister_ids, *controller, &random_string());
+
+    let too_many_tags = vec![Tag::Art, Tag::Bitcoin, Tag::Crypto, Tag::DeFi, Tag::Education, Tag::Gaming];
+    let response = client::group::update_group_v2(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::update_group_v2::Args {
+            tags: Some(too_many_tags),
+            ..Default::default()
+        },
+    );
+    assert!(matches!(response, group_canister::update_group_v2::Response::TooManyTags(5)));
+
+    let response = client::group::update_group_v2(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::update_group_v2::Args {
+            languages: Some(vec!["english".to_string()]),
+            ..Default::default()
+        },
+    );
+    assert!(matches!(response, group_canister::update_group_v2::Response::InvalidLanguage));
+
+    client::group::happy_path::update_group(
+        env,
+        user1.principal,
+        group_id,
+        &group_canister::update_group_v2::Args {
+            tags: Some(vec![Tag::Music]),
+            languages: Some(vec!["fr".to_string()]),
+            ..Default::default()
+        },
+    );
+
+    for public in [false, true] {
+        client::group::happy_path::update_group(
+            env,
+            user1.principal,
+            group_id,
+            &group_canister::update_group_v2::Args {
+                public: Some(public),
+                ..Default::default()
+            },
+        );
+    }
+
+    let response = client::group_index::explore_groups(
+        env,
+        user1.principal,
+        canister_ids.group_index,
+        &group_index_canister::explore_groups::Args {
+            search_term: None,
+            tags: None,
+            languages: Some(vec!["fr".to_string()]),
+            page_index: 0,
+            page_size: 50,
+        },
+    );
+
+    let group_index_canister::explore_groups::Response::Success(result) = response else {
+        panic!("'explore_groups' error: {response:?}");
+    };
+
+    let group = result.matches.iter().find(|m| m.id == group_id).expect("Group not found");
+    assert_eq!(group.tags, vec![Tag::Music]);
+    assert_eq!(group.languages, vec!["fr".to_string()]);
+}
+
 fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal, group_name: &str) -> TestData {
     let user1 = client::register_diamond_user(env, canister_ids, controller);
     let user2 = client::register_user(env, canister_ids);
@@ -29,6 +29,7 @@ lazy_static! {
     pub static ref SNS_WASM: CanisterWasm = get_canister_wasm("sns_wasm");
     pub static ref STORAGE_BUCKET: CanisterWasm = get_canister_wasm("storage_bucket");
     pub static ref STORAGE_INDEX: CanisterWasm = get_canister_wasm("storage_index");
+    pub static ref TEST_ICRC7_LEDGER: CanisterWasm = get_canister_wasm("test_icrc7_ledger");
     pub static ref TRANSLATIONS: CanisterWasm = get_canister_wasm("translations");
     pub static ref USER: CanisterWasm = get_canister_wasm("user");
     pub static ref USER_INDEX: CanisterWasm = get_canister_wasm("user_index");
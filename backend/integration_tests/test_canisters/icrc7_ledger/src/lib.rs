@@ -0,0 +1,49 @@
+// A minimal ICRC-7 collection used as a stand-in ledger by the integration tests. It only
+// implements the endpoints needed to check NFT access gates, plus an unrestricted `mint`.
+use candid::{CandidType, Nat};
+use ic_cdk::{query, update};
+use icrc_ledger_types::icrc1::account::Account;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+thread_local! {
+    static OWNERS: RefCell<BTreeMap<u128, Account>> = RefCell::default();
+}
+
+#[derive(CandidType, Deserialize)]
+struct MintArgs {
+    token_id: u128,
+    owner: Account,
+}
+
+#[update]
+fn mint(args: MintArgs) -> u128 {
+    OWNERS.with_borrow_mut(|owners| owners.insert(args.token_id, args.owner));
+    args.token_id
+}
+
+#[query]
+fn icrc7_balance_of(accounts: Vec<Account>) -> Vec<Nat> {
+    OWNERS.with_borrow(|owners| {
+        accounts
+            .iter()
+            .map(|account| owners.values().filter(|o| *o == account).count().into())
+            .collect()
+    })
+}
+
+#[query]
+fn icrc7_tokens_of(account: Account, prev: Option<Nat>, take: Option<Nat>) -> Vec<Nat> {
+    let prev: Option<u128> = prev.and_then(|p| p.0.try_into().ok());
+    let take: usize = take.and_then(|t| t.0.try_into().ok()).unwrap_or(100);
+
+    OWNERS.with_borrow(|owners| {
+        owners
+            .iter()
+            .filter(|(id, owner)| **owner == account && prev.is_none_or(|p| **id > p))
+            .take(take)
+            .map(|(id, _)| Nat::from(*id))
+            .collect()
+    })
+}
@@ -8,9 +8,10 @@ use types::{
     ChannelId, Chat, ChatEventType, ChatId, CommunityId, DeletedBy, DirectChatCreated, EventIndex, EventWrapperInternal,
     EventsTimeToLiveUpdated, ExternalUrlUpdated, GroupCreated, GroupDescriptionChanged, GroupFrozen, GroupGateUpdated,
     GroupInviteCodeChanged, GroupNameChanged, GroupReplyContext, GroupRulesChanged, GroupUnfrozen, GroupVisibilityChanged,
-    MemberJoinedInternal, MemberLeft, MembersAdded, MembersAddedToDefaultChannel, MembersRemoved, Message, MessageContent,
-    MessageId, MessageIndex, MessagePinned, MessageUnpinned, MultiUserChat, PermissionsChanged, PushIfNotContains, Reaction,
-    ReplyContext, RoleChanged, ThreadSummary, TimestampMillis, Tips, UserId, UsersBlocked, UsersInvited, UsersUnblocked,
+    JoinRequestRejected, JoinRequested, MemberJoinedInternal, MemberLeft, MembersAdded, MembersAddedToDefaultChannel,
+    MembersRemoved, Message, MessageContent, MessageId, MessageIndex, MessagePinned, MessageUnpinned, MessageVersion,
+    MultiUserChat, PermissionsChanged, PushIfNotContains, Reaction, ReadReceiptsChanged, ReplyContext, RoleChanged,
+    ThreadSummary, TimestampMillis, Tips, UserId, UsersBlocked, UsersInvited, UsersUnblocked,
 };
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -73,6 +74,12 @@ pub enum ChatEventInternal {
     BotRemoved(Box<BotRemoved>),
     #[serde(rename = "bu")]
     BotUpdated(Box<BotUpdated>),
+    #[serde(rename = "jr")]
+    JoinRequested(Box<JoinRequested>),
+    #[serde(rename = "jrr")]
+    JoinRequestRejected(Box<JoinRequestRejected>),
+    #[serde(rename = "rrc")]
+    ReadReceiptsChanged(Box<ReadReceiptsChanged>),
     #[serde(rename = "e")]
     Empty,
     // This should never happen!
@@ -123,6 +130,9 @@ impl ChatEventInternal {
                 | ChatEventInternal::BotAdded(_)
                 | ChatEventInternal::BotRemoved(_)
                 | ChatEventInternal::BotUpdated(_)
+                | ChatEventInternal::JoinRequested(_)
+                | ChatEventInternal::JoinRequestRejected(_)
+                | ChatEventInternal::ReadReceiptsChanged(_)
         )
     }
 
@@ -168,7 +178,8 @@ impl ChatEventInternal {
             | ChatEventInternal::ChatUnfrozen(_)
             | ChatEventInternal::EventsTimeToLiveUpdated(_)
             | ChatEventInternal::GroupGateUpdated(_)
-            | ChatEventInternal::ExternalUrlUpdated(_) => Some(ChatEventType::ChatDetailsUpdate),
+            | ChatEventInternal::ExternalUrlUpdated(_)
+            | ChatEventInternal::ReadReceiptsChanged(_) => Some(ChatEventType::ChatDetailsUpdate),
             ChatEventInternal::ParticipantsAdded(_)
             | ChatEventInternal::ParticipantsRemoved(_)
             | ChatEventInternal::ParticipantJoined(_)
@@ -180,7 +191,9 @@ impl ChatEventInternal {
             | ChatEventInternal::MembersAddedToPublicChannel(_)
             | ChatEventInternal::BotAdded(_)
             | ChatEventInternal::BotRemoved(_)
-            | ChatEventInternal::BotUpdated(_) => Some(ChatEventType::MembershipUpdate),
+            | ChatEventInternal::BotUpdated(_)
+            | ChatEventInternal::JoinRequested(_)
+            | ChatEventInternal::JoinRequestRejected(_) => Some(ChatEventType::MembershipUpdate),
             ChatEventInternal::Empty | ChatEventInternal::FailedToDeserialize => None,
         }
     }
@@ -238,6 +251,8 @@ pub struct MessageInternal {
     pub forwarded: bool,
     #[serde(rename = "b", default, skip_serializing_if = "is_default")]
     pub block_level_markdown: bool,
+    #[serde(rename = "h", default, skip_serializing_if = "Vec::is_empty")]
+    pub edit_history: Vec<MessageVersionInternal>,
 }
 
 impl MessageInternal {
@@ -386,6 +401,29 @@ impl From<&MembersAddedToPublicChannelInternal> for MembersAddedToDefaultChannel
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MessageVersionInternal {
+    #[serde(rename = "c")]
+    pub content: MessageContentInternal,
+    #[serde(rename = "b", default, skip_serializing_if = "is_default")]
+    pub block_level_markdown: bool,
+    #[serde(rename = "t")]
+    pub timestamp: TimestampMillis,
+    #[serde(rename = "r")]
+    pub replaced_at: TimestampMillis,
+}
+
+impl MessageVersionInternal {
+    pub fn hydrate(self, my_user_id: Option<UserId>) -> MessageVersion {
+        MessageVersion {
+            content: self.content.hydrate(my_user_id),
+            block_level_markdown: self.block_level_markdown,
+            timestamp: self.timestamp,
+            replaced_at: self.replaced_at,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ThreadSummaryInternal {
     #[serde(rename = "p")]
@@ -533,6 +571,7 @@ mod tests {
             thread_summary: None,
             forwarded: false,
             block_level_markdown: false,
+            edit_history: Vec::new(),
         };
 
         let message_bytes_len = msgpack::serialize_then_unwrap(&message).len();
@@ -12,15 +12,15 @@ use search::simple::{Document, Query};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use sha2::{Digest, Sha256};
-use std::cmp::max;
+use std::cmp::{max, Reverse};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::{BTreeMap, HashSet};
 use std::mem;
 use std::ops::DerefMut;
 use tracing::{error, info};
 use types::{
-    AcceptP2PSwapResult, BlobReference, BotMessageContext, CallParticipant, CancelP2PSwapResult, CanisterId, Chat,
-    ChatEventType, ChatType, CompleteP2PSwapResult, CompletedCryptoTransaction, Cryptocurrency, DirectChatCreated,
+    AcceptP2PSwapResult, ArchivedEvent, BlobReference, BotMessageContext, CallParticipant, CancelP2PSwapResult, CanisterId,
+    Chat, ChatEventType, ChatType, CompleteP2PSwapResult, CompletedCryptoTransaction, Cryptocurrency, DirectChatCreated,
     EventContext, EventIndex, EventMetaData, EventWrapper, EventWrapperInternal, EventsTimeToLiveUpdated,
     GroupCanisterThreadDetails, GroupCreated, GroupFrozen, GroupUnfrozen, Hash, HydratedMention, Mention, Message,
     MessageEditedEventPayload, MessageEventPayload, MessageId, MessageIndex, MessageMatch, MessageReport,
@@ -31,6 +31,12 @@ use types::{
     VoteOperation,
 };
 
+// Only the most recent previous versions of each edited message are retained
+const MAX_EDIT_HISTORY_LENGTH: usize = 20;
+
+// The maximum number of messages scanned by each call to `search_messages_in_range`
+const MAX_MESSAGES_SCANNED: usize = 10_000;
+
 #[derive(Serialize, Deserialize)]
 pub struct ChatEvents {
     chat: Chat,
@@ -150,6 +156,75 @@ impl ChatEvents {
         stable_memory::read_events_as_bytes(self.chat, after, ONE_MB as usize)
     }
 
+    // Returns up to `max_events` events starting after `after`, iterating over the main events list
+    // first and then each thread in order of thread root message index
+    pub fn export_events(
+        &self,
+        after: Option<EventContext>,
+        max_events: usize,
+        my_user_id: Option<UserId>,
+    ) -> Vec<ArchivedEvent> {
+        let mut events = Vec::new();
+
+        let (first_thread, start) = match after {
+            Some(context) => (context.thread_root_message_index, Some(context.event_index.incr())),
+            None => (None, None),
+        };
+
+        if first_thread.is_none() {
+            self.export_events_from_list(&self.main, None, start, max_events, my_user_id, &mut events);
+        }
+
+        let threads = match first_thread {
+            Some(root_message_index) => self.threads.range(root_message_index..),
+            None => self.threads.range(..),
+        };
+
+        for (root_message_index, events_list) in threads {
+            if events.len() >= max_events {
+                break;
+            }
+            let start = if Some(*root_message_index) == first_thread { start } else { None };
+            self.export_events_from_list(
+                events_list,
+                Some(*root_message_index),
+                start,
+                max_events,
+                my_user_id,
+                &mut events,
+            );
+        }
+
+        events
+    }
+
+    fn export_events_from_list(
+        &self,
+        events_list: &ChatEventsList,
+        thread_root_message_index: Option<MessageIndex>,
+        start: Option<EventIndex>,
+        max_events: usize,
+        my_user_id: Option<UserId>,
+        events: &mut Vec<ArchivedEvent>,
+    ) {
+        if start.is_some_and(|s| events_list.latest_event_index().is_none_or(|latest| s > latest)) {
+            return;
+        }
+
+        let reader = ChatEventsListReader::new(events_list, &self.last_updated_timestamps);
+        let remaining = max_events.saturating_sub(events.len());
+
+        events.extend(
+            reader
+                .iter_events(start.map(EventKey::from), true)
+                .take(remaining)
+                .map(|e| ArchivedEvent {
+                    thread_root_message_index,
+                    event: reader.hydrate_event(e, my_user_id),
+                }),
+        );
+    }
+
     pub fn iter_recently_updated_events(
         &self,
     ) -> impl Iterator<Item = (Option<MessageIndex>, EventIndex, TimestampMillis)> + '_ {
@@ -220,6 +295,7 @@ impl ChatEvents {
             thread_summary: None,
             forwarded: args.forwarded,
             block_level_markdown: args.block_level_markdown,
+            edit_history: Vec::new(),
         };
 
         add_to_metrics(
@@ -340,12 +416,22 @@ impl ChatEvents {
                 || block_level_markdown_update.is_some();
 
             let old_length = message.content.text_length();
-            message.content = args.content;
+            let previous_content = mem::replace(&mut message.content, args.content);
 
             let message_index = message.message_index;
             let document = Document::from(&message.content);
 
             if edited {
+                if message.edit_history.len() >= MAX_EDIT_HISTORY_LENGTH {
+                    message.edit_history.remove(0);
+                }
+                message.edit_history.push(MessageVersionInternal {
+                    content: previous_content,
+                    block_level_markdown: message.block_level_markdown,
+                    timestamp: message.last_edited.unwrap_or(event.timestamp),
+                    replaced_at: args.now,
+                });
+
                 if let Some(block_level_markdown) = block_level_markdown_update {
                     message.block_level_markdown = block_level_markdown;
                 }
@@ -555,6 +641,7 @@ impl ChatEvents {
 
         let content = std::mem::replace(&mut message.content, MessageContentInternal::Deleted(deleted_by));
         let sender = message.sender;
+        message.edit_history.clear();
 
         Ok((content, sender))
     }
@@ -1716,6 +1803,73 @@ impl ChatEvents {
             .collect()
     }
 
+    // Returns up to `max_results` of the messages sent within the given time range which match the query, along with
+    // their timestamps and scores, ranked by score and then by recency. To bound the cost of each search, at most
+    // `MAX_MESSAGES_SCANNED` messages are scanned, starting from the most recent message sent at or before `to`.
+    pub fn search_messages_in_range(
+        &self,
+        min_visible_message_index: MessageIndex,
+        query: Query,
+        users: HashSet<UserId>,
+        from: Option<TimestampMillis>,
+        to: Option<TimestampMillis>,
+        max_results: u8,
+    ) -> Vec<(MessageInternal, TimestampMillis, u32)> {
+        let start = from.map_or(0, |from| self.message_partition_point(|ts| ts < from));
+        let end = to.map_or_else(
+            || self.main.latest_message_index().map_or(0, |i| u32::from(i) + 1),
+            |to| self.message_partition_point(|ts| ts <= to),
+        );
+        let start = MessageIndex::from(start.max(min_visible_message_index.into()));
+        let end = MessageIndex::from(end);
+
+        if start >= end {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<_> = self
+            .search_index
+            .search_messages_with_scores(start..end, query, users, MAX_MESSAGES_SCANNED)
+            .collect();
+
+        matches.sort_unstable_by_key(|(message_index, score)| (Reverse(*score), Reverse(*message_index)));
+
+        matches
+            .into_iter()
+            .filter_map(|(message_index, score)| {
+                self.main
+                    .get_event(message_index.into(), EventIndex::default(), None)
+                    .and_then(|e| e.event.into_message().map(|m| (m, e.timestamp, score)))
+            })
+            .take(max_results as usize)
+            .collect()
+    }
+
+    // Messages are ordered by timestamp, so this binary searches for the number of messages whose timestamps satisfy
+    // `predicate`. Messages which have expired are the oldest, so they are counted as satisfying the predicate.
+    fn message_partition_point<F: Fn(TimestampMillis) -> bool>(&self, predicate: F) -> u32 {
+        let Some(latest_message_index) = self.main.latest_message_index() else {
+            return 0;
+        };
+
+        let mut low = 0;
+        let mut high = u32::from(latest_message_index) + 1;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let satisfied = self
+                .main
+                .get_event(MessageIndex::from(mid).into(), EventIndex::default(), None)
+                .map_or(true, |e| predicate(e.timestamp));
+
+            if satisfied {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
     pub fn push_main_event(&mut self, event: ChatEventInternal, correlation_id: u64, now: TimestampMillis) -> PushEventResult {
         self.push_event(None, event, correlation_id, now)
     }
@@ -1765,6 +1919,10 @@ impl ChatEvents {
             .filter(|m| if let Some(since) = if_updated_since { m.last_active > since } else { true })
     }
 
+    pub fn iter_user_metrics(&self) -> impl Iterator<Item = (&UserId, &ChatMetricsInternal)> {
+        self.per_user_metrics.iter()
+    }
+
     pub fn event_count_since<F: Fn(&ChatEventInternal) -> bool>(&self, since: TimestampMillis, filter: F) -> usize {
         self.main.event_count_since(since, &filter)
             + self
@@ -524,6 +524,9 @@ pub trait Reader {
             ChatEventInternal::BotAdded(e) => ChatEvent::BotAdded(e),
             ChatEventInternal::BotRemoved(e) => ChatEvent::BotRemoved(e),
             ChatEventInternal::BotUpdated(e) => ChatEvent::BotUpdated(e),
+            ChatEventInternal::JoinRequested(e) => ChatEvent::JoinRequested(*e),
+            ChatEventInternal::JoinRequestRejected(e) => ChatEvent::JoinRequestRejected(*e),
+            ChatEventInternal::ReadReceiptsChanged(e) => ChatEvent::ReadReceiptsChanged(*e),
         };
 
         EventWrapper {
@@ -177,6 +177,20 @@ impl MessageContentInternal {
         }
     }
 
+    // Only content types which don't involve transfers, votes or other state owned by the original
+    // chat can be replayed from a chat archive
+    pub fn from_archived(content: MessageContent) -> Option<MessageContentInternal> {
+        match content {
+            MessageContent::Text(t) => Some(MessageContentInternal::Text(t.into())),
+            MessageContent::Image(i) => Some(MessageContentInternal::Image(i.into())),
+            MessageContent::Video(v) => Some(MessageContentInternal::Video(v.into())),
+            MessageContent::Audio(a) => Some(MessageContentInternal::Audio(a.into())),
+            MessageContent::File(f) => Some(MessageContentInternal::File(f.into())),
+            MessageContent::Giphy(g) => Some(MessageContentInternal::Giphy(g.into())),
+            _ => None,
+        }
+    }
+
     pub fn hydrate(self, my_user_id: Option<UserId>) -> MessageContent {
         match self {
             MessageContentInternal::Text(t) => MessageContent::Text(t.hydrate(my_user_id)),
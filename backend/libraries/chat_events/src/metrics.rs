@@ -162,6 +162,25 @@ impl ChatMetricsInternal {
         }
     }
 
+    pub fn message_count(&self) -> u32 {
+        [
+            MetricKey::TextMessages,
+            MetricKey::ImageMessages,
+            MetricKey::VideoMessages,
+            MetricKey::AudioMessages,
+            MetricKey::FileMessages,
+            MetricKey::Polls,
+            MetricKey::CryptoMessages,
+            MetricKey::GiphyMessages,
+            MetricKey::PrizeMessages,
+            MetricKey::P2pSwaps,
+            MetricKey::CustomTypeMessages,
+        ]
+        .into_iter()
+        .map(|key| self.get(key))
+        .sum()
+    }
+
     fn get(&self, key: MetricKey) -> u32 {
         self.metrics.iter().find(|m| m.key() == key).map_or(0, |m| m.count())
     }
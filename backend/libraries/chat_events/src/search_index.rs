@@ -3,6 +3,7 @@ use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::{BTreeMap, HashSet};
 use std::fmt::Formatter;
+use std::ops::RangeBounds;
 use types::{MessageIndex, UserId};
 
 #[derive(Serialize, Deserialize, Default)]
@@ -34,6 +35,26 @@ impl SearchIndex {
             })
             .map(|(id, _)| *id)
     }
+
+    // Scans at most `max_scanned` messages within the range, most recent first, returning those which match along
+    // with their scores. If there is no search term then each message from the given users scores 1.
+    pub fn search_messages_with_scores(
+        &self,
+        range: impl RangeBounds<MessageIndex>,
+        query: Query,
+        users: HashSet<UserId>,
+        max_scanned: usize,
+    ) -> impl Iterator<Item = (MessageIndex, u32)> + '_ {
+        self.map
+            .range(range)
+            .rev()
+            .take(max_scanned)
+            .filter(move |(_, (sender, _))| users.is_empty() || users.contains(sender))
+            .filter_map(move |(id, (_, doc))| {
+                let score = if query.tokens.is_empty() { 1 } else { doc.score(&query) };
+                (score > 0).then_some((*id, score))
+            })
+    }
 }
 
 struct SearchIndexVisitor;
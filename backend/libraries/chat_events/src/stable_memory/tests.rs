@@ -8,9 +8,10 @@ use crate::{
     AudioContentInternal, BlobReferenceInternal, CallParticipantInternal, ChatEventInternal, ChatInternal,
     CompletedCryptoTransactionInternal, CryptoContentInternal, CustomContentInternal, DeletedByInternal, FileContentInternal,
     GiphyContentInternal, GiphyImageVariantInternal, ImageContentInternal, MessageContentInternal, MessageInternal,
-    MessageReminderContentInternal, MessageReminderCreatedContentInternal, P2PSwapContentInternal, PollConfigInternal,
-    PollContentInternal, PrizeContentInternal, PrizeWinnerContentInternal, ProposalContentInternal, ReplyContextInternal,
-    ReportedMessageInternal, TextContentInternal, ThreadSummaryInternal, VideoCallContentInternal, VideoContentInternal,
+    MessageReminderContentInternal, MessageReminderCreatedContentInternal, MessageVersionInternal, P2PSwapContentInternal,
+    PollConfigInternal, PollContentInternal, PrizeContentInternal, PrizeWinnerContentInternal, ProposalContentInternal,
+    ReplyContextInternal, ReportedMessageInternal, TextContentInternal, ThreadSummaryInternal, VideoCallContentInternal,
+    VideoContentInternal,
 };
 use rand::random;
 use testing::rng::{random_from_principal, random_from_u128, random_from_u32, random_principal, random_string};
@@ -487,6 +488,12 @@ fn generate_value(content: MessageContentInternal) -> EventWrapperInternal<ChatE
             forwarded: true,
             block_level_markdown: true,
             bot_context: None,
+            edit_history: vec![MessageVersionInternal {
+                content: MessageContentInternal::Text(TextContentInternal { text: random_string() }),
+                block_level_markdown: true,
+                timestamp: random(),
+                replaced_at: random(),
+            }],
         })),
     }
 }
@@ -47,6 +47,7 @@ pub const MEMO_PRIZE_FEE: [u8; 9] = [0x4f, 0x43, 0x5f, 0x50, 0x52, 0x5a, 0x46, 0
 pub const MEMO_SWAP: [u8; 7] = [0x4F, 0x43, 0x5F, 0x53, 0x57, 0x41, 0x50]; // OC_SWAP
 pub const MEMO_SWAP_APPROVAL: [u8; 8] = [0x4F, 0x43, 0x5F, 0x53, 0x57, 0x41, 0x50, 0x41]; // OC_SWAPA
 pub const MEMO_JOINING_FEE: [u8; 7] = [0x4f, 0x43, 0x5f, 0x4A, 0x4F, 0x49, 0x4E]; // OC_JOIN
+pub const MEMO_SUBSCRIPTION_FEE: [u8; 7] = [0x4f, 0x43, 0x5f, 0x53, 0x55, 0x42, 0x53]; // OC_SUBS
 pub const MEMO_P2P_SWAP_CREATE: [u8; 8] = [0x4f, 0x43, 0x5f, 0x50, 0x32, 0x50, 0x53, 0x43]; // OC_P2PSC
 pub const MEMO_P2P_SWAP_ACCEPT: [u8; 8] = [0x4f, 0x43, 0x5f, 0x50, 0x32, 0x50, 0x53, 0x41]; // OC_P2PSA
 pub const MEMO_TRANSLATION_PAYMENT: [u8; 7] = [0x4f, 0x43, 0x5f, 0x54, 0x52, 0x41, 0x4e]; // OC_TRAN
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use index_store::IndexStore;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use types::{CanisterId, Error};
+
+// Stores the notification indexes processed up to in a local JSON file so that the notification
+// pusher can resume where it left off after a restart without relying on any external services.
+// Each write goes to a temporary file which is then renamed over the original, so the file on
+// disk is never left in a partially written state.
+#[derive(Clone)]
+pub struct FileIndexStore {
+    path: PathBuf,
+    indexes_processed_up_to: Arc<Mutex<HashMap<CanisterId, u64>>>,
+}
+
+impl FileIndexStore {
+    pub async fn load(path: PathBuf) -> Result<FileIndexStore, Error> {
+        let indexes = match tokio::fs::read(&path).await {
+            Ok(bytes) => deserialize(&bytes)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(FileIndexStore {
+            path,
+            indexes_processed_up_to: Arc::new(Mutex::new(indexes)),
+        })
+    }
+
+    async fn write_to_file(&self, indexes: &HashMap<CanisterId, u64>) -> Result<(), Error> {
+        let bytes = serialize(indexes)?;
+        let temp_path = self.path.with_extension("tmp");
+
+        let mut file = tokio::fs::File::create(&temp_path).await?;
+        file.write_all(&bytes).await?;
+        file.sync_all().await?;
+
+        tokio::fs::rename(&temp_path, &self.path).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IndexStore for FileIndexStore {
+    async fn get(&self, canister_id: CanisterId) -> Result<Option<u64>, Error> {
+        Ok(self.indexes_processed_up_to.lock().await.get(&canister_id).copied())
+    }
+
+    async fn set(&self, canister_id: CanisterId, index: u64) -> Result<(), Error> {
+        let mut indexes = self.indexes_processed_up_to.lock().await;
+        if indexes.get(&canister_id) == Some(&index) {
+            return Ok(());
+        }
+
+        let mut updated = indexes.clone();
+        updated.insert(canister_id, index);
+        self.write_to_file(&updated).await?;
+
+        *indexes = updated;
+        Ok(())
+    }
+}
+
+fn serialize(indexes: &HashMap<CanisterId, u64>) -> Result<Vec<u8>, Error> {
+    let map: BTreeMap<String, u64> = indexes.iter().map(|(c, i)| (c.to_text(), *i)).collect();
+    Ok(serde_json::to_vec_pretty(&map)?)
+}
+
+fn deserialize(bytes: &[u8]) -> Result<HashMap<CanisterId, u64>, Error> {
+    let map: BTreeMap<String, u64> = serde_json::from_slice(bytes)?;
+    map.into_iter()
+        .map(|(c, i)| CanisterId::from_text(c).map(|c| (c, i)).map_err(|e| e.into()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn indexes_persisted_across_loads() {
+        let path = std::env::temp_dir().join(format!("file_index_store_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let canister_id1 = CanisterId::from_slice(&[1]);
+        let canister_id2 = CanisterId::from_slice(&[2]);
+
+        let store = FileIndexStore::load(path.clone()).await.unwrap();
+        assert_eq!(store.get(canister_id1).await.unwrap(), None);
+
+        store.set(canister_id1, 10).await.unwrap();
+        store.set(canister_id2, 20).await.unwrap();
+        store.set(canister_id1, 11).await.unwrap();
+
+        let reloaded = FileIndexStore::load(path.clone()).await.unwrap();
+        assert_eq!(reloaded.get(canister_id1).await.unwrap(), Some(11));
+        assert_eq!(reloaded.get(canister_id2).await.unwrap(), Some(20));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,14 +1,15 @@
 use candid::Principal;
 // use ic_verifiable_credentials::issuer_api::{ArgumentValue, CredentialSpec};
 // use ic_verifiable_credentials::VcFlowSigners;
-use constants::{DAY_IN_MS, MEMO_JOINING_FEE, NANOS_PER_MILLISECOND};
+use constants::{DAY_IN_MS, MEMO_JOINING_FEE, MEMO_SUBSCRIPTION_FEE, NANOS_PER_MILLISECOND};
 use icrc_ledger_types::icrc1::account::Account;
 use icrc_ledger_types::icrc2::transfer_from::TransferFromArgs;
 use sns_governance_canister::types::neuron::DissolveState;
 use sns_governance_canister::types::Neuron;
 use types::{
-    AccessGate, AccessGateNonComposite, AccessGateScope, CanisterId, CompositeGate, GateCheckFailedReason, PaymentGate,
-    SnsNeuronGate, TimestampMillis, TokenBalanceGate, UserId, VerifiedCredentialGate,
+    AccessGate, AccessGateNonComposite, AccessGateScope, CanisterId, Chit, ChitGate, CommunityMessageCountGate,
+    CommunityTenureGate, CompositeGate, GateCheckFailedReason, NftGate, PaymentGate, SnsNeuronGate, SubscriptionGate,
+    TimestampMillis, TokenBalanceGate, UserId, VerifiedCredentialGate,
 };
 
 pub enum CheckIfPassesGateResult {
@@ -37,6 +38,11 @@ pub struct CheckGateArgs {
     pub is_unique_person: bool,
     pub verified_credential_args: Option<CheckVerifiedCredentialGateArgs>,
     pub referred_by_member: bool,
+    // If `None` the user's CHIT is looked up from the user index
+    pub chit: Option<Chit>,
+    pub user_index_canister_id: CanisterId,
+    pub community_member_since: Option<TimestampMillis>,
+    pub community_message_count: u32,
     pub now: TimestampMillis,
 }
 
@@ -74,8 +80,20 @@ async fn check_non_composite_gate(gate: AccessGateNonComposite, args: CheckGateA
             check_verified_credential_gate(&g, args.verified_credential_args, args.now)
         }
         AccessGateNonComposite::SnsNeuron(g) => check_sns_neuron_gate(&g, args.user_id).await,
-        AccessGateNonComposite::Payment(g) => try_transfer_from(&g, args.user_id, args.this_canister, args.now).await,
+        AccessGateNonComposite::Payment(g) => try_pay_joining_fee(&g, args.user_id, args.this_canister, args.now).await,
+        AccessGateNonComposite::Subscription(g) => {
+            try_pay_subscription_fee(&g, args.user_id, args.this_canister, args.now).await
+        }
         AccessGateNonComposite::TokenBalance(g) => check_token_balance_gate(&g, args.user_id).await,
+        AccessGateNonComposite::Nft(g) => check_nft_gate(&g, args.user_id).await,
+        AccessGateNonComposite::Chit(g) => match args.chit {
+            Some(chit) => check_chit_gate(&g, chit),
+            None => lookup_chit_then_check_gate(&g, args.user_id, args.user_index_canister_id, args.now).await,
+        },
+        AccessGateNonComposite::CommunityTenure(g) => check_community_tenure_gate(&g, args.community_member_since, args.now),
+        AccessGateNonComposite::CommunityMessageCount(g) => {
+            check_community_message_count_gate(&g, args.community_message_count)
+        }
         AccessGateNonComposite::Locked => CheckIfPassesGateResult::Failed(GateCheckFailedReason::Locked),
         AccessGateNonComposite::ReferredByMember => check_referred_by_member_gate(args.referred_by_member),
     }
@@ -96,6 +114,13 @@ fn check_non_composite_gate_synchronously(
             Some(check_verified_credential_gate(&g, args.verified_credential_args, args.now))
         }
         AccessGateNonComposite::ReferredByMember => Some(check_referred_by_member_gate(args.referred_by_member)),
+        AccessGateNonComposite::Chit(g) => args.chit.map(|chit| check_chit_gate(&g, chit)),
+        AccessGateNonComposite::CommunityTenure(g) => {
+            Some(check_community_tenure_gate(&g, args.community_member_since, args.now))
+        }
+        AccessGateNonComposite::CommunityMessageCount(g) => {
+            Some(check_community_message_count_gate(&g, args.community_message_count))
+        }
         _ => None,
     }
 }
@@ -108,6 +133,54 @@ fn check_referred_by_member_gate(referred_by_member: bool) -> CheckIfPassesGateR
     }
 }
 
+async fn lookup_chit_then_check_gate(
+    gate: &ChitGate,
+    user_id: UserId,
+    user_index_canister_id: CanisterId,
+    now: TimestampMillis,
+) -> CheckIfPassesGateResult {
+    match user_index_canister_c2c_client::lookup_chit(user_id, user_index_canister_id, now).await {
+        Ok(chit) => check_chit_gate(gate, chit),
+        Err(error) => CheckIfPassesGateResult::InternalError(format!("Error calling 'users_chit': {error}")),
+    }
+}
+
+fn check_chit_gate(gate: &ChitGate, chit: Chit) -> CheckIfPassesGateResult {
+    if let Some(min_balance) = gate.min_balance {
+        if (chit.balance as i64) < min_balance as i64 {
+            return CheckIfPassesGateResult::Failed(GateCheckFailedReason::InsufficientChit(chit.balance));
+        }
+    }
+    if let Some(min_streak) = gate.min_streak {
+        if chit.streak < min_streak {
+            return CheckIfPassesGateResult::Failed(GateCheckFailedReason::InsufficientStreak(chit.streak));
+        }
+    }
+    CheckIfPassesGateResult::Success(Vec::new())
+}
+
+fn check_community_tenure_gate(
+    gate: &CommunityTenureGate,
+    community_member_since: Option<TimestampMillis>,
+    now: TimestampMillis,
+) -> CheckIfPassesGateResult {
+    let tenure = community_member_since.map_or(0, |since| now.saturating_sub(since));
+
+    if tenure >= gate.min_membership_duration {
+        CheckIfPassesGateResult::Success(Vec::new())
+    } else {
+        CheckIfPassesGateResult::Failed(GateCheckFailedReason::InsufficientCommunityTenure(tenure))
+    }
+}
+
+fn check_community_message_count_gate(gate: &CommunityMessageCountGate, message_count: u32) -> CheckIfPassesGateResult {
+    if message_count >= gate.min_messages {
+        CheckIfPassesGateResult::Success(Vec::new())
+    } else {
+        CheckIfPassesGateResult::Failed(GateCheckFailedReason::InsufficientCommunityMessages(message_count))
+    }
+}
+
 fn check_diamond_member_gate(
     diamond_membership_expires_at: Option<TimestampMillis>,
     now: TimestampMillis,
@@ -288,30 +361,55 @@ async fn check_sns_neuron_gate(gate: &SnsNeuronGate, user_id: UserId) -> CheckIf
     }
 }
 
-async fn try_transfer_from(
+async fn try_pay_joining_fee(
     gate: &PaymentGate,
     user_id: UserId,
     this_canister_id: CanisterId,
     now: TimestampMillis,
+) -> CheckIfPassesGateResult {
+    let payment = GatePayment {
+        ledger_canister_id: gate.ledger_canister_id,
+        amount: gate.amount,
+        fee: gate.fee,
+    };
+    try_transfer_from(payment, &MEMO_JOINING_FEE, user_id, this_canister_id, now).await
+}
+
+async fn try_pay_subscription_fee(
+    gate: &SubscriptionGate,
+    user_id: UserId,
+    this_canister_id: CanisterId,
+    now: TimestampMillis,
+) -> CheckIfPassesGateResult {
+    let payment = GatePayment {
+        ledger_canister_id: gate.ledger_canister_id,
+        amount: gate.amount,
+        fee: gate.fee,
+    };
+    try_transfer_from(payment, &MEMO_SUBSCRIPTION_FEE, user_id, this_canister_id, now).await
+}
+
+async fn try_transfer_from(
+    payment: GatePayment,
+    memo: &[u8],
+    user_id: UserId,
+    this_canister_id: CanisterId,
+    now: TimestampMillis,
 ) -> CheckIfPassesGateResult {
     let from: Principal = user_id.into();
-    let amount = gate.amount - 2 * gate.fee;
+    let amount = payment.amount - 2 * payment.fee;
     let transfer_args = TransferFromArgs {
         spender_subaccount: None,
         from: from.into(),
         to: this_canister_id.into(),
         // The amount the gate amount less the approval fee and the transfer_from fee
         amount: amount.into(),
-        fee: Some(gate.fee.into()),
-        memo: Some(MEMO_JOINING_FEE.to_vec().into()),
+        fee: Some(payment.fee.into()),
+        memo: Some(memo.to_vec().into()),
         created_at_time: Some(now * NANOS_PER_MILLISECOND),
     };
-    match icrc_ledger_canister_c2c_client::icrc2_transfer_from(gate.ledger_canister_id, &transfer_args).await {
-        Ok(Ok(_)) => CheckIfPassesGateResult::Success(vec![GatePayment {
-            ledger_canister_id: gate.ledger_canister_id,
-            amount: gate.amount,
-            fee: gate.fee,
-        }]),
+    match icrc_ledger_canister_c2c_client::icrc2_transfer_from(payment.ledger_canister_id, &transfer_args).await {
+        Ok(Ok(_)) => CheckIfPassesGateResult::Success(vec![payment]),
         Ok(Err(err)) => CheckIfPassesGateResult::Failed(GateCheckFailedReason::PaymentFailed(err)),
         Err(error) => CheckIfPassesGateResult::InternalError(format!("Error calling 'try_transfer_from': {error:?}")),
     }
@@ -327,6 +425,57 @@ async fn check_token_balance_gate(gate: &TokenBalanceGate, user_id: UserId) -> C
     }
 }
 
+async fn check_nft_gate(gate: &NftGate, user_id: UserId) -> CheckIfPassesGateResult {
+    let account = Account::from(user_id);
+    let min_count = gate.min_count();
+
+    let Some(token_ids) = &gate.token_ids else {
+        return match icrc7_canister_c2c_client::icrc7_balance_of(gate.collection_canister_id, &vec![account]).await {
+            Ok(balances) => {
+                let balance = balances
+                    .into_iter()
+                    .next()
+                    .map_or(0, |b| u32::try_from(b.0).unwrap_or(u32::MAX));
+                if balance >= min_count {
+                    CheckIfPassesGateResult::Success(Vec::new())
+                } else {
+                    CheckIfPassesGateResult::Failed(GateCheckFailedReason::InsufficientNfts(balance))
+                }
+            }
+            Err(error) => CheckIfPassesGateResult::InternalError(format!("Error calling 'icrc7_balance_of': {error:?}")),
+        };
+    };
+
+    // Page through the user's tokens, counting those which are in the gate's set of token ids.
+    // Collections may return short pages before the end, so only an empty page means we're done.
+    const PAGE_SIZE: u32 = 100;
+    const MAX_PAGES: u32 = 50;
+    let mut matched = 0;
+    let mut prev = None;
+    for _ in 0..MAX_PAGES {
+        let args = (account, prev.clone(), Some(PAGE_SIZE.into()));
+        match icrc7_canister_c2c_client::icrc7_tokens_of(gate.collection_canister_id, &args).await {
+            Ok(tokens) => {
+                if tokens.is_empty() {
+                    return CheckIfPassesGateResult::Failed(GateCheckFailedReason::InsufficientNfts(matched));
+                }
+                for token_id in tokens {
+                    if u128::try_from(token_id.0.clone()).is_ok_and(|id| token_ids.contains(&id)) {
+                        matched += 1;
+                        if matched >= min_count {
+                            return CheckIfPassesGateResult::Success(Vec::new());
+                        }
+                    }
+                    prev = Some(token_id);
+                }
+            }
+            Err(error) => return CheckIfPassesGateResult::InternalError(format!("Error calling 'icrc7_tokens_of': {error:?}")),
+        }
+    }
+
+    CheckIfPassesGateResult::InternalError(format!("Gave up paging through 'icrc7_tokens_of' after {MAX_PAGES} pages"))
+}
+
 fn dissolve_delay_seconds(neuron: &Neuron, now_seconds: u64) -> u64 {
     match neuron.dissolve_state {
         Some(DissolveState::DissolveDelaySeconds(d)) => d,
@@ -1,3 +1,4 @@
+use candid::Principal;
 use chat_events::{
     AddRemoveReactionArgs, ChatEventInternal, ChatEvents, ChatEventsListReader, DeleteMessageResult,
     DeleteUndeleteMessagesArgs, EditMessageArgs, GroupGateUpdatedInternal, MessageContentInternal, PushMessageArgs, Reader,
@@ -5,7 +6,7 @@ use chat_events::{
 };
 use event_store_producer::{EventStoreClient, Runtime};
 use event_store_producer_cdk_runtime::CdkRuntime;
-use group_community_common::MemberUpdate;
+use group_community_common::{AddJoinRequestError, JoinRequests, MemberUpdate};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex_lite::Regex;
@@ -13,16 +14,19 @@ use search::simple::Query;
 use serde::{Deserialize, Serialize};
 use std::cmp::{max, min, Reverse};
 use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::ops::Bound::{Excluded, Unbounded};
 use types::{
-    AccessGate, AccessGateConfig, AccessGateConfigInternal, AvatarChanged, BotMessageContext, Caller, CustomPermission,
-    Document, EventIndex, EventOrExpiredRange, EventWrapper, EventsCaller, EventsResponse, ExternalUrlUpdated,
-    FieldTooLongResult, FieldTooShortResult, GroupDescriptionChanged, GroupMember, GroupNameChanged, GroupPermissions,
-    GroupReplyContext, GroupRole, GroupRulesChanged, GroupSubtype, GroupVisibilityChanged, HydratedMention, MemberLeft,
-    MembersRemoved, Message, MessageContent, MessageId, MessageIndex, MessageMatch, MessagePermissions, MessagePinned,
-    MessageUnpinned, MessagesResponse, Milliseconds, MultiUserChat, OptionUpdate, OptionalGroupPermissions,
-    OptionalMessagePermissions, PermissionsChanged, PushEventResult, Reaction, RoleChanged, Rules, SelectedGroupUpdates,
-    ThreadPreview, TimestampMillis, Timestamped, UpdatedRules, UserId, UserType, UsersBlocked, UsersInvited, Version,
-    Versioned, VersionedRules, VideoCall, MAX_RETURNED_MENTIONS,
+    AccessGate, AccessGateConfig, AccessGateConfigInternal, ArchivedEvent, AvatarChanged, BotMessageContext, Caller,
+    ChatArchiveCursor, ChatArchiveEventsPage, ChatArchiveMembersPage, ChatEvent, CustomPermission, Document, EventIndex,
+    EventOrExpiredRange, EventWrapper, EventsCaller, EventsResponse, ExternalUrlUpdated, FieldTooLongResult,
+    FieldTooShortResult, GroupDescriptionChanged, GroupMember, GroupNameChanged, GroupPermissions, GroupReplyContext,
+    GroupRole, GroupRulesChanged, GroupSubtype, GroupVisibilityChanged, HydratedMention, JoinRequest, JoinRequestRejected,
+    JoinRequested, MemberLeft, MembersRemoved, Message, MessageContent, MessageId, MessageIndex, MessageMatch,
+    MessagePermissions, MessagePinned, MessageUnpinned, MessageVersion, MessagesResponse, Milliseconds, MultiUserChat,
+    OptionUpdate, OptionalGroupPermissions, OptionalMessagePermissions, PermissionsChanged, PushEventResult, Reaction,
+    RoleChanged, Rules, SelectedGroupUpdates, ThreadPreview, TimestampMillis, Timestamped, UpdatedRules, UserId, UserType,
+    UsersBlocked, UsersInvited, Version, Versioned, VersionedRules, VideoCall, CHAT_ARCHIVE_FORMAT_VERSION,
+    MAX_RETURNED_MENTIONS,
 };
 use utils::document::validate_avatar;
 use utils::text_validation::{
@@ -39,6 +43,8 @@ pub use members::*;
 pub use mentions::*;
 pub use roles::*;
 
+const MAX_INVITES: usize = 100;
+
 #[derive(Serialize, Deserialize)]
 pub struct GroupChatCore {
     pub is_public: Timestamped<bool>,
@@ -59,6 +65,8 @@ pub struct GroupChatCore {
     pub date_last_pinned: Option<TimestampMillis>,
     pub gate_config: Timestamped<Option<AccessGateConfigInternal>>,
     pub invited_users: InvitedUsers,
+    #[serde(default)]
+    pub join_requests: JoinRequests,
     pub min_visible_indexes_for_new_members: Option<(EventIndex, MessageIndex)>,
     pub external_url: Timestamped<Option<String>>,
     at_everyone_mentions: BTreeMap<TimestampMillis, AtEveryoneMention>,
@@ -115,6 +123,7 @@ impl GroupChatCore {
             date_last_pinned: None,
             gate_config: Timestamped::new(gate_config, now),
             invited_users: InvitedUsers::default(),
+            join_requests: JoinRequests::default(),
             min_visible_indexes_for_new_members: None,
             external_url: Timestamped::new(external_url, now),
             at_everyone_mentions: BTreeMap::new(),
@@ -362,13 +371,14 @@ impl GroupChatCore {
         match self.events_reader(&caller, thread_root_message_index) {
             EventsReaderResult::Success(reader) => {
                 let user_id = caller.user_id();
-                let (events, expired_event_ranges, unauthorized) = EventOrExpiredRange::split(reader.scan(
+                let (mut events, expired_event_ranges, unauthorized) = EventOrExpiredRange::split(reader.scan(
                     Some(start_index.into()),
                     ascending,
                     max_messages as usize,
                     max_events as usize,
                     user_id,
                 ));
+                self.hide_join_request_events(user_id, &mut events);
                 let expired_message_ranges = self.events.convert_to_message_ranges(&expired_event_ranges);
                 let latest_event_index = reader.latest_event_index().unwrap();
                 let chat_last_updated = self.last_updated(user_id);
@@ -400,8 +410,9 @@ impl GroupChatCore {
         match self.events_reader(&caller, thread_root_message_index) {
             EventsReaderResult::Success(reader) => {
                 let user_id = caller.user_id();
-                let (events, expired_event_ranges, unauthorized) =
+                let (mut events, expired_event_ranges, unauthorized) =
                     EventOrExpiredRange::split(reader.get_by_indexes(&events, user_id));
+                self.hide_join_request_events(user_id, &mut events);
                 let expired_message_ranges = self.events.convert_to_message_ranges(&expired_event_ranges);
                 let latest_event_index = reader.latest_event_index().unwrap();
                 let chat_last_updated = self.last_updated(user_id);
@@ -435,12 +446,13 @@ impl GroupChatCore {
         match self.events_reader(&caller, thread_root_message_index) {
             EventsReaderResult::Success(reader) => {
                 let user_id = caller.user_id();
-                let (events, expired_event_ranges, unauthorized) = EventOrExpiredRange::split(reader.window(
+                let (mut events, expired_event_ranges, unauthorized) = EventOrExpiredRange::split(reader.window(
                     mid_point.into(),
                     max_messages as usize,
                     max_events as usize,
                     user_id,
                 ));
+                self.hide_join_request_events(user_id, &mut events);
                 let expired_message_ranges = self.events.convert_to_message_ranges(&expired_event_ranges);
                 let latest_event_index = reader.latest_event_index().unwrap();
                 let chat_last_updated = self.last_updated(user_id);
@@ -492,6 +504,29 @@ impl GroupChatCore {
         }
     }
 
+    pub fn message_edit_history(
+        &self,
+        caller: EventsCaller,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+    ) -> MessageEditHistoryResult {
+        use MessageEditHistoryResult::*;
+
+        match self.events_reader(&caller, thread_root_message_index) {
+            EventsReaderResult::Success(reader) => match reader.message_internal(message_id.into()) {
+                Some(message) if message.deleted_by.is_none() => {
+                    let user_id = caller.user_id();
+                    Success(message.edit_history.into_iter().map(|v| v.hydrate(user_id)).collect())
+                }
+                _ => MessageNotFound,
+            },
+            EventsReaderResult::ThreadNotFound => ThreadNotFound,
+            EventsReaderResult::UserNotInGroup => UserNotInGroup,
+            EventsReaderResult::UserSuspended => UserSuspended,
+            EventsReaderResult::UserLapsed => UserLapsed,
+        }
+    }
+
     pub fn deleted_message(
         &self,
         user_id: UserId,
@@ -556,23 +591,10 @@ impl GroupChatCore {
     ) -> SearchResults {
         use SearchResults::*;
 
-        const MIN_TERM_LENGTH: u8 = 3;
-        const MAX_TERM_LENGTH: u8 = 30;
-        const MAX_USERS: u8 = 5;
-
-        let term_length = search_term.len() as u8;
         let users = users.unwrap_or_default();
 
-        if users.is_empty() && term_length < MIN_TERM_LENGTH {
-            return TermTooShort(MIN_TERM_LENGTH);
-        }
-
-        if term_length > MAX_TERM_LENGTH {
-            return TermTooLong(MAX_TERM_LENGTH);
-        }
-
-        if users.len() as u8 > MAX_USERS {
-            return TooManyUsers(MAX_USERS);
+        if let Err(error) = validate_search_args(&search_term, &users) {
+            return error;
         }
 
         let member = match self.members.get(&user_id) {
@@ -589,6 +611,139 @@ impl GroupChatCore {
         Success(matches)
     }
 
+    // Callers must validate the search args using `validate_search_args` before calling this.
+    // Returns None if the user is not a member of the group or if their membership has lapsed.
+    pub fn search_in_range(
+        &self,
+        user_id: UserId,
+        search_term: &str,
+        users: HashSet<UserId>,
+        from: Option<TimestampMillis>,
+        to: Option<TimestampMillis>,
+        max_results: u8,
+    ) -> Option<Vec<(Message, TimestampMillis, u32)>> {
+        let member = self.members.get(&user_id).filter(|m| !m.lapsed().value)?;
+
+        Some(
+            self.events
+                .search_messages_in_range(
+                    member.min_visible_message_index(),
+                    Query::new(search_term),
+                    users,
+                    from,
+                    to,
+                    max_results,
+                )
+                .into_iter()
+                .map(|(message, timestamp, score)| (message.hydrate(Some(user_id)), timestamp, score))
+                .collect(),
+        )
+    }
+
+    // Callers must check that the user is permitted to export the chat before calling this
+    pub fn export_events(&self, after: Option<ChatArchiveCursor>, max_events: u32) -> ChatArchiveEventsPage {
+        let events = self.events.export_events(after.map(|c| c.into()), max_events as usize, None);
+
+        ChatArchiveEventsPage {
+            format_version: CHAT_ARCHIVE_FORMAT_VERSION,
+            finished: events.len() < max_events as usize,
+            events,
+        }
+    }
+
+    // Callers must check that the user is permitted to export the chat before calling this
+    pub fn export_members(&self, after: Option<UserId>, max_members: u32) -> ChatArchiveMembersPage {
+        let members: Vec<_> = match after {
+            Some(user_id) => self.members.member_ids().range((Excluded(user_id), Unbounded)),
+            None => self.members.member_ids().range(..),
+        }
+        .filter_map(|user_id| self.members.get(user_id))
+        .take(max_members as usize)
+        .map(|m| GroupMember::from(&m))
+        .collect();
+
+        ChatArchiveMembersPage {
+            format_version: CHAT_ARCHIVE_FORMAT_VERSION,
+            finished: members.len() < max_members as usize,
+            members,
+        }
+    }
+
+    // Replays the messages from a chat archive into this chat. The original senders can't be
+    // verified from the archive, so each message is sent by the importing user and marked as
+    // forwarded. `message_index_map` maps message indexes from the archive to message indexes in
+    // this chat so that thread replies can be attached to their new root messages.
+    pub fn import_archived_events(
+        &mut self,
+        user_id: UserId,
+        events: Vec<ArchivedEvent>,
+        message_index_map: &mut BTreeMap<MessageIndex, MessageIndex>,
+        now: TimestampMillis,
+    ) -> ImportArchivedEventsResult {
+        use ImportArchivedEventsResult::*;
+
+        match self.members.get_verified_member(user_id) {
+            Ok(member) if member.role().is_owner() => {}
+            Ok(_) => return NotAuthorized,
+            Err(error) => {
+                return match error {
+                    VerifyMemberError::NotFound => UserNotInGroup,
+                    VerifyMemberError::Lapsed => UserLapsed,
+                    VerifyMemberError::Suspended => UserSuspended,
+                }
+            }
+        }
+
+        let mut imported = 0;
+        for archived_event in events {
+            let ChatEvent::Message(message) = archived_event.event.event else {
+                continue;
+            };
+            let Some(content) = MessageContentInternal::from_archived(message.content) else {
+                continue;
+            };
+            let thread_root_message_index = match archived_event.thread_root_message_index {
+                Some(root_message_index) => match message_index_map.get(&root_message_index) {
+                    Some(index) => Some(*index),
+                    None => continue,
+                },
+                None => None,
+            };
+            if self
+                .events
+                .message_ids(thread_root_message_index, message.message_id.into())
+                .is_some()
+            {
+                continue;
+            }
+
+            let message_event = self.events.push_message::<CdkRuntime>(
+                PushMessageArgs {
+                    sender: user_id,
+                    thread_root_message_index,
+                    message_id: message.message_id,
+                    content,
+                    bot_context: None,
+                    mentioned: Vec::new(),
+                    replies_to: None,
+                    forwarded: true,
+                    sender_is_bot: false,
+                    block_level_markdown: message.block_level_markdown,
+                    correlation_id: 0,
+                    now,
+                },
+                None,
+            );
+
+            if thread_root_message_index.is_none() {
+                message_index_map.insert(message.message_index, message_event.event.message_index);
+            }
+            imported += 1;
+        }
+
+        Success(imported)
+    }
+
     pub fn send_message<R: Runtime + Send + 'static>(
         &mut self,
         caller: &Caller,
@@ -1323,8 +1478,6 @@ impl GroupChatCore {
     pub fn invite_users(&mut self, invited_by: UserId, user_ids: Vec<UserId>, now: TimestampMillis) -> InvitedUsersResult {
         use InvitedUsersResult::*;
 
-        const MAX_INVITES: usize = 100;
-
         match self.members.get_verified_member(invited_by) {
             Ok(member) => {
                 // The original caller must be authorized to invite other users
@@ -1411,6 +1564,180 @@ impl GroupChatCore {
         self.invited_users.remove(user_id, now);
     }
 
+    pub fn request_to_join(
+        &mut self,
+        user_id: UserId,
+        principal: Principal,
+        answer: Option<String>,
+        now: TimestampMillis,
+    ) -> RequestToJoinResult {
+        use RequestToJoinResult::*;
+
+        if self.members.contains(&user_id) {
+            return AlreadyInGroup;
+        }
+        if self.members.is_blocked(&user_id) {
+            return UserBlocked;
+        }
+        if self.invited_users.contains(&user_id) {
+            return AlreadyInvited;
+        }
+
+        match self.join_requests.add(user_id, principal, answer, now) {
+            Ok(()) => {
+                self.events
+                    .push_main_event(ChatEventInternal::JoinRequested(Box::new(JoinRequested { user_id })), 0, now);
+
+                Success
+            }
+            Err(AddJoinRequestError::AlreadyRequested) => AlreadyRequested,
+            Err(AddJoinRequestError::RecentlyRejected(until)) => RecentlyRejected(until),
+            Err(AddJoinRequestError::TooManyRequests(max)) => TooManyRequests(max),
+            Err(AddJoinRequestError::RateLimited(until)) => RateLimited(until),
+            Err(AddJoinRequestError::AnswerTooLong(max)) => AnswerTooLong(max),
+        }
+    }
+
+    pub fn join_requests(&self, user_id: UserId) -> JoinRequestsResult {
+        use JoinRequestsResult::*;
+
+        match self.members.get_verified_member(user_id) {
+            Ok(member) => {
+                if member.role().can_invite_users(&self.permissions) {
+                    Success(self.join_requests.pending())
+                } else {
+                    NotAuthorized
+                }
+            }
+            Err(error) => match error {
+                VerifyMemberError::NotFound => UserNotInGroup,
+                VerifyMemberError::Lapsed => UserLapsed,
+                VerifyMemberError::Suspended => UserSuspended,
+            },
+        }
+    }
+
+    // Join request events are only visible to the members who can act on join requests, for
+    // everyone else they are returned as empty events so that event indexes remain contiguous
+    fn hide_join_request_events(&self, user_id: Option<UserId>, events: &mut [EventWrapper<ChatEvent>]) {
+        if user_id
+            .and_then(|u| self.members.get(&u))
+            .is_some_and(|m| m.role().can_invite_users(&self.permissions))
+        {
+            return;
+        }
+
+        for event in events.iter_mut() {
+            if matches!(event.event, ChatEvent::JoinRequested(_) | ChatEvent::JoinRequestRejected(_)) {
+                event.event = ChatEvent::Empty;
+            }
+        }
+    }
+
+    // Returns the members who should be notified of new join requests
+    pub fn join_request_approvers(&self) -> Vec<UserId> {
+        self.members
+            .owners()
+            .iter()
+            .chain(self.members.admins().iter())
+            .chain(self.members.moderators().iter())
+            .filter(|u| {
+                self.members
+                    .get(*u)
+                    .is_some_and(|m| !m.lapsed().value && m.role().can_invite_users(&self.permissions))
+            })
+            .copied()
+            .collect()
+    }
+
+    // Approving a request invites the user, so they can then join via the usual flow, bypassing
+    // any access gate in the same way as any other invited user
+    pub fn approve_join_request(
+        &mut self,
+        approved_by: UserId,
+        user_id: UserId,
+        now: TimestampMillis,
+    ) -> ApproveJoinRequestResult {
+        use ApproveJoinRequestResult::*;
+
+        match self.members.get_verified_member(approved_by) {
+            Ok(member) => {
+                if !member.role().can_invite_users(&self.permissions) {
+                    return NotAuthorized;
+                }
+                if !self.join_requests.contains(&user_id) {
+                    return RequestNotFound;
+                }
+                if self.invited_users.len() >= MAX_INVITES {
+                    return TooManyInvites(MAX_INVITES as u32);
+                }
+
+                let request = self.join_requests.remove(&user_id, now).unwrap();
+                let (min_visible_event_index, min_visible_message_index) = self.min_visible_indexes_for_new_members();
+
+                self.invited_users.add(UserInvitation {
+                    invited: user_id,
+                    invited_by: member.user_id(),
+                    timestamp: now,
+                    min_visible_event_index,
+                    min_visible_message_index,
+                });
+
+                self.events.push_main_event(
+                    ChatEventInternal::UsersInvited(Box::new(UsersInvited {
+                        user_ids: vec![user_id],
+                        invited_by: member.user_id(),
+                    })),
+                    0,
+                    now,
+                );
+
+                Success(request.principal)
+            }
+            Err(error) => match error {
+                VerifyMemberError::NotFound => UserNotInGroup,
+                VerifyMemberError::Lapsed => UserLapsed,
+                VerifyMemberError::Suspended => UserSuspended,
+            },
+        }
+    }
+
+    pub fn reject_join_request(
+        &mut self,
+        rejected_by: UserId,
+        user_id: UserId,
+        now: TimestampMillis,
+    ) -> RejectJoinRequestResult {
+        use RejectJoinRequestResult::*;
+
+        match self.members.get_verified_member(rejected_by) {
+            Ok(member) => {
+                if !member.role().can_invite_users(&self.permissions) {
+                    return NotAuthorized;
+                }
+                if self.join_requests.reject(&user_id, now).is_none() {
+                    return RequestNotFound;
+                }
+
+                self.events.push_main_event(
+                    ChatEventInternal::JoinRequestRejected(Box::new(JoinRequestRejected {
+                        user_id,
+                        rejected_by: member.user_id(),
+                    })),
+                    0,
+                    now,
+                );
+
+                Success
+            }
+            Err(error) => match error {
+                VerifyMemberError::NotFound => UserNotInGroup,
+                VerifyMemberError::Lapsed => UserLapsed,
+                VerifyMemberError::Suspended => UserSuspended,
+            },
+        }
+    }
+
     pub fn can_leave(&self, user_id: UserId) -> CanLeaveResult {
         use CanLeaveResult::*;
 
@@ -1803,6 +2130,32 @@ impl GroupChatCore {
         result
     }
 
+    // Overwrites the group's permissions, eg. when applying a community permission template. Returns false if the
+    // permissions were unchanged, in which case no event is pushed.
+    pub fn set_permissions(&mut self, user_id: UserId, permissions: GroupPermissions, now: TimestampMillis) -> bool {
+        if self.permissions.value == permissions {
+            return false;
+        }
+
+        let old_permissions_v2 = std::mem::replace(&mut self.permissions, Timestamped::new(permissions.clone(), now)).value;
+
+        self.events.push_main_event(
+            ChatEventInternal::PermissionsChanged(Box::new(PermissionsChanged {
+                old_permissions_v2,
+                new_permissions_v2: permissions,
+                changed_by: user_id,
+            })),
+            0,
+            now,
+        );
+        true
+    }
+
+    pub fn update_permissions(&mut self, user_id: UserId, permissions: OptionalGroupPermissions, now: TimestampMillis) -> bool {
+        let new_permissions = GroupChatCore::merge_permissions(permissions, self.permissions.value.clone());
+        self.set_permissions(user_id, new_permissions, now)
+    }
+
     pub fn follow_thread(
         &mut self,
         user_id: UserId,
@@ -1968,7 +2321,7 @@ impl GroupChatCore {
             .map(|message| message.sender)
     }
 
-    fn merge_permissions(new: OptionalGroupPermissions, old: GroupPermissions) -> GroupPermissions {
+    pub fn merge_permissions(new: OptionalGroupPermissions, old: GroupPermissions) -> GroupPermissions {
         let message_permissions = match new.message_permissions {
             Some(mp) => GroupChatCore::merge_message_permissions(mp, old.message_permissions),
             None => old.message_permissions,
@@ -2077,6 +2430,15 @@ pub enum MessagesResult {
     UserLapsed,
 }
 
+pub enum MessageEditHistoryResult {
+    Success(Vec<MessageVersion>),
+    UserNotInGroup,
+    ThreadNotFound,
+    MessageNotFound,
+    UserSuspended,
+    UserLapsed,
+}
+
 #[allow(clippy::large_enum_variant)]
 pub enum SendMessageResult {
     Success(SendMessageSuccess),
@@ -2239,6 +2601,36 @@ pub enum ThreadPreviewsResult {
     UserNotInGroup,
 }
 
+pub enum ImportArchivedEventsResult {
+    Success(u32),
+    NotAuthorized,
+    UserNotInGroup,
+    UserSuspended,
+    UserLapsed,
+}
+
+pub fn validate_search_args(search_term: &str, users: &HashSet<UserId>) -> Result<(), SearchResults> {
+    const MIN_TERM_LENGTH: u8 = 3;
+    const MAX_TERM_LENGTH: u8 = 30;
+    const MAX_USERS: u8 = 5;
+
+    let term_length = search_term.len() as u8;
+
+    if users.is_empty() && term_length < MIN_TERM_LENGTH {
+        return Err(SearchResults::TermTooShort(MIN_TERM_LENGTH));
+    }
+
+    if term_length > MAX_TERM_LENGTH {
+        return Err(SearchResults::TermTooLong(MAX_TERM_LENGTH));
+    }
+
+    if users.len() as u8 > MAX_USERS {
+        return Err(SearchResults::TooManyUsers(MAX_USERS));
+    }
+
+    Ok(())
+}
+
 pub enum SearchResults {
     Success(Vec<MessageMatch>),
     InvalidTerm,
@@ -2270,6 +2662,45 @@ pub enum CancelInvitesResult {
     UserLapsed,
 }
 
+pub enum RequestToJoinResult {
+    Success,
+    AlreadyInGroup,
+    AlreadyInvited,
+    AlreadyRequested,
+    RecentlyRejected(TimestampMillis),
+    UserBlocked,
+    TooManyRequests(u32),
+    RateLimited(TimestampMillis),
+    AnswerTooLong(u32),
+}
+
+pub enum JoinRequestsResult {
+    Success(Vec<JoinRequest>),
+    UserNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    NotAuthorized,
+}
+
+pub enum ApproveJoinRequestResult {
+    Success(Principal),
+    RequestNotFound,
+    TooManyInvites(u32),
+    UserNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    NotAuthorized,
+}
+
+pub enum RejectJoinRequestResult {
+    Success,
+    RequestNotFound,
+    UserNotInGroup,
+    UserSuspended,
+    UserLapsed,
+    NotAuthorized,
+}
+
 pub enum FollowThreadResult {
     Success,
     AlreadyFollowing,
@@ -0,0 +1,182 @@
+use candid::Principal;
+use constants::{HOUR_IN_MS, WEEK_IN_MS};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use types::{JoinRequest, Milliseconds, TimestampMillis, UserId};
+
+pub const MAX_JOIN_REQUESTS: usize = 1000;
+pub const MAX_JOIN_REQUEST_ANSWER_LENGTH: usize = 1000;
+pub const MAX_JOIN_REQUESTS_PER_HOUR: usize = 500;
+pub const MAX_JOIN_REQUESTS_PER_USER_PER_HOUR: usize = 3;
+pub const JOIN_REQUEST_REJECTION_COOLDOWN: Milliseconds = WEEK_IN_MS;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct JoinRequests {
+    #[serde(rename = "l")]
+    last_updated: TimestampMillis,
+    #[serde(rename = "r")]
+    requests: BTreeMap<UserId, JoinRequestInternal>,
+    // Users whose requests were rejected, mapped to when they were rejected
+    #[serde(rename = "x", default)]
+    rejected: HashMap<UserId, TimestampMillis>,
+    // The requests received within the last hour along with who made them, oldest first
+    #[serde(rename = "hu", default)]
+    recent: VecDeque<(TimestampMillis, UserId)>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct JoinRequestInternal {
+    #[serde(rename = "p")]
+    pub principal: Principal,
+    #[serde(rename = "t")]
+    pub requested_at: TimestampMillis,
+    #[serde(rename = "a", default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+}
+
+impl JoinRequests {
+    pub fn add(
+        &mut self,
+        user_id: UserId,
+        principal: Principal,
+        answer: Option<String>,
+        now: TimestampMillis,
+    ) -> Result<(), AddJoinRequestError> {
+        self.prune(now);
+
+        if answer
+            .as_ref()
+            .is_some_and(|a| a.chars().count() > MAX_JOIN_REQUEST_ANSWER_LENGTH)
+        {
+            Err(AddJoinRequestError::AnswerTooLong(MAX_JOIN_REQUEST_ANSWER_LENGTH as u32))
+        } else if self.requests.contains_key(&user_id) {
+            Err(AddJoinRequestError::AlreadyRequested)
+        } else if let Some(rejected_at) = self.rejected.get(&user_id) {
+            Err(AddJoinRequestError::RecentlyRejected(
+                rejected_at + JOIN_REQUEST_REJECTION_COOLDOWN,
+            ))
+        } else if self.requests.len() >= MAX_JOIN_REQUESTS {
+            Err(AddJoinRequestError::TooManyRequests(MAX_JOIN_REQUESTS as u32))
+        } else if let Some(until) = self.rate_limited_until(user_id) {
+            Err(AddJoinRequestError::RateLimited(until))
+        } else {
+            self.requests.insert(
+                user_id,
+                JoinRequestInternal {
+                    principal,
+                    requested_at: now,
+                    answer,
+                },
+            );
+            self.recent.push_back((now, user_id));
+            self.last_updated = now;
+            Ok(())
+        }
+    }
+
+    pub fn remove(&mut self, user_id: &UserId, now: TimestampMillis) -> Option<JoinRequestInternal> {
+        let request = self.requests.remove(user_id)?;
+        self.last_updated = now;
+        Some(request)
+    }
+
+    // The user won't be able to request to join again until the cooldown has passed
+    pub fn reject(&mut self, user_id: &UserId, now: TimestampMillis) -> Option<JoinRequestInternal> {
+        let request = self.remove(user_id, now)?;
+        self.rejected.insert(*user_id, now);
+        Some(request)
+    }
+
+    fn prune(&mut self, now: TimestampMillis) {
+        let rejection_cutoff = now.saturating_sub(JOIN_REQUEST_REJECTION_COOLDOWN);
+        self.rejected.retain(|_, rejected_at| *rejected_at > rejection_cutoff);
+
+        let recent_cutoff = now.saturating_sub(HOUR_IN_MS);
+        while self.recent.front().is_some_and(|(ts, _)| *ts <= recent_cutoff) {
+            self.recent.pop_front();
+        }
+    }
+
+    // Each user is limited to a few requests per hour so that they can't use up the much higher
+    // overall limit and stop anyone else from requesting to join
+    fn rate_limited_until(&self, user_id: UserId) -> Option<TimestampMillis> {
+        let by_user: Vec<_> = self.recent.iter().filter(|(_, u)| *u == user_id).map(|(ts, _)| *ts).collect();
+        if by_user.len() >= MAX_JOIN_REQUESTS_PER_USER_PER_HOUR {
+            by_user.first().map(|ts| ts + HOUR_IN_MS)
+        } else if self.recent.len() >= MAX_JOIN_REQUESTS_PER_HOUR {
+            self.recent.front().map(|(ts, _)| ts + HOUR_IN_MS)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, user_id: &UserId) -> Option<&JoinRequestInternal> {
+        self.requests.get(user_id)
+    }
+
+    pub fn contains(&self, user_id: &UserId) -> bool {
+        self.requests.contains_key(user_id)
+    }
+
+    // Returns the pending requests, oldest first
+    pub fn pending(&self) -> Vec<JoinRequest> {
+        let mut requests: Vec<_> = self
+            .requests
+            .iter()
+            .map(|(user_id, r)| JoinRequest {
+                user_id: *user_id,
+                requested_at: r.requested_at,
+                answer: r.answer.clone(),
+            })
+            .collect();
+
+        requests.sort_by_key(|r| r.requested_at);
+        requests
+    }
+
+    pub fn last_updated(&self) -> TimestampMillis {
+        self.last_updated
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+}
+
+pub enum AddJoinRequestError {
+    AlreadyRequested,
+    RecentlyRejected(TimestampMillis),
+    TooManyRequests(u32),
+    RateLimited(TimestampMillis),
+    AnswerTooLong(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_user_cannot_use_up_the_hourly_limit() {
+        let mut join_requests = JoinRequests::default();
+        let spammer: UserId = Principal::from_slice(&[1]).into();
+        let other: UserId = Principal::from_slice(&[2]).into();
+        let now = 1_000_000;
+
+        for i in 0..MAX_JOIN_REQUESTS_PER_USER_PER_HOUR as u64 {
+            assert!(join_requests.add(spammer, spammer.into(), None, now + i).is_ok());
+            join_requests.remove(&spammer, now + i);
+        }
+
+        assert!(matches!(
+            join_requests.add(spammer, spammer.into(), None, now + 10),
+            Err(AddJoinRequestError::RateLimited(until)) if until == now + HOUR_IN_MS
+        ));
+        assert!(join_requests.add(other, other.into(), None, now + 10).is_ok());
+
+        assert!(join_requests.add(spammer, spammer.into(), None, now + HOUR_IN_MS + 1).is_ok());
+    }
+}
@@ -1,15 +1,25 @@
 mod achievements;
 mod expiring_member_actions;
 mod expiring_members;
+mod join_requests;
 mod member;
 mod payment_receipts;
 mod pending_payments_queue;
+mod personal_block_lists;
+mod scoped_principals;
+mod subscriptions;
+mod typing;
 mod user_cache;
 
 pub use achievements::*;
 pub use expiring_member_actions::*;
 pub use expiring_members::*;
+pub use join_requests::*;
 pub use member::*;
 pub use payment_receipts::*;
 pub use pending_payments_queue::*;
+pub use personal_block_lists::*;
+pub use scoped_principals::*;
+pub use subscriptions::*;
+pub use typing::*;
 pub use user_cache::*;
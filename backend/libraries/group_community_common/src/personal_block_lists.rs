@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use types::{ChatEvent, EventWrapper, EventsCaller, EventsResponse, Message, ThreadPreview, UserId};
+
+// The maximum number of messages from blocked users to skip over when finding the latest message visible to a member
+const MAX_HIDDEN_MESSAGES_SKIPPED: usize = 100;
+
+// The users each member has blocked, as pushed to us by the member's user canister.
+// Blocked users' messages and reactions are hidden from the member, and blocked users can't mention or invite them.
+#[derive(Serialize, Deserialize, Default)]
+pub struct PersonalBlockLists {
+    map: BTreeMap<UserId, HashSet<UserId>>,
+}
+
+impl PersonalBlockLists {
+    pub fn update(&mut self, user_id: UserId, blocked: Vec<UserId>, unblocked: Vec<UserId>) {
+        let blocked_users = self.map.entry(user_id).or_default();
+        blocked_users.extend(blocked);
+        for other in unblocked {
+            blocked_users.remove(&other);
+        }
+        if blocked_users.is_empty() {
+            self.map.remove(&user_id);
+        }
+    }
+
+    pub fn remove(&mut self, user_id: &UserId) {
+        self.map.remove(user_id);
+    }
+
+    pub fn has_blocked(&self, user_id: &UserId, other: &UserId) -> bool {
+        self.map.get(user_id).is_some_and(|b| b.contains(other))
+    }
+
+    // Messages from blocked users are returned as empty events so that event indexes remain contiguous
+    pub fn hide_blocked_users(&self, caller: &EventsCaller, response: &mut EventsResponse) {
+        let EventsCaller::User(user_id) = caller else {
+            return;
+        };
+
+        if !self.map.contains_key(user_id) {
+            return;
+        }
+
+        for event in response.events.iter_mut() {
+            if let ChatEvent::Message(message) = &mut event.event {
+                if !self.filter_message(user_id, message) {
+                    event.event = ChatEvent::Empty;
+                }
+            }
+        }
+    }
+
+    pub fn hide_blocked_users_in_messages(&self, user_id: &UserId, messages: &mut Vec<EventWrapper<Message>>) {
+        if self.map.contains_key(user_id) {
+            messages.retain_mut(|m| self.filter_message(user_id, &mut m.event));
+        }
+    }
+
+    // Threads whose root message was sent by a user the member has blocked are removed entirely
+    pub fn hide_blocked_users_in_thread_previews(&self, user_id: &UserId, threads: &mut Vec<ThreadPreview>) {
+        if self.map.contains_key(user_id) {
+            threads.retain_mut(|t| {
+                self.hide_blocked_users_in_messages(user_id, &mut t.latest_replies);
+                self.filter_message(user_id, &mut t.root_message.event)
+            });
+        }
+    }
+
+    // Returns false if the message was sent by a user the member has blocked, otherwise removes any reactions from
+    // users the member has blocked and returns true
+    pub fn filter_message(&self, user_id: &UserId, message: &mut Message) -> bool {
+        let Some(blocked_users) = self.map.get(user_id) else {
+            return true;
+        };
+
+        if blocked_users.contains(&message.sender) {
+            return false;
+        }
+
+        for (_, users) in message.reactions.iter_mut() {
+            users.retain(|u| !blocked_users.contains(u));
+        }
+        message.reactions.retain(|(_, users)| !users.is_empty());
+        true
+    }
+
+    // Returns the first message, starting from the most recent, which wasn't sent by a user the member has blocked
+    pub fn latest_visible_message(
+        &self,
+        user_id: &UserId,
+        latest_messages: impl Iterator<Item = EventWrapper<Message>>,
+    ) -> Option<EventWrapper<Message>> {
+        latest_messages
+            .take(MAX_HIDDEN_MESSAGES_SKIPPED + 1)
+            .find_map(|mut m| self.filter_message(user_id, &mut m.event).then_some(m))
+    }
+}
@@ -0,0 +1,56 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use types::{DelegationScope, ScopedPrincipalUpdate, TimestampMillis, UserId};
+
+// Principals derived from members' identities which may only call the endpoints matching their scope, as pushed to us
+// by each member's user canister
+#[derive(Serialize, Deserialize, Default)]
+pub struct ScopedPrincipals {
+    map: HashMap<Principal, ScopedPrincipal>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ScopedPrincipal {
+    user_id: UserId,
+    scope: DelegationScope,
+    expires: TimestampMillis,
+}
+
+impl ScopedPrincipals {
+    pub fn update(&mut self, user_id: UserId, updates: Vec<ScopedPrincipalUpdate>, now: TimestampMillis) {
+        self.map.retain(|_, p| p.expires > now);
+
+        for update in updates {
+            // A principal can never be reassigned from one member to another
+            if self.map.get(&update.principal).is_some_and(|p| p.user_id != user_id) {
+                continue;
+            }
+
+            if let Some(scope) = update.scope.filter(|_| update.expires > now) {
+                self.map.insert(
+                    update.principal,
+                    ScopedPrincipal {
+                        user_id,
+                        scope,
+                        expires: update.expires,
+                    },
+                );
+            } else {
+                self.map.remove(&update.principal);
+            }
+        }
+    }
+
+    pub fn remove_user(&mut self, user_id: &UserId) {
+        self.map.retain(|_, p| p.user_id != *user_id);
+    }
+
+    // Returns the member the principal belongs to if its scope matches and it hasn't expired
+    pub fn resolve(&self, principal: &Principal, scope: DelegationScope, now: TimestampMillis) -> Option<UserId> {
+        self.map
+            .get(principal)
+            .filter(|p| p.scope == scope && p.expires > now)
+            .map(|p| p.user_id)
+    }
+}
@@ -0,0 +1,95 @@
+use constants::HOUR_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{CanisterId, ChannelId, Milliseconds, SubscriptionPayment, TimestampMillis, UserId};
+
+// How long to wait before retrying a failed renewal while the member is within the grace period
+pub const SUBSCRIPTION_RENEWAL_RETRY_INTERVAL: Milliseconds = HOUR_IN_MS;
+
+const MAX_PAYMENTS_PER_MEMBER: usize = 100;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Subscriptions {
+    members: BTreeMap<(Option<ChannelId>, UserId), MemberSubscription>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct MemberSubscription {
+    #[serde(rename = "u")]
+    paid_until: TimestampMillis,
+    #[serde(rename = "p")]
+    payments: Vec<SubscriptionPayment>,
+}
+
+impl Subscriptions {
+    pub fn record_payment(
+        &mut self,
+        user_id: UserId,
+        channel_id: Option<ChannelId>,
+        ledger_canister_id: CanisterId,
+        amount: u128,
+        paid_until: TimestampMillis,
+        now: TimestampMillis,
+    ) {
+        let subscription = self.members.entry((channel_id, user_id)).or_default();
+        subscription.paid_until = paid_until;
+        subscription.payments.push(SubscriptionPayment {
+            timestamp: now,
+            ledger_canister_id,
+            amount,
+            paid_until,
+        });
+
+        if subscription.payments.len() > MAX_PAYMENTS_PER_MEMBER {
+            subscription.payments.remove(0);
+        }
+    }
+
+    pub fn remove_member(&mut self, user_id: UserId, channel_id: Option<ChannelId>) {
+        self.members.remove(&(channel_id, user_id));
+    }
+
+    // Removes the user's subscriptions to the community and to each of its channels
+    pub fn remove_user(&mut self, user_id: UserId) {
+        self.members.retain(|(_, u), _| *u != user_id);
+    }
+
+    pub fn remove_gate(&mut self, channel_id: Option<ChannelId>) {
+        self.members.retain(|(c, _), _| *c != channel_id);
+    }
+
+    pub fn paid_until(&self, user_id: UserId, channel_id: Option<ChannelId>) -> Option<TimestampMillis> {
+        self.members.get(&(channel_id, user_id)).map(|s| s.paid_until)
+    }
+
+    // Returns the member's payments, most recent first
+    pub fn payments(&self, user_id: UserId, channel_id: Option<ChannelId>) -> Vec<SubscriptionPayment> {
+        self.members
+            .get(&(channel_id, user_id))
+            .map(|s| s.payments.iter().rev().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Called when a renewal payment fails. If the member's grace period is still running, returns the
+    // time at which it ends. Members who have never paid (eg. because the gate was added after they
+    // joined) have their grace period measured from the end of their current period.
+    pub fn renewal_failed(
+        &mut self,
+        user_id: UserId,
+        channel_id: Option<ChannelId>,
+        period_end: TimestampMillis,
+        grace_period: Milliseconds,
+        now: TimestampMillis,
+    ) -> Option<TimestampMillis> {
+        let subscription = self
+            .members
+            .entry((channel_id, user_id))
+            .or_insert_with(|| MemberSubscription {
+                paid_until: period_end,
+                payments: Vec::new(),
+            });
+        let grace_period_end = subscription.paid_until.saturating_add(grace_period);
+
+        (now < grace_period_end).then_some(grace_period_end)
+    }
+}
@@ -0,0 +1,69 @@
+use constants::{MINUTE_IN_MS, SECOND_IN_MS};
+use std::collections::HashMap;
+use std::hash::Hash;
+use types::{Milliseconds, TimestampMillis, UserId};
+
+const TYPING_TIMEOUT: Milliseconds = 5 * SECOND_IN_MS;
+const PRUNE_INTERVAL: Milliseconds = MINUTE_IN_MS;
+
+// Ephemeral record of which members are currently typing in each chat / thread, identified by `K`. This is held in
+// memory only and is not persisted across upgrades. Each entry expires after `TYPING_TIMEOUT` unless refreshed.
+pub struct Typing<K> {
+    chats: HashMap<K, HashMap<UserId, TimestampMillis>>,
+    last_pruned: TimestampMillis,
+}
+
+impl<K> Default for Typing<K> {
+    fn default() -> Self {
+        Typing {
+            chats: HashMap::new(),
+            last_pruned: 0,
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash> Typing<K> {
+    pub fn set(&mut self, user_id: UserId, key: K, is_typing: bool, now: TimestampMillis) {
+        if now.saturating_sub(self.last_pruned) > PRUNE_INTERVAL {
+            self.prune(now);
+        }
+
+        if is_typing {
+            self.chats.entry(key).or_default().insert(user_id, now + TYPING_TIMEOUT);
+        } else if let Some(users) = self.chats.get_mut(&key) {
+            users.remove(&user_id);
+            if users.is_empty() {
+                self.chats.remove(&key);
+            }
+        }
+    }
+
+    // Returns the users, other than the caller, who are currently typing in the chat / thread
+    pub fn get(&self, caller: UserId, key: &K, now: TimestampMillis) -> Vec<UserId> {
+        self.chats
+            .get(key)
+            .map(|users| {
+                users
+                    .iter()
+                    .filter(|(u, expires)| **expires > now && **u != caller)
+                    .map(|(u, _)| *u)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn remove_user(&mut self, user_id: &UserId) {
+        self.chats.retain(|_, users| {
+            users.remove(user_id);
+            !users.is_empty()
+        });
+    }
+
+    fn prune(&mut self, now: TimestampMillis) {
+        self.chats.retain(|_, users| {
+            users.retain(|_, expires| *expires > now);
+            !users.is_empty()
+        });
+        self.last_pruned = now;
+    }
+}
@@ -3,6 +3,7 @@ use std::{collections::HashMap, str::FromStr};
 use types::{ChannelId, FileId, TimestampMillis, UserId};
 
 pub enum Route {
+    AchievementImage(u32, Option<u128>),
     Avatar(Option<u128>),
     BotAvatar(UserId, Option<u128>),
     Banner(Option<u128>),
@@ -27,6 +28,14 @@ pub fn extract_route(path: &str) -> Route {
     let parts: Vec<_> = path.split('/').collect();
 
     match parts[0] {
+        "achievement" => {
+            if let Some(achievement_id) = parts.get(1).and_then(|p| u32::from_str(p).ok()) {
+                if parts.get(2).is_some_and(|p| *p == "image") {
+                    let blob_id = parts.get(3).and_then(|p| u128::from_str(p).ok());
+                    return Route::AchievementImage(achievement_id, blob_id);
+                }
+            }
+        }
         "avatar" => {
             if let Some(user_id) = parts.get(1).and_then(|p| Principal::from_text(*p).ok()).map(UserId::from) {
                 let blob_id = parts.get(2).and_then(|p| u128::from_str(p).ok());
@@ -94,6 +103,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn achievement_image() {
+        const BLOB_ID: u128 = 367253521351235123;
+        match extract_route(&format!("/achievement/123/image/{BLOB_ID}")) {
+            Route::AchievementImage(123, Some(id)) => assert_eq!(BLOB_ID, id),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn logs() {
         assert!(matches!(extract_route("/logs/1633649663014109000"), Route::Logs(_)));
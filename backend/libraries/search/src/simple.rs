@@ -41,6 +41,19 @@ impl Document {
             query.tokens.iter().all(|t| self.0.iter().any(|f| f.0.contains(&t.0)))
         }
     }
+
+    // Returns 0 if the document doesn't match the query, else the total number of occurrences of the query's tokens
+    pub fn score(&self, query: &Query) -> u32 {
+        if !self.is_match(query) {
+            return 0;
+        }
+
+        query
+            .tokens
+            .iter()
+            .map(|t| self.0.iter().map(|f| f.0.matches(&t.0).count() as u32).sum::<u32>())
+            .sum()
+    }
 }
 
 fn parse_tokens(text: &str) -> Vec<Token> {
@@ -66,4 +79,17 @@ mod tests {
 
         assert_eq!(doc.is_match(&Query::new(query)), should_match);
     }
+
+    #[test_case(vec!["abc"], "xyz", 0)]
+    #[test_case(vec!["abc abc"], "abc", 2)]
+    #[test_case(vec!["abc abc", "xyz"], "abc xyz", 3)]
+    #[test_case(vec!["abc abc"], "abc xyz", 0)]
+    fn scores_calculated_correctly(doc_fields: Vec<&str>, query: &str, expected_score: u32) {
+        let mut doc = Document::default();
+        for field in doc_fields {
+            doc.add_field(field);
+        }
+
+        assert_eq!(doc.score(&Query::new(query)), expected_score);
+    }
 }
@@ -0,0 +1,14 @@
+use crate::{ChannelId, GroupPermissions};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChannelCategory {
+    pub id: u32,
+    pub name: String,
+    pub channel_ids: Vec<ChannelId>,
+    pub collapsed_by_default: bool,
+    pub default_permissions: Option<GroupPermissions>,
+}
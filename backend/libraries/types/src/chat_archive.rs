@@ -0,0 +1,60 @@
+use crate::{ChatEvent, EventContext, EventIndex, EventWrapper, GroupMember, MessageIndex};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+// Chat archives are built by paging through the `export_*` queries. An archive consists of every
+// event in the chat, main events first followed by the events of each thread (ordered by thread
+// root message index), plus the list of members for group chats and channels.
+//
+// Each page is tagged with the format version so that importers can reject archives which were
+// exported using a format they don't understand. The version must be bumped whenever a breaking
+// change is made to any of the types included in an archive.
+pub const CHAT_ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ArchivedEvent {
+    pub thread_root_message_index: Option<MessageIndex>,
+    #[ts(as = "crate::EventWrapperChatEvent")]
+    pub event: EventWrapper<ChatEvent>,
+}
+
+impl ArchivedEvent {
+    pub fn cursor(&self) -> ChatArchiveCursor {
+        ChatArchiveCursor {
+            thread_root_message_index: self.thread_root_message_index,
+            event_index: self.event.index,
+        }
+    }
+}
+
+// Identifies the last event read by the previous page, the next page starts from the event after it
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ChatArchiveCursor {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub event_index: EventIndex,
+}
+
+impl From<ChatArchiveCursor> for EventContext {
+    fn from(value: ChatArchiveCursor) -> Self {
+        EventContext::new(value.thread_root_message_index, value.event_index)
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChatArchiveEventsPage {
+    pub format_version: u32,
+    pub events: Vec<ArchivedEvent>,
+    pub finished: bool,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChatArchiveMembersPage {
+    pub format_version: u32,
+    pub members: Vec<GroupMember>,
+    pub finished: bool,
+}
@@ -169,6 +169,7 @@ pub struct GroupCanisterGroupChatSummary {
     pub membership: Option<GroupMembership>,
     pub video_call_in_progress: Option<VideoCall>,
     pub verified: bool,
+    pub read_receipts_enabled: bool,
 }
 
 #[ts_export]
@@ -215,6 +216,7 @@ pub struct GroupCanisterGroupChatSummaryUpdates {
     pub video_call_in_progress: OptionUpdate<VideoCall>,
     pub any_updates_missed: bool,
     pub verified: Option<bool>,
+    pub read_receipts_enabled: Option<bool>,
 }
 
 #[ts_export]
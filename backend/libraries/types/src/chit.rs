@@ -11,6 +11,14 @@ pub struct ChitEarned {
     pub reason: ChitEarnedReason,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ChitBalances {
+    pub user_id: UserId,
+    pub week: i32,
+    pub month: i32,
+    pub all_time: i32,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct NotifyChit {
     pub timestamp: TimestampMillis,
@@ -19,6 +27,14 @@ pub struct NotifyChit {
     pub streak_ends: TimestampMillis,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ChitLeaderboardWindow {
+    Week,
+    Month,
+    AllTime,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub enum ChitEarnedReason {
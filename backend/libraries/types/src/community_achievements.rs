@@ -0,0 +1,36 @@
+use crate::{Milliseconds, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct CommunityAchievement {
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    pub image_id: Option<u128>,
+    pub criteria: CommunityAchievementCriteria,
+    pub awarded_count: u32,
+    pub created: TimestampMillis,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommunityAchievementCriteria {
+    // Only awarded by members or bots with the `ManageAchievements` permission
+    Manual,
+    // Awarded automatically once the member has earned at least this much CHIT
+    ChitEarned(u32),
+    // Awarded automatically once the member has been in the community for at least this long
+    MembershipDuration(Milliseconds),
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct AwardedCommunityAchievement {
+    pub achievement_id: u32,
+    pub awarded: TimestampMillis,
+    // None if the achievement was awarded automatically
+    pub awarded_by: Option<UserId>,
+}
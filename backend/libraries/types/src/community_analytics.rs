@@ -0,0 +1,56 @@
+use crate::{ChannelId, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnalyticsWindow {
+    Week,
+    Month,
+    Quarter,
+}
+
+impl AnalyticsWindow {
+    pub fn days(&self) -> u64 {
+        match self {
+            AnalyticsWindow::Week => 7,
+            AnalyticsWindow::Month => 30,
+            AnalyticsWindow::Quarter => 90,
+        }
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct DailyActivity {
+    pub date: TimestampMillis,
+    pub active_members: u32,
+    pub messages: u32,
+    pub joins: u32,
+    pub leaves: u32,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChannelMessageCount {
+    pub channel_id: ChannelId,
+    pub messages: u32,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct TopContributor {
+    pub user_id: UserId,
+    pub messages: u32,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct RetentionCohort {
+    // The start of the week in which the cohort's members joined
+    pub week_starting: TimestampMillis,
+    pub members: u32,
+    // The number of the cohort's members who were active in each week since joining, starting with the week they joined
+    pub active_by_week: Vec<u32>,
+}
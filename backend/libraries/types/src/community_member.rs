@@ -14,4 +14,7 @@ pub struct CommunityMember {
     pub display_name: Option<String>,
     pub referred_by: Option<UserId>,
     pub lapsed: bool,
+    // The Ids of the community achievements awarded to the member
+    #[serde(default)]
+    pub achievements: Vec<u32>,
 }
@@ -26,6 +26,7 @@ pub enum CommunityPermission {
     CreatePublicChannel = 4,
     CreatePrivateChannel = 5,
     ManageUserGroups = 6,
+    ManageAchievements = 7,
 }
 
 impl From<CommunityPermission> for u8 {
@@ -46,6 +47,7 @@ impl TryFrom<u8> for CommunityPermission {
             4 => Ok(CommunityPermission::CreatePublicChannel),
             5 => Ok(CommunityPermission::CreatePrivateChannel),
             6 => Ok(CommunityPermission::ManageUserGroups),
+            7 => Ok(CommunityPermission::ManageAchievements),
             _ => Err(()),
         }
     }
@@ -61,6 +63,8 @@ pub struct CommunityPermissions {
     pub create_public_channel: CommunityPermissionRole,
     pub create_private_channel: CommunityPermissionRole,
     pub manage_user_groups: CommunityPermissionRole,
+    #[serde(default = "admin")]
+    pub manage_achievements: CommunityPermissionRole,
 }
 
 #[ts_export]
@@ -73,6 +77,7 @@ pub struct OptionalCommunityPermissions {
     pub create_public_channel: Option<CommunityPermissionRole>,
     pub create_private_channel: Option<CommunityPermissionRole>,
     pub manage_user_groups: Option<CommunityPermissionRole>,
+    pub manage_achievements: Option<CommunityPermissionRole>,
 }
 
 impl Default for CommunityPermissions {
@@ -85,10 +90,15 @@ impl Default for CommunityPermissions {
             create_public_channel: CommunityPermissionRole::Admins,
             create_private_channel: CommunityPermissionRole::Admins,
             manage_user_groups: CommunityPermissionRole::Admins,
+            manage_achievements: CommunityPermissionRole::Admins,
         }
     }
 }
 
+fn admin() -> CommunityPermissionRole {
+    CommunityPermissionRole::Admins
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug)]
 pub enum CommunityPermissionRole {
@@ -150,6 +160,10 @@ impl CommunityRole {
         self.is_permitted(permissions.manage_user_groups)
     }
 
+    pub fn can_manage_achievements(&self, permissions: &CommunityPermissions) -> bool {
+        self.is_permitted(permissions.manage_achievements)
+    }
+
     pub fn can_delete_community(&self) -> bool {
         self.has_owner_rights()
     }
@@ -188,6 +202,7 @@ impl CommunityRole {
             (rps.create_private_channel, CommunityPermission::CreatePrivateChannel),
             (rps.create_public_channel, CommunityPermission::CreatePublicChannel),
             (rps.invite_users, CommunityPermission::InviteUsers),
+            (rps.manage_achievements, CommunityPermission::ManageAchievements),
             (rps.manage_user_groups, CommunityPermission::ManageUserGroups),
             (rps.remove_members, CommunityPermission::RemoveMembers),
             (rps.update_details, CommunityPermission::UpdateDetails),
@@ -1,6 +1,6 @@
 use crate::user_groups::UserGroupSummary;
 use crate::{
-    AccessGate, AccessGateConfig, CanisterId, ChannelId, ChatMetrics, CommunityCanisterChannelSummary,
+    AccessGate, AccessGateConfig, CanisterId, ChannelCategory, ChannelId, ChatMetrics, CommunityCanisterChannelSummary,
     CommunityCanisterChannelSummaryUpdates, CommunityId, CommunityPermissions, CommunityRole, EventIndex, FrozenGroupInfo,
     OptionUpdate, TimestampMillis,
 };
@@ -29,6 +29,7 @@ pub struct CommunityCanisterCommunitySummary {
     pub channels: Vec<CommunityCanisterChannelSummary>,
     pub membership: Option<CommunityMembership>,
     pub user_groups: Vec<UserGroupSummary>,
+    pub channel_categories: Vec<ChannelCategory>,
     pub is_invited: Option<bool>,
     pub metrics: ChatMetrics,
     pub verified: bool,
@@ -42,6 +43,8 @@ pub struct CommunityMembership {
     pub rules_accepted: bool,
     pub display_name: Option<String>,
     pub lapsed: bool,
+    #[serde(default)]
+    pub achievements: Vec<u32>,
 }
 
 #[ts_export]
@@ -72,6 +75,7 @@ pub struct CommunityCanisterCommunitySummaryUpdates {
     pub membership: Option<CommunityMembershipUpdates>,
     pub user_groups: Vec<UserGroupSummary>,
     pub user_groups_deleted: Vec<u32>,
+    pub channel_categories: Option<Vec<ChannelCategory>>,
     pub metrics: Option<ChatMetrics>,
     pub verified: Option<bool>,
 }
@@ -84,4 +88,5 @@ pub struct CommunityMembershipUpdates {
     #[ts(as = "crate::OptionUpdateString")]
     pub display_name: OptionUpdate<String>,
     pub lapsed: Option<bool>,
+    pub achievements: Option<Vec<u32>>,
 }
@@ -1,5 +1,5 @@
-use crate::TimestampNanos;
-use candid::{CandidType, Deserialize};
+use crate::{TimestampMillis, TimestampNanos};
+use candid::{CandidType, Deserialize, Principal};
 use serde::Serialize;
 use ts_export::ts_export;
 
@@ -18,3 +18,21 @@ pub struct SignedDelegation {
     #[serde(with = "serde_bytes")]
     pub signature: Vec<u8>,
 }
+
+// Restricts what a scoped delegation (eg. one handed to a third-party client) can be used for. Canisters which
+// recognise scoped principals only permit them to call endpoints matching their scope.
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DelegationScope {
+    ReadOnly,
+    SendMessages,
+}
+
+// A scoped principal as pushed by a user's canister to the groups and communities the user is a member of. A scope of
+// `None` means the principal has been revoked.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ScopedPrincipalUpdate {
+    pub principal: Principal,
+    pub scope: Option<DelegationScope>,
+    pub expires: TimestampMillis,
+}
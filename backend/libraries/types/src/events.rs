@@ -40,6 +40,9 @@ pub enum ChatEvent {
     BotAdded(Box<BotAdded>),
     BotRemoved(Box<BotRemoved>),
     BotUpdated(Box<BotUpdated>),
+    JoinRequested(JoinRequested),
+    JoinRequestRejected(JoinRequestRejected),
+    ReadReceiptsChanged(ReadReceiptsChanged),
     FailedToDeserialize,
 }
 
@@ -367,6 +370,13 @@ pub struct ExternalUrlUpdated {
     pub new_url: Option<String>,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ReadReceiptsChanged {
+    pub enabled: bool,
+    pub changed_by: UserId,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug)]
 pub struct DirectChatCreated {}
@@ -378,6 +388,19 @@ pub struct UsersInvited {
     pub invited_by: UserId,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct JoinRequested {
+    pub user_id: UserId,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct JoinRequestRejected {
+    pub user_id: UserId,
+    pub rejected_by: UserId,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct ChannelDeleted {
@@ -488,7 +511,8 @@ impl ChatEvent {
             | ChatEvent::ChatUnfrozen(_)
             | ChatEvent::EventsTimeToLiveUpdated(_)
             | ChatEvent::GroupGateUpdated(_)
-            | ChatEvent::ExternalUrlUpdated(_) => Some(ChatEventType::ChatDetailsUpdate),
+            | ChatEvent::ExternalUrlUpdated(_)
+            | ChatEvent::ReadReceiptsChanged(_) => Some(ChatEventType::ChatDetailsUpdate),
             ChatEvent::ParticipantsAdded(_)
             | ChatEvent::ParticipantsRemoved(_)
             | ChatEvent::ParticipantJoined(_)
@@ -500,7 +524,9 @@ impl ChatEvent {
             | ChatEvent::MembersAddedToDefaultChannel(_)
             | ChatEvent::BotAdded(_)
             | ChatEvent::BotRemoved(_)
-            | ChatEvent::BotUpdated(_) => Some(ChatEventType::MembershipUpdate),
+            | ChatEvent::BotUpdated(_)
+            | ChatEvent::JoinRequested(_)
+            | ChatEvent::JoinRequestRejected(_) => Some(ChatEventType::MembershipUpdate),
             ChatEvent::Empty | ChatEvent::FailedToDeserialize => None,
         }
     }
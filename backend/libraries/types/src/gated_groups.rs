@@ -1,5 +1,5 @@
 use crate::icrc2::TransferFromError;
-use crate::{CanisterId, Milliseconds};
+use crate::{CanisterId, Milliseconds, TimestampMillis};
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -23,6 +23,11 @@ pub struct AccessGateConfigInternal {
 
 impl AccessGateConfigInternal {
     pub fn expiry(&self) -> Option<Milliseconds> {
+        // Subscription gates always expire at the end of each subscription period
+        if let AccessGate::Subscription(gate) = self.gate() {
+            return Some(gate.period);
+        }
+
         let expiry_type: AccessGateExpiryBehaviour = self.gate().into();
         match expiry_type {
             AccessGateExpiryBehaviour::Invalid => None,
@@ -82,7 +87,12 @@ pub enum AccessGate {
     VerifiedCredential(VerifiedCredentialGate),
     SnsNeuron(SnsNeuronGate),
     Payment(PaymentGate),
+    Subscription(SubscriptionGate),
     TokenBalance(TokenBalanceGate),
+    Nft(NftGate),
+    Chit(ChitGate),
+    CommunityTenure(CommunityTenureGate),
+    CommunityMessageCount(CommunityMessageCountGate),
     Composite(CompositeGate),
     Locked,
     ReferredByMember,
@@ -96,7 +106,12 @@ pub enum AccessGateType {
     VerifiedCredential,
     SnsNeuron,
     Payment,
+    Subscription,
     TokenBalance,
+    Nft,
+    Chit,
+    CommunityTenure,
+    CommunityMessageCount,
     Composite,
     Locked,
     ReferredByMember,
@@ -111,7 +126,12 @@ impl Display for AccessGateType {
             AccessGateType::VerifiedCredential => "verified_credential",
             AccessGateType::SnsNeuron => "sns_neuron",
             AccessGateType::Payment => "payment",
+            AccessGateType::Subscription => "subscription",
             AccessGateType::TokenBalance => "token_balance",
+            AccessGateType::Nft => "nft",
+            AccessGateType::Chit => "chit",
+            AccessGateType::CommunityTenure => "community_tenure",
+            AccessGateType::CommunityMessageCount => "community_message_count",
             AccessGateType::Composite => "composite",
             AccessGateType::Locked => "locked",
             AccessGateType::ReferredByMember => "referred_by_member",
@@ -130,7 +150,12 @@ pub enum AccessGateNonComposite {
     VerifiedCredential(VerifiedCredentialGate),
     SnsNeuron(SnsNeuronGate),
     Payment(PaymentGate),
+    Subscription(SubscriptionGate),
     TokenBalance(TokenBalanceGate),
+    Nft(NftGate),
+    Chit(ChitGate),
+    CommunityTenure(CommunityTenureGate),
+    CommunityMessageCount(CommunityMessageCountGate),
     Locked,
     ReferredByMember,
 }
@@ -160,7 +185,14 @@ impl From<AccessGate> for AccessGateScope {
             }
             AccessGate::SnsNeuron(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::SnsNeuron(gate)),
             AccessGate::Payment(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::Payment(gate)),
+            AccessGate::Subscription(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::Subscription(gate)),
             AccessGate::TokenBalance(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::TokenBalance(gate)),
+            AccessGate::Nft(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::Nft(gate)),
+            AccessGate::Chit(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::Chit(gate)),
+            AccessGate::CommunityTenure(gate) => AccessGateScope::NonComposite(AccessGateNonComposite::CommunityTenure(gate)),
+            AccessGate::CommunityMessageCount(gate) => {
+                AccessGateScope::NonComposite(AccessGateNonComposite::CommunityMessageCount(gate))
+            }
             AccessGate::Locked => AccessGateScope::NonComposite(AccessGateNonComposite::Locked),
             AccessGate::ReferredByMember => AccessGateScope::NonComposite(AccessGateNonComposite::ReferredByMember),
         }
@@ -228,7 +260,13 @@ impl From<AccessGateType> for AccessGateExpiryBehaviour {
                 AccessGateExpiryBehaviour::UserLookup
             }
             AccessGateType::Payment | AccessGateType::VerifiedCredential => AccessGateExpiryBehaviour::Lapse,
-            AccessGateType::SnsNeuron | AccessGateType::TokenBalance => AccessGateExpiryBehaviour::Check,
+            AccessGateType::SnsNeuron
+            | AccessGateType::Subscription
+            | AccessGateType::TokenBalance
+            | AccessGateType::Nft
+            | AccessGateType::Chit
+            | AccessGateType::CommunityTenure
+            | AccessGateType::CommunityMessageCount => AccessGateExpiryBehaviour::Check,
             _ => AccessGateExpiryBehaviour::Invalid,
         }
     }
@@ -243,7 +281,12 @@ impl From<&AccessGate> for AccessGateType {
             AccessGate::VerifiedCredential(_) => AccessGateType::VerifiedCredential,
             AccessGate::SnsNeuron(_) => AccessGateType::SnsNeuron,
             AccessGate::Payment(_) => AccessGateType::Payment,
+            AccessGate::Subscription(_) => AccessGateType::Subscription,
             AccessGate::TokenBalance(_) => AccessGateType::TokenBalance,
+            AccessGate::Nft(_) => AccessGateType::Nft,
+            AccessGate::Chit(_) => AccessGateType::Chit,
+            AccessGate::CommunityTenure(_) => AccessGateType::CommunityTenure,
+            AccessGate::CommunityMessageCount(_) => AccessGateType::CommunityMessageCount,
             AccessGate::Composite(_) => AccessGateType::Composite,
             AccessGate::Locked => AccessGateType::Locked,
             AccessGate::ReferredByMember => AccessGateType::ReferredByMember,
@@ -260,7 +303,12 @@ impl From<&AccessGateNonComposite> for AccessGateType {
             AccessGateNonComposite::VerifiedCredential(_) => AccessGateType::VerifiedCredential,
             AccessGateNonComposite::SnsNeuron(_) => AccessGateType::SnsNeuron,
             AccessGateNonComposite::Payment(_) => AccessGateType::Payment,
+            AccessGateNonComposite::Subscription(_) => AccessGateType::Subscription,
             AccessGateNonComposite::TokenBalance(_) => AccessGateType::TokenBalance,
+            AccessGateNonComposite::Nft(_) => AccessGateType::Nft,
+            AccessGateNonComposite::Chit(_) => AccessGateType::Chit,
+            AccessGateNonComposite::CommunityTenure(_) => AccessGateType::CommunityTenure,
+            AccessGateNonComposite::CommunityMessageCount(_) => AccessGateType::CommunityMessageCount,
             AccessGateNonComposite::Locked => AccessGateType::Locked,
             AccessGateNonComposite::ReferredByMember => AccessGateType::ReferredByMember,
         }
@@ -290,22 +338,63 @@ impl AccessGateConfig {
             }
         }
 
+        if let AccessGate::Subscription(gate) = &self.gate {
+            if !test_mode && gate.period < DAY_IN_MS {
+                return false;
+            }
+        }
+
         self.gate.validate()
     }
 }
 
 impl AccessGate {
     pub fn validate(&self) -> bool {
-        if let AccessGate::Composite(g) = self {
-            if g.inner.is_empty() || g.inner.len() > 10 {
-                return false;
+        match self {
+            AccessGate::Composite(g) => {
+                !g.inner.is_empty()
+                    && g.inner.len() <= 10
+                    && g.inner.iter().all(|i| match i {
+                        AccessGateNonComposite::Nft(n) => n.validate(),
+                        AccessGateNonComposite::Chit(c) => c.validate(),
+                        // Renewals are charged as part of the expiry checks, which only makes sense
+                        // when the subscription is the whole gate
+                        AccessGateNonComposite::Subscription(_) => false,
+                        _ => true,
+                    })
             }
+            AccessGate::Subscription(g) => g.validate(),
+            AccessGate::Nft(g) => g.validate(),
+            AccessGate::Chit(g) => g.validate(),
+            _ => true,
+        }
+    }
+
+    // Tenure and message count gates can only be evaluated against an existing community
+    // membership, so they are only valid on channels
+    pub fn requires_community_membership(&self) -> bool {
+        match self {
+            AccessGate::CommunityTenure(_) | AccessGate::CommunityMessageCount(_) => true,
+            AccessGate::Composite(g) => g.inner.iter().any(|i| {
+                matches!(
+                    i,
+                    AccessGateNonComposite::CommunityTenure(_) | AccessGateNonComposite::CommunityMessageCount(_)
+                )
+            }),
+            _ => false,
         }
-        true
     }
 
     pub fn is_payment_gate(&self) -> bool {
-        matches!(self, AccessGate::Payment(_))
+        matches!(self, AccessGate::Payment(_) | AccessGate::Subscription(_))
+    }
+
+    pub fn subscription_gate(&self) -> Option<&SubscriptionGate> {
+        if let AccessGate::Subscription(gate) = self {
+            Some(gate)
+        } else {
+            None
+        }
     }
 
     pub fn gate_type(&self) -> AccessGateType {
@@ -346,6 +435,33 @@ pub struct PaymentGate {
     pub fee: u128,
 }
 
+// Charges `amount` via ICRC-2 `transfer_from` on joining and again at the end of each `period`.
+// If a renewal fails it is retried until `grace_period` has passed, after which the membership lapses.
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionGate {
+    pub ledger_canister_id: CanisterId,
+    pub amount: u128,
+    pub fee: u128,
+    pub period: Milliseconds,
+    pub grace_period: Milliseconds,
+}
+
+impl SubscriptionGate {
+    fn validate(&self) -> bool {
+        self.amount > 2 * self.fee && self.grace_period <= self.period
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct SubscriptionPayment {
+    pub timestamp: TimestampMillis,
+    pub ledger_canister_id: CanisterId,
+    pub amount: u128,
+    pub paid_until: TimestampMillis,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TokenBalanceGate {
@@ -353,6 +469,63 @@ pub struct TokenBalanceGate {
     pub min_balance: u128,
 }
 
+// Requires the user to own NFTs from an ICRC-7 collection. If `token_ids` is set, only those
+// tokens count towards `min_count`, which defaults to 1.
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NftGate {
+    pub collection_canister_id: CanisterId,
+    pub token_ids: Option<Vec<u128>>,
+    pub min_count: Option<u32>,
+}
+
+impl NftGate {
+    const MAX_TOKEN_IDS: usize = 1000;
+
+    pub fn min_count(&self) -> u32 {
+        self.min_count.unwrap_or(1)
+    }
+
+    fn validate(&self) -> bool {
+        let min_count = self.min_count() as usize;
+        if min_count == 0 {
+            return false;
+        }
+        match &self.token_ids {
+            Some(token_ids) => token_ids.len() >= min_count && token_ids.len() <= NftGate::MAX_TOKEN_IDS,
+            None => true,
+        }
+    }
+}
+
+// Requires the user to have at least `min_balance` CHIT and/or a current streak of at least
+// `min_streak` days. At least one of the two must be set.
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ChitGate {
+    pub min_balance: Option<u32>,
+    pub min_streak: Option<u16>,
+}
+
+impl ChitGate {
+    fn validate(&self) -> bool {
+        self.min_balance.is_some() || self.min_streak.is_some()
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CommunityTenureGate {
+    pub min_membership_duration: Milliseconds,
+}
+
+// Counts the messages the user has sent across all of the community's channels they are in
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CommunityMessageCountGate {
+    pub min_messages: u32,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct CompositeGate {
@@ -371,6 +544,11 @@ pub enum GateCheckFailedReason {
     NoSnsNeuronsWithRequiredDissolveDelayFound,
     PaymentFailed(TransferFromError),
     InsufficientBalance(u128),
+    InsufficientNfts(u32),
+    InsufficientChit(i32),
+    InsufficientStreak(u16),
+    InsufficientCommunityTenure(Milliseconds),
+    InsufficientCommunityMessages(u32),
     FailedVerifiedCredentialCheck(String),
     Locked,
     NotReferredByMember,
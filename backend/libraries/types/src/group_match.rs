@@ -1,4 +1,4 @@
-use crate::{AccessGate, AccessGateConfig, ChannelId, ChatId, CommunityId, GroupSubtype};
+use crate::{AccessGate, AccessGateConfig, ChannelId, ChatId, CommunityId, GroupSubtype, Tag};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
@@ -14,6 +14,8 @@ pub struct GroupMatch {
     pub gate: Option<AccessGate>,
     pub subtype: Option<GroupSubtype>,
     pub verified: bool,
+    pub tags: Vec<Tag>,
+    pub languages: Vec<String>,
 }
 
 #[ts_export]
@@ -32,6 +34,8 @@ pub struct CommunityMatch {
     pub moderation_flags: u32,
     pub primary_language: String,
     pub verified: bool,
+    pub tags: Vec<Tag>,
+    pub languages: Vec<String>,
 }
 
 #[ts_export]
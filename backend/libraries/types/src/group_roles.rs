@@ -1,8 +1,12 @@
 use crate::OptionUpdate;
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use ts_export::ts_export;
 
+const MAX_CUSTOM_PERMISSIONS: usize = 20;
+const MAX_CUSTOM_PERMISSION_SUBTYPE_LENGTH: usize = 50;
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub enum GroupRole {
@@ -14,7 +18,7 @@ pub enum GroupRole {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct GroupPermissions {
     pub change_roles: GroupPermissionRole,
     pub update_group: GroupPermissionRole,
@@ -36,7 +40,7 @@ fn admin() -> GroupPermissionRole {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct MessagePermissions {
     pub default: GroupPermissionRole,
     pub text: Option<GroupPermissionRole>,
@@ -55,7 +59,7 @@ pub struct MessagePermissions {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct CustomPermission {
     pub subtype: String,
     pub role: GroupPermissionRole,
@@ -148,6 +152,26 @@ impl Default for MessagePermissions {
     }
 }
 
+impl GroupPermissions {
+    pub fn validate(&self) -> bool {
+        self.message_permissions.validate() && self.thread_permissions.as_ref().map_or(true, |p| p.validate())
+    }
+}
+
+impl MessagePermissions {
+    // Checks that there are a bounded number of custom permissions and that their subtypes are valid and unique
+    pub fn validate(&self) -> bool {
+        let mut subtypes = HashSet::new();
+
+        self.custom.len() <= MAX_CUSTOM_PERMISSIONS
+            && self.custom.iter().all(|p| {
+                !p.subtype.is_empty()
+                    && p.subtype.len() <= MAX_CUSTOM_PERMISSION_SUBTYPE_LENGTH
+                    && subtypes.insert(p.subtype.as_str())
+            })
+    }
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
 pub enum GroupPermissionRole {
@@ -0,0 +1,12 @@
+use crate::{TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct JoinRequest {
+    pub user_id: UserId,
+    pub requested_at: TimestampMillis,
+    pub answer: Option<String>,
+}
@@ -16,13 +16,17 @@ pub mod c2c_uninstall_bot;
 mod caller;
 mod canister_upgrade_status;
 mod canister_wasm;
+mod channel_categories;
 mod channel_id;
 mod channel_summary;
 mod chat;
+mod chat_archive;
 mod chat_id;
 mod chat_summary;
 mod chit;
 mod claims;
+mod community_achievements;
+mod community_analytics;
 mod community_id;
 mod community_member;
 mod community_roles;
@@ -51,6 +55,7 @@ mod group_roles;
 mod http;
 mod idempotency;
 mod indexed_event;
+mod join_requests;
 mod mention;
 mod message;
 mod message_content;
@@ -63,6 +68,7 @@ mod p2p_swaps;
 mod phone_number;
 mod pin_number;
 mod polls;
+mod presence;
 mod proof_of_uniqueness;
 mod proposals;
 mod range_set;
@@ -73,6 +79,7 @@ mod relayed_args;
 mod source_group;
 mod subscription;
 mod suspension;
+mod tags;
 mod thread_preview;
 mod thread_summary;
 mod timestamped;
@@ -93,13 +100,17 @@ pub use build_version::*;
 pub use caller::*;
 pub use canister_upgrade_status::*;
 pub use canister_wasm::*;
+pub use channel_categories::*;
 pub use channel_id::*;
 pub use channel_summary::*;
 pub use chat::*;
+pub use chat_archive::*;
 pub use chat_id::*;
 pub use chat_summary::*;
 pub use chit::*;
 pub use claims::*;
+pub use community_achievements::*;
+pub use community_analytics::*;
 pub use community_id::*;
 pub use community_member::*;
 pub use community_roles::*;
@@ -128,6 +139,7 @@ pub use group_roles::*;
 pub use http::*;
 pub use idempotency::*;
 pub use indexed_event::*;
+pub use join_requests::*;
 pub use mention::*;
 pub use message::*;
 pub use message_content::*;
@@ -140,6 +152,7 @@ pub use p2p_swaps::*;
 pub use phone_number::*;
 pub use pin_number::*;
 pub use polls::*;
+pub use presence::*;
 pub use proof_of_uniqueness::*;
 pub use proposals::*;
 pub use range_set::*;
@@ -150,6 +163,7 @@ pub use relayed_args::*;
 pub use source_group::*;
 pub use subscription::*;
 pub use suspension::*;
+pub use tags::*;
 pub use thread_preview::*;
 pub use thread_summary::*;
 pub use timestamped::*;
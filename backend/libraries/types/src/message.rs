@@ -1,6 +1,6 @@
 use crate::{
     Achievement, BotCaller, BotCommand, CanisterId, Chat, EventIndex, MessageContent, MessageId, MessageIndex, Reaction,
-    ThreadSummary, UserId,
+    ThreadSummary, TimestampMillis, UserId,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -24,6 +24,17 @@ pub struct Message {
     pub block_level_markdown: bool,
 }
 
+// A previous version of an edited message. `timestamp` is when this version was sent or last
+// edited, `replaced_at` is when it was superseded by the next edit.
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MessageVersion {
+    pub content: MessageContent,
+    pub block_level_markdown: bool,
+    pub timestamp: TimestampMillis,
+    pub replaced_at: TimestampMillis,
+}
+
 impl Message {
     pub fn achievements(&self, direct: bool, is_thread: bool) -> Vec<Achievement> {
         let mut achievements = Vec::new();
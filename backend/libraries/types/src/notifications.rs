@@ -46,6 +46,10 @@ pub enum Notification {
     GroupMessageTipped(GroupMessageTipped),
     #[serde(rename = "ct")]
     ChannelMessageTipped(ChannelMessageTipped),
+    #[serde(rename = "gj")]
+    GroupJoinRequested(GroupJoinRequestedNotification),
+    #[serde(rename = "cj")]
+    CommunityJoinRequested(CommunityJoinRequestedNotification),
 }
 
 #[ts_export]
@@ -320,6 +324,36 @@ pub struct ChannelMessageTipped {
     pub channel_avatar_id: Option<u128>,
 }
 
+#[ts_export]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GroupJoinRequestedNotification {
+    #[serde(rename = "c")]
+    pub chat_id: ChatId,
+    #[serde(rename = "g")]
+    pub group_name: String,
+    #[serde(rename = "r")]
+    pub requested_by: UserId,
+    #[serde(rename = "n")]
+    pub requested_by_name: String,
+    #[serde(rename = "av")]
+    pub group_avatar_id: Option<u128>,
+}
+
+#[ts_export]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommunityJoinRequestedNotification {
+    #[serde(rename = "ci")]
+    pub community_id: CommunityId,
+    #[serde(rename = "cn")]
+    pub community_name: String,
+    #[serde(rename = "r")]
+    pub requested_by: UserId,
+    #[serde(rename = "n")]
+    pub requested_by_name: String,
+    #[serde(rename = "ca")]
+    pub community_avatar_id: Option<u128>,
+}
+
 #[ts_export]
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CryptoTransferDetails {
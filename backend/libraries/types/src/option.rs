@@ -84,6 +84,7 @@ option_update!(OptionUpdateU128, u128);
 option_update!(OptionUpdateAccessGate, crate::AccessGate);
 option_update!(OptionUpdateAccessGateConfig, crate::AccessGateConfig);
 option_update!(OptionUpdateAirdropConfig, crate::AirdropConfig);
+option_update!(OptionUpdateCustomStatus, crate::CustomStatus);
 option_update!(OptionUpdateDocument, crate::Document);
 option_update!(OptionUpdateFrozenGroupInfo, crate::FrozenGroupInfo);
 option_update!(OptionUpdateGroupPermissionRole, crate::GroupPermissionRole);
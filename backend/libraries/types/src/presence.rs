@@ -0,0 +1,57 @@
+use crate::{Milliseconds, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PresenceStatus {
+    #[default]
+    Online,
+    Away,
+    DoNotDisturb,
+    // Appear offline to everyone else
+    Invisible,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EffectivePresence {
+    Online,
+    Away,
+    DoNotDisturb,
+    Offline,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CustomStatus {
+    pub text: String,
+    pub emoji: Option<String>,
+    pub expires_at: Option<TimestampMillis>,
+}
+
+impl CustomStatus {
+    pub fn is_expired(&self, now: TimestampMillis) -> bool {
+        self.expires_at.is_some_and(|ts| ts <= now)
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub enum PresenceVisibility {
+    #[default]
+    Everyone,
+    // Only the specified users (typically the user's contacts) can see their presence
+    Contacts(Vec<UserId>),
+    Nobody,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct UserPresence {
+    pub user_id: UserId,
+    pub presence: EffectivePresence,
+    pub custom_status: Option<CustomStatus>,
+    pub duration_since_last_online: Milliseconds,
+}
@@ -0,0 +1,92 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use ts_export::ts_export;
+
+pub const MAX_TAGS: usize = 5;
+pub const MAX_LANGUAGES: usize = 5;
+pub const MAX_LANGUAGE_CODE_LENGTH: usize = 3;
+
+// The curated set of tags which owners can assign to public groups and communities to make them easier to discover
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Tag {
+    Art,
+    Bitcoin,
+    Crypto,
+    DeFi,
+    Developers,
+    Education,
+    Entertainment,
+    Ethereum,
+    Finance,
+    Gaming,
+    Governance,
+    InternetComputer,
+    Memes,
+    Music,
+    News,
+    Nft,
+    Science,
+    Social,
+    Sport,
+    Technology,
+    Trading,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct TagCount {
+    pub tag: Tag,
+    pub count: u32,
+}
+
+impl Tag {
+    // Sorts the tags and removes any duplicates, returning None if there are more than `MAX_TAGS`
+    pub fn normalise(mut tags: Vec<Tag>) -> Option<Vec<Tag>> {
+        tags.sort();
+        tags.dedup();
+        (tags.len() <= MAX_TAGS).then_some(tags)
+    }
+
+    pub fn count<'a>(tags: impl Iterator<Item = &'a Tag>) -> Vec<TagCount> {
+        let mut counts: BTreeMap<Tag, u32> = BTreeMap::new();
+        for tag in tags {
+            *counts.entry(*tag).or_default() += 1;
+        }
+        counts.into_iter().map(|(tag, count)| TagCount { tag, count }).collect()
+    }
+}
+
+// Language codes are ISO 639 codes, so must be 2 or 3 letters long
+pub fn is_valid_language_code(language: &str) -> bool {
+    (2..=MAX_LANGUAGE_CODE_LENGTH).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+// Trims and lowercases each language code and removes any duplicates, returning None if any code is
+// invalid or if there are more than `MAX_LANGUAGES`
+pub fn normalise_languages(languages: Vec<String>) -> Option<Vec<String>> {
+    let mut normalised: Vec<String> = Vec::new();
+    for language in languages.into_iter().map(|l| l.trim().to_lowercase()) {
+        if !is_valid_language_code(&language) {
+            return None;
+        }
+        if !normalised.contains(&language) {
+            normalised.push(language);
+        }
+    }
+    (normalised.len() <= MAX_LANGUAGES).then_some(normalised)
+}
+
+// Normalises the languages used to filter search results, ignoring any invalid codes
+pub fn languages_filter(languages: Vec<String>) -> Vec<String> {
+    let mut filter: Vec<String> = languages
+        .into_iter()
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| is_valid_language_code(l))
+        .collect();
+    filter.sort();
+    filter.dedup();
+    filter.truncate(MAX_LANGUAGES);
+    filter
+}
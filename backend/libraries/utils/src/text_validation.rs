@@ -10,6 +10,13 @@ const MAX_GROUP_DESCRIPTION_LENGTH: u32 = 1024;
 const MAX_GROUP_RULES_LENGTH: u32 = 1024;
 const MIN_USER_GROUP_NAME_LENGTH: u32 = 3;
 const MAX_USER_GROUP_NAME_LENGTH: u32 = 25;
+const MIN_CHANNEL_CATEGORY_NAME_LENGTH: u32 = 1;
+const MAX_CHANNEL_CATEGORY_NAME_LENGTH: u32 = 30;
+const MIN_PERMISSION_TEMPLATE_NAME_LENGTH: u32 = 1;
+const MAX_PERMISSION_TEMPLATE_NAME_LENGTH: u32 = 30;
+const MIN_ACHIEVEMENT_NAME_LENGTH: u32 = 1;
+const MAX_ACHIEVEMENT_NAME_LENGTH: u32 = 40;
+const MAX_ACHIEVEMENT_DESCRIPTION_LENGTH: u32 = 256;
 
 const RESERVED_GROUP_NAMES: [&str; 8] = [
     "channel",
@@ -83,6 +90,22 @@ fn is_username_reserved(username: &str) -> bool {
     false
 }
 
+// Reduces a username to a form in which visually confusable usernames are equal, eg. "J0hn_Sm1th",
+// "johnsmith" and "JohnSmlth" all share the skeleton "johnsmlth"
+pub fn username_skeleton(username: &str) -> String {
+    let mapped: String = username
+        .chars()
+        .filter(|c| *c != '_')
+        .map(|c| match c.to_ascii_lowercase() {
+            '0' => 'o',
+            '1' | 'i' => 'l',
+            c => c,
+        })
+        .collect();
+
+    mapped.replace("rn", "m").replace("vv", "w")
+}
+
 pub fn validate_community_name(name: &str, is_public: bool) -> Result<(), NameValidationError> {
     validate_group_name(name, is_public, None)
 }
@@ -123,6 +146,25 @@ pub fn validate_user_group_name(name: &str) -> Result<(), UsernameValidationErro
     }
 }
 
+pub fn validate_channel_category_name(name: &str) -> Result<(), StringLengthValidationError> {
+    validate_string_length(name, MIN_CHANNEL_CATEGORY_NAME_LENGTH, MAX_CHANNEL_CATEGORY_NAME_LENGTH)
+}
+
+pub fn validate_permission_template_name(name: &str) -> Result<(), StringLengthValidationError> {
+    validate_string_length(name, MIN_PERMISSION_TEMPLATE_NAME_LENGTH, MAX_PERMISSION_TEMPLATE_NAME_LENGTH)
+}
+
+pub fn validate_achievement_name(name: &str) -> Result<(), StringLengthValidationError> {
+    validate_string_length(name, MIN_ACHIEVEMENT_NAME_LENGTH, MAX_ACHIEVEMENT_NAME_LENGTH)
+}
+
+pub fn validate_achievement_description(description: &str) -> Result<(), FieldTooLongResult> {
+    validate_string_length(description, 0, MAX_ACHIEVEMENT_DESCRIPTION_LENGTH).map_err(|e| match e {
+        StringLengthValidationError::TooLong(f) => f,
+        _ => unreachable!(),
+    })
+}
+
 pub fn validate_description(description: &str) -> Result<(), FieldTooLongResult> {
     validate_string_length(description, 0, MAX_GROUP_DESCRIPTION_LENGTH).map_err(|e| match e {
         StringLengthValidationError::TooLong(f) => f,
@@ -208,6 +250,15 @@ mod tests {
         assert!(matches!(validate_username("SNS2_B0T"), Err(UsernameValidationError::Invalid)));
     }
 
+    #[test]
+    fn confusable_usernames_share_skeleton() {
+        assert_eq!(username_skeleton("J0hn_Sm1th"), username_skeleton("johnsmith"));
+        assert_eq!(username_skeleton("JohnSmlth"), username_skeleton("JOHNSMITH"));
+        assert_eq!(username_skeleton("rnatt"), username_skeleton("Matt"));
+        assert_eq!(username_skeleton("vvilliam"), username_skeleton("William"));
+        assert_ne!(username_skeleton("johnsmith"), username_skeleton("janesmith"));
+    }
+
     #[test]
     fn valid_display_names() {
         assert!(validate_display_name("John* $Smith--(*)").is_ok());
@@ -1,5 +1,6 @@
 use candid::Principal;
-use index_store::DummyStore;
+use file_index_store::FileIndexStore;
+use index_store::{DummyStore, IndexStore};
 use notification_pusher_core::ic_agent::IcAgent;
 use notification_pusher_core::{run_notifications_pusher, write_metrics};
 use std::collections::HashMap;
@@ -20,7 +21,7 @@ async fn main() -> Result<(), Error> {
     let vapid_private_pem = dotenv::var("VAPID_PRIVATE_PEM")?;
     let index_canister_id = Principal::from_text(dotenv::var("NOTIFICATIONS_INDEX_CANISTER_ID")?)?;
     let notifications_canister_id = Principal::from_text(dotenv::var("NOTIFICATIONS_CANISTER_ID")?)?;
-    let index_store = DummyStore::new(HashMap::from([(notifications_canister_id, index)]));
+    let index_store_path = dotenv::var("INDEX_STORE_PATH").ok();
     let ic_url = dotenv::var("IC_URL")?;
     let ic_identity_pem = dotenv::var("IC_IDENTITY_PEM")?;
     let is_production = bool::from_str(&dotenv::var("IS_PRODUCTION")?).unwrap();
@@ -35,15 +36,18 @@ async fn main() -> Result<(), Error> {
 
     tokio::spawn(write_metrics_to_file());
 
-    run_notifications_pusher(
-        ic_agent,
-        index_canister_id,
-        vec![notifications_canister_id],
-        index_store,
-        vapid_private_pem,
-        pusher_count,
-    )
-    .await;
+    // If a path is provided, indexes are persisted to file so that processing resumes from where
+    // it left off after a restart, otherwise they are only held in memory
+    if let Some(path) = index_store_path {
+        let index_store = FileIndexStore::load(path.into()).await?;
+        if args.get(1).is_some() {
+            index_store.set(notifications_canister_id, index).await?;
+        }
+        run_notifications_pusher(ic_agent, index_canister_id, index_store, vapid_private_pem, pusher_count).await;
+    } else {
+        let index_store = DummyStore::new(HashMap::from([(notifications_canister_id, index)]));
+        run_notifications_pusher(ic_agent, index_canister_id, index_store, vapid_private_pem, pusher_count).await;
+    }
 
     Ok(())
 }
@@ -1,7 +1,7 @@
 use ic_agent::identity::{BasicIdentity, Secp256k1Identity};
 use ic_agent::{Agent, Identity};
 use notifications_canister::{latest_notification_index, notifications, remove_notifications};
-use notifications_index_canister::remove_subscriptions;
+use notifications_index_canister::{notifications_canisters, remove_subscriptions};
 use std::collections::HashMap;
 use tracing::trace;
 use types::{CanisterId, Error, UserId};
@@ -70,6 +70,17 @@ impl IcAgent {
         Ok(())
     }
 
+    pub async fn notifications_canisters(&self, index_canister_id: &CanisterId) -> Result<Vec<CanisterId>, Error> {
+        let args = notifications_canisters::Args {};
+
+        let notifications_canisters::Response::Success(canister_ids) =
+            notifications_index_canister_client::notifications_canisters(&self.agent, index_canister_id, &args).await?;
+
+        trace!(?canister_ids, "notifications_canisters::result");
+
+        Ok(canister_ids)
+    }
+
     pub async fn remove_subscriptions(
         &self,
         index_canister_id: &CanisterId,
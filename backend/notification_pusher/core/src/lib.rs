@@ -2,7 +2,7 @@ use crate::ic_agent::IcAgent;
 use crate::metrics::{collect_metrics, Metrics};
 use crate::processor::Processor;
 use crate::pusher::Pusher;
-use crate::reader::Reader;
+use crate::reader_manager::ReaderManager;
 use crate::subscription_remover::SubscriptionRemover;
 use index_store::IndexStore;
 use prometheus::{Encoder, TextEncoder};
@@ -18,12 +18,12 @@ mod metrics;
 mod processor;
 mod pusher;
 mod reader;
+mod reader_manager;
 mod subscription_remover;
 
 pub async fn run_notifications_pusher<I: IndexStore + 'static>(
     ic_agent: IcAgent,
     index_canister_id: CanisterId,
-    notifications_canister_ids: Vec<CanisterId>,
     index_store: I,
     vapid_private_pem: String,
     pusher_count: u32,
@@ -40,15 +40,8 @@ pub async fn run_notifications_pusher<I: IndexStore + 'static>(
         subscriptions_to_remove_sender.clone(),
     );
 
-    for notification_canister_id in notifications_canister_ids {
-        let reader = Reader::new(
-            ic_agent.clone(),
-            notification_canister_id,
-            index_store.clone(),
-            to_process_sender.clone(),
-        );
-        tokio::spawn(reader.run());
-    }
+    let reader_manager = ReaderManager::new(ic_agent.clone(), index_canister_id, index_store, to_process_sender.clone());
+    tokio::spawn(reader_manager.run());
 
     let invalid_subscriptions = Arc::new(RwLock::default());
     let throttled_subscriptions = Arc::new(RwLock::default());
@@ -1,7 +1,7 @@
 use crate::{Notification, NotificationToPush};
 use async_channel::Sender;
 use prometheus::proto::MetricFamily;
-use prometheus::{Histogram, HistogramOpts, HistogramVec, IntGaugeVec, Opts, PullingGauge, Registry};
+use prometheus::{Histogram, HistogramOpts, HistogramVec, IntGauge, IntGaugeVec, Opts, PullingGauge, Registry};
 use std::sync::OnceLock;
 use types::{CanisterId, Milliseconds, UserId};
 
@@ -11,6 +11,7 @@ const BASE_BUCKETS: [f64; 13] = [
 
 pub struct Metrics {
     registry: Registry,
+    notifications_canisters: IntGauge,
     latest_notification_index_read: IntGaugeVec,
     latest_notification_index_processed: IntGaugeVec,
     latest_notification_index_pushed: IntGaugeVec,
@@ -74,6 +75,9 @@ impl Metrics {
         registry.register(Box::new(notifications_to_push_queue.clone())).unwrap();
         registry.register(Box::new(subscriptions_to_remove_queue.clone())).unwrap();
 
+        let notifications_canisters =
+            IntGauge::new("notifications_canisters", "Number of notifications canisters being read from").unwrap();
+
         let latest_notification_index_read = IntGaugeVec::new(
             Opts::new("latest_notification_index_read", "Per notifications canister"),
             &["canisterId"],
@@ -122,6 +126,7 @@ impl Metrics {
         )
         .unwrap();
 
+        registry.register(Box::new(notifications_canisters.clone())).unwrap();
         registry.register(Box::new(latest_notification_index_read.clone())).unwrap();
         registry
             .register(Box::new(latest_notification_index_processed.clone()))
@@ -137,6 +142,7 @@ impl Metrics {
 
         Metrics {
             registry,
+            notifications_canisters,
             latest_notification_index_read,
             latest_notification_index_processed,
             latest_notification_index_pushed,
@@ -152,6 +158,10 @@ impl Metrics {
         self.registry.gather()
     }
 
+    pub fn set_notifications_canisters(&self, count: usize) {
+        self.notifications_canisters.set(count as i64);
+    }
+
     pub fn set_latest_notification_index_read(&self, index: u64, canister_id: CanisterId) {
         self.latest_notification_index_read
             .with_label_values(&[&canister_id.to_string()])
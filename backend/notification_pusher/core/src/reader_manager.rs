@@ -0,0 +1,192 @@
+use crate::ic_agent::IcAgent;
+use crate::metrics::write_metrics;
+use crate::reader::Reader;
+use crate::Notification;
+use async_channel::Sender;
+use index_store::IndexStore;
+use std::collections::{HashMap, HashSet};
+use tokio::task::JoinHandle;
+use tokio::time;
+use tracing::{error, info, warn};
+use types::{CanisterId, Error};
+
+const REFRESH_INTERVAL_SECS: u64 = 60;
+
+// Periodically fetches the set of notifications canisters from the NotificationsIndex and ensures
+// there is exactly one reader running per canister. Each reader tracks its own progress in the
+// `IndexStore` (keyed by canister Id), so readers can be started and stopped independently.
+pub struct ReaderManager<I: IndexStore> {
+    ic_agent: IcAgent,
+    index_canister_id: CanisterId,
+    index_store: I,
+    sender: Sender<Notification>,
+    readers: HashMap<CanisterId, JoinHandle<()>>,
+}
+
+impl<I: IndexStore + 'static> ReaderManager<I> {
+    pub fn new(ic_agent: IcAgent, index_canister_id: CanisterId, index_store: I, sender: Sender<Notification>) -> Self {
+        Self {
+            ic_agent,
+            index_canister_id,
+            index_store,
+            sender,
+            readers: HashMap::new(),
+        }
+    }
+
+    pub async fn run(mut self) {
+        info!("Notifications reader manager started");
+
+        let mut interval = time::interval(time::Duration::from_secs(REFRESH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = self.refresh_readers().await {
+                error!(?error, "Failed to refresh notifications readers");
+            }
+        }
+    }
+
+    async fn refresh_readers(&mut self) -> Result<(), Error> {
+        let canister_ids: HashSet<CanisterId> = self
+            .ic_agent
+            .notifications_canisters(&self.index_canister_id)
+            .await?
+            .into_iter()
+            .collect();
+
+        let running: HashMap<CanisterId, bool> = self
+            .readers
+            .iter()
+            .map(|(canister_id, reader)| (*canister_id, reader.is_finished()))
+            .collect();
+
+        let changes = calculate_changes(&running, &canister_ids);
+
+        for canister_id in changes.to_stop {
+            if let Some(reader) = self.readers.remove(&canister_id) {
+                reader.abort();
+                info!(%canister_id, "Notifications reader stopped");
+            }
+        }
+
+        for canister_id in changes.to_restart.iter() {
+            self.readers.remove(canister_id);
+            warn!(%canister_id, "Notifications reader exited unexpectedly, restarting");
+        }
+
+        for canister_id in changes.to_restart.into_iter().chain(changes.to_start) {
+            let reader = Reader::new(
+                self.ic_agent.clone(),
+                canister_id,
+                self.index_store.clone(),
+                self.sender.clone(),
+            );
+            self.readers.insert(canister_id, tokio::spawn(reader.run()));
+        }
+
+        let readers_count = self.readers.len();
+        write_metrics(|m| m.set_notifications_canisters(readers_count));
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ReaderChanges {
+    // Readers for canisters which are no longer returned by the NotificationsIndex
+    to_stop: Vec<CanisterId>,
+    // Readers which have exited unexpectedly
+    to_restart: Vec<CanisterId>,
+    // Canisters which don't yet have a reader
+    to_start: Vec<CanisterId>,
+}
+
+// `running` maps each canister which has a reader to whether that reader has finished
+fn calculate_changes(running: &HashMap<CanisterId, bool>, canister_ids: &HashSet<CanisterId>) -> ReaderChanges {
+    let mut changes = ReaderChanges::default();
+
+    for (canister_id, finished) in running {
+        if !canister_ids.contains(canister_id) {
+            changes.to_stop.push(*canister_id);
+        } else if *finished {
+            changes.to_restart.push(*canister_id);
+        }
+    }
+
+    changes.to_start = canister_ids
+        .iter()
+        .filter(|canister_id| !running.contains_key(canister_id))
+        .copied()
+        .collect();
+
+    changes.to_stop.sort();
+    changes.to_restart.sort();
+    changes.to_start.sort();
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canister_id(id: u8) -> CanisterId {
+        CanisterId::from_slice(&[id])
+    }
+
+    #[test]
+    fn readers_started_for_new_canisters() {
+        let running = HashMap::from([(canister_id(1), false)]);
+        let canister_ids = HashSet::from([canister_id(1), canister_id(2), canister_id(3)]);
+
+        let changes = calculate_changes(&running, &canister_ids);
+
+        assert_eq!(
+            changes,
+            ReaderChanges {
+                to_start: vec![canister_id(2), canister_id(3)],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn readers_stopped_for_removed_canisters() {
+        let running = HashMap::from([(canister_id(1), false), (canister_id(2), false), (canister_id(3), true)]);
+        let canister_ids = HashSet::from([canister_id(1)]);
+
+        let changes = calculate_changes(&running, &canister_ids);
+
+        assert_eq!(
+            changes,
+            ReaderChanges {
+                to_stop: vec![canister_id(2), canister_id(3)],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn finished_readers_restarted() {
+        let running = HashMap::from([(canister_id(1), true), (canister_id(2), false)]);
+        let canister_ids = HashSet::from([canister_id(1), canister_id(2)]);
+
+        let changes = calculate_changes(&running, &canister_ids);
+
+        assert_eq!(
+            changes,
+            ReaderChanges {
+                to_restart: vec![canister_id(1)],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn no_changes_when_all_readers_running() {
+        let running = HashMap::from([(canister_id(1), false), (canister_id(2), false)]);
+        let canister_ids = HashSet::from([canister_id(1), canister_id(2)]);
+
+        assert_eq!(calculate_changes(&running, &canister_ids), ReaderChanges::default());
+    }
+}
@@ -43,6 +43,7 @@ cargo build --locked --target wasm32-unknown-unknown --release \
   --package registry_canister_impl \
   --package storage_bucket_canister_impl \
   --package storage_index_canister_impl \
+  --package test_icrc7_ledger_canister_impl \
   --package translations_canister_impl \
   --package user_canister_impl \
   --package user_index_canister_impl || exit 1
@@ -75,6 +76,7 @@ ic-wasm ./target/wasm32-unknown-unknown/release/proposals_bot_canister_impl.wasm
 ic-wasm ./target/wasm32-unknown-unknown/release/registry_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/registry_canister_impl-opt.wasm shrink
 ic-wasm ./target/wasm32-unknown-unknown/release/storage_bucket_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/storage_bucket_canister_impl-opt.wasm shrink
 ic-wasm ./target/wasm32-unknown-unknown/release/storage_index_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/storage_index_canister_impl-opt.wasm shrink
+ic-wasm ./target/wasm32-unknown-unknown/release/test_icrc7_ledger_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/test_icrc7_ledger_canister_impl-opt.wasm shrink
 ic-wasm ./target/wasm32-unknown-unknown/release/translations_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/translations_canister_impl-opt.wasm shrink
 ic-wasm ./target/wasm32-unknown-unknown/release/user_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/user_canister_impl-opt.wasm shrink
 ic-wasm ./target/wasm32-unknown-unknown/release/user_index_canister_impl.wasm -o ./target/wasm32-unknown-unknown/release/user_index_canister_impl-opt.wasm shrink
@@ -102,6 +104,7 @@ gzip -fckn9 target/wasm32-unknown-unknown/release/proposals_bot_canister_impl-op
 gzip -fckn9 target/wasm32-unknown-unknown/release/registry_canister_impl-opt.wasm > ./wasms/registry.wasm.gz
 gzip -fckn9 target/wasm32-unknown-unknown/release/storage_bucket_canister_impl-opt.wasm > ./wasms/storage_bucket.wasm.gz
 gzip -fckn9 target/wasm32-unknown-unknown/release/storage_index_canister_impl-opt.wasm > ./wasms/storage_index.wasm.gz
+gzip -fckn9 target/wasm32-unknown-unknown/release/test_icrc7_ledger_canister_impl-opt.wasm > ./wasms/test_icrc7_ledger.wasm.gz
 gzip -fckn9 target/wasm32-unknown-unknown/release/translations_canister_impl-opt.wasm > ./wasms/translations.wasm.gz
 gzip -fckn9 target/wasm32-unknown-unknown/release/user_canister_impl-opt.wasm > ./wasms/user.wasm.gz
 gzip -fckn9 target/wasm32-unknown-unknown/release/user_index_canister_impl-opt.wasm > ./wasms/user_index.wasm.gz
@@ -43,4 +43,10 @@ cd ../..
 ./scripts/download-canister-wasm-dfx.sh event_store || exit 1
 ./scripts/download-canister-wasm-dfx.sh sign_in_with_email || exit 1
 
+# The stand-in ICRC-7 ledger is only used by the tests so is never downloaded
+if [[ $WASM_SRC != "build" ]]
+then
+    ./scripts/generate-wasm.sh test_icrc7_ledger || exit 1
+fi
+
 cargo test --package integration_tests $TESTNAME -- --test-threads $TEST_THREADS